@@ -37,6 +37,19 @@ pub enum ParticleType {
     Neutron = 5,
 }
 
+/// Flag bit (`color_and_flags.y`): slot is reserved and inactive until string breaking
+/// spawns a quark/antiquark pair into it
+pub const PARTICLE_FLAG_DORMANT: u32 = 1;
+
+/// Flag bit (`color_and_flags.y`): particle has been absorbed into a coarse-grained
+/// super-particle. Always set together with `PARTICLE_FLAG_DORMANT` so other passes ignore it.
+pub const PARTICLE_FLAG_ABSORBED: u32 = 2;
+
+/// Flag bit (`color_and_flags.y`): particle is a coarse-grained super-particle standing in for
+/// its absorbed members. Hadron detection, decays and the pair pool leave it alone, so its
+/// members' mass and charge come back intact on release.
+pub const PARTICLE_FLAG_SUPER: u32 = 4;
+
 /// GPU-compatible particle structure
 /// Using vec4 for ALL fields to ensure perfect alignment with WGSL (16-byte aligned)
 #[repr(C)]
@@ -48,10 +61,11 @@ pub struct Particle {
     /// Velocity (xyz) and mass (w component)
     pub velocity: [f32; 4],
 
-    /// Data: x = charge, y = size, z/w = unused padding
+    /// Data: x = charge, y = size, z = cohort tag (0 = untagged),
+    /// w = vacuum pair partner index + 1 (0 = real particle)
     pub data: [f32; 4],
 
-    /// Color and flags: x = color_charge, y = flags, z/w = unused padding
+    /// Color and flags: x = color_charge, y = flags, z = hadron_id, w = electron energy level (0 = unbound)
     pub color_and_flags: [u32; 4],
 }
 
@@ -105,6 +119,31 @@ impl Particle {
         }
     }
 
+    /// Create a dormant pool slot (invisible, ignored by all passes until spawned into)
+    pub fn new_dormant() -> Self {
+        Self {
+            position: [0.0, 0.0, 0.0, ParticleType::QuarkUp as u32 as f32],
+            velocity: [0.0, 0.0, 0.0, crate::constants::QUARK_UP_MASS],
+            data: [0.0, 0.0, 0.0, 0.0], // no charge, zero size (not rendered)
+            color_and_flags: [0, PARTICLE_FLAG_DORMANT, 0, 0],
+        }
+    }
+
+    /// Whether this slot is a dormant pool slot
+    pub fn is_dormant(&self) -> bool {
+        self.color_and_flags[1] & PARTICLE_FLAG_DORMANT != 0
+    }
+
+    /// Cohort tag (stored in data.z, 0 = untagged)
+    pub fn tag(&self) -> u32 {
+        self.data[2] as u32
+    }
+
+    /// Set the cohort tag (stored in data.z, 0 = untagged)
+    pub fn set_tag(&mut self, tag: u32) {
+        self.data[2] = tag as f32;
+    }
+
     /// Get particle type (stored in position.w)
     pub fn get_type(&self) -> Option<ParticleType> {
         match self.position[3] as u32 {
@@ -0,0 +1,461 @@
+//! HDR scene target + bloom post-process
+//!
+//! The 3D scene renders into an `HDR_FORMAT` target instead of the surface, so bright things
+//! (photon flashes, energetic particles) can exceed 1.0. Bloom thresholds that target, blurs it
+//! through a chain of half-size mips, and the composite pass adds it back and tone maps onto the
+//! surface.
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+/// Format of the HDR scene target; every scene pipeline must render to it
+pub const HDR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// Maximum number of bloom mips (the first is half the surface size)
+const BLOOM_MIPS: u32 = 5;
+
+/// User-facing bloom controls
+#[derive(Clone, Copy, Debug)]
+pub struct BloomSettings {
+    pub enabled: bool,
+    /// Brightness (max channel, linear) where bloom starts
+    pub threshold: f32,
+    /// Bloom contribution in the composite
+    pub intensity: f32,
+}
+
+impl Default for BloomSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: 1.0,
+            intensity: 0.6,
+        }
+    }
+}
+
+/// Bloom parameters (uniform, matches WGSL `BloomParams`)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+struct BloomParams {
+    threshold: f32,
+    knee: f32,
+    intensity: f32,
+    enabled: f32,
+}
+
+impl From<BloomSettings> for BloomParams {
+    fn from(settings: BloomSettings) -> Self {
+        Self {
+            threshold: settings.threshold,
+            knee: settings.threshold * 0.5,
+            intensity: settings.intensity,
+            enabled: if settings.enabled { 1.0 } else { 0.0 },
+        }
+    }
+}
+
+/// Size-dependent resources, rebuilt on resize
+struct BloomTargets {
+    hdr_view: wgpu::TextureView,
+    mip_views: Vec<wgpu::TextureView>,
+    /// `[0]` reads the scene, `[i]` reads mip `i - 1`
+    down_bind_groups: Vec<wgpu::BindGroup>,
+    /// `[i]` reads mip `i + 1` (drawn into mip `i`)
+    up_bind_groups: Vec<wgpu::BindGroup>,
+    composite_bind_group: wgpu::BindGroup,
+}
+
+pub struct BloomRenderer {
+    sample_layout: wgpu::BindGroupLayout,
+    composite_layout: wgpu::BindGroupLayout,
+    prefilter_pipeline: wgpu::RenderPipeline,
+    downsample_pipeline: wgpu::RenderPipeline,
+    upsample_pipeline: wgpu::RenderPipeline,
+    composite_pipeline: wgpu::RenderPipeline,
+    sampler: wgpu::Sampler,
+    params_buffer: wgpu::Buffer,
+    enabled: bool,
+    targets: BloomTargets,
+}
+
+impl BloomRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        surface_config: &wgpu::SurfaceConfiguration,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Bloom Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/bloom.wgsl").into()),
+        });
+
+        let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let sampler_entry = wgpu::BindGroupLayoutEntry {
+            binding: 1,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+            count: None,
+        };
+        let params_entry = wgpu::BindGroupLayoutEntry {
+            binding: 2,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+
+        let sample_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Bloom Sample Bind Group Layout"),
+            entries: &[texture_entry(0), sampler_entry, params_entry],
+        });
+        let composite_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Bloom Composite Bind Group Layout"),
+            entries: &[
+                texture_entry(0),
+                sampler_entry,
+                params_entry,
+                texture_entry(3),
+            ],
+        });
+
+        let create_pipeline = |label,
+                               layout: &wgpu::BindGroupLayout,
+                               entry_point,
+                               format,
+                               blend: Option<wgpu::BlendState>| {
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some(label),
+                bind_group_layouts: &[layout],
+                immediate_size: 0,
+            });
+
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_fullscreen"),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview_mask: None,
+                cache,
+            })
+        };
+
+        // Upsampling adds onto the mip that was downsampled into it
+        let additive = wgpu::BlendState {
+            color: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha: wgpu::BlendComponent::REPLACE,
+        };
+
+        let prefilter_pipeline = create_pipeline(
+            "Bloom Prefilter Pipeline",
+            &sample_layout,
+            "fs_prefilter",
+            HDR_FORMAT,
+            None,
+        );
+        let downsample_pipeline = create_pipeline(
+            "Bloom Downsample Pipeline",
+            &sample_layout,
+            "fs_downsample",
+            HDR_FORMAT,
+            None,
+        );
+        let upsample_pipeline = create_pipeline(
+            "Bloom Upsample Pipeline",
+            &sample_layout,
+            "fs_upsample",
+            HDR_FORMAT,
+            Some(additive),
+        );
+        let composite_pipeline = create_pipeline(
+            "Bloom Composite Pipeline",
+            &composite_layout,
+            "fs_composite",
+            surface_config.format,
+            None,
+        );
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Bloom Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let settings = BloomSettings::default();
+        let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Bloom Params Buffer"),
+            contents: bytemuck::cast_slice(&[BloomParams::from(settings)]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let targets = Self::create_targets(
+            device,
+            surface_config,
+            &sample_layout,
+            &composite_layout,
+            &sampler,
+            &params_buffer,
+        );
+
+        Self {
+            sample_layout,
+            composite_layout,
+            prefilter_pipeline,
+            downsample_pipeline,
+            upsample_pipeline,
+            composite_pipeline,
+            sampler,
+            params_buffer,
+            enabled: settings.enabled,
+            targets,
+        }
+    }
+
+    fn create_targets(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        sample_layout: &wgpu::BindGroupLayout,
+        composite_layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        params_buffer: &wgpu::Buffer,
+    ) -> BloomTargets {
+        let hdr_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("HDR Scene Texture"),
+            size: wgpu::Extent3d {
+                width: config.width,
+                height: config.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let hdr_view = hdr_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Half-size mip chain; stop before a side would drop below one texel
+        let width = (config.width / 2).max(1);
+        let height = (config.height / 2).max(1);
+        let mip_count = BLOOM_MIPS.min(32 - width.min(height).leading_zeros());
+        let bloom_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Bloom Texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: mip_count,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let mip_views: Vec<_> = (0..mip_count)
+            .map(|mip| {
+                bloom_texture.create_view(&wgpu::TextureViewDescriptor {
+                    label: Some("Bloom Mip View"),
+                    base_mip_level: mip,
+                    mip_level_count: Some(1),
+                    ..Default::default()
+                })
+            })
+            .collect();
+
+        let sample_bind_group = |view: &wgpu::TextureView| {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Bloom Sample Bind Group"),
+                layout: sample_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(sampler),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: params_buffer.as_entire_binding(),
+                    },
+                ],
+            })
+        };
+
+        let down_bind_groups = std::iter::once(&hdr_view)
+            .chain(&mip_views[..mip_views.len() - 1])
+            .map(sample_bind_group)
+            .collect();
+        let up_bind_groups = mip_views[1..].iter().map(sample_bind_group).collect();
+
+        let composite_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bloom Composite Bind Group"),
+            layout: composite_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&hdr_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::TextureView(&mip_views[0]),
+                },
+            ],
+        });
+
+        BloomTargets {
+            hdr_view,
+            mip_views,
+            down_bind_groups,
+            up_bind_groups,
+            composite_bind_group,
+        }
+    }
+
+    pub fn resize(&mut self, device: &wgpu::Device, new_config: &wgpu::SurfaceConfiguration) {
+        self.targets = Self::create_targets(
+            device,
+            new_config,
+            &self.sample_layout,
+            &self.composite_layout,
+            &self.sampler,
+            &self.params_buffer,
+        );
+    }
+
+    /// The HDR target the scene renders into (same size as the surface).
+    pub fn hdr_view(&self) -> &wgpu::TextureView {
+        &self.targets.hdr_view
+    }
+
+    pub fn set_settings(&mut self, queue: &wgpu::Queue, settings: BloomSettings) {
+        self.enabled = settings.enabled;
+        queue.write_buffer(
+            &self.params_buffer,
+            0,
+            bytemuck::cast_slice(&[BloomParams::from(settings)]),
+        );
+    }
+
+    /// Run bloom over the HDR target and composite the result onto `surface_view`.
+    pub fn render(&self, encoder: &mut wgpu::CommandEncoder, surface_view: &wgpu::TextureView) {
+        if self.enabled {
+            let mip_views = &self.targets.mip_views;
+
+            // Threshold + downsample: scene -> mip 0 -> mip 1 -> ...
+            for (i, bind_group) in self.targets.down_bind_groups.iter().enumerate() {
+                let pipeline = if i == 0 {
+                    &self.prefilter_pipeline
+                } else {
+                    &self.downsample_pipeline
+                };
+                Self::fullscreen_pass(
+                    encoder,
+                    "Bloom Downsample Pass",
+                    &mip_views[i],
+                    wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    pipeline,
+                    bind_group,
+                );
+            }
+
+            // Upsample + accumulate: ... -> mip 1 -> mip 0
+            for (i, bind_group) in self.targets.up_bind_groups.iter().enumerate().rev() {
+                Self::fullscreen_pass(
+                    encoder,
+                    "Bloom Upsample Pass",
+                    &mip_views[i],
+                    wgpu::LoadOp::Load,
+                    &self.upsample_pipeline,
+                    bind_group,
+                );
+            }
+        }
+
+        Self::fullscreen_pass(
+            encoder,
+            "Bloom Composite Pass",
+            surface_view,
+            wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+            &self.composite_pipeline,
+            &self.targets.composite_bind_group,
+        );
+    }
+
+    fn fullscreen_pass(
+        encoder: &mut wgpu::CommandEncoder,
+        label: &str,
+        view: &wgpu::TextureView,
+        load: wgpu::LoadOp<wgpu::Color>,
+        pipeline: &wgpu::RenderPipeline,
+        bind_group: &wgpu::BindGroup,
+    ) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load,
+                    store: wgpu::StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        });
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
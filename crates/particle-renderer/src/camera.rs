@@ -1,7 +1,9 @@
 //! Camera system for 3D visualization
 
 use bytemuck::{Pod, Zeroable};
-use glam::{Mat4, Quat, Vec3};
+use glam::{Mat4, Quat, Vec2, Vec3, Vec4};
+
+use crate::lod::LodSettings;
 
 /// Camera uniform for GPU
 #[repr(C)]
@@ -22,8 +24,42 @@ pub struct CameraUniform {
     pub lod_nucleus_fade_start: f32,
     pub lod_nucleus_fade_end: f32,
 
-    // Pad so the uniform binding size is safely >= WGSL's rounded-up struct size.
-    pub _pad: [f32; 5],
+    /// HDR brightness boost per unit kinetic energy (0 = off). Only the particle shader reads
+    /// it; the other shaders leave this slot as implicit padding.
+    pub glow_scale: f32,
+    /// Pick ID under the cursor (0 = nothing). Only the particle shader reads it; the other
+    /// shaders declare this tail as padding.
+    pub hovered_id: u32,
+    pub _pad1: [u32; 3],
+    /// Rest frame the view is drawn in: xyz = its velocity (zero = lab frame), w = simulation
+    /// time between trail samples. Read by the particle, vector and trail shaders.
+    pub rest_frame: [f32; 4],
+    /// Particle impostors: x = near shapes, y = far shapes, 4 bits per species (see
+    /// [`LodSettings::impostors`]). Only the particle shader reads this and `impostor_fade`.
+    pub impostor_shapes: [u32; 4],
+    /// x..y = camera distance over which particles crossfade from near to far impostor
+    pub impostor_fade: [f32; 4],
+}
+
+/// Seconds a perspective <-> orthographic switch takes
+const PROJECTION_TRANSITION_SECONDS: f32 = 0.4;
+
+/// How the camera responds to dragging and movement input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CameraMode {
+    /// Rotate around `target` at `distance`
+    #[default]
+    Orbit,
+    /// Mouse-look around the eye and fly with `Camera::fly`
+    FreeFly,
+}
+
+/// View saved for a mode while the other one is active
+#[derive(Clone, Copy)]
+struct ModeView {
+    target: Vec3,
+    rotation: Quat,
+    distance: f32,
 }
 
 /// Camera for 3D scene navigation
@@ -35,6 +71,16 @@ pub struct Camera {
     pub fovy: f32,
     pub znear: f32,
     pub zfar: f32,
+    /// Target projection; `update_projection` eases `projection_blend` towards it
+    pub orthographic: bool,
+    /// 0 = perspective, 1 = orthographic (linear progress, eased when building the matrix)
+    pub projection_blend: f32,
+    /// Switch with `set_mode` (keeps each mode's view)
+    mode: CameraMode,
+    /// Free-fly speed in world units per second
+    pub fly_speed: f32,
+    /// Last view of each mode, indexed by `CameraMode as usize`
+    mode_views: [Option<ModeView>; 2],
 }
 
 impl Camera {
@@ -49,6 +95,11 @@ impl Camera {
             fovy: 45.0_f32.to_radians(),
             znear: 0.1,
             zfar: 100000.0,
+            orthographic: false,
+            projection_blend: 0.0,
+            mode: CameraMode::Orbit,
+            fly_speed: 40.0,
+            mode_views: [None; 2],
         }
     }
 
@@ -57,7 +108,36 @@ impl Camera {
         self.target + offset
     }
 
+    pub fn mode(&self) -> CameraMode {
+        self.mode
+    }
+
+    /// Switch control modes. The view of the mode being left is kept and restored when
+    /// switching back; a mode used for the first time starts from the current view.
+    pub fn set_mode(&mut self, mode: CameraMode) {
+        if mode == self.mode {
+            return;
+        }
+        self.mode_views[self.mode as usize] = Some(ModeView {
+            target: self.target,
+            rotation: self.rotation,
+            distance: self.distance,
+        });
+        if let Some(view) = self.mode_views[mode as usize] {
+            self.target = view.target;
+            self.rotation = view.rotation;
+            self.distance = view.distance;
+        }
+        self.mode = mode;
+    }
+
+    /// Drag rotation: orbits the target, or turns the view around the eye in free-fly mode.
     pub fn rotate(&mut self, delta_x: f32, delta_y: f32) {
+        if self.mode == CameraMode::FreeFly {
+            self.look(delta_x, delta_y);
+            return;
+        }
+
         let up = self.rotation * Vec3::Y;
         let yaw_rotation = Quat::from_axis_angle(up, delta_x);
 
@@ -68,50 +148,149 @@ impl Camera {
         self.rotation = self.rotation.normalize();
     }
 
+    /// Mouse-look: yaw around the world up axis (no roll) and pitch around the view's right
+    /// axis, keeping the eye in place (the target moves with the view at `distance`).
+    fn look(&mut self, delta_x: f32, delta_y: f32) {
+        let eye = self.position();
+        let yaw_rotation = Quat::from_rotation_y(delta_x);
+        let pitch_rotation = Quat::from_axis_angle(self.rotation * Vec3::X, -delta_y);
+        self.rotation = (yaw_rotation * pitch_rotation * self.rotation).normalize();
+        self.target = eye - self.rotation * Vec3::new(0.0, 0.0, self.distance);
+    }
+
+    /// Move the eye (and target) along a view-space direction (-z forward, +x right, +y up)
+    /// at `fly_speed` for `dt` seconds.
+    pub fn fly(&mut self, direction: Vec3, dt: f32) {
+        self.target += self.rotation * direction.normalize_or_zero() * self.fly_speed * dt;
+    }
+
+    /// Scale the free-fly speed by `1.15^steps` (scroll wheel).
+    pub fn scale_fly_speed(&mut self, steps: f32) {
+        self.fly_speed = (self.fly_speed * 1.15_f32.powf(steps)).clamp(0.1, 10000.0);
+    }
+
+    /// Distance at which a sphere of `radius` around the target spans `fill` of the narrower
+    /// field of view (1.0 = touches the edges).
+    pub fn framing_distance(&self, radius: f32, fill: f32) -> f32 {
+        let fovx = 2.0 * ((self.fovy * 0.5).tan() * self.aspect).atan();
+        let half_fov = 0.5 * self.fovy.min(fovx);
+        radius / (half_fov.sin() * fill)
+    }
+
     pub fn zoom(&mut self, delta: f32) {
         self.distance = (self.distance + delta).clamp(1.0, 50000.0);
     }
 
+    /// Advance the perspective <-> orthographic transition by `dt` seconds.
+    pub fn update_projection(&mut self, dt: f32) {
+        let step = dt / PROJECTION_TRANSITION_SECONDS;
+        self.projection_blend = if self.orthographic {
+            (self.projection_blend + step).min(1.0)
+        } else {
+            (self.projection_blend - step).max(0.0)
+        };
+    }
+
+    /// Fully perspective (not orthographic and not transitioning)
+    pub fn is_perspective(&self) -> bool {
+        !self.orthographic && self.projection_blend <= 0.0
+    }
+
+    /// Perspective, orthographic, or a blend of both mid-transition. The orthographic view
+    /// spans what the perspective one shows at the target distance, so the target plane stays
+    /// put while the projection changes (and zooming still works by changing `distance`).
+    pub fn build_projection_matrix(&self) -> Mat4 {
+        let perspective = Mat4::perspective_rh(self.fovy, self.aspect, self.znear, self.zfar);
+        if self.projection_blend <= 0.0 {
+            return perspective;
+        }
+
+        let half_height = self.distance * (self.fovy * 0.5).tan();
+        let half_width = half_height * self.aspect;
+        let orthographic = Mat4::orthographic_rh(
+            -half_width,
+            half_width,
+            -half_height,
+            half_height,
+            self.znear,
+            self.zfar,
+        );
+
+        // Smoothstep easing; blending the matrices keeps the target plane fixed throughout
+        let t = self.projection_blend.clamp(0.0, 1.0);
+        let t = t * t * (3.0 - 2.0 * t);
+        perspective * (1.0 - t) + orthographic * t
+    }
+
     pub fn build_view_projection_matrix(&self) -> Mat4 {
         let position = self.position();
         let rotation_matrix = Mat4::from_quat(self.rotation.conjugate());
         let translation_matrix = Mat4::from_translation(-position);
         let view = rotation_matrix * translation_matrix;
-        let proj = Mat4::perspective_rh(self.fovy, self.aspect, self.znear, self.zfar);
-        proj * view
-    }
-
-    pub fn to_uniform(
-        &self,
-        particle_size: f32,
-        time: f32,
-        lod_shell_fade_start: f32,
-        lod_shell_fade_end: f32,
-        lod_bound_hadron_fade_start: f32,
-        lod_bound_hadron_fade_end: f32,
-        lod_bond_fade_start: f32,
-        lod_bond_fade_end: f32,
-        lod_quark_fade_start: f32,
-        lod_quark_fade_end: f32,
-        lod_nucleus_fade_start: f32,
-        lod_nucleus_fade_end: f32,
-    ) -> CameraUniform {
+        self.build_projection_matrix() * view
+    }
+
+    /// Pixel position (y down) of a world point in a `width` x `height` target, or None when
+    /// it is behind the camera. Inverse of `PickRay::from_pixel`.
+    pub fn world_to_screen(&self, point: Vec3, width: u32, height: u32) -> Option<Vec2> {
+        // View depth rather than clip w, which is constant for orthographic projections
+        let depth = (point - self.position()).dot(self.rotation * Vec3::NEG_Z);
+        if depth <= self.znear {
+            return None;
+        }
+        let clip = self.build_view_projection_matrix() * point.extend(1.0);
+        let ndc = clip.truncate().truncate() / clip.w;
+        Some(Vec2::new(
+            (ndc.x + 1.0) * 0.5 * width as f32,
+            (1.0 - ndc.y) * 0.5 * height as f32,
+        ))
+    }
+
+    /// Frustum planes (left, right, bottom, top, near, far) with inward unit normals in `xyz`:
+    /// a sphere is (partly) inside when `dot(plane.xyz, center) + plane.w >= -radius` for all six.
+    pub fn frustum_planes(&self) -> [Vec4; 6] {
+        let m = self.build_view_projection_matrix();
+        let (r0, r1, r2, r3) = (m.row(0), m.row(1), m.row(2), m.row(3));
+        // wgpu clip space: -w <= x, y <= w and 0 <= z <= w
+        [r3 + r0, r3 - r0, r3 + r1, r3 - r1, r2, r3 - r2]
+            .map(|plane| plane / plane.truncate().length())
+    }
+
+    /// Projected size in pixels of one world unit at unit distance, for a target `height`
+    /// pixels tall (divide by the distance for the on-screen size). Perspective only: with an
+    /// orthographic projection every depth gets the target distance's scale.
+    pub fn pixels_per_unit(&self, height: u32) -> f32 {
+        height as f32 * 0.5 / (self.fovy * 0.5).tan()
+    }
+
+    pub fn to_uniform(&self, particle_size: f32, time: f32, lod: &LodSettings) -> CameraUniform {
+        let [near_impostors, far_impostors] = lod.packed_impostors();
         CameraUniform {
             view_proj: self.build_view_projection_matrix().to_cols_array_2d(),
             position: self.position().to_array(),
             particle_size,
             time,
-            lod_shell_fade_start,
-            lod_shell_fade_end,
-            lod_bound_hadron_fade_start,
-            lod_bound_hadron_fade_end,
-            lod_bond_fade_start,
-            lod_bond_fade_end,
-            lod_quark_fade_start,
-            lod_quark_fade_end,
-            lod_nucleus_fade_start,
-            lod_nucleus_fade_end,
-            _pad: [0.0; 5],
+            lod_shell_fade_start: lod.shell.start,
+            lod_shell_fade_end: lod.shell.end.max(lod.shell.start),
+            lod_bound_hadron_fade_start: lod.bound_hadron.start,
+            lod_bound_hadron_fade_end: lod.bound_hadron.end.max(lod.bound_hadron.start),
+            lod_bond_fade_start: lod.bond.start,
+            lod_bond_fade_end: lod.bond.end.max(lod.bond.start),
+            lod_quark_fade_start: lod.quark.start,
+            lod_quark_fade_end: lod.quark.end.max(lod.quark.start),
+            lod_nucleus_fade_start: lod.nucleus.start,
+            lod_nucleus_fade_end: lod.nucleus.end.max(lod.nucleus.start),
+            glow_scale: 0.0,
+            hovered_id: 0,
+            _pad1: [0; 3],
+            rest_frame: [0.0; 4],
+            impostor_shapes: [near_impostors, far_impostors, 0, 0],
+            impostor_fade: [
+                lod.impostor.start,
+                lod.impostor.end.max(lod.impostor.start),
+                0.0,
+                0.0,
+            ],
         }
     }
 
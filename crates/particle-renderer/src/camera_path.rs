@@ -0,0 +1,118 @@
+//! Camera bookmarks and fly-through paths
+//!
+//! A [`CameraPose`] captures the orbit camera's target, rotation and distance. A [`FlyThrough`]
+//! moves the camera through a list of poses: the target follows a Catmull-Rom spline through
+//! the key targets, the distance is interpolated the same way in log space (so zooming feels
+//! uniform across scales), and the rotation is slerped per segment. Progress along the whole
+//! path is eased in and out, so the camera starts and stops smoothly but doesn't pause at the
+//! intermediate poses.
+
+use glam::{Quat, Vec3};
+
+use crate::camera::Camera;
+
+/// A named camera view
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraPose {
+    pub name: String,
+    pub target: Vec3,
+    pub rotation: Quat,
+    pub distance: f32,
+}
+
+impl Camera {
+    /// The current view as a pose called `name`.
+    pub fn pose(&self, name: impl Into<String>) -> CameraPose {
+        CameraPose {
+            name: name.into(),
+            target: self.target,
+            rotation: self.rotation,
+            distance: self.distance,
+        }
+    }
+
+    pub fn apply_pose(&mut self, pose: &CameraPose) {
+        self.target = pose.target;
+        self.rotation = pose.rotation;
+        self.distance = pose.distance;
+    }
+}
+
+/// Catmull-Rom interpolation between `p1` and `p2` at `t` in [0, 1]
+fn catmull_rom<T>(p0: T, p1: T, p2: T, p3: T, t: f32) -> T
+where
+    T: Copy
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<f32, Output = T>,
+{
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (p1 * 2.0
+        + (p2 - p0) * t
+        + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * t2
+        + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * t3)
+        * 0.5
+}
+
+/// Animated camera move through a sequence of poses
+pub struct FlyThrough {
+    keys: Vec<CameraPose>,
+    /// Duration of each segment between consecutive keys (seconds)
+    segment_seconds: f32,
+    elapsed: f32,
+}
+
+impl FlyThrough {
+    /// Fly through `keys` in order, spending `segment_seconds` between consecutive poses.
+    /// None with fewer than two keys.
+    pub fn new(keys: Vec<CameraPose>, segment_seconds: f32) -> Option<Self> {
+        (keys.len() >= 2).then_some(Self {
+            keys,
+            segment_seconds: segment_seconds.max(0.01),
+            elapsed: 0.0,
+        })
+    }
+
+    /// Total duration in seconds
+    pub fn duration(&self) -> f32 {
+        (self.keys.len() - 1) as f32 * self.segment_seconds
+    }
+
+    /// Progress in [0, 1]
+    pub fn progress(&self) -> f32 {
+        (self.elapsed / self.duration()).min(1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration()
+    }
+
+    /// Advance by `dt` seconds and move `camera` to the new pose on the path.
+    pub fn advance(&mut self, camera: &mut Camera, dt: f32) {
+        self.elapsed = (self.elapsed + dt.max(0.0)).min(self.duration());
+
+        // Ease in/out over the whole path, then locate the segment
+        let u = self.progress();
+        let u = u * u * (3.0 - 2.0 * u);
+        let position = u * (self.keys.len() - 1) as f32;
+        let segment = (position.floor() as usize).min(self.keys.len() - 2);
+        let t = position - segment as f32;
+
+        // Neighbours for the spline tangents (end keys are repeated)
+        let key = |i: isize| &self.keys[i.clamp(0, self.keys.len() as isize - 1) as usize];
+        let i = segment as isize;
+        let (k0, k1, k2, k3) = (key(i - 1), key(i), key(i + 1), key(i + 2));
+
+        camera.target = catmull_rom(k0.target, k1.target, k2.target, k3.target, t);
+        camera.distance = catmull_rom(
+            k0.distance.ln(),
+            k1.distance.ln(),
+            k2.distance.ln(),
+            k3.distance.ln(),
+            t,
+        )
+        .exp();
+        camera.rotation = k1.rotation.slerp(k2.rotation, t).normalize();
+    }
+}
@@ -0,0 +1,291 @@
+//! Frame capture: an offscreen COPY_SRC target the scene can be rendered into, CPU readback,
+//! a dependency-free PNG encoder and [`capture_screenshot`], which ties them together.
+//!
+//! The encoder writes stored (uncompressed) deflate blocks, so files are roughly
+//! `width * height * 3` bytes; fast to produce and readable by every image tool.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A saved screenshot
+#[derive(Debug, Clone)]
+pub struct Screenshot {
+    pub path: PathBuf,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Re-render a frame at `scale` times the `config` resolution into an offscreen target and save
+/// it as `dir/stem.png` (blocking readback).
+///
+/// `render` draws the scene into the view it gets; the configuration it gets is the supersampled
+/// one, for sizing the scene targets (same aspect ratio, so the camera is unaffected). The scale
+/// is clamped to the device's texture size limit.
+pub fn capture_screenshot(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    config: &wgpu::SurfaceConfiguration,
+    scale: u32,
+    dir: &Path,
+    stem: &str,
+    render: impl FnOnce(&wgpu::SurfaceConfiguration, &wgpu::TextureView),
+) -> io::Result<Screenshot> {
+    let max_dimension = device.limits().max_texture_dimension_2d;
+    let scale = scale.clamp(1, (max_dimension / config.width.max(config.height)).max(1));
+    let capture_config = wgpu::SurfaceConfiguration {
+        width: config.width * scale,
+        height: config.height * scale,
+        ..config.clone()
+    };
+
+    let target = CaptureTarget::new(
+        device,
+        capture_config.width,
+        capture_config.height,
+        config.format,
+    );
+    render(&capture_config, target.view());
+    let rgb = target.read_rgb(device, queue).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("unsupported surface format {:?}", config.format),
+        )
+    })?;
+
+    let path = dir.join(format!("{stem}.png"));
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(&path, encode_png(target.width(), target.height(), &rgb))?;
+    Ok(Screenshot {
+        path,
+        width: target.width(),
+        height: target.height(),
+    })
+}
+
+/// Offscreen color target matching the surface format, readable on the CPU
+pub struct CaptureTarget {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+}
+
+impl CaptureTarget {
+    pub fn new(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Capture Texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self {
+            texture,
+            view,
+            width,
+            height,
+            format,
+        }
+    }
+
+    /// Render into this (in place of the surface view).
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Read the target back as tightly packed 8-bit RGB rows (blocking). None for formats other
+    /// than 8-bit RGBA/BGRA.
+    pub fn read_rgb(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> Option<Vec<u8>> {
+        let swap_red_blue = match self.format {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            _ => return None,
+        };
+
+        // `copy_texture_to_buffer` needs rows aligned to `COPY_BYTES_PER_ROW_ALIGNMENT`
+        let bytes_per_row = (self.width * 4).div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+            * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Capture Readback Buffer"),
+            size: bytes_per_row as u64 * self.height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Capture Readback Encoder"),
+        });
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &staging,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(self.height),
+                },
+            },
+            wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device
+            .poll(wgpu::PollType::Wait {
+                submission_index: None,
+                timeout: None,
+            })
+            .unwrap();
+
+        let mut rgb = Vec::with_capacity(self.width as usize * self.height as usize * 3);
+        {
+            let data = slice.get_mapped_range();
+            for row in data.chunks_exact(bytes_per_row as usize) {
+                for pixel in row[..self.width as usize * 4].chunks_exact(4) {
+                    if swap_red_blue {
+                        rgb.extend_from_slice(&[pixel[2], pixel[1], pixel[0]]);
+                    } else {
+                        rgb.extend_from_slice(&pixel[..3]);
+                    }
+                }
+            }
+        }
+        staging.unmap();
+
+        Some(rgb)
+    }
+}
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Largest stored deflate block
+const DEFLATE_STORED_MAX: usize = 65535;
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+}
+
+fn crc32<'a>(bytes: impl Iterator<Item = &'a u8>) -> u32 {
+    !bytes.fold(!0u32, |crc, &byte| {
+        CRC_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 bytes is the most that can be summed before `b` could overflow
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+/// zlib stream of stored (uncompressed) deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let block_count = data.len().div_ceil(DEFLATE_STORED_MAX).max(1);
+    let mut out = Vec::with_capacity(data.len() + block_count * 5 + 6);
+    // CMF/FLG: deflate, 32K window, no dictionary, fastest (check bits make it divisible by 31)
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    for i in 0..block_count {
+        let block = &data[(i * DEFLATE_STORED_MAX).min(data.len())
+            ..((i + 1) * DEFLATE_STORED_MAX).min(data.len())];
+        let len = block.len() as u16;
+        // BFINAL on the last block, BTYPE = 00 (stored)
+        out.push((i + 1 == block_count) as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    png.extend_from_slice(&crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+/// Encode tightly packed 8-bit RGB rows (`width * height * 3` bytes) as a PNG file.
+pub fn encode_png(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    let row = width as usize * 3;
+    debug_assert_eq!(rgb.len(), row * height as usize);
+
+    // Every scanline starts with its filter type (0 = none)
+    let mut scanlines = Vec::with_capacity((row + 1) * height as usize);
+    for line in rgb.chunks_exact(row) {
+        scanlines.push(0);
+        scanlines.extend_from_slice(line);
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // 8-bit truecolor, deflate, adaptive filtering, no interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = PNG_SIGNATURE.to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
@@ -0,0 +1,195 @@
+//! GPU pass profiler
+//!
+//! Wraps passes (or groups of draws inside one pass) in timestamp queries so the diagnostics
+//! panel can show where GPU time goes per frame. Scopes are recorded in any order during the
+//! frame, resolved at its end, and read back periodically together with the other statistics.
+//! Without timestamp support on the device every call is a no-op and no timings appear.
+
+/// Timestamp features the profiler needs (scopes sit between and inside passes)
+pub fn profiler_features() -> wgpu::Features {
+    wgpu::Features::TIMESTAMP_QUERY
+        | wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS
+        | wgpu::Features::TIMESTAMP_QUERY_INSIDE_PASSES
+}
+
+/// Scopes per frame; later ones are dropped
+const MAX_SCOPES: u32 = 16;
+const RESOLVE_SIZE: u64 = (MAX_SCOPES * 2 * wgpu::QUERY_SIZE) as u64;
+
+/// GPU time spent in one labelled scope (summed if the label occurs more than once)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PassTiming {
+    pub label: &'static str,
+    pub milliseconds: f32,
+}
+
+/// Handle from [`GpuProfiler::begin`], closed with [`GpuProfiler::end`]
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct ProfileScope(Option<u32>);
+
+/// Anything a timestamp can be written into: command encoders and render passes
+pub trait TimestampWriter {
+    fn write_timestamp(&mut self, query_set: &wgpu::QuerySet, index: u32);
+}
+
+impl TimestampWriter for wgpu::CommandEncoder {
+    fn write_timestamp(&mut self, query_set: &wgpu::QuerySet, index: u32) {
+        wgpu::CommandEncoder::write_timestamp(self, query_set, index);
+    }
+}
+
+impl TimestampWriter for wgpu::RenderPass<'_> {
+    fn write_timestamp(&mut self, query_set: &wgpu::QuerySet, index: u32) {
+        wgpu::RenderPass::write_timestamp(self, query_set, index);
+    }
+}
+
+struct ProfilerQueries {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    staging_buffer: wgpu::Buffer,
+    /// Nanoseconds per timestamp tick
+    period: f32,
+}
+
+pub struct GpuProfiler {
+    queries: Option<ProfilerQueries>,
+    /// Labels of the scopes recorded this frame (scope `i` uses queries `2i` and `2i + 1`)
+    scopes: Vec<&'static str>,
+    /// Labels of the scopes in the staging buffer
+    resolved: Vec<&'static str>,
+    timings: Vec<PassTiming>,
+}
+
+impl GpuProfiler {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let queries = device
+            .features()
+            .contains(profiler_features())
+            .then(|| ProfilerQueries {
+                query_set: device.create_query_set(&wgpu::QuerySetDescriptor {
+                    label: Some("Profiler Query Set"),
+                    ty: wgpu::QueryType::Timestamp,
+                    count: MAX_SCOPES * 2,
+                }),
+                resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("Profiler Resolve Buffer"),
+                    size: RESOLVE_SIZE,
+                    usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                    mapped_at_creation: false,
+                }),
+                staging_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("Profiler Staging Buffer"),
+                    size: RESOLVE_SIZE,
+                    usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                }),
+                period: queue.get_timestamp_period(),
+            });
+
+        Self {
+            queries,
+            scopes: Vec::new(),
+            resolved: Vec::new(),
+            timings: Vec::new(),
+        }
+    }
+
+    /// Whether the device supports the timestamp queries
+    pub fn is_supported(&self) -> bool {
+        self.queries.is_some()
+    }
+
+    /// Latest timings in the order the scopes were first recorded
+    pub fn timings(&self) -> &[PassTiming] {
+        &self.timings
+    }
+
+    /// Open a scope labelled `label` at this point of `writer`.
+    pub fn begin(
+        &mut self,
+        writer: &mut impl TimestampWriter,
+        label: &'static str,
+    ) -> ProfileScope {
+        let Some(queries) = &self.queries else {
+            return ProfileScope(None);
+        };
+        let index = self.scopes.len() as u32;
+        if index >= MAX_SCOPES {
+            return ProfileScope(None);
+        }
+        self.scopes.push(label);
+        writer.write_timestamp(&queries.query_set, index * 2);
+        ProfileScope(Some(index))
+    }
+
+    /// Close `scope` at this point of `writer` (the same encoder or pass it was opened in).
+    pub fn end(&self, writer: &mut impl TimestampWriter, scope: ProfileScope) {
+        if let (Some(queries), ProfileScope(Some(index))) = (&self.queries, scope) {
+            writer.write_timestamp(&queries.query_set, index * 2 + 1);
+        }
+    }
+
+    /// Resolve this frame's scopes into the staging buffer and start the next frame. Call once
+    /// per frame after the last scope has ended.
+    pub fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        let Some(queries) = &self.queries else {
+            return;
+        };
+        if self.scopes.is_empty() {
+            return;
+        }
+        let count = self.scopes.len() as u32 * 2;
+        encoder.resolve_query_set(&queries.query_set, 0..count, &queries.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &queries.resolve_buffer,
+            0,
+            &queries.staging_buffer,
+            0,
+            (count * wgpu::QUERY_SIZE) as u64,
+        );
+        self.resolved = std::mem::take(&mut self.scopes);
+    }
+
+    /// Read the last resolved frame back (blocking) and update [`Self::timings`].
+    pub fn read_back(&mut self, device: &wgpu::Device) {
+        let Some(queries) = &self.queries else {
+            return;
+        };
+        if self.resolved.is_empty() {
+            return;
+        }
+
+        let size = (self.resolved.len() as u32 * 2 * wgpu::QUERY_SIZE) as u64;
+        let slice = queries.staging_buffer.slice(..size);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device
+            .poll(wgpu::PollType::Wait {
+                submission_index: None,
+                timeout: None,
+            })
+            .unwrap();
+
+        let mut timings: Vec<PassTiming> = Vec::new();
+        {
+            let data = slice.get_mapped_range();
+            // Begin/end tick pairs, little-endian u64 each
+            for (label, pair) in self.resolved.iter().zip(data.chunks_exact(16)) {
+                let begin = u64::from_le_bytes(pair[..8].try_into().unwrap());
+                let end = u64::from_le_bytes(pair[8..].try_into().unwrap());
+                let milliseconds = end.saturating_sub(begin) as f32 * queries.period / 1e6;
+                match timings.iter_mut().find(|timing| timing.label == *label) {
+                    Some(timing) => timing.milliseconds += milliseconds,
+                    None => timings.push(PassTiming {
+                        label,
+                        milliseconds,
+                    }),
+                }
+            }
+        }
+        queries.staging_buffer.unmap();
+        self.timings = timings;
+        self.resolved.clear();
+    }
+}
@@ -0,0 +1,233 @@
+//! Reference grid, world axes and scale bar
+//!
+//! The grid lies in the world XZ plane under the camera target, with a cell size picked from
+//! the zoom level (a 1/2/5 step in femtometers), so a few dozen cells are always in view. The
+//! X/Y/Z axes run through the world origin. The matching [`ScaleBar`] is computed on the CPU
+//! for the GUI to draw: its length is one grid cell, labeled in femtometers.
+
+use bytemuck::{Pod, Zeroable};
+use particle_physics::constants::PROTON_SIZE;
+
+use crate::camera::Camera;
+
+/// Simulation units per femtometer (a proton, about 1 fm across, is `PROTON_SIZE` wide)
+pub const SIM_UNITS_PER_FM: f32 = PROTON_SIZE;
+
+/// Grid lines on each side of the center line, per direction
+const GRID_HALF_LINES: u32 = 20;
+
+/// Grid line vertices: `2 * GRID_HALF_LINES + 1` lines per direction, two directions
+const GRID_VERTICES: u32 = (2 * GRID_HALF_LINES + 1) * 2 * 2;
+
+/// Axis line vertices (X, Y, Z)
+const AXIS_VERTICES: u32 = 3 * 2;
+
+/// A length that is a round number of femtometers, and how long it is on screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleBar {
+    /// Length in simulation units
+    pub length: f32,
+    /// Length in surface pixels at the camera target's depth
+    pub pixels: f32,
+    /// "2 fm", "0.5 fm", ...
+    pub label: String,
+}
+
+impl ScaleBar {
+    /// Longest 1/2/5 x 10^n femtometer length that is at most `max_pixels` long on screen at
+    /// the camera target, for a surface `height` pixels tall.
+    pub fn fit(camera: &Camera, height: u32, max_pixels: f32) -> Self {
+        // Pixels per simulation unit in the target plane (also holds for the orthographic
+        // projection, whose half-height is `distance * tan(fovy / 2)`)
+        let pixels_per_unit = camera.pixels_per_unit(height) / camera.distance.max(1e-6);
+        let max_fm = (max_pixels / pixels_per_unit / SIM_UNITS_PER_FM).max(1e-6);
+
+        let exponent = max_fm.log10().floor() as i32;
+        let decade = 10f32.powi(exponent);
+        let mantissa = [5.0, 2.0, 1.0]
+            .into_iter()
+            .find(|&m| m * decade <= max_fm)
+            .unwrap_or(1.0);
+        let fm = mantissa * decade;
+        let length = fm * SIM_UNITS_PER_FM;
+
+        Self {
+            length,
+            pixels: length * pixels_per_unit,
+            label: format!("{:.*} fm", (-exponent).max(0) as usize, fm),
+        }
+    }
+}
+
+/// Grid placement (matches WGSL `GridParams`)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct GridParams {
+    /// xyz = grid center (snapped to the cell size), w = cell size
+    origin: [f32; 4],
+    /// x = draw grid, y = draw axes (0/1)
+    options: [u32; 4],
+}
+
+/// Draws the reference grid and axes into the scene (alpha blended, depth tested, no depth
+/// writes).
+pub struct GridRenderer {
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    params_buffer: wgpu::Buffer,
+}
+
+impl GridRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Grid Renderer Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/grid.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Grid Bind Group Layout"),
+            entries: &[
+                // Camera (Uniform) - Binding 0
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: Some(
+                            std::num::NonZeroU64::new(
+                                (std::mem::size_of::<crate::camera::CameraUniform>() as u64)
+                                    .div_ceil(16)
+                                    * 16,
+                            )
+                            .unwrap(),
+                        ),
+                    },
+                    count: None,
+                },
+                // Grid params (Uniform) - Binding 1
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Grid Params Buffer"),
+            size: std::mem::size_of::<GridParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Grid Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            immediate_size: 0,
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Grid Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList,
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
+            multiview_mask: None,
+            cache,
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            params_buffer,
+        }
+    }
+
+    /// Draw the grid (cells of `cell_size`, e.g. `ScaleBar::length`) under the camera target
+    /// and/or the world axes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        render_pass: &mut wgpu::RenderPass,
+        camera_buffer: &wgpu::Buffer,
+        camera: &Camera,
+        cell_size: f32,
+        show_grid: bool,
+        show_axes: bool,
+    ) {
+        if !show_grid && !show_axes {
+            return;
+        }
+
+        let cell_size = cell_size.max(1e-6);
+        let snap = |v: f32| (v / cell_size).round() * cell_size;
+        queue.write_buffer(
+            &self.params_buffer,
+            0,
+            bytemuck::bytes_of(&GridParams {
+                origin: [snap(camera.target.x), 0.0, snap(camera.target.z), cell_size],
+                options: [show_grid as u32, show_axes as u32, 0, 0],
+            }),
+        );
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Grid Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: camera_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: self.params_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..(GRID_VERTICES + AXIS_VERTICES), 0..1);
+    }
+}
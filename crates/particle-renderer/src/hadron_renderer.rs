@@ -1,7 +1,33 @@
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+use crate::oit_renderer::OitRenderer;
+
+/// World-space radius of the bond capsules
+const BOND_RADIUS: f32 = 0.025;
+
+/// Vertices per hadron in the bond pass: three capsule quads (mesons hide two)
+const BOND_VERTICES_PER_HADRON: u32 = 18;
+
+/// Bond capsule shape and strain coloring (matches WGSL `BondParams`)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct BondParams {
+    /// Quark separation drawn unstrained (hadron binding distance)
+    equilibrium: f32,
+    /// Separation drawn fully strained (hadron breakup distance)
+    breakup: f32,
+    radius: f32,
+    _pad: f32,
+}
+
 pub struct HadronRenderer {
     shell_pipeline: wgpu::RenderPipeline,
+    /// Shells into the OIT accumulation pass (see `OitRenderer`)
+    oit_shell_pipeline: wgpu::RenderPipeline,
     bond_pipeline: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
+    bond_params_buffer: wgpu::Buffer,
 }
 
 impl HadronRenderer {
@@ -9,6 +35,8 @@ impl HadronRenderer {
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
         _camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        cache: Option<&wgpu::PipelineCache>,
     ) -> Self {
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Hadron Renderer Shader"),
@@ -70,6 +98,17 @@ impl HadronRenderer {
                     },
                     count: None,
                 },
+                // Bond params (Uniform) - Binding 4
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -115,12 +154,55 @@ impl HadronRenderer {
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
+            multiview_mask: None,
+            cache,
+        });
+
+        // --- OIT SHELL PIPELINE (Instanced Quads, accumulation targets) ---
+        let oit_shell_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Hadron OIT Shell Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_shell"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_shell_oit"),
+                targets: &OitRenderer::shell_targets(),
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                unclipped_depth: false,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: false, // Order-independent: shells never occlude each other
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
             multiview_mask: None,
-            cache: None,
+            cache,
         });
 
-        // --- BOND PIPELINE (Lines) ---
+        // --- BOND PIPELINE (Capsule impostors) ---
         let bond_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Hadron Bond Pipeline"),
             layout: Some(&pipeline_layout),
@@ -141,7 +223,7 @@ impl HadronRenderer {
                 compilation_options: Default::default(),
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::LineList,
+                topology: wgpu::PrimitiveTopology::TriangleList,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: None,
@@ -151,23 +233,54 @@ impl HadronRenderer {
             },
             depth_stencil: Some(wgpu::DepthStencilState {
                 format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: false, // Transparent lines don't write depth
+                depth_write_enabled: false, // Translucent bonds don't write depth
                 depth_compare: wgpu::CompareFunction::Less,
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
             multiview_mask: None,
-            cache: None,
+            cache,
+        });
+
+        let bond_params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Hadron Bond Params Buffer"),
+            contents: bytemuck::bytes_of(&BondParams {
+                equilibrium: 0.8,
+                breakup: 1.0,
+                radius: BOND_RADIUS,
+                _pad: 0.0,
+            }),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
         Self {
             shell_pipeline,
+            oit_shell_pipeline,
             bond_pipeline,
             bind_group_layout,
+            bond_params_buffer,
         }
     }
 
+    /// Bond strain coloring: quark separations up to `equilibrium` are drawn relaxed, at
+    /// `breakup` fully strained (the hadron is about to break).
+    pub fn set_bond_strain_range(&self, queue: &wgpu::Queue, equilibrium: f32, breakup: f32) {
+        queue.write_buffer(
+            &self.bond_params_buffer,
+            0,
+            bytemuck::bytes_of(&BondParams {
+                equilibrium,
+                breakup: breakup.max(equilibrium + 1e-3),
+                radius: BOND_RADIUS,
+                _pad: 0.0,
+            }),
+        );
+    }
+
     pub fn render(
         &self,
         device: &wgpu::Device,
@@ -182,7 +295,67 @@ impl HadronRenderer {
     ) {
         // Create bind group for this frame
         // Note: In a real engine, we would cache this or use a BindGroupAllocator
-        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        let bind_group = self.bind_group(
+            device,
+            camera_buffer,
+            hadron_buffer,
+            particle_buffer,
+            hadron_count_buffer,
+        );
+
+        // Draw Shells
+        if show_shells {
+            render_pass.set_pipeline(&self.shell_pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            // Draw 6 vertices (quad) per instance, max_hadrons instances
+            // The shader will discard invalid instances
+            render_pass.draw(0..6, 0..max_hadrons);
+        }
+
+        // Draw Bonds
+        if show_bonds {
+            render_pass.set_pipeline(&self.bond_pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            // One instance per hadron, three capsule quads each
+            // The shader will discard invalid instances
+            render_pass.draw(0..BOND_VERTICES_PER_HADRON, 0..max_hadrons);
+        }
+    }
+
+    /// Draw shells into the OIT accumulation pass (from `OitRenderer::begin_accumulate`)
+    /// instead of blending them straight into the scene.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_shells_oit(
+        &self,
+        device: &wgpu::Device,
+        render_pass: &mut wgpu::RenderPass,
+        camera_buffer: &wgpu::Buffer,
+        hadron_buffer: &wgpu::Buffer,
+        particle_buffer: &wgpu::Buffer,
+        hadron_count_buffer: &wgpu::Buffer,
+        max_hadrons: u32,
+    ) {
+        let bind_group = self.bind_group(
+            device,
+            camera_buffer,
+            hadron_buffer,
+            particle_buffer,
+            hadron_count_buffer,
+        );
+        render_pass.set_pipeline(&self.oit_shell_pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..6, 0..max_hadrons);
+    }
+
+    fn bind_group(
+        &self,
+        device: &wgpu::Device,
+        camera_buffer: &wgpu::Buffer,
+        hadron_buffer: &wgpu::Buffer,
+        particle_buffer: &wgpu::Buffer,
+        hadron_count_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Hadron Render Bind Group"),
             layout: &self.bind_group_layout,
             entries: &[
@@ -202,25 +375,11 @@ impl HadronRenderer {
                     binding: 3,
                     resource: hadron_count_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: self.bond_params_buffer.as_entire_binding(),
+                },
             ],
-        });
-
-        // Draw Shells
-        if show_shells {
-            render_pass.set_pipeline(&self.shell_pipeline);
-            render_pass.set_bind_group(0, &bind_group, &[]);
-            // Draw 6 vertices (quad) per instance, max_hadrons instances
-            // The shader will discard invalid instances
-            render_pass.draw(0..6, 0..max_hadrons);
-        }
-
-        // Draw Bonds
-        if show_bonds {
-            render_pass.set_pipeline(&self.bond_pipeline);
-            render_pass.set_bind_group(0, &bind_group, &[]);
-            // Draw 6 vertices per hadron (3 lines), 1 instance
-            // The shader will discard invalid vertices
-            render_pass.draw(0..(max_hadrons * 6), 0..1);
-        }
+        })
     }
 }
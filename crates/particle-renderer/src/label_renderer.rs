@@ -0,0 +1,146 @@
+//! Leader lines for screen-space entity labels
+//!
+//! The app projects labelled entities (selection, hover) with `Camera::world_to_screen` and
+//! hands the pixel positions here and to the GUI, which draws the text tags. Lines go straight
+//! onto the surface after bloom so they stay crisp and untouched by tone mapping.
+
+/// Maximum number of labels drawn per frame
+pub const MAX_LABELS: usize = 16;
+
+/// One label in surface pixels (y down)
+#[derive(Clone, Copy, Debug)]
+pub struct ScreenLabel {
+    /// Point on the entity the line starts at
+    pub anchor: [f32; 2],
+    /// Corner of the text tag the line ends at
+    pub tag: [f32; 2],
+}
+
+pub struct LabelRenderer {
+    pipeline: wgpu::RenderPipeline,
+    segment_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+impl LabelRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Label Renderer Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/label.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Label Bind Group Layout"),
+            entries: &[
+                // Segments (Storage) - Binding 0
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let segment_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Label Segment Buffer"),
+            size: (MAX_LABELS * std::mem::size_of::<[f32; 4]>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Label Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: segment_buffer.as_entire_binding(),
+            }],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Label Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            immediate_size: 0,
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Label Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache,
+        });
+
+        Self {
+            pipeline,
+            segment_buffer,
+            bind_group,
+        }
+    }
+
+    /// Draw leader lines for `labels` (at most `MAX_LABELS`) into `render_pass`, whose target
+    /// is `width` x `height` pixels.
+    pub fn render(
+        &self,
+        queue: &wgpu::Queue,
+        render_pass: &mut wgpu::RenderPass,
+        labels: &[ScreenLabel],
+        width: u32,
+        height: u32,
+    ) {
+        let labels = &labels[..labels.len().min(MAX_LABELS)];
+        if labels.is_empty() {
+            return;
+        }
+
+        let to_ndc = |[x, y]: [f32; 2]| {
+            [
+                x / width.max(1) as f32 * 2.0 - 1.0,
+                1.0 - y / height.max(1) as f32 * 2.0,
+            ]
+        };
+        let segments: Vec<[f32; 4]> = labels
+            .iter()
+            .map(|label| {
+                let [ax, ay] = to_ndc(label.anchor);
+                let [tx, ty] = to_ndc(label.tag);
+                [ax, ay, tx, ty]
+            })
+            .collect();
+        queue.write_buffer(&self.segment_buffer, 0, bytemuck::cast_slice(&segments));
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.draw(0..(labels.len() as u32 * 2), 0..1);
+    }
+}
@@ -2,14 +2,44 @@
 //!
 //! Visualization system for particle physics simulation.
 
+pub mod bloom_renderer;
 pub mod camera;
+pub mod camera_path;
+pub mod capture;
+pub mod gpu_profiler;
+pub mod grid_renderer;
 pub mod hadron_renderer;
+pub mod label_renderer;
+pub mod lod;
+pub mod measure_renderer;
+pub mod minimap_renderer;
 pub mod nucleus_renderer;
+pub mod oit_renderer;
+pub mod photon_renderer;
 pub mod picking;
+pub mod pipeline_cache;
+pub mod recorder;
 pub mod renderer;
+pub mod trail_renderer;
+pub mod vector_renderer;
 
+pub use bloom_renderer::*;
 pub use camera::*;
+pub use camera_path::*;
+pub use capture::*;
+pub use gpu_profiler::*;
+pub use grid_renderer::*;
 pub use hadron_renderer::*;
+pub use label_renderer::*;
+pub use lod::*;
+pub use measure_renderer::*;
+pub use minimap_renderer::*;
 pub use nucleus_renderer::*;
+pub use oit_renderer::*;
+pub use photon_renderer::*;
 pub use picking::*;
+pub use pipeline_cache::*;
+pub use recorder::*;
 pub use renderer::*;
+pub use trail_renderer::*;
+pub use vector_renderer::*;
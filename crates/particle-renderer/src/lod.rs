@@ -0,0 +1,103 @@
+//! Level-of-detail settings
+//!
+//! Every LOD transition is a camera-distance range over which something fades: hadron shells
+//! and bonds fade in, bound quarks fade out, nuclei take over from their hadrons. Particle
+//! billboards additionally crossfade per species from a near impostor (e.g. a lit sphere) to a
+//! far one (a soft glow or a small point) over [`LodSettings::impostor`].
+
+/// Camera-distance range of a fade (`end` is raised to `start` when uploaded)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FadeRange {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl FadeRange {
+    pub const fn new(start: f32, end: f32) -> Self {
+        Self { start, end }
+    }
+}
+
+/// How a particle billboard is shaded
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImpostorShape {
+    /// Lit sphere (down quarks keep their hollow center)
+    #[default]
+    Sphere = 0,
+    /// Unlit Gaussian halo
+    SoftGlow = 1,
+    /// Small flat dot
+    Point = 2,
+}
+
+impl ImpostorShape {
+    pub fn label(self) -> &'static str {
+        match self {
+            ImpostorShape::Sphere => "Sphere",
+            ImpostorShape::SoftGlow => "Glow",
+            ImpostorShape::Point => "Point",
+        }
+    }
+}
+
+/// Impostors of one species close to and far from the camera
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SpeciesImpostor {
+    pub near: ImpostorShape,
+    pub far: ImpostorShape,
+}
+
+/// Species with their own impostors, indexed by particle type (up quark, down quark, electron,
+/// gluon). Other types are always drawn as spheres.
+pub const IMPOSTOR_SPECIES: usize = 4;
+
+/// All LOD fade ranges and impostor choices the scene shaders read from the camera uniform
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LodSettings {
+    /// Hadron shells fade in
+    pub shell: FadeRange,
+    /// Hadrons bound into nuclei fade out
+    pub bound_hadron: FadeRange,
+    /// Quark bonds fade out
+    pub bond: FadeRange,
+    /// Quarks bound into hadrons fade out
+    pub quark: FadeRange,
+    /// Nucleus shells fade in
+    pub nucleus: FadeRange,
+    /// Particles crossfade from their near to their far impostor
+    pub impostor: FadeRange,
+    pub impostors: [SpeciesImpostor; IMPOSTOR_SPECIES],
+}
+
+impl Default for LodSettings {
+    fn default() -> Self {
+        Self {
+            shell: FadeRange::new(10.0, 30.0),
+            bound_hadron: FadeRange::new(40.0, 70.0),
+            bond: FadeRange::new(10.0, 30.0),
+            quark: FadeRange::new(10.0, 30.0),
+            // Nuclei appear further out than hadrons
+            nucleus: FadeRange::new(40.0, 70.0),
+            impostor: FadeRange::new(80.0, 160.0),
+            impostors: [SpeciesImpostor::default(); IMPOSTOR_SPECIES],
+        }
+    }
+}
+
+impl LodSettings {
+    /// Near and far shapes packed 4 bits per species (by particle type), as `particle.wgsl`
+    /// unpacks them.
+    pub(crate) fn packed_impostors(&self) -> [u32; 2] {
+        self.impostors
+            .iter()
+            .enumerate()
+            .fold([0, 0], |[near, far], (species, impostor)| {
+                let shift = species as u32 * 4;
+                [
+                    near | (impostor.near as u32) << shift,
+                    far | (impostor.far as u32) << shift,
+                ]
+            })
+    }
+}
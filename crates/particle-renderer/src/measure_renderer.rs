@@ -0,0 +1,380 @@
+//! Measurement overlay: distance and angle between picked entities
+//!
+//! [`MeasureTool`] keeps the picked entity IDs and resolves them to their current centers every
+//! frame, wrapping them in a [`Measurement`] and formatting the readout. The renderer draws its
+//! legs (and, for three points, an arc at the middle one) as screen-space lines straight onto
+//! the surface after bloom, like the label leader lines; the GUI draws the numbers.
+
+use glam::Vec3;
+use particle_simulation::{
+    ParticleSimulation, SelectionTarget, MEASURE_CAPACITY, MEASURE_TARGETS_SIZE,
+    SELECTION_TARGET_SIZE,
+};
+
+use crate::camera::Camera;
+use crate::grid_renderer::SIM_UNITS_PER_FM;
+
+/// Line segments of the angle arc
+const ARC_SEGMENTS: usize = 24;
+/// Arc radius as a fraction of the shorter leg
+const ARC_RADIUS: f32 = 0.3;
+/// Two legs plus the arc
+const MAX_SEGMENTS: usize = 2 + ARC_SEGMENTS;
+
+// Catppuccin Mocha Yellow #f9e2af and Peach #fab387 (the surface is sRGB, so no conversion)
+const LEG_COLOR: [f32; 4] = [0.976, 0.886, 0.686, 0.95];
+const ARC_COLOR: [f32; 4] = [0.980, 0.702, 0.529, 0.95];
+
+/// World-space points of a measurement: a distance between two, or two distances and the angle
+/// at the second point for three
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub points: Vec<Vec3>,
+}
+
+impl Measurement {
+    /// Distances between consecutive points in femtometers
+    pub fn distances_fm(&self) -> Vec<f32> {
+        self.points
+            .windows(2)
+            .map(|pair| pair[0].distance(pair[1]) / SIM_UNITS_PER_FM)
+            .collect()
+    }
+
+    /// Angle at the middle of three points in degrees (None for fewer points or a zero leg)
+    pub fn angle_degrees(&self) -> Option<f32> {
+        let [a, vertex, b] = self.points.get(..3)? else {
+            return None;
+        };
+        let (u, v) = (*a - *vertex, *b - *vertex);
+        (u.length() > 1e-6 && v.length() > 1e-6).then(|| u.angle_between(v).to_degrees())
+    }
+
+    /// World-space line segments with their colors
+    fn segments(&self) -> Vec<(Vec3, Vec3, [f32; 4])> {
+        let mut segments: Vec<_> = self
+            .points
+            .windows(2)
+            .map(|pair| (pair[0], pair[1], LEG_COLOR))
+            .collect();
+
+        if let (Some([a, vertex, b]), Some(_)) = (self.points.get(..3), self.angle_degrees()) {
+            let (u, v) = (*a - *vertex, *b - *vertex);
+            let radius = ARC_RADIUS * u.length().min(v.length());
+            let (u, v) = (u.normalize(), v.normalize());
+            // Slerp between the leg directions (nearly opposite legs bend around any normal)
+            let arc = |t: f32| {
+                let angle = u.angle_between(v);
+                let direction = if angle.sin().abs() < 1e-4 {
+                    let normal = u.any_orthonormal_vector();
+                    u * (angle * t).cos() + normal * (angle * t).sin()
+                } else {
+                    (u * ((1.0 - t) * angle).sin() + v * (t * angle).sin()) / angle.sin()
+                };
+                *vertex + direction * radius
+            };
+            segments.extend((0..ARC_SEGMENTS).map(|i| {
+                let t0 = i as f32 / ARC_SEGMENTS as f32;
+                let t1 = (i + 1) as f32 / ARC_SEGMENTS as f32;
+                (arc(t0), arc(t1), ARC_COLOR)
+            }));
+        }
+        segments
+    }
+}
+
+/// Picked entities of the measurement tool and their last resolved [`Measurement`]
+pub struct MeasureTool {
+    ids: Vec<u32>,
+    staging_buffer: wgpu::Buffer,
+    measurement: Option<Measurement>,
+    readout: Vec<String>,
+}
+
+impl MeasureTool {
+    pub fn new(device: &wgpu::Device) -> Self {
+        // Measurement target readback (`MEASURE_CAPACITY` selection targets)
+        let staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Measure Target Staging Buffer"),
+            size: MEASURE_TARGETS_SIZE,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Self {
+            ids: Vec::new(),
+            staging_buffer,
+            measurement: None,
+            readout: Vec::new(),
+        }
+    }
+
+    /// Add a clicked entity to the measurement (clicking a measured one removes it; a click after
+    /// the last point starts over).
+    pub fn toggle(&mut self, simulation: &ParticleSimulation, id: u32) {
+        if let Some(index) = self.ids.iter().position(|&other| other == id) {
+            self.ids.remove(index);
+        } else {
+            if self.ids.len() >= MEASURE_CAPACITY {
+                self.ids.clear();
+            }
+            self.ids.push(id);
+        }
+        simulation.set_measure_ids(&self.ids);
+    }
+
+    /// Drop every measured entity.
+    pub fn clear(&mut self, simulation: &ParticleSimulation) {
+        self.ids.clear();
+        simulation.set_measure_ids(&self.ids);
+    }
+
+    /// Resolve the measured entities to their current centers (blocking readback, like the
+    /// camera follow) and refresh the readout. `picking` adds the hints for the next click.
+    pub fn update(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        simulation: &ParticleSimulation,
+        picking: bool,
+    ) {
+        if self.ids.is_empty() {
+            self.measurement = None;
+            self.readout = if picking {
+                vec!["Click an entity to start".to_string()]
+            } else {
+                Vec::new()
+            };
+            return;
+        }
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Measure Resolve Encoder"),
+        });
+        simulation.encode_measure_resolve(&mut encoder);
+        encoder.copy_buffer_to_buffer(
+            simulation.measure_target_buffer(),
+            0,
+            &self.staging_buffer,
+            0,
+            MEASURE_TARGETS_SIZE,
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = self.staging_buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device
+            .poll(wgpu::PollType::Wait {
+                submission_index: None,
+                timeout: None,
+            })
+            .unwrap();
+        let targets: Vec<SelectionTarget> = {
+            let data = slice.get_mapped_range();
+            data.chunks_exact(SELECTION_TARGET_SIZE as usize)
+                .take(self.ids.len())
+                .map(SelectionTarget::from_bytes)
+                .collect()
+        };
+        self.staging_buffer.unmap();
+
+        // Entities that no longer exist (broken hadrons, dissolved nuclei) drop out
+        let measurement = Measurement {
+            points: targets
+                .iter()
+                .filter(|target| target.is_some())
+                .map(|target| Vec3::from(target.center))
+                .collect(),
+        };
+
+        self.readout.clear();
+        for (i, distance) in measurement.distances_fm().iter().enumerate() {
+            self.readout
+                .push(format!("Distance {}-{}: {distance:.2} fm", i + 1, i + 2));
+        }
+        if let Some(angle) = measurement.angle_degrees() {
+            self.readout.push(format!("Angle at 2: {angle:.1}°"));
+        }
+        let lost = targets.len() - measurement.points.len();
+        if lost > 0 {
+            self.readout
+                .push(format!("Gone: {lost} of {}", targets.len()));
+        }
+        if measurement.points.len() < MEASURE_CAPACITY && picking {
+            self.readout.push(format!(
+                "{}/{} picked: click another entity",
+                measurement.points.len(),
+                MEASURE_CAPACITY
+            ));
+        }
+        self.measurement = Some(measurement);
+    }
+
+    /// The last resolved measurement (None while nothing is picked)
+    pub fn measurement(&self) -> Option<&Measurement> {
+        self.measurement.as_ref()
+    }
+
+    /// Lines for the measurement panel
+    pub fn readout(&self) -> &[String] {
+        &self.readout
+    }
+
+    /// Value tags: the distances at the leg midpoints and the angle at the vertex
+    pub fn tags(&self) -> Vec<(Vec3, String)> {
+        let Some(measurement) = &self.measurement else {
+            return Vec::new();
+        };
+        let mut tags: Vec<(Vec3, String)> = measurement
+            .points
+            .windows(2)
+            .zip(measurement.distances_fm())
+            .map(|(pair, distance)| ((pair[0] + pair[1]) * 0.5, format!("{distance:.2} fm")))
+            .collect();
+        if let Some(angle) = measurement.angle_degrees() {
+            tags.push((measurement.points[1], format!("{angle:.1}°")));
+        }
+        tags
+    }
+}
+
+/// One segment for `measure.wgsl` (NDC endpoints and color)
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Segment {
+    points: [f32; 4],
+    color: [f32; 4],
+}
+
+pub struct MeasureRenderer {
+    pipeline: wgpu::RenderPipeline,
+    segment_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+impl MeasureRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Measure Renderer Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/measure.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Measure Bind Group Layout"),
+            entries: &[
+                // Segments (Storage) - Binding 0
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let segment_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Measure Segment Buffer"),
+            size: (MAX_SEGMENTS * std::mem::size_of::<Segment>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Measure Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: segment_buffer.as_entire_binding(),
+            }],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Measure Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            immediate_size: 0,
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Measure Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache,
+        });
+
+        Self {
+            pipeline,
+            segment_buffer,
+            bind_group,
+        }
+    }
+
+    /// Draw `measurement` as seen by `camera` into `render_pass`, whose target is `width` x
+    /// `height` pixels. Segments with an end behind the camera are skipped.
+    pub fn render(
+        &self,
+        queue: &wgpu::Queue,
+        render_pass: &mut wgpu::RenderPass,
+        camera: &Camera,
+        measurement: &Measurement,
+        width: u32,
+        height: u32,
+    ) {
+        let to_ndc = |point: Vec3| {
+            camera.world_to_screen(point, width, height).map(|pixel| {
+                [
+                    pixel.x / width.max(1) as f32 * 2.0 - 1.0,
+                    1.0 - pixel.y / height.max(1) as f32 * 2.0,
+                ]
+            })
+        };
+        let segments: Vec<Segment> = measurement
+            .segments()
+            .into_iter()
+            .take(MAX_SEGMENTS)
+            .filter_map(|(start, end, color)| {
+                let ([sx, sy], [ex, ey]) = (to_ndc(start)?, to_ndc(end)?);
+                Some(Segment {
+                    points: [sx, sy, ex, ey],
+                    color,
+                })
+            })
+            .collect();
+        if segments.is_empty() {
+            return;
+        }
+        queue.write_buffer(&self.segment_buffer, 0, bytemuck::cast_slice(&segments));
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.draw(0..(segments.len() as u32 * 2), 0..1);
+    }
+}
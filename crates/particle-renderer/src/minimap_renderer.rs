@@ -0,0 +1,296 @@
+//! Overview minimap
+//!
+//! A small inset in a screen corner shows the whole simulation from a fixed overhead
+//! orthographic camera: every particle as a point, the simulation boundary, and the main
+//! camera's view frustum (clipped to a few view distances), so it stays clear where the main
+//! view is when zoomed deep into a nucleus. Drawn straight onto the surface after bloom.
+
+use bytemuck::{Pod, Zeroable};
+use glam::{Mat4, Vec3};
+
+use crate::camera::Camera;
+
+/// Segments of the boundary circle
+const BOUNDARY_SEGMENTS: usize = 48;
+/// Frustum edges: four from the eye, four around the far rectangle
+const FRUSTUM_SEGMENTS: usize = 8;
+const MAX_SEGMENTS: usize = BOUNDARY_SEGMENTS + FRUSTUM_SEGMENTS;
+/// The drawn frustum reaches this many camera distances past the eye
+const FRUSTUM_DEPTH: f32 = 2.0;
+
+// Catppuccin Mocha Overlay 0 #6c7086 and Mauve #cba6f7 (the surface is sRGB, so no conversion)
+const BOUNDARY_COLOR: [f32; 4] = [0.424, 0.439, 0.525, 0.8];
+const FRUSTUM_COLOR: [f32; 4] = [0.796, 0.651, 0.969, 0.95];
+
+/// Screen corner the minimap sits in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MinimapCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl MinimapCorner {
+    /// Square viewport (x, y, width, height in surface pixels) of side `size`, `margin` pixels
+    /// from the corner of a `width` x `height` surface. Shrinks to fit small surfaces.
+    pub fn viewport(self, width: u32, height: u32, size: f32, margin: f32) -> [f32; 4] {
+        let size = size.min(width.min(height) as f32 - margin * 2.0).max(0.0);
+        let right = width as f32 - size - margin;
+        let bottom = height as f32 - size - margin;
+        let (x, y) = match self {
+            MinimapCorner::TopLeft => (margin, margin),
+            MinimapCorner::TopRight => (right, margin),
+            MinimapCorner::BottomLeft => (margin, bottom),
+            MinimapCorner::BottomRight => (right, bottom),
+        };
+        [x, y, size, size]
+    }
+}
+
+/// Uniform for `minimap.wgsl`
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct MinimapUniform {
+    view_proj: [[f32; 4]; 4],
+}
+
+/// One world-space line segment with its color (matches WGSL `Segment`)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct Segment {
+    start: [f32; 4],
+    end: [f32; 4],
+    color: [f32; 4],
+}
+
+pub struct MinimapRenderer {
+    background_pipeline: wgpu::RenderPipeline,
+    particle_pipeline: wgpu::RenderPipeline,
+    line_pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    uniform_buffer: wgpu::Buffer,
+    segment_buffer: wgpu::Buffer,
+}
+
+impl MinimapRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Minimap Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/minimap.wgsl").into()),
+        });
+
+        let buffer_entry = |binding, ty| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Buffer {
+                ty,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Minimap Bind Group Layout"),
+            entries: &[
+                // Overhead camera (Uniform) - Binding 0
+                buffer_entry(0, wgpu::BufferBindingType::Uniform),
+                // Particles (Storage) - Binding 1
+                buffer_entry(1, wgpu::BufferBindingType::Storage { read_only: true }),
+                // Boundary and frustum segments (Storage) - Binding 2
+                buffer_entry(2, wgpu::BufferBindingType::Storage { read_only: true }),
+            ],
+        });
+
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Minimap Uniform Buffer"),
+            size: std::mem::size_of::<MinimapUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let segment_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Minimap Segment Buffer"),
+            size: (MAX_SEGMENTS * std::mem::size_of::<Segment>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Minimap Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            immediate_size: 0,
+        });
+
+        let pipeline = |label, vs_entry, topology| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some(vs_entry),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview_mask: None,
+                cache,
+            })
+        };
+
+        Self {
+            background_pipeline: pipeline(
+                "Minimap Background Pipeline",
+                "vs_background",
+                wgpu::PrimitiveTopology::TriangleList,
+            ),
+            particle_pipeline: pipeline(
+                "Minimap Particle Pipeline",
+                "vs_particle",
+                wgpu::PrimitiveTopology::PointList,
+            ),
+            line_pipeline: pipeline(
+                "Minimap Line Pipeline",
+                "vs_line",
+                wgpu::PrimitiveTopology::LineList,
+            ),
+            bind_group_layout,
+            uniform_buffer,
+            segment_buffer,
+        }
+    }
+
+    /// Boundary circle and the main camera's frustum edges in world space
+    fn segments(camera: &Camera, boundary_radius: f32) -> Vec<Segment> {
+        let mut segments = Vec::with_capacity(MAX_SEGMENTS);
+
+        if boundary_radius > 0.0 {
+            let point = |i: usize| {
+                let angle = i as f32 / BOUNDARY_SEGMENTS as f32 * std::f32::consts::TAU;
+                [
+                    angle.cos() * boundary_radius,
+                    0.0,
+                    angle.sin() * boundary_radius,
+                    1.0,
+                ]
+            };
+            segments.extend((0..BOUNDARY_SEGMENTS).map(|i| Segment {
+                start: point(i),
+                end: point(i + 1),
+                color: BOUNDARY_COLOR,
+            }));
+        }
+
+        // The orbit eye sits at `target + rotation * (0, 0, distance)`, looking down -Z
+        let eye = camera.position();
+        let forward = camera.rotation * -Vec3::Z;
+        let right = camera.rotation * Vec3::X;
+        let up = camera.rotation * Vec3::Y;
+        let half_height = (camera.fovy * 0.5).tan();
+        let half_width = half_height * camera.aspect;
+        let depth = camera.distance * FRUSTUM_DEPTH;
+        let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].map(|(x, y)| {
+            eye + (forward + right * (x * half_width) + up * (y * half_height)) * depth
+        });
+        for (i, corner) in corners.iter().enumerate() {
+            let next = corners[(i + 1) % corners.len()];
+            segments.push(Segment {
+                start: eye.extend(1.0).to_array(),
+                end: corner.extend(1.0).to_array(),
+                color: FRUSTUM_COLOR,
+            });
+            segments.push(Segment {
+                start: corner.extend(1.0).to_array(),
+                end: next.extend(1.0).to_array(),
+                color: FRUSTUM_COLOR,
+            });
+        }
+        segments
+    }
+
+    /// Draw the minimap into `viewport` (x, y, width, height in surface pixels, see
+    /// `MinimapCorner::viewport`) of `render_pass`'s target. The overhead view spans `extent`
+    /// world units from the origin in X and Z. Leaves the pass's viewport and scissor on the
+    /// inset, so it goes last in a shared pass.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        render_pass: &mut wgpu::RenderPass,
+        camera: &Camera,
+        particle_buffer: &wgpu::Buffer,
+        particle_count: u32,
+        boundary_radius: f32,
+        extent: f32,
+        viewport: [f32; 4],
+    ) {
+        let [x, y, width, height] = viewport;
+        if width < 1.0 || height < 1.0 {
+            return;
+        }
+
+        // Looking straight down: +X to the right, -Z up
+        let extent = extent.max(1.0);
+        let view = Mat4::look_at_rh(Vec3::Y * extent * 2.0, Vec3::ZERO, -Vec3::Z);
+        let projection = Mat4::orthographic_rh(-extent, extent, -extent, extent, 0.0, extent * 4.0);
+        queue.write_buffer(
+            &self.uniform_buffer,
+            0,
+            bytemuck::bytes_of(&MinimapUniform {
+                view_proj: (projection * view).to_cols_array_2d(),
+            }),
+        );
+        let segments = Self::segments(camera, boundary_radius);
+        queue.write_buffer(&self.segment_buffer, 0, bytemuck::cast_slice(&segments));
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Minimap Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: self.uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.segment_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        render_pass.set_viewport(x, y, width, height, 0.0, 1.0);
+        render_pass.set_scissor_rect(x as u32, y as u32, width as u32, height as u32);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+
+        render_pass.set_pipeline(&self.background_pipeline);
+        render_pass.draw(0..6, 0..1);
+        render_pass.set_pipeline(&self.particle_pipeline);
+        render_pass.draw(0..particle_count, 0..1);
+        render_pass.set_pipeline(&self.line_pipeline);
+        render_pass.draw(0..(segments.len() as u32 * 2), 0..1);
+    }
+}
@@ -1,6 +1,191 @@
+//! Nucleus shells
+//!
+//! Each nucleus is a translucent shell colored and sized per element from a palette uniform:
+//! the color comes from the atomic number (CPK/Jmol scheme by default), the radius from the
+//! mass number as `radius_scale * A^(1/3) * entry scale`, like real nuclear radii. The
+//! simulation's bounding radius can be blended back in with `size_blend`.
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+use crate::oit_renderer::OitRenderer;
+
+/// Palette entries: index 0 is the fallback, 1..=118 are the elements by atomic number (Z)
+pub const NUCLEUS_PALETTE_SIZE: usize = 119;
+
+/// CPK/Jmol shell colors (PubChem) by atomic number; `a` scales the shell radius
+const CPK_PALETTE: [[f32; 4]; NUCLEUS_PALETTE_SIZE] = [
+    [0.8, 0.8, 0.8, 1.0],       // Fallback gray (unknown Z)
+    [1.000, 1.000, 1.000, 1.0], // H - White
+    [0.851, 1.000, 1.000, 1.0], // He - Cyan
+    [0.800, 0.502, 1.000, 1.0], // Li - Purple
+    [0.761, 1.000, 0.000, 1.0], // Be - Green
+    [1.000, 0.710, 0.710, 1.0], // B - Peach
+    [0.565, 0.565, 0.565, 1.0], // C - Gray
+    [0.188, 0.314, 0.973, 1.0], // N - Blue
+    [1.000, 0.051, 0.051, 1.0], // O - Red
+    [0.565, 0.878, 0.314, 1.0], // F - Green
+    [0.702, 0.890, 0.961, 1.0], // Ne - Cyan
+    [0.671, 0.361, 0.949, 1.0], // Na - Purple
+    [0.541, 1.000, 0.000, 1.0], // Mg - Green
+    [0.749, 0.651, 0.651, 1.0], // Al - Pink gray
+    [0.941, 0.784, 0.627, 1.0], // Si - Goldenrod
+    [1.000, 0.502, 0.000, 1.0], // P - Orange
+    [1.000, 1.000, 0.188, 1.0], // S - Yellow
+    [0.122, 0.941, 0.122, 1.0], // Cl - Green
+    [0.502, 0.820, 0.890, 1.0], // Ar - Cyan
+    [0.561, 0.251, 0.831, 1.0], // K - Purple
+    [0.239, 1.000, 0.000, 1.0], // Ca - Green
+    [0.902, 0.902, 0.902, 1.0], // Sc - Gray
+    [0.749, 0.761, 0.780, 1.0], // Ti - Gray
+    [0.651, 0.651, 0.671, 1.0], // V - Gray
+    [0.541, 0.600, 0.780, 1.0], // Cr - Steel blue
+    [0.612, 0.478, 0.780, 1.0], // Mn - Purple
+    [0.878, 0.400, 0.200, 1.0], // Fe - Orange
+    [0.941, 0.565, 0.627, 1.0], // Co - Pink
+    [0.314, 0.816, 0.314, 1.0], // Ni - Green
+    [0.784, 0.502, 0.200, 1.0], // Cu - Brown
+    [0.490, 0.502, 0.690, 1.0], // Zn - Blue gray
+    [0.761, 0.561, 0.561, 1.0], // Ga - Pink
+    [0.400, 0.561, 0.561, 1.0], // Ge - Gray green
+    [0.741, 0.502, 0.890, 1.0], // As - Purple
+    [1.000, 0.631, 0.000, 1.0], // Se - Orange
+    [0.651, 0.161, 0.161, 1.0], // Br - Brown
+    [0.361, 0.722, 0.820, 1.0], // Kr - Cyan
+    [0.439, 0.180, 0.690, 1.0], // Rb - Purple
+    [0.000, 1.000, 0.000, 1.0], // Sr - Green
+    [0.580, 1.000, 1.000, 1.0], // Y - Cyan
+    [0.580, 0.878, 0.878, 1.0], // Zr - Cyan
+    [0.451, 0.761, 0.788, 1.0], // Nb - Cyan
+    [0.329, 0.710, 0.710, 1.0], // Mo - Cyan
+    [0.231, 0.620, 0.620, 1.0], // Tc - Teal
+    [0.141, 0.561, 0.561, 1.0], // Ru - Teal
+    [0.039, 0.490, 0.549, 1.0], // Rh - Teal
+    [0.000, 0.412, 0.522, 1.0], // Pd - Teal
+    [0.753, 0.753, 0.753, 1.0], // Ag - Silver
+    [1.000, 0.851, 0.561, 1.0], // Cd - Gold
+    [0.651, 0.459, 0.451, 1.0], // In - Brown
+    [0.400, 0.502, 0.502, 1.0], // Sn - Gray
+    [0.620, 0.388, 0.710, 1.0], // Sb - Purple
+    [0.831, 0.478, 0.000, 1.0], // Te - Orange
+    [0.580, 0.000, 0.580, 1.0], // I - Purple
+    [0.259, 0.620, 0.690, 1.0], // Xe - Cyan
+    [0.341, 0.090, 0.561, 1.0], // Cs - Purple
+    [0.000, 0.788, 0.000, 1.0], // Ba - Green
+    [0.439, 0.831, 1.000, 1.0], // La - Blue
+    [1.000, 1.000, 0.780, 1.0], // Ce - Yellow
+    [0.851, 1.000, 0.780, 1.0], // Pr - Yellow green
+    [0.780, 1.000, 0.780, 1.0], // Nd - Green
+    [0.639, 1.000, 0.780, 1.0], // Pm - Green
+    [0.561, 1.000, 0.780, 1.0], // Sm - Green
+    [0.380, 1.000, 0.780, 1.0], // Eu - Green
+    [0.271, 1.000, 0.780, 1.0], // Gd - Green
+    [0.188, 1.000, 0.780, 1.0], // Tb - Green
+    [0.122, 1.000, 0.780, 1.0], // Dy - Green
+    [0.000, 1.000, 0.612, 1.0], // Ho - Green
+    [0.000, 0.902, 0.459, 1.0], // Er - Green
+    [0.000, 0.831, 0.322, 1.0], // Tm - Green
+    [0.000, 0.749, 0.220, 1.0], // Yb - Green
+    [0.000, 0.671, 0.141, 1.0], // Lu - Green
+    [0.302, 0.761, 1.000, 1.0], // Hf - Blue
+    [0.302, 0.651, 1.000, 1.0], // Ta - Blue
+    [0.129, 0.580, 0.839, 1.0], // W - Blue
+    [0.149, 0.490, 0.671, 1.0], // Re - Blue
+    [0.149, 0.400, 0.588, 1.0], // Os - Blue
+    [0.090, 0.329, 0.529, 1.0], // Ir - Blue
+    [0.816, 0.816, 0.878, 1.0], // Pt - Silver
+    [1.000, 0.820, 0.137, 1.0], // Au - Gold
+    [0.722, 0.722, 0.816, 1.0], // Hg - Blue gray
+    [0.651, 0.329, 0.302, 1.0], // Tl - Brown
+    [0.341, 0.349, 0.380, 1.0], // Pb - Gray
+    [0.620, 0.310, 0.710, 1.0], // Bi - Purple
+    [0.671, 0.361, 0.000, 1.0], // Po - Brown
+    [0.459, 0.310, 0.271, 1.0], // At - Brown
+    [0.259, 0.510, 0.588, 1.0], // Rn - Teal
+    [0.259, 0.000, 0.400, 1.0], // Fr - Purple
+    [0.000, 0.490, 0.000, 1.0], // Ra - Green
+    [0.439, 0.671, 0.980, 1.0], // Ac - Blue
+    [0.000, 0.729, 1.000, 1.0], // Th - Cyan
+    [0.000, 0.631, 1.000, 1.0], // Pa - Blue
+    [0.000, 0.561, 1.000, 1.0], // U - Blue
+    [0.000, 0.502, 1.000, 1.0], // Np - Blue
+    [0.000, 0.420, 1.000, 1.0], // Pu - Blue
+    [0.329, 0.361, 0.949, 1.0], // Am - Purple
+    [0.471, 0.361, 0.890, 1.0], // Cm - Purple
+    [0.541, 0.310, 0.890, 1.0], // Bk - Purple
+    [0.631, 0.212, 0.831, 1.0], // Cf - Purple
+    [0.702, 0.122, 0.831, 1.0], // Es - Purple
+    [0.702, 0.122, 0.729, 1.0], // Fm - Purple
+    [0.702, 0.051, 0.651, 1.0], // Md - Purple
+    [0.741, 0.051, 0.529, 1.0], // No - Purple
+    [0.780, 0.000, 0.400, 1.0], // Lr - Magenta
+    [0.800, 0.000, 0.349, 1.0], // Rf - Magenta
+    [0.820, 0.000, 0.310, 1.0], // Db - Magenta
+    [0.851, 0.000, 0.271, 1.0], // Sg - Magenta
+    [0.878, 0.000, 0.220, 1.0], // Bh - Magenta
+    [0.902, 0.000, 0.180, 1.0], // Hs - Magenta
+    [0.922, 0.000, 0.149, 1.0], // Mt - Magenta
+    // Elements 110-118: Catppuccin-compatible gradient continuation
+    [0.945, 0.000, 0.125, 1.0], // Ds - Red/magenta
+    [0.965, 0.000, 0.102, 1.0], // Rg - Red
+    [0.980, 0.000, 0.082, 1.0], // Cn - Red
+    [0.996, 0.106, 0.106, 1.0], // Nh - Red
+    [0.996, 0.184, 0.184, 1.0], // Fl - Light red
+    [0.996, 0.243, 0.259, 1.0], // Mc - Pink red
+    [0.996, 0.302, 0.333, 1.0], // Lv - Pink
+    [0.996, 0.361, 0.408, 1.0], // Ts - Pink
+    [0.996, 0.420, 0.482, 1.0], // Og - Light pink
+];
+
+/// Element colors and shell sizes for the nucleus renderer
+#[derive(Debug, Clone, PartialEq)]
+pub struct NucleusPalette {
+    /// Shell color (rgb) and radius multiplier (a) by atomic number; index 0 is used for
+    /// atomic numbers past the table
+    pub entries: [[f32; 4]; NUCLEUS_PALETTE_SIZE],
+    /// Shell radius of a single nucleon; nuclei scale it by A^(1/3)
+    pub radius_scale: f32,
+    /// 0 = simulation bounding radius, 1 = A^(1/3) radius (steady, doesn't breathe with the
+    /// nucleons' motion)
+    pub size_blend: f32,
+}
+
+impl Default for NucleusPalette {
+    fn default() -> Self {
+        Self {
+            entries: CPK_PALETTE,
+            // Roughly the bounding radius of a relaxed He-4 at A = 4
+            radius_scale: 1.75,
+            size_blend: 1.0,
+        }
+    }
+}
+
+/// Palette as uploaded (matches WGSL `NucleusPalette`)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct NucleusPaletteUniform {
+    entries: [[f32; 4]; NUCLEUS_PALETTE_SIZE],
+    /// x = radius_scale, y = size_blend
+    params: [f32; 4],
+}
+
+impl From<&NucleusPalette> for NucleusPaletteUniform {
+    fn from(palette: &NucleusPalette) -> Self {
+        Self {
+            entries: palette.entries,
+            params: [palette.radius_scale, palette.size_blend, 0.0, 0.0],
+        }
+    }
+}
+
 pub struct NucleusRenderer {
     shell_pipeline: wgpu::RenderPipeline,
+    /// Shells into the OIT accumulation pass (see `OitRenderer`)
+    oit_shell_pipeline: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
+    palette_buffer: wgpu::Buffer,
+    palette: NucleusPalette,
 }
 
 impl NucleusRenderer {
@@ -8,6 +193,8 @@ impl NucleusRenderer {
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
         _camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        cache: Option<&wgpu::PipelineCache>,
     ) -> Self {
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Nucleus Renderer Shader"),
@@ -58,6 +245,17 @@ impl NucleusRenderer {
                     },
                     count: None,
                 },
+                // Palette (Uniform) - Binding 3
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -103,17 +301,84 @@ impl NucleusRenderer {
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
+            multiview_mask: None,
+            cache,
+        });
+
+        // OIT shell pipeline (same quads into the accumulation targets)
+        let oit_shell_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Nucleus OIT Shell Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_shell"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_shell_oit"),
+                targets: &OitRenderer::shell_targets(),
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                unclipped_depth: false,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: false, // Order-independent: shells never occlude each other
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
             multiview_mask: None,
-            cache: None,
+            cache,
+        });
+
+        let palette = NucleusPalette::default();
+        let palette_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Nucleus Palette Buffer"),
+            contents: bytemuck::bytes_of(&NucleusPaletteUniform::from(&palette)),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
         Self {
             shell_pipeline,
+            oit_shell_pipeline,
             bind_group_layout,
+            palette_buffer,
+            palette,
         }
     }
 
+    pub fn palette(&self) -> &NucleusPalette {
+        &self.palette
+    }
+
+    /// Replace the element colors and shell sizing (takes effect with the next draw).
+    pub fn set_palette(&mut self, queue: &wgpu::Queue, palette: NucleusPalette) {
+        queue.write_buffer(
+            &self.palette_buffer,
+            0,
+            bytemuck::bytes_of(&NucleusPaletteUniform::from(&palette)),
+        );
+        self.palette = palette;
+    }
+
     pub fn render(
         &self,
         device: &wgpu::Device,
@@ -129,7 +394,45 @@ impl NucleusRenderer {
         }
 
         // Create bind group for this frame
-        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        let bind_group =
+            self.bind_group(device, camera_buffer, nucleus_buffer, nucleus_count_buffer);
+
+        render_pass.set_pipeline(&self.shell_pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+
+        // Each nucleus shell is rendered as a quad (6 vertices)
+        render_pass.draw(0..6, 0..max_nuclei);
+    }
+
+    /// Draw shells into the OIT accumulation pass (from `OitRenderer::begin_accumulate`).
+    pub fn render_shells_oit(
+        &self,
+        device: &wgpu::Device,
+        render_pass: &mut wgpu::RenderPass,
+        camera_buffer: &wgpu::Buffer,
+        nucleus_buffer: &wgpu::Buffer,
+        nucleus_count_buffer: &wgpu::Buffer,
+        max_nuclei: u32,
+    ) {
+        if max_nuclei == 0 {
+            return;
+        }
+
+        let bind_group =
+            self.bind_group(device, camera_buffer, nucleus_buffer, nucleus_count_buffer);
+        render_pass.set_pipeline(&self.oit_shell_pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..6, 0..max_nuclei);
+    }
+
+    fn bind_group(
+        &self,
+        device: &wgpu::Device,
+        camera_buffer: &wgpu::Buffer,
+        nucleus_buffer: &wgpu::Buffer,
+        nucleus_count_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Nucleus Render Bind Group"),
             layout: &self.bind_group_layout,
             entries: &[
@@ -145,14 +448,12 @@ impl NucleusRenderer {
                     binding: 2,
                     resource: nucleus_count_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: self.palette_buffer.as_entire_binding(),
+                },
             ],
-        });
-
-        render_pass.set_pipeline(&self.shell_pipeline);
-        render_pass.set_bind_group(0, &bind_group, &[]);
-
-        // Each nucleus shell is rendered as a quad (6 vertices)
-        render_pass.draw(0..6, 0..max_nuclei);
+        })
     }
 
     pub fn bind_group_layout(&self) -> &wgpu::BindGroupLayout {
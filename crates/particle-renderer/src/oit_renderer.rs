@@ -0,0 +1,298 @@
+//! Weighted blended order-independent transparency for hadron and nucleus shells
+//!
+//! Translucent shells overlap in arbitrary order, which plain alpha blending can't composite
+//! correctly. With OIT the shells render into an accumulation target (weighted premultiplied
+//! color, additive) and a revealage target (product of `1 - alpha`) instead of the scene, and a
+//! fullscreen composite blends the weighted average over the scene. The result is independent
+//! of draw order, at the cost of only approximating the front-to-back ordering through a
+//! distance weight.
+
+use crate::bloom_renderer::HDR_FORMAT;
+
+/// Format of the revealage target (product of `1 - alpha` over all shells)
+pub const OIT_REVEAL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R16Float;
+
+/// Size-dependent resources, rebuilt on resize
+struct OitTargets {
+    /// Accumulation attachment (multisampled with MSAA)
+    accum_view: wgpu::TextureView,
+    /// Single-sample accumulation the composite reads (None without MSAA: `accum_view` is it)
+    accum_resolve_view: Option<wgpu::TextureView>,
+    reveal_view: wgpu::TextureView,
+    reveal_resolve_view: Option<wgpu::TextureView>,
+    composite_bind_group: wgpu::BindGroup,
+}
+
+pub struct OitRenderer {
+    composite_layout: wgpu::BindGroupLayout,
+    composite_pipeline: wgpu::RenderPipeline,
+    sample_count: u32,
+    targets: OitTargets,
+}
+
+impl OitRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        surface_config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("OIT Composite Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/oit.wgsl").into()),
+        });
+
+        let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let composite_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("OIT Composite Bind Group Layout"),
+            entries: &[texture_entry(0), texture_entry(1)],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("OIT Composite Pipeline Layout"),
+            bind_group_layouts: &[&composite_layout],
+            immediate_size: 0,
+        });
+
+        // Drawn inside a scene pass, so it matches the scene's sample count and depth target
+        let composite_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("OIT Composite Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_fullscreen"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_composite"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: HDR_FORMAT,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::Always,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
+            multiview_mask: None,
+            cache,
+        });
+
+        let targets = Self::create_targets(device, surface_config, sample_count, &composite_layout);
+
+        Self {
+            composite_layout,
+            composite_pipeline,
+            sample_count,
+            targets,
+        }
+    }
+
+    fn create_targets(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
+        composite_layout: &wgpu::BindGroupLayout,
+    ) -> OitTargets {
+        let create_view = |label, format, sample_count, usage| {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width: config.width,
+                    height: config.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage,
+                view_formats: &[],
+            });
+            texture.create_view(&wgpu::TextureViewDescriptor::default())
+        };
+        let sampled = wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING;
+
+        let (accum_view, accum_resolve_view, reveal_view, reveal_resolve_view) = if sample_count > 1
+        {
+            let attachment = wgpu::TextureUsages::RENDER_ATTACHMENT;
+            (
+                create_view(
+                    "OIT Accum MSAA Texture",
+                    HDR_FORMAT,
+                    sample_count,
+                    attachment,
+                ),
+                Some(create_view("OIT Accum Texture", HDR_FORMAT, 1, sampled)),
+                create_view(
+                    "OIT Reveal MSAA Texture",
+                    OIT_REVEAL_FORMAT,
+                    sample_count,
+                    attachment,
+                ),
+                Some(create_view(
+                    "OIT Reveal Texture",
+                    OIT_REVEAL_FORMAT,
+                    1,
+                    sampled,
+                )),
+            )
+        } else {
+            (
+                create_view("OIT Accum Texture", HDR_FORMAT, 1, sampled),
+                None,
+                create_view("OIT Reveal Texture", OIT_REVEAL_FORMAT, 1, sampled),
+                None,
+            )
+        };
+
+        let composite_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("OIT Composite Bind Group"),
+            layout: composite_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(
+                        accum_resolve_view.as_ref().unwrap_or(&accum_view),
+                    ),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(
+                        reveal_resolve_view.as_ref().unwrap_or(&reveal_view),
+                    ),
+                },
+            ],
+        });
+
+        OitTargets {
+            accum_view,
+            accum_resolve_view,
+            reveal_view,
+            reveal_resolve_view,
+            composite_bind_group,
+        }
+    }
+
+    pub fn resize(&mut self, device: &wgpu::Device, new_config: &wgpu::SurfaceConfiguration) {
+        self.targets = Self::create_targets(
+            device,
+            new_config,
+            self.sample_count,
+            &self.composite_layout,
+        );
+    }
+
+    /// Begin the shell accumulation pass (clears both targets, depth-tests against the scene
+    /// without writing). Draw shells with their OIT pipelines into it.
+    pub fn begin_accumulate<'encoder>(
+        &self,
+        encoder: &'encoder mut wgpu::CommandEncoder,
+        depth_view: &wgpu::TextureView,
+    ) -> wgpu::RenderPass<'encoder> {
+        // The multisampled attachments only feed their resolve targets
+        let store = if self.sample_count > 1 {
+            wgpu::StoreOp::Discard
+        } else {
+            wgpu::StoreOp::Store
+        };
+
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("OIT Shell Pass"),
+            color_attachments: &[
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &self.targets.accum_view,
+                    resolve_target: self.targets.accum_resolve_view.as_ref(),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store,
+                    },
+                    depth_slice: None,
+                }),
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &self.targets.reveal_view,
+                    resolve_target: self.targets.reveal_resolve_view.as_ref(),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                        store,
+                    },
+                    depth_slice: None,
+                }),
+            ],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        })
+    }
+
+    /// Blend the accumulated shells over the scene (call inside a scene pass, after the
+    /// accumulation pass has ended).
+    pub fn composite(&self, render_pass: &mut wgpu::RenderPass) {
+        render_pass.set_pipeline(&self.composite_pipeline);
+        render_pass.set_bind_group(0, &self.targets.composite_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+
+    /// Color targets for shell pipelines that render into the accumulation pass.
+    pub fn shell_targets() -> [Option<wgpu::ColorTargetState>; 2] {
+        let additive = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::One,
+            operation: wgpu::BlendOperation::Add,
+        };
+        // dst * (1 - alpha)
+        let reveal = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::Zero,
+            dst_factor: wgpu::BlendFactor::OneMinusSrc,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        [
+            Some(wgpu::ColorTargetState {
+                format: HDR_FORMAT,
+                blend: Some(wgpu::BlendState {
+                    color: additive,
+                    alpha: additive,
+                }),
+                write_mask: wgpu::ColorWrites::ALL,
+            }),
+            Some(wgpu::ColorTargetState {
+                format: OIT_REVEAL_FORMAT,
+                blend: Some(wgpu::BlendState {
+                    color: reveal,
+                    alpha: reveal,
+                }),
+                write_mask: wgpu::ColorWrites::ALL,
+            }),
+        ]
+    }
+}
@@ -13,17 +13,53 @@
 //! - (particle_index + 1)       => particle
 //! - 0x8000_0000 | (hadron_index + 1)  => hadron
 //! - 0x4000_0000 | (anchor_hadron_index + 1) => nucleus
+//!
+//! Hover picking reuses the same ID texture: while the cursor moves, the app renders a
+//! scissored pick pass around the cursor every `HOVER_PICK_INTERVAL` frames and reads the
+//! pixel back asynchronously (no device stall), see `GpuPicker::hover_pick_due`. The same
+//! readback can carry a copy of the hovered entity's GPU record for tooltips.
+//!
+//! Region picking (box select) copies a whole rectangle of the ID texture and returns the
+//! deduplicated IDs inside it, see `GpuPicker::select_region`.
+//!
+//! `ray::CpuPicker` is a CPU fallback that intersects a camera ray with a readback snapshot
+//! instead of rendering IDs.
 
+pub mod ray;
 pub mod renderer;
 
+pub use ray::{CpuPicker, PickRay};
 pub use renderer::PickingRenderer;
 
 // Picking overlay visualization removed from the public API (debug-only; keep internal as needed).
 // pub mod overlay;
 // pub use overlay::PickingOverlay;
 
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+
 use wgpu::util::DeviceExt;
 
+/// Frames between hover picks while the cursor is moving.
+pub const HOVER_PICK_INTERVAL: u32 = 4;
+
+/// Frames between hover picks while the cursor rests (entities still move underneath it).
+pub const HOVER_IDLE_INTERVAL: u32 = 30;
+
+/// Half-size in pixels of the scissor rect rendered by a hover pick.
+pub const HOVER_PICK_RADIUS: u32 = 8;
+
+/// Maximum size in bytes of the entity record copied along with a hover pick.
+pub const HOVER_RECORD_SIZE: u64 = 64;
+
+// The record sits after the 256-byte aligned pixel row in the hover staging buffer
+const HOVER_RECORD_OFFSET: u64 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as u64;
+
+// Hover readback states (written by the `map_async` callback)
+const HOVER_PENDING: u8 = 0;
+const HOVER_MAPPED: u8 = 1;
+const HOVER_FAILED: u8 = 2;
+
 /// Result of a pick, as returned by the GPU readback.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PickResult {
@@ -47,6 +83,26 @@ pub struct GpuPicker {
     /// Buffer used to copy the ID pixel into CPU-visible memory.
     staging: wgpu::Buffer,
 
+    /// Hover readback (separate from `staging` so a click never waits on a hover map).
+    hover_staging: wgpu::Buffer,
+    /// Set by the `map_async` callback (`HOVER_MAPPED` once `hover_staging` can be read).
+    hover_map_state: Arc<AtomicU8>,
+    hover_in_flight: bool,
+    /// Cursor in pick-target pixels (None = outside the scene or over the UI).
+    hover_cursor: Option<(u32, u32)>,
+    hover_moved: bool,
+    hover_frames: u32,
+    hover: PickResult,
+    /// Entity record copied with the last hover pick, and the ID it belongs to.
+    hover_record: [u8; HOVER_RECORD_SIZE as usize],
+    hover_record_id: u32,
+    /// ID whose record the in-flight readback carries (0 = none).
+    hover_record_pending: u32,
+
+    /// Region readback (grown on demand) and the `[x, y, width, height]` it last copied.
+    region_staging: Option<wgpu::Buffer>,
+    region: [u32; 4],
+
     /// Dimensions of the pick target. Kept flexible for future (e.g. NxN region).
     width: u32,
     height: u32,
@@ -104,11 +160,28 @@ impl GpuPicker {
             contents: &[0u8; wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as usize],
             usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
         });
+        let hover_staging = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Picking Hover Readback Buffer"),
+            contents: &[0u8; (HOVER_RECORD_OFFSET + HOVER_RECORD_SIZE) as usize],
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        });
 
         Self {
             id_texture_view,
             id_texture,
             staging,
+            hover_staging,
+            hover_map_state: Arc::new(AtomicU8::new(HOVER_PENDING)),
+            hover_in_flight: false,
+            hover_cursor: None,
+            hover_moved: false,
+            hover_frames: 0,
+            hover: PickResult { id: 0 },
+            hover_record: [0; HOVER_RECORD_SIZE as usize],
+            hover_record_id: 0,
+            hover_record_pending: 0,
+            region_staging: None,
+            region: [0; 4],
             width,
             height,
             format,
@@ -154,6 +227,16 @@ impl GpuPicker {
     /// The render pass that writes to `id_texture` must be submitted before this copy
     /// if you expect deterministic results.
     pub fn encode_read_pixel(&self, encoder: &mut wgpu::CommandEncoder, x: u32, y: u32) {
+        self.encode_copy_pixel(encoder, &self.staging, x, y);
+    }
+
+    fn encode_copy_pixel(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        buffer: &wgpu::Buffer,
+        x: u32,
+        y: u32,
+    ) {
         let x = x.min(self.width.saturating_sub(1));
 
         let y = y.min(self.height.saturating_sub(1));
@@ -167,7 +250,7 @@ impl GpuPicker {
                 aspect: wgpu::TextureAspect::All,
             },
             wgpu::TexelCopyBufferInfo {
-                buffer: &self.staging,
+                buffer,
                 layout: wgpu::TexelCopyBufferLayout {
                     offset: 0,
                     bytes_per_row: Some(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT),
@@ -199,7 +282,13 @@ impl GpuPicker {
         let slice = self.staging.slice(..);
         let data = slice.get_mapped_range();
 
-        let id = match self.format {
+        PickResult {
+            id: self.decode_id(&data),
+        }
+    }
+
+    fn decode_id(&self, data: &[u8]) -> u32 {
+        match self.format {
             wgpu::TextureFormat::R32Uint => {
                 // 4 bytes: u32
                 u32::from_le_bytes(data[0..4].try_into().unwrap())
@@ -213,9 +302,262 @@ impl GpuPicker {
                 let a = data[3] as u32;
                 r | (g << 8) | (b << 16) | (a << 24)
             }
+        }
+    }
+
+    /// Update the hover cursor (pick-target pixels), or clear it when the pointer leaves the
+    /// scene. Clearing also drops the current hover result.
+    pub fn set_hover_cursor(&mut self, cursor: Option<(u32, u32)>) {
+        if cursor.is_none() {
+            self.hover = PickResult { id: 0 };
+        }
+        self.hover_moved |= cursor != self.hover_cursor;
+        self.hover_cursor = cursor;
+    }
+
+    /// Call once per frame. Returns the cursor pixel when a hover pick should be rendered this
+    /// frame: every `HOVER_PICK_INTERVAL` frames while the cursor moves, every
+    /// `HOVER_IDLE_INTERVAL` frames while it rests, and never while a readback is in flight.
+    pub fn hover_pick_due(&mut self) -> Option<(u32, u32)> {
+        self.hover_frames = self.hover_frames.saturating_add(1);
+        let cursor = self.hover_cursor?;
+        if self.hover_in_flight {
+            return None;
+        }
+
+        let interval = if self.hover_moved {
+            HOVER_PICK_INTERVAL
+        } else {
+            HOVER_IDLE_INTERVAL
+        };
+        if self.hover_frames < interval {
+            return None;
+        }
+        self.hover_frames = 0;
+        self.hover_moved = false;
+        Some(cursor)
+    }
+
+    /// Scissor rect (`[x, y, width, height]`) covering the hover pick around `(x, y)`.
+    pub fn hover_scissor(&self, x: u32, y: u32) -> [u32; 4] {
+        let x0 = x.saturating_sub(HOVER_PICK_RADIUS).min(self.width - 1);
+        let y0 = y.saturating_sub(HOVER_PICK_RADIUS).min(self.height - 1);
+        let x1 = (x + HOVER_PICK_RADIUS + 1).min(self.width);
+        let y1 = (y + HOVER_PICK_RADIUS + 1).min(self.height);
+        [x0, y0, x1 - x0, y1 - y0]
+    }
+
+    /// Copy the hover pixel into the hover staging buffer. Submit the encoder, then call
+    /// `begin_hover_readback`.
+    ///
+    /// `record` optionally copies `(buffer, offset, size)` bytes (at most `HOVER_RECORD_SIZE`)
+    /// describing the currently hovered entity, e.g. its particle struct, so the app can show a
+    /// tooltip once the readback lands. It is tagged with the current hover ID, so it trails the
+    /// pick by one readback.
+    pub fn encode_read_hover(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        x: u32,
+        y: u32,
+        record: Option<(&wgpu::Buffer, u64, u64)>,
+    ) {
+        self.encode_copy_pixel(encoder, &self.hover_staging, x, y);
+
+        self.hover_record_pending = 0;
+        if let Some((buffer, offset, size)) = record {
+            let size = size.min(HOVER_RECORD_SIZE);
+            if self.hover.is_hit() && offset + size <= buffer.size() {
+                encoder.copy_buffer_to_buffer(
+                    buffer,
+                    offset,
+                    &self.hover_staging,
+                    HOVER_RECORD_OFFSET,
+                    size,
+                );
+                self.hover_record_pending = self.hover.id;
+            }
+        }
+    }
+
+    /// Start mapping the hover staging buffer without waiting for it.
+    pub fn begin_hover_readback(&mut self) {
+        let state = self.hover_map_state.clone();
+        state.store(HOVER_PENDING, Ordering::Release);
+        self.hover_staging
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let done = if result.is_ok() {
+                    HOVER_MAPPED
+                } else {
+                    HOVER_FAILED
+                };
+                state.store(done, Ordering::Release);
+            });
+        self.hover_in_flight = true;
+    }
+
+    /// True while a hover readback is waiting for the GPU (keep polling the device).
+    pub fn hover_in_flight(&self) -> bool {
+        self.hover_in_flight
+    }
+
+    /// Finish a completed hover readback. Returns the new hover result once, when it lands.
+    pub fn poll_hover(&mut self) -> Option<PickResult> {
+        if !self.hover_in_flight {
+            return None;
+        }
+        match self.hover_map_state.swap(HOVER_PENDING, Ordering::Acquire) {
+            HOVER_MAPPED => {}
+            HOVER_FAILED => {
+                self.hover_in_flight = false;
+                return None;
+            }
+            _ => return None,
+        }
+
+        let id = {
+            let data = self.hover_staging.slice(..).get_mapped_range();
+            if self.hover_record_pending != 0 {
+                let start = HOVER_RECORD_OFFSET as usize;
+                self.hover_record
+                    .copy_from_slice(&data[start..start + HOVER_RECORD_SIZE as usize]);
+                self.hover_record_id = self.hover_record_pending;
+            }
+            self.decode_id(&data)
+        };
+        self.hover_staging.unmap();
+        self.hover_in_flight = false;
+
+        // The cursor may have left the scene while the readback was in flight
+        if self.hover_cursor.is_some() {
+            if id != self.hover.id {
+                // Fetch the new entity's record on the next (fast) pick
+                self.hover_moved = true;
+            }
+            self.hover = PickResult { id };
+        }
+        Some(self.hover)
+    }
+
+    /// The most recent hover result (id 0 = nothing under the cursor).
+    pub fn hover(&self) -> PickResult {
+        self.hover
+    }
+
+    /// Record bytes for the current hover ID, once a readback carrying them has landed.
+    pub fn hover_record(&self) -> Option<&[u8]> {
+        (self.hover.is_hit() && self.hover_record_id == self.hover.id)
+            .then_some(&self.hover_record[..])
+    }
+
+    /// Read back every entity inside the rectangle spanned by the corners `a` and `b`
+    /// (inclusive, clamped to the target) as sorted, deduplicated non-zero IDs.
+    ///
+    /// `encoder` must already hold the ID pass over the full target (no scissor); it is
+    /// submitted here and the readback blocks until the GPU is done. Box selects are rare user
+    /// actions, so that is fine.
+    pub fn select_region(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        mut encoder: wgpu::CommandEncoder,
+        a: (u32, u32),
+        b: (u32, u32),
+    ) -> Vec<u32> {
+        self.encode_read_region(device, &mut encoder, a, b);
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let Some(buffer) = &self.region_staging else {
+            return Vec::new();
+        };
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device
+            .poll(wgpu::PollType::Wait {
+                submission_index: None,
+                timeout: None,
+            })
+            .unwrap();
+        let ids = self.read_region_mapped();
+        buffer.unmap();
+        ids
+    }
+
+    /// Copy the ID texture pixels inside the rectangle spanned by `a` and `b` into the region
+    /// staging buffer.
+    fn encode_read_region(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        a: (u32, u32),
+        b: (u32, u32),
+    ) {
+        let x0 = a.0.min(b.0).min(self.width - 1);
+        let y0 = a.1.min(b.1).min(self.height - 1);
+        let x1 = a.0.max(b.0).min(self.width - 1);
+        let y1 = a.1.max(b.1).min(self.height - 1);
+        let (w, h) = (x1 - x0 + 1, y1 - y0 + 1);
+        self.region = [x0, y0, w, h];
+
+        let bytes_per_row = Self::region_bytes_per_row(w);
+        let size = bytes_per_row as u64 * h as u64;
+        if self.region_staging.as_ref().is_none_or(|b| b.size() < size) {
+            self.region_staging = Some(device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Picking Region Readback Buffer"),
+                size,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            }));
+        }
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.id_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x: x0, y: y0, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: self.region_staging.as_ref().unwrap(),
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(h),
+                },
+            },
+            wgpu::Extent3d {
+                width: w,
+                height: h,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
+    // 4 bytes per pixel, rows padded to `COPY_BYTES_PER_ROW_ALIGNMENT`
+    fn region_bytes_per_row(width: u32) -> u32 {
+        (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+    }
+
+    /// Decode the mapped region buffer into the sorted, deduplicated non-zero IDs it contains.
+    fn read_region_mapped(&self) -> Vec<u32> {
+        let Some(buffer) = &self.region_staging else {
+            return Vec::new();
         };
+        let [_, _, w, h] = self.region;
+        let bytes_per_row = Self::region_bytes_per_row(w) as usize;
 
-        PickResult { id }
+        let data = buffer.slice(..).get_mapped_range();
+        let mut ids: Vec<u32> = (0..h as usize)
+            .flat_map(|row| {
+                let start = row * bytes_per_row;
+                data[start..start + w as usize * 4].chunks_exact(4)
+            })
+            .map(|pixel| self.decode_id(pixel))
+            .filter(|&id| id != 0)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
     }
 
     /// Access the staging buffer for mapping control (caller-driven).
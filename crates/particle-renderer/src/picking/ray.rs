@@ -0,0 +1,184 @@
+//! CPU pick-ray fallback.
+//!
+//! Instead of rendering the ID pass, read back a snapshot of the particle and hadron buffers
+//! once and intersect a camera ray with their bounding spheres on the CPU. Useful when the
+//! extra render pass is too costly or the ID target format is unsupported. Returns IDs in the
+//! same encoding as the GPU path (particles and hadrons; nuclei are reached via their hadrons).
+
+use glam::{Vec2, Vec3};
+use particle_physics::{Hadron, Particle, PARTICLE_FLAG_DORMANT};
+
+use super::PickResult;
+use crate::camera::Camera;
+
+/// World-space ray through a pixel.
+#[derive(Debug, Clone, Copy)]
+pub struct PickRay {
+    pub origin: Vec3,
+    /// Unit direction
+    pub direction: Vec3,
+}
+
+impl PickRay {
+    /// Ray from the near plane through pixel `(x, y)` of a `width` x `height` target.
+    pub fn from_pixel(camera: &Camera, x: f32, y: f32, width: u32, height: u32) -> Self {
+        // Pixel centers, y down -> NDC, y up
+        let ndc = Vec2::new(
+            (x + 0.5) / width.max(1) as f32 * 2.0 - 1.0,
+            1.0 - (y + 0.5) / height.max(1) as f32 * 2.0,
+        );
+        let inverse = camera.build_view_projection_matrix().inverse();
+        let near = inverse.project_point3(ndc.extend(0.0));
+        let far = inverse.project_point3(ndc.extend(1.0));
+
+        Self {
+            origin: near,
+            direction: (far - near).normalize(),
+        }
+    }
+
+    /// Distance along the ray to the first intersection with a sphere (None if missed or the
+    /// sphere is behind the ray).
+    pub fn intersect_sphere(&self, center: Vec3, radius: f32) -> Option<f32> {
+        let to_center = center - self.origin;
+        let along = to_center.dot(self.direction);
+        let miss_sq = to_center.length_squared() - along * along;
+        let radius_sq = radius * radius;
+        if miss_sq > radius_sq {
+            return None;
+        }
+
+        let half_chord = (radius_sq - miss_sq).sqrt();
+        let t = if along - half_chord >= 0.0 {
+            along - half_chord
+        } else {
+            along + half_chord
+        };
+        (t >= 0.0).then_some(t)
+    }
+}
+
+/// CPU picker over a readback snapshot of the simulation buffers.
+pub struct CpuPicker {
+    particle_staging: wgpu::Buffer,
+    hadron_staging: wgpu::Buffer,
+    hadron_count_staging: wgpu::Buffer,
+
+    particles: Vec<Particle>,
+    hadrons: Vec<Hadron>,
+}
+
+impl CpuPicker {
+    /// Create staging buffers matching the simulation's particle and hadron buffer sizes.
+    pub fn new(device: &wgpu::Device, particle_buffer_size: u64, hadron_buffer_size: u64) -> Self {
+        let staging = |label: &str, size: u64| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            })
+        };
+
+        Self {
+            particle_staging: staging("CPU Picking Particle Snapshot", particle_buffer_size),
+            hadron_staging: staging("CPU Picking Hadron Snapshot", hadron_buffer_size),
+            // Counter buffer: 4x u32, [0] = total hadrons
+            hadron_count_staging: staging("CPU Picking Hadron Count Snapshot", 16),
+            particles: Vec::new(),
+            hadrons: Vec::new(),
+        }
+    }
+
+    /// Read back the current particle/hadron state (blocking; picks are rare user actions).
+    pub fn snapshot(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        particle_buffer: &wgpu::Buffer,
+        hadron_buffer: &wgpu::Buffer,
+        hadron_count_buffer: &wgpu::Buffer,
+    ) {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("CPU Picking Snapshot Encoder"),
+        });
+        for (source, staging) in [
+            (particle_buffer, &self.particle_staging),
+            (hadron_buffer, &self.hadron_staging),
+            (hadron_count_buffer, &self.hadron_count_staging),
+        ] {
+            let size = source.size().min(staging.size());
+            encoder.copy_buffer_to_buffer(source, 0, staging, 0, size);
+        }
+        queue.submit(std::iter::once(encoder.finish()));
+
+        for staging in [
+            &self.particle_staging,
+            &self.hadron_staging,
+            &self.hadron_count_staging,
+        ] {
+            staging.slice(..).map_async(wgpu::MapMode::Read, |_| {});
+        }
+        device
+            .poll(wgpu::PollType::Wait {
+                submission_index: None,
+                timeout: None,
+            })
+            .unwrap();
+
+        {
+            let data = self.particle_staging.slice(..).get_mapped_range();
+            self.particles = bytemuck::cast_slice(&data).to_vec();
+        }
+        let hadron_count = {
+            let data = self.hadron_count_staging.slice(..).get_mapped_range();
+            u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize
+        };
+        {
+            let data = self.hadron_staging.slice(..).get_mapped_range();
+            let hadrons: &[Hadron] = bytemuck::cast_slice(&data);
+            self.hadrons = hadrons[..hadron_count.min(hadrons.len())].to_vec();
+        }
+
+        self.particle_staging.unmap();
+        self.hadron_staging.unmap();
+        self.hadron_count_staging.unmap();
+    }
+
+    /// Nearest particle or hadron hit by `ray` in the last snapshot.
+    ///
+    /// Particles are spheres of `particle_size * data.y` (the billboard radius the renderer
+    /// draws), hadrons spheres of their shell radius.
+    pub fn pick(&self, ray: &PickRay, particle_size: f32) -> PickResult {
+        let mut nearest = f32::INFINITY;
+        let mut id = 0;
+
+        for (i, p) in self.particles.iter().enumerate() {
+            if p.color_and_flags[1] & PARTICLE_FLAG_DORMANT != 0 {
+                continue;
+            }
+            let center = Vec3::from_slice(&p.position[..3]);
+            if let Some(t) = ray.intersect_sphere(center, particle_size * p.data[1]) {
+                if t < nearest {
+                    nearest = t;
+                    id = i as u32 + 1;
+                }
+            }
+        }
+
+        for (i, h) in self.hadrons.iter().enumerate() {
+            if h.type_id == u32::MAX {
+                continue;
+            }
+            let center = Vec3::from_slice(&h.center[..3]);
+            if let Some(t) = ray.intersect_sphere(center, h.center[3]) {
+                if t < nearest {
+                    nearest = t;
+                    id = 0x8000_0000 | (i as u32 + 1);
+                }
+            }
+        }
+
+        PickResult { id }
+    }
+}
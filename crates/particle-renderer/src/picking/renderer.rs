@@ -17,6 +17,7 @@
 //! - The particle/hadron SSBO layouts match the existing WGSL shaders.
 
 use crate::camera::{Camera, CameraUniform};
+use crate::lod::LodSettings;
 
 /// Runs an offscreen picking pass producing packed IDs in RGBA8.
 pub struct PickingRenderer {
@@ -35,6 +36,10 @@ pub struct PickingRenderer {
     /// The surface width/height we currently size the depth buffer to.
     width: u32,
     height: u32,
+
+    /// Optional `[x, y, width, height]` scissor for subsequent passes (hover picks only need
+    /// the pixels around the cursor).
+    scissor: Option<[u32; 4]>,
 }
 
 impl PickingRenderer {
@@ -48,6 +53,7 @@ impl PickingRenderer {
         depth_format: wgpu::TextureFormat,
         width: u32,
         height: u32,
+        cache: Option<&wgpu::PipelineCache>,
     ) -> Self {
         let width = width.max(1);
         let height = height.max(1);
@@ -199,7 +205,7 @@ impl PickingRenderer {
             depth_stencil: depth_stencil.clone(),
             multisample: wgpu::MultisampleState::default(),
             multiview_mask: None,
-            cache: None,
+            cache,
         });
 
         let hadron_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
@@ -225,7 +231,7 @@ impl PickingRenderer {
             depth_stencil: depth_stencil.clone(),
             multisample: wgpu::MultisampleState::default(),
             multiview_mask: None,
-            cache: None,
+            cache,
         });
 
         let nucleus_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
@@ -251,7 +257,7 @@ impl PickingRenderer {
             depth_stencil,
             multisample: wgpu::MultisampleState::default(),
             multiview_mask: None,
-            cache: None,
+            cache,
         });
 
         Self {
@@ -264,9 +270,16 @@ impl PickingRenderer {
             camera_buffer,
             width,
             height,
+            scissor: None,
         }
     }
 
+    /// Restrict subsequent passes to `[x, y, width, height]` (clamped to the target), or
+    /// render the full target with `None`.
+    pub fn set_scissor(&mut self, scissor: Option<[u32; 4]>) {
+        self.scissor = scissor;
+    }
+
     /// Resize depth targets as needed (pick target itself is owned by `GpuPicker`).
     pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
         let width = width.max(1);
@@ -302,35 +315,13 @@ impl PickingRenderer {
         max_nuclei: u32,
         particle_size: f32,
         time: f32,
-        lod_shell_fade_start: f32,
-        lod_shell_fade_end: f32,
-        lod_bound_hadron_fade_start: f32,
-        lod_bound_hadron_fade_end: f32,
-        lod_bond_fade_start: f32,
-        lod_bond_fade_end: f32,
-        lod_quark_fade_start: f32,
-        lod_quark_fade_end: f32,
-        lod_nucleus_fade_start: f32,
-        lod_nucleus_fade_end: f32,
+        lod: &LodSettings,
     ) {
         // Update camera uniform. We reuse the same struct as regular rendering.
         queue.write_buffer(
             &self.camera_buffer,
             0,
-            bytemuck::cast_slice(&[camera.to_uniform(
-                particle_size,
-                time,
-                lod_shell_fade_start,
-                lod_shell_fade_end,
-                lod_bound_hadron_fade_start,
-                lod_bound_hadron_fade_end,
-                lod_bond_fade_start,
-                lod_bond_fade_end,
-                lod_quark_fade_start,
-                lod_quark_fade_end,
-                lod_nucleus_fade_start,
-                lod_nucleus_fade_end,
-            )]),
+            bytemuck::cast_slice(&[camera.to_uniform(particle_size, time, lod)]),
         );
 
         let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -398,6 +389,17 @@ impl PickingRenderer {
         // Depending on your desired UI, you may reverse this.
         pass.set_bind_group(0, &bind_group, &[]);
 
+        if let Some([x, y, w, h]) = self.scissor {
+            let x = x.min(self.width - 1);
+            let y = y.min(self.height - 1);
+            pass.set_scissor_rect(
+                x,
+                y,
+                w.clamp(1, self.width - x),
+                h.clamp(1, self.height - y),
+            );
+        }
+
         pass.set_pipeline(&self.particle_pipeline);
         pass.draw(0..6, 0..particle_count);
 
@@ -0,0 +1,76 @@
+//! Driver pipeline cache persisted between runs
+//!
+//! Turning the WGSL into driver pipelines takes seconds on the first launch with some drivers.
+//! Where the device supports `PIPELINE_CACHE` (Vulkan), the renderers' pipelines go through one
+//! `wgpu::PipelineCache` that is loaded from a file per adapter and driver, and written back
+//! once they exist, so later launches skip most of the compilation. Elsewhere there is no
+//! cache and pipelines are created as before.
+
+use std::path::{Path, PathBuf};
+
+/// Feature the cache needs (request it when the adapter has it)
+pub fn pipeline_cache_features() -> wgpu::Features {
+    wgpu::Features::PIPELINE_CACHE
+}
+
+pub struct PipelineCache {
+    cache: Option<wgpu::PipelineCache>,
+    /// File the cache was loaded from and is saved to
+    path: Option<PathBuf>,
+}
+
+impl PipelineCache {
+    /// Load the cache for this device's adapter from `dir`. Starts empty when there is no file
+    /// yet; data from another driver version is ignored by wgpu.
+    pub fn load(device: &wgpu::Device, adapter_info: &wgpu::AdapterInfo, dir: &Path) -> Self {
+        if !device.features().contains(pipeline_cache_features()) {
+            return Self {
+                cache: None,
+                path: None,
+            };
+        }
+
+        // The key names the adapter, driver and backend; `None` where caching is unsupported
+        let path = wgpu::util::pipeline_cache_key(adapter_info).map(|key| dir.join(key));
+        let data = path.as_ref().and_then(|path| std::fs::read(path).ok());
+        // SAFETY: the file only ever holds `get_data` output (see `save`) for an adapter with
+        // the same cache key, and `fallback` makes wgpu start empty on data it cannot use.
+        let cache = unsafe {
+            device.create_pipeline_cache(&wgpu::PipelineCacheDescriptor {
+                label: Some("Pipeline Cache"),
+                data: data.as_deref(),
+                fallback: true,
+            })
+        };
+
+        Self {
+            cache: Some(cache),
+            path,
+        }
+    }
+
+    /// Cache for the `cache` field of pipeline descriptors (`None` without support)
+    pub fn get(&self) -> Option<&wgpu::PipelineCache> {
+        self.cache.as_ref()
+    }
+
+    /// Write the cache, including the pipelines created since `load`, back to its file.
+    /// Returns the file written, or `None` without a cache.
+    pub fn save(&self) -> std::io::Result<Option<&Path>> {
+        let (Some(cache), Some(path)) = (&self.cache, &self.path) else {
+            return Ok(None);
+        };
+        let Some(data) = cache.get_data() else {
+            return Ok(None);
+        };
+
+        // Write next to it and rename, so an interrupted write never leaves a truncated cache
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let temp = path.with_extension("tmp");
+        std::fs::write(&temp, data)?;
+        std::fs::rename(&temp, path)?;
+        Ok(Some(path))
+    }
+}
@@ -0,0 +1,276 @@
+//! Frame sequence / video recorder
+//!
+//! [`Recording`] tone maps each rendered frame into its own target again and hands it over as
+//! tightly packed RGB rows (see [`CaptureTarget::read_rgb`](crate::CaptureTarget::read_rgb)); a
+//! worker thread encodes them so the render loop only pays for the readback. Output is a numbered PNG sequence, a raw y4m
+//! stream (4:4:4, plays in mpv/ffplay and imports into any editor), or the same y4m stream piped
+//! into an `ffmpeg` process that writes an H.264 mp4.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, SyncSender};
+use std::thread::JoinHandle;
+
+use crate::bloom_renderer::BloomRenderer;
+use crate::capture::{encode_png, CaptureTarget};
+
+/// Frames that can wait for the worker before `push_frame` blocks the render loop
+const QUEUE_FRAMES: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordingFormat {
+    /// `frame-000000.png`, ... in a directory
+    #[default]
+    PngSequence,
+    /// Raw YUV4MPEG2 stream
+    Y4m,
+    /// y4m piped into `ffmpeg` (must be on PATH), encoded to H.264 mp4
+    Ffmpeg,
+}
+
+impl RecordingFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            RecordingFormat::PngSequence => "PNG sequence",
+            RecordingFormat::Y4m => "y4m",
+            RecordingFormat::Ffmpeg => "ffmpeg (mp4)",
+        }
+    }
+}
+
+/// Where the worker writes frames
+enum Sink {
+    PngSequence(PathBuf),
+    Y4m(BufWriter<File>),
+    Ffmpeg(Child),
+}
+
+pub struct FrameRecorder {
+    sender: SyncSender<Vec<u8>>,
+    worker: JoinHandle<io::Result<()>>,
+    output: PathBuf,
+    width: u32,
+    height: u32,
+    frames: u32,
+}
+
+impl FrameRecorder {
+    /// Start recording `width` x `height` frames played back at `fps`. Output goes to
+    /// `dir/stem/` (PNG sequence), `dir/stem.y4m` or `dir/stem.mp4`.
+    pub fn start(
+        dir: &Path,
+        stem: &str,
+        format: RecordingFormat,
+        width: u32,
+        height: u32,
+        fps: u32,
+    ) -> io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+
+        let (output, sink) = match format {
+            RecordingFormat::PngSequence => {
+                let output = dir.join(stem);
+                std::fs::create_dir_all(&output)?;
+                (output.clone(), Sink::PngSequence(output))
+            }
+            RecordingFormat::Y4m => {
+                let output = dir.join(format!("{stem}.y4m"));
+                let mut writer = BufWriter::new(File::create(&output)?);
+                write_y4m_header(&mut writer, width, height, fps)?;
+                (output, Sink::Y4m(writer))
+            }
+            RecordingFormat::Ffmpeg => {
+                let output = dir.join(format!("{stem}.mp4"));
+                let mut child = Command::new("ffmpeg")
+                    .args(["-y", "-loglevel", "error", "-f", "yuv4mpegpipe", "-i", "-"])
+                    // yuv420p needs even dimensions
+                    .args(["-vf", "pad=ceil(iw/2)*2:ceil(ih/2)*2"])
+                    .args(["-c:v", "libx264", "-pix_fmt", "yuv420p"])
+                    .arg(&output)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::null())
+                    .spawn()?;
+                let stdin = child.stdin.as_mut().expect("ffmpeg stdin is piped");
+                write_y4m_header(stdin, width, height, fps)?;
+                (output, Sink::Ffmpeg(child))
+            }
+        };
+
+        let (sender, receiver) = mpsc::sync_channel::<Vec<u8>>(QUEUE_FRAMES);
+        let worker = std::thread::Builder::new()
+            .name("frame-recorder".into())
+            .spawn(move || {
+                let mut sink = sink;
+                let mut planes = Vec::new();
+                for (index, rgb) in receiver.into_iter().enumerate() {
+                    match &mut sink {
+                        Sink::PngSequence(dir) => std::fs::write(
+                            dir.join(format!("frame-{index:06}.png")),
+                            encode_png(width, height, &rgb),
+                        )?,
+                        Sink::Y4m(writer) => write_y4m_frame(writer, &rgb, &mut planes)?,
+                        Sink::Ffmpeg(child) => write_y4m_frame(
+                            child.stdin.as_mut().expect("ffmpeg stdin is piped"),
+                            &rgb,
+                            &mut planes,
+                        )?,
+                    }
+                }
+
+                // The sender is gone: flush and close the stream
+                match sink {
+                    Sink::PngSequence(_) => Ok(()),
+                    Sink::Y4m(mut writer) => writer.flush(),
+                    Sink::Ffmpeg(mut child) => {
+                        drop(child.stdin.take());
+                        let status = child.wait()?;
+                        if status.success() {
+                            Ok(())
+                        } else {
+                            Err(io::Error::other(format!("ffmpeg exited with {status}")))
+                        }
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            sender,
+            worker,
+            output,
+            width,
+            height,
+            frames: 0,
+        })
+    }
+
+    /// Queue one frame (`width * height * 3` RGB bytes). Blocks while the worker is
+    /// `QUEUE_FRAMES` behind; false once the worker has stopped on an error (see `finish`).
+    pub fn push_frame(&mut self, rgb: Vec<u8>) -> bool {
+        debug_assert_eq!(rgb.len(), self.width as usize * self.height as usize * 3);
+        let sent = self.sender.send(rgb).is_ok();
+        if sent {
+            self.frames += 1;
+        }
+        sent
+    }
+
+    /// Finish encoding the queued frames and close the output. Returns the frame count.
+    pub fn finish(self) -> io::Result<u32> {
+        drop(self.sender);
+        self.worker
+            .join()
+            .map_err(|_| io::Error::other("frame recorder thread panicked"))??;
+        Ok(self.frames)
+    }
+
+    /// Output directory (PNG sequence) or file
+    pub fn output(&self) -> &Path {
+        &self.output
+    }
+
+    /// Frames queued so far
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+/// A running recording: the recorder plus the offscreen target each frame is tone mapped into.
+/// Recordings have a fixed frame size.
+pub struct Recording {
+    recorder: FrameRecorder,
+    target: CaptureTarget,
+}
+
+impl Recording {
+    /// Start recording frames of the surface `config` size, see [`FrameRecorder::start`].
+    pub fn start(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        dir: &Path,
+        stem: &str,
+        format: RecordingFormat,
+        fps: u32,
+    ) -> io::Result<Self> {
+        let recorder = FrameRecorder::start(dir, stem, format, config.width, config.height, fps)?;
+        let target = CaptureTarget::new(device, config.width, config.height, config.format);
+        Ok(Self { recorder, target })
+    }
+
+    /// Run the bloom composite of the frame just drawn into the recording target again and hand
+    /// it to the worker thread (the readback itself blocks).
+    ///
+    /// Fails once the surface is no longer `width` x `height`, its format cannot be read back,
+    /// or the worker has stopped; `finish` the recording then.
+    pub fn record_frame(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        bloom: &BloomRenderer,
+        width: u32,
+        height: u32,
+    ) -> io::Result<()> {
+        if (self.target.width(), self.target.height()) != (width, height) {
+            return Err(io::Error::other("window resized"));
+        }
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Recording Encoder"),
+        });
+        bloom.render(&mut encoder, self.target.view());
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let rgb = self.target.read_rgb(device, queue).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::Unsupported, "unsupported surface format")
+        })?;
+        if self.recorder.push_frame(rgb) {
+            Ok(())
+        } else {
+            Err(io::Error::other("encoder stopped"))
+        }
+    }
+
+    /// See [`FrameRecorder::finish`].
+    pub fn finish(self) -> io::Result<u32> {
+        self.recorder.finish()
+    }
+
+    pub fn output(&self) -> &Path {
+        self.recorder.output()
+    }
+
+    pub fn frames(&self) -> u32 {
+        self.recorder.frames()
+    }
+}
+
+fn write_y4m_header(out: &mut impl Write, width: u32, height: u32, fps: u32) -> io::Result<()> {
+    writeln!(out, "YUV4MPEG2 W{width} H{height} F{fps}:1 Ip A1:1 C444")
+}
+
+/// One 4:4:4 frame: BT.601 limited-range Y, Cb and Cr planes from sRGB-encoded RGB.
+fn write_y4m_frame(out: &mut impl Write, rgb: &[u8], planes: &mut Vec<u8>) -> io::Result<()> {
+    let pixels = rgb.len() / 3;
+    planes.resize(pixels * 3, 0);
+    let (y_plane, chroma) = planes.split_at_mut(pixels);
+    let (cb_plane, cr_plane) = chroma.split_at_mut(pixels);
+
+    for (i, pixel) in rgb.chunks_exact(3).enumerate() {
+        let (r, g, b) = (pixel[0] as i32, pixel[1] as i32, pixel[2] as i32);
+        y_plane[i] = (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8;
+        cb_plane[i] = (((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8;
+        cr_plane[i] = (((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8;
+    }
+
+    out.write_all(b"FRAME\n")?;
+    out.write_all(planes)
+}
@@ -1,19 +1,114 @@
 //! Particle rendering system
 
+use bytemuck::{Pod, Zeroable};
+use glam::Vec3;
+use wgpu::util::DrawIndirectArgs;
+
+use crate::bloom_renderer::HDR_FORMAT;
 use crate::camera::{Camera, CameraUniform};
+use crate::gpu_profiler::GpuProfiler;
+use crate::lod::LodSettings;
+use crate::oit_renderer::OIT_REVEAL_FORMAT;
 
 const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
 
+/// Workgroup size of `cull.wgsl`
+const CULL_WORKGROUP_SIZE: u32 = 64;
+/// Billboards with a smaller on-screen radius (pixels) are culled: they'd cover under a pixel
+const CULL_MIN_PIXEL_RADIUS: f32 = 0.5;
+/// Edge length (pixels) of the screen tiles the density pass bins particles into
+const DENSITY_TILE_SIZE: u32 = 16;
+/// Size of one `DensityTile` in `cull.wgsl` (five u32)
+const DENSITY_TILE_BYTES: u64 = 5 * 4;
+
+/// Uniform for `cull.wgsl`
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct CullParams {
+    planes: [[f32; 4]; 6],
+    /// xyz = eye, w = pixels per world unit at unit distance
+    camera_position: [f32; 4],
+    particle_size: f32,
+    min_pixel_radius: f32,
+    particle_count: u32,
+    enabled: u32,
+    view_proj: [[f32; 4]; 4],
+    /// x, y = viewport, z = density tile size, w = splat threshold (0 = off)
+    density: [u32; 4],
+}
+
+/// MSAA sample counts the scene passes can use on this adapter (HDR color, depth and the OIT
+/// revealage target).
+///
+/// 1 and 4 are always allowed; other counts need `TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`
+/// in `device_features`.
+pub fn supported_sample_counts(
+    adapter: &wgpu::Adapter,
+    device_features: wgpu::Features,
+) -> Vec<u32> {
+    if !device_features.contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES) {
+        return vec![1, 4];
+    }
+    let color = adapter.get_texture_format_features(HDR_FORMAT).flags;
+    let depth = adapter.get_texture_format_features(DEPTH_FORMAT).flags;
+    let reveal = adapter.get_texture_format_features(OIT_REVEAL_FORMAT).flags;
+    [1, 2, 4, 8]
+        .into_iter()
+        .filter(|&count| {
+            color.sample_count_supported(count)
+                && depth.sample_count_supported(count)
+                && reveal.sample_count_supported(count)
+        })
+        .collect()
+}
+
 pub struct ParticleRenderer {
     render_pipeline: wgpu::RenderPipeline,
     pub camera_buffer: wgpu::Buffer,
     bind_group_layout: wgpu::BindGroupLayout,
     pub depth_texture: wgpu::TextureView,
+    /// Multisampled scene color target (None without MSAA), resolved into the HDR target
+    msaa_view: Option<wgpu::TextureView>,
+    sample_count: u32,
     surface_config: wgpu::SurfaceConfiguration,
+    /// Pick ID under the cursor, highlighted in the next `render`
+    hovered_id: u32,
+    /// HDR brightness boost per unit kinetic energy
+    glow_scale: f32,
+    /// Velocity of the rest frame (xyz) and simulation time per trail sample (w)
+    rest_frame: [f32; 4],
+
+    // GPU culling: a compute pass compacts the visible particle indices and the instance count
+    // of the indirect draw
+    cull_pipeline: wgpu::ComputePipeline,
+    cull_bind_group_layout: wgpu::BindGroupLayout,
+    cull_params_buffer: wgpu::Buffer,
+    visible_buffer: wgpu::Buffer,
+    visible_capacity: u32,
+    draw_args_buffer: wgpu::Buffer,
+    /// Skip off-screen and sub-pixel particles (off: every particle is drawn)
+    culling: bool,
+
+    // Density LOD: a compute pass bins small particles into screen tiles; tiles holding at
+    // least `density_threshold` particles are drawn as one aggregated splat instead
+    density_pipeline: wgpu::ComputePipeline,
+    splat_pipeline: wgpu::RenderPipeline,
+    splat_bind_group_layout: wgpu::BindGroupLayout,
+    density_tiles: wgpu::Buffer,
+    density_tile_count: u32,
+    /// Particles per tile from which a tile is aggregated (0 = off)
+    density_threshold: u32,
 }
 
 impl ParticleRenderer {
-    pub fn new(device: &wgpu::Device, surface_config: &wgpu::SurfaceConfiguration) -> Self {
+    /// `sample_count` (1 = no MSAA) must be supported for both `HDR_FORMAT` and the depth
+    /// format; every pipeline drawing into the scene passes must use the same count.
+    pub fn new(
+        device: &wgpu::Device,
+        surface_config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
         // Create camera buffer
         let camera_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Camera Buffer"),
@@ -28,7 +123,8 @@ impl ParticleRenderer {
         });
 
         // Create depth texture
-        let depth_texture = Self::create_depth_texture(device, surface_config);
+        let depth_texture = Self::create_depth_texture(device, surface_config, sample_count);
+        let msaa_view = Self::create_msaa_view(device, surface_config, sample_count);
 
         // Load shader
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
@@ -91,6 +187,39 @@ impl ParticleRenderer {
                     },
                     count: None,
                 },
+                // Quark color vectors (Storage) - Binding 4
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Selection set mask (Storage) - Binding 5
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Visible particle indices from the cull pass (Storage) - Binding 6
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -114,7 +243,8 @@ impl ParticleRenderer {
                 module: &shader,
                 entry_point: Some("fragment"),
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: surface_config.format,
+                    // Renders into the HDR scene target (see `BloomRenderer`)
+                    format: HDR_FORMAT,
                     blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
@@ -136,23 +266,253 @@ impl ParticleRenderer {
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
             multiview_mask: None,
-            cache: None,
+            cache,
+        });
+
+        // Culling compute pass
+        let cull_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Particle Cull Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/cull.wgsl").into()),
+        });
+
+        let storage_entry = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let cull_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Particle Cull Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    storage_entry(1, true),
+                    storage_entry(2, false),
+                    storage_entry(3, false),
+                    storage_entry(4, false),
+                    storage_entry(5, true),
+                ],
+            });
+
+        let cull_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Particle Cull Pipeline Layout"),
+            bind_group_layouts: &[&cull_bind_group_layout],
+            immediate_size: 0,
         });
 
+        let cull_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Particle Cull Pipeline"),
+            layout: Some(&cull_pipeline_layout),
+            module: &cull_shader,
+            entry_point: Some("cull_main"),
+            compilation_options: Default::default(),
+            cache,
+        });
+        let density_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Particle Density Pipeline"),
+            layout: Some(&cull_pipeline_layout),
+            module: &cull_shader,
+            entry_point: Some("density_main"),
+            compilation_options: Default::default(),
+            cache,
+        });
+
+        // Aggregated splats of dense tiles
+        let splat_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Density Splat Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/density.wgsl").into()),
+        });
+        let splat_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Density Splat Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+        let splat_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Density Splat Pipeline Layout"),
+                bind_group_layouts: &[&splat_bind_group_layout],
+                immediate_size: 0,
+            });
+        let splat_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Density Splat Pipeline"),
+            layout: Some(&splat_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &splat_shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &splat_shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: HDR_FORMAT,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            // Splats are soft, so they don't occlude what is drawn after them
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
+            multiview_mask: None,
+            cache,
+        });
+        let (density_tiles, density_tile_count) =
+            Self::create_density_tiles(device, surface_config);
+
+        let cull_params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Particle Cull Params Buffer"),
+            size: std::mem::size_of::<CullParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let draw_args_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Particle Draw Args Buffer"),
+            size: std::mem::size_of::<DrawIndirectArgs>() as u64,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::INDIRECT
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        // Grown to the particle count on the first `render`
+        let visible_capacity = 1;
+        let visible_buffer = Self::create_visible_buffer(device, visible_capacity);
+
         Self {
             render_pipeline,
             camera_buffer,
             bind_group_layout,
             depth_texture,
+            msaa_view,
+            sample_count,
             surface_config: surface_config.clone(),
+            hovered_id: 0,
+            glow_scale: 0.0,
+            rest_frame: [0.0; 4],
+            cull_pipeline,
+            cull_bind_group_layout,
+            cull_params_buffer,
+            visible_buffer,
+            visible_capacity,
+            draw_args_buffer,
+            culling: true,
+            density_pipeline,
+            splat_pipeline,
+            splat_bind_group_layout,
+            density_tiles,
+            density_tile_count,
+            density_threshold: 0,
         }
     }
 
+    fn create_density_tiles(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> (wgpu::Buffer, u32) {
+        let count =
+            config.width.div_ceil(DENSITY_TILE_SIZE) * config.height.div_ceil(DENSITY_TILE_SIZE);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Density Tile Buffer"),
+            size: count.max(1) as u64 * DENSITY_TILE_BYTES,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        (buffer, count)
+    }
+
+    fn create_visible_buffer(device: &wgpu::Device, capacity: u32) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Visible Particle Buffer"),
+            size: capacity as u64 * std::mem::size_of::<u32>() as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Cull particles outside the view frustum or under a pixel on screen before drawing
+    /// (on by default).
+    pub fn set_culling(&mut self, enabled: bool) {
+        self.culling = enabled;
+    }
+
+    /// Draw screen tiles (16 px) holding at least `threshold` small particles as one aggregated
+    /// splat instead of shading each particle (0 = off). Tagged and selected particles are
+    /// always drawn individually.
+    pub fn set_density_splats(&mut self, threshold: u32) {
+        self.density_threshold = threshold;
+    }
+
+    /// Highlight the particle, hadron or nucleus with this pick ID (0 = nothing hovered)
+    pub fn set_hovered_id(&mut self, id: u32) {
+        self.hovered_id = id;
+    }
+
+    /// Brighten particles by `scale` per unit kinetic energy (capped in the shader) so fast,
+    /// heavy particles glow through bloom.
+    pub fn set_glow_scale(&mut self, scale: f32) {
+        self.glow_scale = scale.max(0.0);
+    }
+
+    /// Draw velocities, kinetic glow and trails relative to a frame moving at `velocity`
+    /// (`Vec3::ZERO` = lab frame). `sample_dt` is the simulation time between trail samples.
+    pub fn set_rest_frame(&mut self, velocity: Vec3, sample_dt: f32) {
+        self.rest_frame = velocity.extend(sample_dt).to_array();
+    }
+
     fn create_depth_texture(
         device: &wgpu::Device,
         config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
     ) -> wgpu::TextureView {
         let depth_texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("Depth Texture"),
@@ -162,7 +522,7 @@ impl ParticleRenderer {
                 depth_or_array_layers: 1,
             },
             mip_level_count: 1,
-            sample_count: 1,
+            sample_count,
             dimension: wgpu::TextureDimension::D2,
             format: DEPTH_FORMAT,
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
@@ -171,13 +531,92 @@ impl ParticleRenderer {
         depth_texture.create_view(&wgpu::TextureViewDescriptor::default())
     }
 
+    fn create_msaa_view(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
+    ) -> Option<wgpu::TextureView> {
+        if sample_count <= 1 {
+            return None;
+        }
+
+        let msaa_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("MSAA Scene Texture"),
+            size: wgpu::Extent3d {
+                width: config.width,
+                height: config.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        Some(msaa_texture.create_view(&wgpu::TextureViewDescriptor::default()))
+    }
+
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Color attachment for a scene pass that ends by producing `target`: the multisampled
+    /// target plus `target` as resolve target with MSAA, `target` itself without.
+    pub fn color_attachment<'a>(
+        &'a self,
+        target: &'a wgpu::TextureView,
+    ) -> (&'a wgpu::TextureView, Option<&'a wgpu::TextureView>) {
+        match &self.msaa_view {
+            Some(msaa_view) => (msaa_view, Some(target)),
+            None => (target, None),
+        }
+    }
+
+    /// Begin a pass that draws on top of the scene this renderer started: loads color and
+    /// depth (resolving into `target` again with MSAA).
+    pub fn begin_scene_pass<'encoder>(
+        &self,
+        encoder: &'encoder mut wgpu::CommandEncoder,
+        label: &str,
+        target: &wgpu::TextureView,
+    ) -> wgpu::RenderPass<'encoder> {
+        let (view, resolve_target) = self.color_attachment(target);
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.depth_texture,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        })
+    }
+
     pub fn resize(&mut self, device: &wgpu::Device, new_config: &wgpu::SurfaceConfiguration) {
         self.surface_config = new_config.clone();
-        self.depth_texture = Self::create_depth_texture(device, new_config);
+        self.depth_texture = Self::create_depth_texture(device, new_config, self.sample_count);
+        self.msaa_view = Self::create_msaa_view(device, new_config, self.sample_count);
+        (self.density_tiles, self.density_tile_count) =
+            Self::create_density_tiles(device, new_config);
     }
 
     pub fn render(
-        &self,
+        &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         surface_view: &wgpu::TextureView,
@@ -185,39 +624,122 @@ impl ParticleRenderer {
         particle_buffer: &wgpu::Buffer,
         hadron_buffer: &wgpu::Buffer,
         hadron_count_buffer: &wgpu::Buffer,
+        color_buffer: &wgpu::Buffer,
+        selection_mask_buffer: &wgpu::Buffer,
         particle_count: u32,
         particle_size: f32,
         time: f32,
-        lod_shell_fade_start: f32,
-        lod_shell_fade_end: f32,
-        lod_bound_hadron_fade_start: f32,
-        lod_bound_hadron_fade_end: f32,
-        lod_bond_fade_start: f32,
-        lod_bond_fade_end: f32,
-        lod_quark_fade_start: f32,
-        lod_quark_fade_end: f32,
-        lod_nucleus_fade_start: f32,
-        lod_nucleus_fade_end: f32,
+        lod: &LodSettings,
+        profiler: &mut GpuProfiler,
     ) {
         // Update camera
         queue.write_buffer(
             &self.camera_buffer,
             0,
-            bytemuck::cast_slice(&[camera.to_uniform(
+            bytemuck::cast_slice(&[CameraUniform {
+                hovered_id: self.hovered_id,
+                glow_scale: self.glow_scale,
+                rest_frame: self.rest_frame,
+                ..camera.to_uniform(particle_size, time, lod)
+            }]),
+        );
+
+        if particle_count > self.visible_capacity {
+            self.visible_capacity = particle_count;
+            self.visible_buffer = Self::create_visible_buffer(device, particle_count);
+        }
+
+        let planes = camera.frustum_planes();
+        queue.write_buffer(
+            &self.cull_params_buffer,
+            0,
+            bytemuck::bytes_of(&CullParams {
+                planes: planes.map(|plane| plane.to_array()),
+                camera_position: camera
+                    .position()
+                    .extend(camera.pixels_per_unit(self.surface_config.height))
+                    .to_array(),
                 particle_size,
-                time,
-                lod_shell_fade_start,
-                lod_shell_fade_end,
-                lod_bound_hadron_fade_start,
-                lod_bound_hadron_fade_end,
-                lod_bond_fade_start,
-                lod_bond_fade_end,
-                lod_quark_fade_start,
-                lod_quark_fade_end,
-                lod_nucleus_fade_start,
-                lod_nucleus_fade_end,
-            )]),
+                // The size test assumes perspective; orthographic views only frustum cull
+                min_pixel_radius: if camera.is_perspective() {
+                    CULL_MIN_PIXEL_RADIUS
+                } else {
+                    0.0
+                },
+                particle_count,
+                enabled: self.culling as u32,
+                view_proj: camera.build_view_projection_matrix().to_cols_array_2d(),
+                density: [
+                    self.surface_config.width,
+                    self.surface_config.height,
+                    DENSITY_TILE_SIZE,
+                    // The tile binning assumes perspective sizes, like the cull size test
+                    if camera.is_perspective() {
+                        self.density_threshold
+                    } else {
+                        0
+                    },
+                ],
+            }),
         );
+        let density = self.density_threshold > 0 && camera.is_perspective();
+        // The cull pass counts the instances up from zero
+        queue.write_buffer(
+            &self.draw_args_buffer,
+            0,
+            DrawIndirectArgs {
+                vertex_count: 6,
+                instance_count: 0,
+                first_vertex: 0,
+                first_instance: 0,
+            }
+            .as_bytes(),
+        );
+
+        let cull_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Particle Cull Bind Group"),
+            layout: &self.cull_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: self.cull_params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.visible_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: self.draw_args_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: self.density_tiles.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: selection_mask_buffer.as_entire_binding(),
+                },
+            ],
+        });
+        let splat_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Density Splat Bind Group"),
+            layout: &self.splat_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: self.cull_params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: self.density_tiles.as_entire_binding(),
+                },
+            ],
+        });
 
         // Create bind group for this frame
         let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -240,20 +762,55 @@ impl ParticleRenderer {
                     binding: 3,
                     resource: hadron_count_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: color_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: selection_mask_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: self.visible_buffer.as_entire_binding(),
+                },
             ],
         });
 
-        // Render
+        // Render (resolved into `surface_view` with MSAA; later passes load the multisampled
+        // target and resolve again)
+        let (view, resolve_target) = self.color_attachment(surface_view);
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Render Encoder"),
         });
 
+        let scope = profiler.begin(&mut encoder, "Particles");
+        if density {
+            encoder.clear_buffer(&self.density_tiles, 0, None);
+        }
+
+        {
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Particle Cull Pass"),
+                timestamp_writes: None,
+            });
+            let workgroups = particle_count.div_ceil(CULL_WORKGROUP_SIZE);
+            compute_pass.set_bind_group(0, &cull_bind_group, &[]);
+            // Bin first: the cull pass needs the complete tile counts
+            if density {
+                compute_pass.set_pipeline(&self.density_pipeline);
+                compute_pass.dispatch_workgroups(workgroups, 1, 1);
+            }
+            compute_pass.set_pipeline(&self.cull_pipeline);
+            compute_pass.dispatch_workgroups(workgroups, 1, 1);
+        }
+
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Render Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: surface_view,
-                    resolve_target: None,
+                    view,
+                    resolve_target,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color {
                             // Catppuccin Mocha base #1e1e2e RGB(30,30,46) in linear
@@ -281,8 +838,15 @@ impl ParticleRenderer {
 
             render_pass.set_pipeline(&self.render_pipeline);
             render_pass.set_bind_group(0, &bind_group, &[]);
-            render_pass.draw(0..6, 0..particle_count);
+            render_pass.draw_indirect(&self.draw_args_buffer, 0);
+
+            if density {
+                render_pass.set_pipeline(&self.splat_pipeline);
+                render_pass.set_bind_group(0, &splat_bind_group, &[]);
+                render_pass.draw(0..6, 0..self.density_tile_count);
+            }
         }
+        profiler.end(&mut encoder, scope);
 
         queue.submit(std::iter::once(encoder.finish()));
     }
@@ -0,0 +1,124 @@
+// Bloom post-process over the HDR scene target
+// `fs_prefilter`:  soft-threshold the scene while downsampling it into the first bloom mip
+// `fs_downsample`: 13-tap downsample into the next (half size) mip
+// `fs_upsample`:   3x3 tent upsample, added onto the next larger mip
+// `fs_composite`:  scene + bloom, tone mapped onto the surface
+//
+// Tone mapping is the identity below `TONEMAP_KNEE` and compresses smoothly above it, so
+// LDR colors look exactly as they did before the HDR target existed.
+
+const TONEMAP_KNEE: f32 = 0.8;
+
+struct BloomParams {
+    threshold: f32, // Brightness where bloom starts
+    knee: f32,      // Soft threshold width
+    intensity: f32, // Bloom contribution in the composite
+    enabled: f32,   // 0 = composite the scene only
+}
+
+@group(0) @binding(0)
+var source: texture_2d<f32>;
+
+@group(0) @binding(1)
+var source_sampler: sampler;
+
+@group(0) @binding(2)
+var<uniform> params: BloomParams;
+
+// Composite only: the finished bloom (largest mip)
+@group(0) @binding(3)
+var bloom: texture_2d<f32>;
+
+struct FullscreenOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+// One oversized triangle covering the viewport
+@vertex
+fn vs_fullscreen(@builtin(vertex_index) vertex_index: u32) -> FullscreenOutput {
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+
+    var out: FullscreenOutput;
+    out.position = vec4<f32>(uv * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+fn texel_size() -> vec2<f32> {
+    return 1.0 / vec2<f32>(textureDimensions(source));
+}
+
+fn sample_at(uv: vec2<f32>, offset: vec2<f32>) -> vec3<f32> {
+    return textureSample(source, source_sampler, uv + offset * texel_size()).rgb;
+}
+
+// 13-tap downsample (weighted 2x2 box groups; avoids the shimmering of a plain 2x2 box)
+fn downsample(uv: vec2<f32>) -> vec3<f32> {
+    let a = sample_at(uv, vec2<f32>(-2.0, 2.0));
+    let b = sample_at(uv, vec2<f32>(0.0, 2.0));
+    let c = sample_at(uv, vec2<f32>(2.0, 2.0));
+    let d = sample_at(uv, vec2<f32>(-2.0, 0.0));
+    let e = sample_at(uv, vec2<f32>(0.0, 0.0));
+    let f = sample_at(uv, vec2<f32>(2.0, 0.0));
+    let g = sample_at(uv, vec2<f32>(-2.0, -2.0));
+    let h = sample_at(uv, vec2<f32>(0.0, -2.0));
+    let i = sample_at(uv, vec2<f32>(2.0, -2.0));
+    let j = sample_at(uv, vec2<f32>(-1.0, 1.0));
+    let k = sample_at(uv, vec2<f32>(1.0, 1.0));
+    let l = sample_at(uv, vec2<f32>(-1.0, -1.0));
+    let m = sample_at(uv, vec2<f32>(1.0, -1.0));
+
+    return e * 0.125
+        + (a + c + g + i) * 0.03125
+        + (b + d + f + h) * 0.0625
+        + (j + k + l + m) * 0.125;
+}
+
+// Quadratic soft threshold on the brightest channel
+fn soft_threshold(color: vec3<f32>) -> vec3<f32> {
+    let brightness = max(color.r, max(color.g, color.b));
+    var soft = clamp(brightness - params.threshold + params.knee, 0.0, 2.0 * params.knee);
+    soft = soft * soft / (4.0 * params.knee + 1e-4);
+    let contribution = max(soft, brightness - params.threshold) / max(brightness, 1e-4);
+    return color * contribution;
+}
+
+@fragment
+fn fs_prefilter(input: FullscreenOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(soft_threshold(downsample(input.uv)), 1.0);
+}
+
+@fragment
+fn fs_downsample(input: FullscreenOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(downsample(input.uv), 1.0);
+}
+
+@fragment
+fn fs_upsample(input: FullscreenOutput) -> @location(0) vec4<f32> {
+    var sum = sample_at(input.uv, vec2<f32>(0.0, 0.0)) * 4.0;
+    sum += (sample_at(input.uv, vec2<f32>(-1.0, 0.0))
+        + sample_at(input.uv, vec2<f32>(1.0, 0.0))
+        + sample_at(input.uv, vec2<f32>(0.0, -1.0))
+        + sample_at(input.uv, vec2<f32>(0.0, 1.0))) * 2.0;
+    sum += sample_at(input.uv, vec2<f32>(-1.0, -1.0))
+        + sample_at(input.uv, vec2<f32>(1.0, -1.0))
+        + sample_at(input.uv, vec2<f32>(-1.0, 1.0))
+        + sample_at(input.uv, vec2<f32>(1.0, 1.0));
+    return vec4<f32>(sum / 16.0, 1.0);
+}
+
+fn tonemap(color: vec3<f32>) -> vec3<f32> {
+    let over = max(color - TONEMAP_KNEE, vec3<f32>(0.0));
+    let compressed = TONEMAP_KNEE + (1.0 - TONEMAP_KNEE) * (1.0 - exp(-over / (1.0 - TONEMAP_KNEE)));
+    return select(color, compressed, color > vec3<f32>(TONEMAP_KNEE));
+}
+
+@fragment
+fn fs_composite(input: FullscreenOutput) -> @location(0) vec4<f32> {
+    var color = textureSample(source, source_sampler, input.uv).rgb;
+    if (params.enabled > 0.5) {
+        color += textureSample(bloom, source_sampler, input.uv).rgb * params.intensity;
+    }
+    return vec4<f32>(tonemap(color), 1.0);
+}
@@ -0,0 +1,181 @@
+// Particle culling: compacts the indices of particles that are inside the view frustum and
+// at least `min_pixel_radius` on screen into `visible`, and counts them into the indirect draw
+// arguments the particle pass draws with (`particle.wgsl` reads `visible[instance_index]`).
+//
+// Density LOD: `density_main` runs first and bins small visible particles into screen tiles
+// (count, nearest depth, summed color). `cull_main` then drops the particles of tiles holding
+// at least `density.w` of them; `density.wgsl` draws one aggregated splat per such tile.
+
+struct CullParams {
+    planes: array<vec4<f32>, 6>, // Frustum planes, inward unit normals (see `Camera::frustum_planes`)
+    camera_position: vec4<f32>,  // xyz = eye, w = pixels per world unit at unit distance
+    particle_size: f32,          // Global billboard scale (radius = particle_size * data.y)
+    min_pixel_radius: f32,       // Smaller billboards are skipped
+    particle_count: u32,
+    enabled: u32,                // 0 = keep every particle (identity list)
+    view_proj: mat4x4<f32>,
+    density: vec4<u32>,          // x, y = viewport (pixels), z = tile size (pixels), w = splat threshold (0 = off)
+}
+
+// One screen tile of the density pass (`DensityTile` in `density.wgsl` reads it)
+struct DensityTile {
+    count: atomic<u32>,
+    depth: atomic<u32>, // bits of 1 - nearest NDC depth (max = nearest; zeroed each frame)
+    red: atomic<u32>,   // Color sums in 1/255 steps
+    green: atomic<u32>,
+    blue: atomic<u32>,
+}
+
+struct Particle {
+    position: vec4<f32>,
+    velocity: vec4<f32>,
+    data: vec4<f32>,
+    color_and_flags: vec4<u32>,
+}
+
+// Matches `wgpu::util::DrawIndirectArgs`
+struct DrawArgs {
+    vertex_count: u32,
+    instance_count: atomic<u32>,
+    first_vertex: u32,
+    first_instance: u32,
+}
+
+@group(0) @binding(0)
+var<uniform> params: CullParams;
+
+@group(0) @binding(1)
+var<storage, read> particles: array<Particle>;
+
+@group(0) @binding(2)
+var<storage, read_write> visible: array<u32>;
+
+@group(0) @binding(3)
+var<storage, read_write> draw_args: DrawArgs;
+
+@group(0) @binding(4)
+var<storage, read_write> tiles: array<DensityTile>;
+
+// Selection set membership; selected particles are never aggregated
+@group(0) @binding(5)
+var<storage, read> selection_mask: array<u32>;
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    return pow(c, vec3<f32>(2.2));
+}
+
+// Base colors of `particle.wgsl` (without color flow blending)
+fn particle_color(particle_type: u32, color_charge: u32) -> vec3<f32> {
+    if (particle_type <= 1u) {
+        switch (color_charge) {
+            case 0u: { return srgb_to_linear(vec3<f32>(0.953, 0.545, 0.659)); }
+            case 1u: { return srgb_to_linear(vec3<f32>(0.647, 0.859, 0.627)); }
+            case 2u: { return srgb_to_linear(vec3<f32>(0.549, 0.753, 0.984)); }
+            case 3u: { return srgb_to_linear(vec3<f32>(0.961, 0.718, 0.741)); }
+            case 4u: { return srgb_to_linear(vec3<f32>(0.580, 0.886, 0.820)); }
+            case 5u: { return srgb_to_linear(vec3<f32>(0.553, 0.827, 0.937)); }
+            default: { return srgb_to_linear(vec3<f32>(0.803, 0.816, 0.839)); }
+        }
+    }
+    switch (particle_type) {
+        case 2u: { return srgb_to_linear(vec3<f32>(0.976, 0.886, 0.686)); }
+        case 3u: { return srgb_to_linear(vec3<f32>(0.980, 0.702, 0.529)); }
+        case 4u: { return srgb_to_linear(vec3<f32>(0.647, 0.859, 0.627)); }
+        case 5u: { return srgb_to_linear(vec3<f32>(0.549, 0.753, 0.984)); }
+        default: { return srgb_to_linear(vec3<f32>(0.803, 0.816, 0.839)); }
+    }
+}
+
+fn is_visible(particle: Particle) -> bool {
+    let center = particle.position.xyz;
+    let radius = params.particle_size * particle.data.y;
+
+    for (var i = 0u; i < 6u; i++) {
+        let plane = params.planes[i];
+        if (dot(plane.xyz, center) + plane.w < -radius) {
+            return false;
+        }
+    }
+
+    let dist = max(distance(params.camera_position.xyz, center), 1e-4);
+    return radius * params.camera_position.w / dist >= params.min_pixel_radius;
+}
+
+// Density tile of a visible particle that may be aggregated, or -1 (too large on screen,
+// behind the camera, tagged or selected)
+fn density_tile(index: u32, particle: Particle) -> i32 {
+    if (particle.data.z > 0.0 || selection_mask[index] != 0u) {
+        return -1;
+    }
+
+    let tile_size = params.density.z;
+    let dist = max(distance(params.camera_position.xyz, particle.position.xyz), 1e-4);
+    let pixel_radius = params.particle_size * particle.data.y * params.camera_position.w / dist;
+    if (pixel_radius > f32(tile_size) * 0.5) {
+        return -1;
+    }
+
+    let clip = params.view_proj * vec4<f32>(particle.position.xyz, 1.0);
+    if (clip.w <= 0.0) {
+        return -1;
+    }
+    let ndc = clip.xy / clip.w;
+    let pixel = vec2<f32>(ndc.x + 1.0, 1.0 - ndc.y) * 0.5 * vec2<f32>(params.density.xy);
+    if (any(pixel < vec2<f32>(0.0)) || any(pixel >= vec2<f32>(params.density.xy))) {
+        return -1;
+    }
+    let tiles_x = (params.density.x + tile_size - 1u) / tile_size;
+    let tile = vec2<u32>(pixel) / tile_size;
+    return i32(tile.y * tiles_x + tile.x);
+}
+
+@compute @workgroup_size(64)
+fn density_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if (index >= params.particle_count || params.density.w == 0u) {
+        return;
+    }
+
+    let particle = particles[index];
+    if (!is_visible(particle)) {
+        return;
+    }
+    let tile = density_tile(index, particle);
+    if (tile < 0) {
+        return;
+    }
+
+    let clip = params.view_proj * vec4<f32>(particle.position.xyz, 1.0);
+    let depth = clamp(clip.z / clip.w, 0.0, 1.0);
+    let color = vec3<u32>(
+        particle_color(u32(particle.position.w), particle.color_and_flags.x) * 255.0 + 0.5
+    );
+    atomicAdd(&tiles[tile].count, 1u);
+    atomicMax(&tiles[tile].depth, bitcast<u32>(1.0 - depth));
+    atomicAdd(&tiles[tile].red, color.x);
+    atomicAdd(&tiles[tile].green, color.y);
+    atomicAdd(&tiles[tile].blue, color.z);
+}
+
+@compute @workgroup_size(64)
+fn cull_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if (index >= params.particle_count) {
+        return;
+    }
+
+    if (params.enabled != 0u && !is_visible(particles[index])) {
+        return;
+    }
+
+    // Particles in dense tiles are drawn as that tile's splat instead
+    if (params.density.w != 0u) {
+        let tile = density_tile(index, particles[index]);
+        if (tile >= 0 && atomicLoad(&tiles[tile].count) >= params.density.w) {
+            return;
+        }
+    }
+
+    let slot = atomicAdd(&draw_args.instance_count, 1u);
+    visible[slot] = index;
+}
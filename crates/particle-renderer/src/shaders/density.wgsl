@@ -0,0 +1,88 @@
+// Aggregated splats for dense screen tiles (see the density pass in `cull.wgsl`): one soft,
+// unlit quad per tile holding at least `density.w` particles, at the tile's nearest depth and
+// in the mean color of its particles. One instance per tile.
+
+struct CullParams {
+    planes: array<vec4<f32>, 6>,
+    camera_position: vec4<f32>,
+    particle_size: f32,
+    min_pixel_radius: f32,
+    particle_count: u32,
+    enabled: u32,
+    view_proj: mat4x4<f32>,
+    density: vec4<u32>, // x, y = viewport (pixels), z = tile size (pixels), w = splat threshold
+}
+
+struct DensityTile {
+    count: u32,
+    depth: u32, // bits of 1 - nearest NDC depth
+    red: u32,   // Color sums in 1/255 steps
+    green: u32,
+    blue: u32,
+}
+
+@group(0) @binding(0)
+var<uniform> params: CullParams;
+
+@group(0) @binding(1)
+var<storage, read> tiles: array<DensityTile>;
+
+// Splat half size in tiles (> 0.5 so neighbouring splats overlap into a continuous haze)
+const SPLAT_EXTENT: f32 = 1.0;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>, // -1..1 across the splat
+    @location(1) @interpolate(flat) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32
+) -> VertexOutput {
+    var out: VertexOutput;
+    let tile = tiles[instance_index];
+    if (params.density.w == 0u || tile.count < params.density.w) {
+        out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+        return out;
+    }
+
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+    );
+    let corner = corners[vertex_index];
+
+    let tile_size = f32(params.density.z);
+    let tiles_x = (params.density.x + params.density.z - 1u) / params.density.z;
+    let tile_xy = vec2<f32>(f32(instance_index % tiles_x), f32(instance_index / tiles_x));
+    let pixel = (tile_xy + 0.5 + corner * SPLAT_EXTENT) * tile_size;
+    let ndc = vec2<f32>(pixel.x, -pixel.y) / vec2<f32>(params.density.xy) * 2.0
+        + vec2<f32>(-1.0, 1.0);
+    let depth = 1.0 - bitcast<f32>(tile.depth);
+
+    // Denser tiles get more opaque, up to fully covering what is behind them
+    let count = f32(tile.count);
+    let density = count / f32(params.density.w);
+    out.clip_position = vec4<f32>(ndc, depth, 1.0);
+    out.uv = corner;
+    out.color = vec4<f32>(
+        vec3<f32>(f32(tile.red), f32(tile.green), f32(tile.blue)) / (255.0 * count),
+        clamp(0.55 + 0.15 * log2(density), 0.55, 0.95),
+    );
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let falloff = exp(-dot(in.uv, in.uv) * 2.5);
+    if (falloff * in.color.a < 0.01) {
+        discard;
+    }
+    return vec4<f32>(in.color.rgb, in.color.a * falloff);
+}
@@ -0,0 +1,121 @@
+// Reference grid in the world XZ plane plus the X/Y/Z axes through the origin.
+// Vertices [0, GRID_VERTICES) are grid lines (first the lines along X, then along Z); the last
+// six are the axes.
+
+struct Camera {
+    view_proj: mat4x4<f32>,
+    position: vec3<f32>,
+    particle_size: f32,
+    time: f32,
+    lod_shell_fade_start: f32,
+    lod_shell_fade_end: f32,
+    lod_bound_hadron_fade_start: f32,
+    lod_bound_hadron_fade_end: f32,
+    lod_bond_fade_start: f32,
+    lod_bond_fade_end: f32,
+    lod_quark_fade_start: f32,
+    lod_quark_fade_end: f32,
+    lod_nucleus_fade_start: f32,
+    lod_nucleus_fade_end: f32,
+
+    // Uniforms are laid out in 16-byte chunks; use 16-byte padding to avoid rounding up to 144 bytes.
+    _pad: vec4<f32>,
+}
+
+struct GridParams {
+    origin: vec4<f32>,  // xyz = grid center (snapped to a cell corner), w = cell size
+    options: vec4<u32>, // x = draw grid, y = draw axes
+}
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+@group(0) @binding(1)
+var<uniform> grid: GridParams;
+
+// Must match `GRID_HALF_LINES` in `grid_renderer.rs`
+const GRID_HALF_LINES: u32 = 20u;
+const LINES_PER_DIRECTION: u32 = 2u * GRID_HALF_LINES + 1u;
+const GRID_VERTICES: u32 = LINES_PER_DIRECTION * 4u;
+
+// Every fifth line is drawn brighter
+const MAJOR_EVERY: i32 = 5;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+fn hidden() -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    out.color = vec4<f32>(0.0);
+    return out;
+}
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    return pow(c, vec3<f32>(2.2));
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let cell = grid.origin.w;
+    let extent = cell * f32(GRID_HALF_LINES);
+    var out: VertexOutput;
+
+    if (vertex_index < GRID_VERTICES) {
+        if (grid.options.x == 0u) {
+            return hidden();
+        }
+
+        let line = vertex_index / 2u;
+        let along_z = line >= LINES_PER_DIRECTION;
+        let offset = i32(line % LINES_PER_DIRECTION) - i32(GRID_HALF_LINES);
+        let end = select(-extent, extent, (vertex_index & 1u) == 1u);
+
+        // Lines along X sit at z offsets and vice versa
+        var local = vec3<f32>(end, 0.0, f32(offset) * cell);
+        if (along_z) {
+            local = vec3<f32>(f32(offset) * cell, 0.0, end);
+        }
+        let position = grid.origin.xyz + local;
+
+        // Major lines: every fifth in world space, not relative to the moving center
+        let world_index = i32(round(select(position.z, position.x, along_z) / cell));
+        let major = world_index % MAJOR_EVERY == 0;
+        // Fade toward the edge so the grid has no hard border
+        let edge = 1.0 - abs(f32(offset)) / f32(GRID_HALF_LINES + 1u);
+
+        out.clip_position = camera.view_proj * vec4<f32>(position, 1.0);
+        let base = srgb_to_linear(vec3<f32>(0.576, 0.600, 0.698)); // Overlay 2 #9399b2
+        out.color = vec4<f32>(base, select(0.12, 0.3, major) * edge);
+        return out;
+    }
+
+    if (grid.options.y == 0u) {
+        return hidden();
+    }
+
+    // Axes: X (red), Y (green), Z (blue) through the world origin
+    let axis = (vertex_index - GRID_VERTICES) / 2u;
+    let sign = select(-1.0, 1.0, (vertex_index & 1u) == 1u);
+    let axis_length = max(extent, length(grid.origin.xyz) + extent);
+    var direction = vec3<f32>(1.0, 0.0, 0.0);
+    var color = srgb_to_linear(vec3<f32>(0.953, 0.545, 0.659)); // Red #f38ba8
+    if (axis == 1u) {
+        direction = vec3<f32>(0.0, 1.0, 0.0);
+        color = srgb_to_linear(vec3<f32>(0.651, 0.890, 0.631)); // Green #a6e3a1
+    } else if (axis == 2u) {
+        direction = vec3<f32>(0.0, 0.0, 1.0);
+        color = srgb_to_linear(vec3<f32>(0.537, 0.706, 0.980)); // Blue #89b4fa
+    }
+
+    out.clip_position = camera.view_proj * vec4<f32>(direction * axis_length * sign, 1.0);
+    out.color = vec4<f32>(color, 0.8);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
@@ -126,15 +126,15 @@ fn vs_shell(
     return out;
 }
 
-@fragment
-fn fs_shell(in: VertexOutput) -> @location(0) vec4<f32> {
+// Lit shell color; alpha 0 outside the sphere or when faded out by LOD
+fn shell_color(in: VertexOutput) -> vec4<f32> {
     // Draw sphere
     let center = vec2<f32>(0.5, 0.5);
     let uv_centered = in.uv - center;
     let dist = length(uv_centered);
 
     if (dist > 0.5) {
-        discard;
+        return vec4<f32>(0.0);
     }
 
     // Fake 3D normal
@@ -161,78 +161,183 @@ fn fs_shell(in: VertexOutput) -> @location(0) vec4<f32> {
     }
 
     if (final_alpha < 0.01) {
-        discard;
+        return vec4<f32>(0.0);
     }
 
     let lighting = 0.5 + diffuse * 0.5;
     return vec4<f32>(in.color.rgb * lighting, final_alpha);
 }
 
-// --- BOND RENDERER (Lines) ---
+@fragment
+fn fs_shell(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = shell_color(in);
+    if (color.a < 0.01) {
+        discard;
+    }
+    return color;
+}
 
-@vertex
-fn vs_bond(
-    @builtin(vertex_index) vertex_index: u32
-) -> VertexOutput {
-    var out: VertexOutput;
+// --- ORDER-INDEPENDENT SHELLS (weighted blended OIT, composited by oit.wgsl) ---
 
-    let hadron_idx = vertex_index / 6u;
-    let line_idx = vertex_index % 6u; // 0-1, 2-3, 4-5
+// Distance where the OIT weight starts falling off steeply (world units)
+const OIT_DEPTH_SCALE: f32 = 100.0;
 
-    if (hadron_idx >= counter.counters.x) {
-        out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
-        return out;
+struct OitOutput {
+    @location(0) accum: vec4<f32>,
+    @location(1) reveal: f32,
+}
+
+// Nearer shells dominate the weighted average (McGuire & Bavoil, eq. 9)
+fn oit_weight(alpha: f32, dist_to_cam: f32) -> f32 {
+    let falloff = pow(dist_to_cam / OIT_DEPTH_SCALE, 4.0);
+    return alpha * clamp(0.03 / (1e-5 + falloff), 1e-2, 3e3);
+}
+
+@fragment
+fn fs_shell_oit(in: VertexOutput) -> OitOutput {
+    let color = shell_color(in);
+    if (color.a < 0.01) {
+        discard;
+    }
+
+    var out: OitOutput;
+    out.accum = vec4<f32>(color.rgb * color.a, color.a) * oit_weight(color.a, in.dist_to_cam);
+    out.reveal = color.a;
+    return out;
+}
+
+// --- BOND RENDERER (Capsule impostors) ---
+
+struct BondParams {
+    equilibrium: f32, // separation drawn relaxed
+    breakup: f32,     // separation drawn fully strained
+    radius: f32,      // capsule radius (world units)
+    _pad: f32,
+}
+
+@group(0) @binding(4)
+var<uniform> bond_params: BondParams;
+
+struct BondOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    // x = across the capsule (-1..1), y = along the axis in world units (0..bond_length)
+    @location(0) local: vec2<f32>,
+    @location(1) @interpolate(flat) bond_length: f32,
+    @location(2) @interpolate(flat) strain: f32,
+    @location(3) dist_to_cam: f32,
+    @location(4) @interpolate(flat) is_bound: u32,
+}
+
+fn hidden_bond() -> BondOutput {
+    var out: BondOutput;
+    out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    return out;
+}
+
+// Relaxed bonds are teal, strained ones shift through yellow to red
+fn strain_color(strain: f32) -> vec3<f32> {
+    let relaxed = vec3<f32>(0.580, 0.886, 0.835); // Teal #94e2d5
+    let tense = vec3<f32>(0.976, 0.886, 0.686);   // Yellow #f9e2af
+    let breaking = vec3<f32>(0.953, 0.545, 0.659); // Red #f38ba8
+    let color = select(
+        mix(tense, breaking, strain * 2.0 - 1.0),
+        mix(relaxed, tense, strain * 2.0),
+        strain < 0.5,
+    );
+    return pow(color, vec3<f32>(2.2));
+}
+
+@vertex
+fn vs_bond(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32
+) -> BondOutput {
+    if (instance_index >= counter.counters.x) {
+        return hidden_bond();
     }
 
-    let hadron = hadrons[hadron_idx];
+    let hadron = hadrons[instance_index];
 
     // Skip invalid hadrons (broken/invalidated hadrons)
     if (hadron.indices_type.w == 0xFFFFFFFFu) {
-        out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
-        return out;
+        return hidden_bond();
     }
-    var pos = vec3<f32>(0.0, 0.0, 0.0);
 
-    // Determine which particle position to use
-    // Line 1: p1 -> p2
-    // Line 2: p2 -> p3
-    // Line 3: p3 -> p1
+    // Bond 0: p1 -> p2, bond 1: p2 -> p3, bond 2: p3 -> p1 (mesons only have bond 0)
+    let bond = vertex_index / 6u;
+    let is_meson = hadron.indices_type.z == 0xFFFFFFFFu;
+    if (is_meson && bond > 0u) {
+        return hidden_bond();
+    }
 
-    // Helper to get position safely
     let p1 = particles[hadron.indices_type.x].position.xyz;
     let p2 = particles[hadron.indices_type.y].position.xyz;
-    // For p3, check if it exists (Mesons have p3 = 0xFFFFFFFF)
     var p3 = p1;
-    if (hadron.indices_type.z != 0xFFFFFFFFu) {
+    if (!is_meson) {
         p3 = particles[hadron.indices_type.z].position.xyz;
     }
 
-    switch (line_idx) {
-        case 0u: { pos = p1; }
-        case 1u: { pos = p2; }
-        case 2u: { pos = p2; }
-        case 3u: { pos = p3; }
-        case 4u: { pos = p3; }
-        case 5u: { pos = p1; }
+    var a = p1;
+    var b = p2;
+    switch (bond) {
+        case 1u: { a = p2; b = p3; }
+        case 2u: { a = p3; b = p1; }
         default: {}
     }
 
-    // If Meson, collapse lines 2 and 3 (p2->p3, p3->p1) to degenerate lines
-    if (hadron.indices_type.z == 0xFFFFFFFFu && line_idx >= 2u) {
-        pos = p1; // Collapse to point
+    let axis = b - a;
+    let bond_length = length(axis);
+    if (bond_length < 1e-5) {
+        return hidden_bond();
+    }
+    let dir = axis / bond_length;
+
+    // Camera-facing quad around the segment, extended by the radius for the round caps
+    let mid = (a + b) * 0.5;
+    let to_camera = normalize(camera.position - mid);
+    var side = cross(dir, to_camera);
+    if (dot(side, side) < 1e-8) {
+        side = cross(dir, vec3<f32>(0.0, 1.0, 0.0)); // Looking straight down the bond
+    }
+    side = normalize(side);
+
+    var corner = vec2<f32>(0.0, 0.0); // x = side (-1/1), y = end (0 = a, 1 = b)
+    switch (vertex_index % 6u) {
+        case 0u, 3u: { corner = vec2<f32>(-1.0, 0.0); }
+        case 1u: { corner = vec2<f32>(1.0, 0.0); }
+        case 2u, 4u: { corner = vec2<f32>(1.0, 1.0); }
+        case 5u: { corner = vec2<f32>(-1.0, 1.0); }
+        default: {}
     }
 
-    out.clip_position = camera.view_proj * vec4<f32>(pos, 1.0);
-    out.color = vec4<f32>(1.0, 1.0, 1.0, 0.5); // White lines
-    out.uv = vec2<f32>(0.0, 0.0); // Unused
+    let radius = bond_params.radius;
+    let along = mix(-radius, bond_length + radius, corner.y);
+    let world_pos = a + dir * along + side * corner.x * radius;
+
+    var out: BondOutput;
+    out.clip_position = camera.view_proj * vec4<f32>(world_pos, 1.0);
+    out.local = vec2<f32>(corner.x, along);
+    out.bond_length = bond_length;
+    out.strain = clamp(
+        (bond_length - bond_params.equilibrium) / (bond_params.breakup - bond_params.equilibrium),
+        0.0,
+        1.0,
+    );
     out.dist_to_cam = distance(camera.position, hadron.center.xyz);
     out.is_bound = select(0u, 1u, u32(hadron.velocity.w) != 0u);
-
     return out;
 }
 
 @fragment
-fn fs_bond(in: VertexOutput) -> @location(0) vec4<f32> {
+fn fs_bond(in: BondOutput) -> @location(0) vec4<f32> {
+    // Capsule: distance to the axis segment, in units of the radius
+    let radius = bond_params.radius;
+    let beyond = max(max(-in.local.y, in.local.y - in.bond_length), 0.0) / radius;
+    let r2 = in.local.x * in.local.x + beyond * beyond;
+    if (r2 > 1.0) {
+        discard;
+    }
+
     // LOD: Fade out bonds when far away (controlled by bond sliders)
     // < bond_fade_start: Fully visible (alpha = 1)
     // bond_fade_start to bond_fade_end: Fade from 1 to 0
@@ -252,5 +357,10 @@ fn fs_bond(in: VertexOutput) -> @location(0) vec4<f32> {
         discard;
     }
 
-    return vec4<f32>(in.color.rgb, in.color.a * alpha_factor);
+    // Cylinder shading from the fake normal across the capsule
+    let facing = sqrt(1.0 - r2);
+    let lighting = 0.45 + 0.55 * facing;
+    // Near-breaking bonds glow a little through bloom
+    let glow = 1.0 + smoothstep(0.8, 1.0, in.strain);
+    return vec4<f32>(strain_color(in.strain) * lighting * glow, 0.8 * alpha_factor);
 }
@@ -0,0 +1,25 @@
+// Shader for label leader lines
+// Each label is one screen-space segment (NDC) from the entity to its text tag; the tags
+// themselves are astra-gui nodes drawn on top.
+
+// Matches mocha TEXT, slightly translucent so lines don't hide what they point at
+const LINE_COLOR: vec4<f32> = vec4<f32>(0.804, 0.839, 0.957, 0.8);
+
+struct Segment {
+    points: vec4<f32>, // xy = entity anchor, zw = tag corner (NDC)
+}
+
+@group(0) @binding(0)
+var<storage, read> segments: array<Segment>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let segment = segments[vertex_index / 2u].points;
+    let point = select(segment.xy, segment.zw, (vertex_index & 1u) == 1u);
+    return vec4<f32>(point, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return LINE_COLOR;
+}
@@ -0,0 +1,32 @@
+// Shader for the measurement overlay
+// Each segment is a screen-space line (NDC) with its own color: the legs between the measured
+// entities and the angle arc. The numbers are astra-gui tags drawn on top.
+
+struct Segment {
+    points: vec4<f32>, // xy = start, zw = end (NDC)
+    color: vec4<f32>,
+}
+
+@group(0) @binding(0)
+var<storage, read> segments: array<Segment>;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) @interpolate(flat) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let segment = segments[vertex_index / 2u];
+    let point = select(segment.points.xy, segment.points.zw, (vertex_index & 1u) == 1u);
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(point, 0.0, 1.0);
+    out.color = segment.color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
@@ -0,0 +1,121 @@
+// Shader for the overview minimap
+// A fixed overhead orthographic camera over the whole simulation, drawn into a corner viewport:
+// a translucent backdrop, every particle as a point, and world-space line segments for the
+// boundary circle and the main camera's frustum. Height is flattened (depth pinned mid-range) so
+// nothing is clipped above or below the overhead camera.
+
+struct Particle {
+    position: vec4<f32>,
+    velocity: vec4<f32>,
+    data: vec4<f32>,
+    color_and_flags: vec4<u32>,
+}
+
+struct Segment {
+    start: vec4<f32>,
+    end: vec4<f32>,
+    color: vec4<f32>,
+}
+
+struct MinimapUniform {
+    view_proj: mat4x4<f32>,
+}
+
+// Must match `FLAG_DORMANT` in the simulation crate
+const FLAG_DORMANT: u32 = 1u;
+
+// Catppuccin Mocha Crust #11111b, translucent
+const BACKGROUND_COLOR: vec4<f32> = vec4<f32>(0.067, 0.067, 0.106, 0.8);
+
+@group(0) @binding(0)
+var<uniform> minimap: MinimapUniform;
+
+@group(0) @binding(1)
+var<storage, read> particles: array<Particle>;
+
+@group(0) @binding(2)
+var<storage, read> segments: array<Segment>;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) @interpolate(flat) color: vec4<f32>,
+}
+
+fn project(position: vec3<f32>) -> vec4<f32> {
+    let clip = minimap.view_proj * vec4<f32>(position, 1.0);
+    return vec4<f32>(clip.xy, 0.5, clip.w);
+}
+
+// Catppuccin Mocha sRGB literals, like the other surface overlays
+fn particle_color(particle_type: u32, color_charge: u32) -> vec3<f32> {
+    if (particle_type <= 1u) {
+        switch (color_charge) {
+            case 0u: { return vec3<f32>(0.953, 0.545, 0.659); }
+            case 1u: { return vec3<f32>(0.647, 0.859, 0.627); }
+            case 2u: { return vec3<f32>(0.549, 0.753, 0.984); }
+            case 3u: { return vec3<f32>(0.961, 0.718, 0.741); }
+            case 4u: { return vec3<f32>(0.580, 0.886, 0.820); }
+            case 5u: { return vec3<f32>(0.553, 0.827, 0.937); }
+            default: { return vec3<f32>(0.803, 0.816, 0.839); }
+        }
+    }
+    switch (particle_type) {
+        case 2u: { return vec3<f32>(0.976, 0.886, 0.686); }
+        case 3u: { return vec3<f32>(0.980, 0.702, 0.529); }
+        case 4u: { return vec3<f32>(0.647, 0.859, 0.627); }
+        case 5u: { return vec3<f32>(0.549, 0.753, 0.984); }
+        default: { return vec3<f32>(0.803, 0.816, 0.839); }
+    }
+}
+
+@vertex
+fn vs_background(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    // Two triangles covering the viewport
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+    );
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(corners[vertex_index], 0.0, 1.0);
+    out.color = BACKGROUND_COLOR;
+    return out;
+}
+
+@vertex
+fn vs_particle(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let particle = particles[vertex_index];
+
+    var out: VertexOutput;
+    if ((particle.color_and_flags.y & FLAG_DORMANT) != 0u) {
+        // Outside the clip volume, so the point is dropped
+        out.clip_position = vec4<f32>(2.0, 2.0, 2.0, 1.0);
+        out.color = vec4<f32>(0.0);
+        return out;
+    }
+
+    out.clip_position = project(particle.position.xyz);
+    let particle_type = u32(particle.position.w);
+    out.color = vec4<f32>(particle_color(particle_type, particle.color_and_flags.x), 1.0);
+    return out;
+}
+
+@vertex
+fn vs_line(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let segment = segments[vertex_index / 2u];
+    let point = select(segment.start, segment.end, (vertex_index & 1u) == 1u);
+
+    var out: VertexOutput;
+    out.clip_position = project(point.xyz);
+    out.color = segment.color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
@@ -0,0 +1,32 @@
+// Weighted blended order-independent transparency: composite pass
+// The shell pipelines (`fs_shell_oit` in hadron.wgsl / nucleus.wgsl) accumulate
+// `(rgb * a, a) * weight` additively and multiply revealage by `1 - a`; this resolves the
+// weighted average color and blends it over the scene with coverage `1 - revealage`.
+
+@group(0) @binding(0)
+var accum_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var reveal_texture: texture_2d<f32>;
+
+// One oversized triangle covering the viewport
+@vertex
+fn vs_fullscreen(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+    return vec4<f32>(uv * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+}
+
+@fragment
+fn fs_composite(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let texel = vec2<i32>(position.xy);
+    let reveal = textureLoad(reveal_texture, texel, 0).r;
+
+    // Nothing translucent covers this pixel
+    if (reveal >= 0.999) {
+        discard;
+    }
+
+    let accum = textureLoad(accum_texture, texel, 0);
+    let color = accum.rgb / max(accum.a, 1e-4);
+    return vec4<f32>(color, 1.0 - reveal);
+}
@@ -15,9 +15,13 @@ struct Camera {
     lod_quark_fade_end: f32,
     lod_nucleus_fade_start: f32,
     lod_nucleus_fade_end: f32,
+    glow_scale: f32, // HDR boost per unit kinetic energy (0 = off)
 
     // Uniforms are laid out in 16-byte chunks; use 16-byte padding to avoid rounding up to 144 bytes.
-    _pad: vec4<f32>,
+    hover: vec4<u32>, // x = pick ID under the cursor (0 = nothing)
+    rest_frame: vec4<f32>, // xyz = velocity of the frame the view is drawn in (0 = lab frame)
+    impostor_shapes: vec4<u32>, // x = near shapes, y = far shapes (4 bits per particle type)
+    impostor_fade: vec4<f32>,   // x..y = camera distance of the near -> far crossfade
 }
 
 @group(0) @binding(0)
@@ -26,8 +30,8 @@ var<uniform> camera: Camera;
 struct Particle {
     position: vec4<f32>,        // xyz = position, w = particle_type (as f32)
     velocity: vec4<f32>,        // xyz = velocity, w = mass
-    data: vec4<f32>,            // x = charge, y = size, z/w = padding
-    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z/w = padding
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag, w = padding
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
 }
 
 @group(0) @binding(1)
@@ -36,7 +40,7 @@ var<storage, read> particles: array<Particle>;
 struct Hadron {
     indices_type: vec4<u32>, // x=p1, y=p2, z=p3, w=type_id
     center: vec4<f32>, // xyz, w=radius
-    velocity: vec4<f32>, // xyz, w=padding
+    velocity: vec4<f32>, // xyz, w=nucleus_id (as f32, 0 = unbound)
 }
 
 struct HadronCounter {
@@ -54,12 +58,47 @@ var<storage, read> hadrons: array<Hadron>;
 @group(0) @binding(3)
 var<storage, read> hadron_counter: HadronCounter;
 
+// Continuous quark color (xyz = weights over red/green/blue, or the anti-colors for antiquarks;
+// w = sync marker, 0 until the color flow pass has seen the particle)
+@group(0) @binding(4)
+var<storage, read> color_vectors: array<vec4<f32>>;
+
+// Selection set membership (1 = selected directly or through its hadron/nucleus)
+@group(0) @binding(5)
+var<storage, read> selection_mask: array<u32>;
+
+// Indices of the particles that survived culling (`cull.wgsl`); one instance per entry
+@group(0) @binding(6)
+var<storage, read> visible: array<u32>;
+
+// `VertexOutput.tagged` bits
+const TAG_COHORT: u32 = 1u;
+const TAG_SELECTED: u32 = 2u;
+const TAG_HOVERED: u32 = 4u;
+
+// Pick ID classes (see `picking.wgsl`)
+const HADRON_BIT: u32 = 0x80000000u;
+const NUCLEUS_BIT: u32 = 0x40000000u;
+
+// Largest HDR boost from kinetic energy (color is scaled by 1 + glow)
+const MAX_GLOW: f32 = 8.0;
+
+// Impostor shapes (`ImpostorShape`); the first `IMPOSTOR_SPECIES` particle types pick their own
+const IMPOSTOR_SPHERE: u32 = 0u;
+const IMPOSTOR_SOFT_GLOW: u32 = 1u;
+const IMPOSTOR_POINT: u32 = 2u;
+const IMPOSTOR_SPECIES: u32 = 4u;
+
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) color: vec3<f32>,
     @location(2) @interpolate(flat) particle_type: u32,
     @location(3) hadron_distance: f32,
+    @location(4) @interpolate(flat) tagged: u32,
+    @location(5) @interpolate(flat) glow: f32,
+    @location(6) @interpolate(flat) impostor: u32, // near shape | far shape << 4
+    @location(7) @interpolate(flat) impostor_blend: f32, // 0 = near shape, 1 = far shape
 }
 
 // Catppuccin Mocha colors (in linear RGB, converted from sRGB)
@@ -80,6 +119,18 @@ fn quark_color(color_charge: u32) -> vec3<f32> {
     }
 }
 
+// Blend the pure color tints by a continuous color vector (color flow)
+fn blended_quark_color(weights: vec3<f32>, anti: bool) -> vec3<f32> {
+    let w = max(weights, vec3<f32>(0.0));
+    let total = w.x + w.y + w.z;
+    if (total <= 1e-6) {
+        return quark_color(select(0u, 3u, anti));
+    }
+    let base = select(0u, 3u, anti);
+    return (quark_color(base) * w.x + quark_color(base + 1u) * w.y + quark_color(base + 2u) * w.z)
+        / total;
+}
+
 // Color mapping for composite particles (by particle type)
 fn particle_color(particle_type: u32, color_charge: u32) -> vec3<f32> {
     switch (particle_type) {
@@ -136,19 +187,40 @@ fn get_hadron_distance(particle_index: u32, particle_type: u32) -> f32 {
     return -1.0;
 }
 
+// Whether the hovered pick ID covers this particle (directly, via its hadron or its nucleus)
+fn is_hovered(particle_index: u32, hadron_id: u32) -> bool {
+    let id = camera.hover.x;
+    if (id == 0u) {
+        return false;
+    }
+    if ((id & HADRON_BIT) != 0u) {
+        return hadron_id != 0u && (id & ~HADRON_BIT) == hadron_id;
+    }
+    if ((id & NUCLEUS_BIT) != 0u) {
+        let anchor = (id & ~NUCLEUS_BIT) - 1u;
+        if (hadron_id == 0u || anchor >= arrayLength(&hadrons) || hadron_id > arrayLength(&hadrons)) {
+            return false;
+        }
+        let nucleus_id = u32(hadrons[hadron_id - 1u].velocity.w);
+        return nucleus_id != 0u && u32(hadrons[anchor].velocity.w) == nucleus_id;
+    }
+    return id == particle_index + 1u;
+}
+
 @vertex
 fn vertex(
     @builtin(vertex_index) vertex_index: u32,
     @builtin(instance_index) instance_index: u32
 ) -> VertexOutput {
-    let particle = particles[instance_index];
+    let particle_index = visible[instance_index];
+    let particle = particles[particle_index];
 
     // Extract particle type and color charge
     let particle_type = u32(particle.position.w);
     let color_charge = particle.color_and_flags.x;
 
     // Get hadron distance for LOD
-    let hadron_dist = get_hadron_distance(instance_index, particle_type);
+    let hadron_dist = get_hadron_distance(particle_index, particle_type);
 
     // Generate quad vertices
     var uv = vec2<f32>(0.0, 0.0);
@@ -188,14 +260,67 @@ fn vertex(
     out.clip_position = camera.view_proj * vec4<f32>(world_pos, 1.0);
     out.uv = uv;
     out.color = particle_color(particle_type, color_charge);
+    if (particle_type <= 1u && color_vectors[particle_index].w > 0.0) {
+        out.color = blended_quark_color(color_vectors[particle_index].xyz, color_charge >= 3u);
+    }
     out.particle_type = particle_type;
     out.hadron_distance = hadron_dist;
+    // Kinetic energy in the rest frame (mass in velocity.w) drives the HDR glow
+    let velocity = particle.velocity.xyz - camera.rest_frame.xyz;
+    let kinetic = 0.5 * particle.velocity.w * dot(velocity, velocity);
+    out.glow = min(kinetic * camera.glow_scale, MAX_GLOW);
+    if (particle_type < IMPOSTOR_SPECIES) {
+        let shift = particle_type * 4u;
+        let near = (camera.impostor_shapes.x >> shift) & 0xFu;
+        let far = (camera.impostor_shapes.y >> shift) & 0xFu;
+        out.impostor = near | (far << 4u);
+    } else {
+        out.impostor = IMPOSTOR_SPHERE | (IMPOSTOR_SPHERE << 4u);
+    }
+    out.impostor_blend = smoothstep(
+        camera.impostor_fade.x,
+        camera.impostor_fade.y,
+        distance(camera.position, particle_pos)
+    );
+    out.tagged = select(0u, TAG_COHORT, particle.data.z > 0.0) // cohort tag in data.z
+        | select(0u, TAG_SELECTED, selection_mask[particle_index] != 0u)
+        | select(0u, TAG_HOVERED, is_hovered(particle_index, particle.color_and_flags.z));
     return out;
 }
 
+// Color (rgb) and coverage (a) of one impostor shape; `uv_centered` spans -0.5..0.5
+fn shade_impostor(shape: u32, particle_type: u32, uv_centered: vec2<f32>) -> vec4<f32> {
+    let dist = length(uv_centered);
+    switch (shape) {
+        case IMPOSTOR_SOFT_GLOW: {
+            let falloff = exp(-dist * dist * 18.0);
+            return vec4<f32>(vec3<f32>(0.8 + 0.6 * falloff), falloff);
+        }
+        case IMPOSTOR_POINT: {
+            return vec4<f32>(vec3<f32>(1.0), select(0.0, 1.0, dist <= 0.18));
+        }
+        default: {
+            // Hollow center for Down quarks (type 1) to distinguish from Up quarks
+            if (dist > 0.5 || (particle_type == 1u && dist < 0.2)) {
+                return vec4<f32>(0.0);
+            }
+
+            // Calculate fake sphere normal and lighting
+            // z component based on distance from center (sphere equation: x² + y² + z² = r²)
+            let z = sqrt(max(0.0, 0.25 - dist * dist)) * 2.0;
+            let normal = normalize(vec3<f32>(uv_centered.x, uv_centered.y, z));
+
+            // Simple directional lighting (light from top-right-front)
+            let light_dir = normalize(vec3<f32>(0.5, 0.5, 1.0));
+            let diffuse = max(dot(normal, light_dir), 0.0);
+            let ambient = 0.4;
+            return vec4<f32>(vec3<f32>(ambient + diffuse * 0.6), 1.0);
+        }
+    }
+}
+
 @fragment
 fn fragment(input: VertexOutput) -> @location(0) vec4<f32> {
-    // Draw sphere
     let center = vec2<f32>(0.5, 0.5);
     let uv_centered = input.uv - center;
     let dist = length(uv_centered);
@@ -204,38 +329,51 @@ fn fragment(input: VertexOutput) -> @location(0) vec4<f32> {
         discard;
     }
 
-    // Hollow center for Down quarks (type 1) to distinguish from Up quarks
-    if (input.particle_type == 1u && dist < 0.2) {
-        discard;
-    }
+    // Crossfade the near and far impostors (colors weighted by their coverage)
+    let near = shade_impostor(input.impostor & 0xFu, input.particle_type, uv_centered);
+    let far = shade_impostor(input.impostor >> 4u, input.particle_type, uv_centered);
+    let near_weight = near.a * (1.0 - input.impostor_blend);
+    let far_weight = far.a * input.impostor_blend;
+    var coverage = near_weight + far_weight;
+    let lighting = (near.rgb * near_weight + far.rgb * far_weight) / max(coverage, 1e-4);
+
+    // Energetic particles exceed 1.0 and bloom (rims below stay LDR)
+    var final_color = input.color * lighting * (1.0 + input.glow);
 
-    // Calculate fake sphere normal and lighting
-    // z component based on distance from center (sphere equation: x² + y² + z² = r²)
-    let z = sqrt(max(0.0, 0.25 - dist * dist)) * 2.0;
-    let normal = normalize(vec3<f32>(uv_centered.x, uv_centered.y, z));
+    // Hovered entities are brightened (the rims below still mark tag/selection)
+    if ((input.tagged & TAG_HOVERED) != 0u) {
+        final_color = mix(final_color, vec3<f32>(1.0, 1.0, 1.0), 0.35);
+    }
 
-    // Simple directional lighting (light from top-right-front)
-    let light_dir = normalize(vec3<f32>(0.5, 0.5, 1.0));
-    let diffuse = max(dot(normal, light_dir), 0.0);
-    let ambient = 0.4;
-    let lighting = ambient + diffuse * 0.6;
+    // Tagged cohort particles get a bright rim so they can be followed; selection set members
+    // get a wider mauve rim (outside the cohort rim when both apply). Rims stay opaque whatever
+    // the impostor, so tagged particles remain visible as glows or points.
+    if ((input.tagged & TAG_SELECTED) != 0u && dist > 0.42) {
+        final_color = srgb_to_linear(vec3<f32>(0.796, 0.651, 0.969)); // Mauve #cba6f7
+        coverage = 1.0;
+    } else if ((input.tagged & TAG_COHORT) != 0u && dist > 0.34) {
+        final_color = vec3<f32>(1.0, 1.0, 1.0);
+        coverage = 1.0;
+    }
 
-    let final_color = input.color * lighting;
+    if (coverage < 0.01) {
+        discard;
+    }
 
     // LOD: Fade out quarks that are part of hadrons (controlled by quark sliders)
-    // Free quarks (not part of hadrons) remain fully opaque
+    // Free quarks (not part of hadrons) do not fade
     // < quark_fade_start: Fully visible (alpha = 1)
     // quark_fade_start to quark_fade_end: Fade from 1 to 0
     // > quark_fade_end: Invisible (alpha = 0)
-    var alpha = 1.0;
+    var alpha = coverage;
     if (input.hadron_distance >= 0.0) {
         // This quark is part of a hadron - apply fade OUT
-        alpha = 1.0 - smoothstep(camera.lod_quark_fade_start, camera.lod_quark_fade_end, input.hadron_distance);
+        alpha *= 1.0 - smoothstep(camera.lod_quark_fade_start, camera.lod_quark_fade_end, input.hadron_distance);
         if (alpha < 0.01) {
             discard;
         }
     }
-    // Free quarks keep alpha = 1.0 (no fading)
+    // Free quarks keep their impostor coverage (no fading)
 
     return vec4<f32>(final_color, alpha);
 }
@@ -0,0 +1,112 @@
+// Shader for rendering emitted photons
+// Each photon is an expanding, fading ring at its emission point, colored by its energy
+// (low energy = red, high energy = violet) so characteristic emission lines stand out.
+
+// Seconds (simulation time) a photon flash stays visible
+const PHOTON_LIFETIME: f32 = 1.5;
+// World-space radius of the flash at the end of its lifetime
+const PHOTON_MAX_RADIUS: f32 = 1.5;
+// HDR brightness of a fresh flash (values above 1.0 bloom)
+const PHOTON_INTENSITY: f32 = 4.0;
+
+struct Camera {
+    view_proj: mat4x4<f32>,
+    position: vec3<f32>,
+    particle_size: f32,
+    time: f32,
+    lod_shell_fade_start: f32,
+    lod_shell_fade_end: f32,
+    lod_bound_hadron_fade_start: f32,
+    lod_bound_hadron_fade_end: f32,
+    lod_bond_fade_start: f32,
+    lod_bond_fade_end: f32,
+    lod_quark_fade_start: f32,
+    lod_quark_fade_end: f32,
+    lod_nucleus_fade_start: f32,
+    lod_nucleus_fade_end: f32,
+
+    // Uniforms are laid out in 16-byte chunks; use 16-byte padding to avoid rounding up to 144 bytes.
+    _pad: vec4<f32>,
+}
+
+struct Photon {
+    position: vec4<f32>, // xyz = emission position, w = energy (0 = empty slot)
+    info: vec4<f32>,     // x = emission time, y = upper level, z = lower level, w = normalized energy
+}
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+@group(0) @binding(1)
+var<storage, read> photons: array<Photon>;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) uv: vec2<f32>,
+}
+
+// Map normalized energy to a visible-spectrum color (red -> yellow -> green -> blue -> violet)
+fn spectrum_color(t: f32) -> vec3<f32> {
+    let x = clamp(t, 0.0, 1.0);
+    let r = clamp(1.5 - abs(x * 4.0 - 0.0) + max(x * 4.0 - 3.0, 0.0), 0.0, 1.0);
+    let g = clamp(1.5 - abs(x * 4.0 - 1.5), 0.0, 1.0);
+    let b = clamp(1.5 - abs(x * 4.0 - 3.0), 0.0, 1.0);
+    return vec3<f32>(r, g, b);
+}
+
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32
+) -> VertexOutput {
+    var out: VertexOutput;
+
+    let photon = photons[instance_index];
+    let age = camera.time - photon.info.x;
+
+    // Empty slot or expired flash: degenerate quad
+    if (photon.position.w <= 0.0 || age < 0.0 || age > PHOTON_LIFETIME) {
+        out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+        return out;
+    }
+
+    var uv = vec2<f32>(0.0, 0.0);
+    switch (vertex_index) {
+        case 0u, 3u: { uv = vec2<f32>(-1.0, -1.0); }
+        case 1u: { uv = vec2<f32>(1.0, -1.0); }
+        case 2u, 4u: { uv = vec2<f32>(1.0, 1.0); }
+        case 5u: { uv = vec2<f32>(-1.0, 1.0); }
+        default: {}
+    }
+
+    // Billboard
+    let center = photon.position.xyz;
+    let to_camera = normalize(camera.position - center);
+    let right = normalize(cross(vec3<f32>(0.0, 1.0, 0.0), to_camera));
+    let up = cross(to_camera, right);
+
+    let life = age / PHOTON_LIFETIME;
+    let radius = PHOTON_MAX_RADIUS * (0.2 + 0.8 * life);
+    let world_pos = center + (right * uv.x + up * uv.y) * radius;
+
+    out.clip_position = camera.view_proj * vec4<f32>(world_pos, 1.0);
+    out.uv = uv;
+    out.color = vec4<f32>(spectrum_color(photon.info.w), 1.0 - life);
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let dist = length(input.uv);
+    if (dist > 1.0) {
+        discard;
+    }
+
+    // Bright ring with a soft core
+    let ring = exp(-pow((dist - 0.8) * 8.0, 2.0));
+    let core = exp(-dist * dist * 6.0) * 0.5;
+    let intensity = (ring + core) * input.color.a;
+
+    return vec4<f32>(input.color.rgb * intensity * PHOTON_INTENSITY, intensity);
+}
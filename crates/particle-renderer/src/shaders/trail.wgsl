@@ -0,0 +1,115 @@
+// Shader for rendering particle trails
+// Each particle instance draws `length - 1` line segments through its ring of recent positions
+// (written by the simulation's integration pass); segments fade out with age.
+
+// Samples per particle in the trail buffer (must match the simulation's TRAIL_CAPACITY)
+const TRAIL_CAPACITY: u32 = 64u;
+const FLAG_DORMANT: u32 = 1u;
+
+struct Camera {
+    view_proj: mat4x4<f32>,
+    position: vec3<f32>,
+    particle_size: f32,
+    time: f32,
+    lod_shell_fade_start: f32,
+    lod_shell_fade_end: f32,
+    lod_bound_hadron_fade_start: f32,
+    lod_bound_hadron_fade_end: f32,
+    lod_bond_fade_start: f32,
+    lod_bond_fade_end: f32,
+    lod_quark_fade_start: f32,
+    lod_quark_fade_end: f32,
+    lod_nucleus_fade_start: f32,
+    lod_nucleus_fade_end: f32,
+
+    // Uniforms are laid out in 16-byte chunks; use 16-byte padding to avoid rounding up to 144 bytes.
+    _pad: vec4<f32>,
+    rest_frame: vec4<f32>, // xyz = velocity of the frame the view is drawn in, w = simulation time per sample
+}
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag, w = vacuum partner + 1
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+struct TrailParams {
+    length: u32,
+    head: u32, // slot of the newest sample
+    enabled: u32,
+    _pad: u32,
+}
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+@group(0) @binding(1)
+var<storage, read> particles: array<Particle>;
+
+// xyz = position, w = link to the previous sample (0 = empty, 1 = starts a run, 2 = continues)
+@group(0) @binding(2)
+var<storage, read> trails: array<vec4<f32>>;
+
+@group(0) @binding(3)
+var<uniform> trail_params: TrailParams;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    return pow(c, vec3<f32>(2.2));
+}
+
+// Catppuccin Mocha tint per particle type (quarks by flavor, not color charge)
+fn trail_color(particle_type: u32) -> vec3<f32> {
+    switch (particle_type) {
+        case 0u: { return srgb_to_linear(vec3<f32>(0.953, 0.545, 0.659)); } // Up (red)
+        case 1u: { return srgb_to_linear(vec3<f32>(0.549, 0.753, 0.984)); } // Down (blue)
+        case 2u: { return srgb_to_linear(vec3<f32>(0.976, 0.886, 0.686)); } // Electron (yellow)
+        case 3u: { return srgb_to_linear(vec3<f32>(0.980, 0.702, 0.529)); } // Gluon (peach)
+        default: { return srgb_to_linear(vec3<f32>(0.803, 0.816, 0.839)); } // Text
+    }
+}
+
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32
+) -> VertexOutput {
+    var out: VertexOutput;
+    // Off-screen by default (empty/broken segments and dormant particles)
+    out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    out.color = vec4<f32>(0.0);
+
+    let particle = particles[instance_index];
+    if ((particle.color_and_flags.y & FLAG_DORMANT) != 0u) {
+        return out;
+    }
+
+    // Segment `s` joins the samples aged s + 1 (older end) and s (newer end)
+    let length = trail_params.length;
+    let segment = vertex_index / 2u;
+    let base = instance_index * TRAIL_CAPACITY;
+    let newer = trails[base + (trail_params.head + length - segment) % length];
+    if (newer.w < 2.0) {
+        return out;
+    }
+
+    let age = segment + 1u - (vertex_index % 2u);
+    let sample = trails[base + (trail_params.head + length - age) % length];
+
+    // In a moving rest frame, older samples are carried along with it
+    let position = sample.xyz + camera.rest_frame.xyz * camera.rest_frame.w * f32(age);
+    out.clip_position = camera.view_proj * vec4<f32>(position, 1.0);
+    let fade = 1.0 - f32(age) / f32(length);
+    out.color = vec4<f32>(trail_color(u32(particle.position.w)), fade * fade * 0.8);
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    return input.color;
+}
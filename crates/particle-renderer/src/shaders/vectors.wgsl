@@ -0,0 +1,176 @@
+// Shader for vector visualizations (debug mode)
+// `vs_velocity`: one arrow per particle along its velocity
+// `vs_field`:    one arrow per force field sample (see the simulation's `force_field.wgsl`)
+//
+// Arrows are drawn as line lists, 6 vertices each: shaft, then two barbs at the tip.
+
+const FLAG_DORMANT: u32 = 1u;
+const VERTICES_PER_ARROW: u32 = 6u;
+// Decades of magnitude below the maximum that still get a visible color
+const FIELD_DECADES: f32 = 3.0;
+
+struct Camera {
+    view_proj: mat4x4<f32>,
+    position: vec3<f32>,
+    particle_size: f32,
+    time: f32,
+    lod_shell_fade_start: f32,
+    lod_shell_fade_end: f32,
+    lod_bound_hadron_fade_start: f32,
+    lod_bound_hadron_fade_end: f32,
+    lod_bond_fade_start: f32,
+    lod_bond_fade_end: f32,
+    lod_quark_fade_start: f32,
+    lod_quark_fade_end: f32,
+    lod_nucleus_fade_start: f32,
+    lod_nucleus_fade_end: f32,
+
+    // Uniforms are laid out in 16-byte chunks; use 16-byte padding to avoid rounding up to 144 bytes.
+    _pad: vec4<f32>,
+    rest_frame: vec4<f32>, // xyz = velocity of the frame the view is drawn in (0 = lab frame)
+}
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag, w = vacuum partner + 1
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+struct VectorParams {
+    velocity_scale: f32, // world units per unit speed
+    max_length: f32,     // velocity arrows are capped at this length
+    _pad0: f32,
+    _pad1: f32,
+}
+
+struct FieldSample {
+    position: vec4<f32>, // xyz = grid point, w = arrow length
+    force: vec4<f32>,    // xyz = force, w = magnitude
+}
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+// Velocity arrows
+@group(0) @binding(1)
+var<storage, read> particles: array<Particle>;
+
+@group(0) @binding(2)
+var<uniform> vector_params: VectorParams;
+
+// Force field arrows
+@group(0) @binding(3)
+var<storage, read> samples: array<FieldSample>;
+
+@group(0) @binding(4)
+var<storage, read> field_max: u32; // f32 bit pattern
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    return pow(c, vec3<f32>(2.2));
+}
+
+// Catppuccin Mocha ramp: blue (weak/slow) -> yellow -> red (strong/fast)
+fn ramp(t: f32) -> vec3<f32> {
+    let blue = vec3<f32>(0.537, 0.706, 0.980);
+    let yellow = vec3<f32>(0.976, 0.886, 0.686);
+    let red = vec3<f32>(0.953, 0.545, 0.659);
+    if (t < 0.5) {
+        return srgb_to_linear(mix(blue, yellow, t * 2.0));
+    }
+    return srgb_to_linear(mix(yellow, red, t * 2.0 - 1.0));
+}
+
+// Vertex `corner` (0..6) of an arrow from `tail` along `arrow`
+fn arrow_vertex(corner: u32, tail: vec3<f32>, arrow: vec3<f32>) -> vec3<f32> {
+    let tip = tail + arrow;
+    if (corner == 0u) {
+        return tail;
+    }
+    if (corner % 2u == 1u) {
+        return tip;
+    }
+
+    // Barbs lie in the plane facing the camera
+    let len = length(arrow);
+    let dir = arrow / len;
+    var side = cross(dir, camera.position - tip);
+    if (dot(side, side) < 1e-8) {
+        side = cross(dir, vec3<f32>(0.0, 1.0, 0.0));
+        if (dot(side, side) < 1e-8) {
+            side = cross(dir, vec3<f32>(1.0, 0.0, 0.0));
+        }
+    }
+    side = normalize(side);
+    let side_sign = select(-1.0, 1.0, corner == 2u);
+    return tip - dir * len * 0.25 + side * side_sign * len * 0.12;
+}
+
+fn hidden() -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    out.color = vec4<f32>(0.0);
+    return out;
+}
+
+@vertex
+fn vs_velocity(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32
+) -> VertexOutput {
+    let particle = particles[instance_index];
+    if ((particle.color_and_flags.y & FLAG_DORMANT) != 0u) {
+        return hidden();
+    }
+
+    var arrow = (particle.velocity.xyz - camera.rest_frame.xyz) * vector_params.velocity_scale;
+    let len = length(arrow);
+    if (len < 1e-4) {
+        return hidden();
+    }
+    if (len > vector_params.max_length) {
+        arrow = arrow / len * vector_params.max_length;
+    }
+
+    let position = arrow_vertex(vertex_index % VERTICES_PER_ARROW, particle.position.xyz, arrow);
+    let t = clamp(len / vector_params.max_length, 0.0, 1.0);
+
+    var out: VertexOutput;
+    out.clip_position = camera.view_proj * vec4<f32>(position, 1.0);
+    out.color = vec4<f32>(ramp(t), 0.9);
+    return out;
+}
+
+@vertex
+fn vs_field(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32
+) -> VertexOutput {
+    let sample = samples[instance_index];
+    let magnitude = sample.force.w;
+    let max_magnitude = bitcast<f32>(field_max);
+    if (magnitude <= 0.0 || max_magnitude <= 0.0) {
+        return hidden();
+    }
+
+    // Fixed-length arrows centered on the grid point; magnitude goes into the color (log scale)
+    let arrow = sample.force.xyz / length(sample.force.xyz) * sample.position.w;
+    let tail = sample.position.xyz - arrow * 0.5;
+    let position = arrow_vertex(vertex_index % VERTICES_PER_ARROW, tail, arrow);
+    let t = clamp(1.0 + log(magnitude / max_magnitude) / (log(10.0) * FIELD_DECADES), 0.0, 1.0);
+
+    var out: VertexOutput;
+    out.clip_position = camera.view_proj * vec4<f32>(position, 1.0);
+    out.color = vec4<f32>(ramp(t), mix(0.25, 0.9, t));
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    return input.color;
+}
@@ -0,0 +1,263 @@
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+/// Vertices per arrow (shaft + two barbs, line list)
+const VERTICES_PER_ARROW: u32 = 6;
+
+/// Size in bytes of one force field sample (matches WGSL `FieldSample`)
+const FIELD_SAMPLE_SIZE: u64 = 32;
+
+/// Arrow scaling for the velocity view (uniform, matches WGSL `VectorParams`)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+struct VectorParams {
+    velocity_scale: f32,
+    max_length: f32,
+    _pad: [f32; 2],
+}
+
+/// Debug vector visualization: per-particle velocity arrows and force field slice arrows
+/// (alpha blended lines, depth tested, no depth writes).
+pub struct VectorRenderer {
+    velocity_pipeline: wgpu::RenderPipeline,
+    field_pipeline: wgpu::RenderPipeline,
+    velocity_bind_group_layout: wgpu::BindGroupLayout,
+    field_bind_group_layout: wgpu::BindGroupLayout,
+    params_buffer: wgpu::Buffer,
+}
+
+impl VectorRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+        cache: Option<&wgpu::PipelineCache>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Vector Renderer Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/vectors.wgsl").into()),
+        });
+
+        let camera_entry = wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: Some(
+                    std::num::NonZeroU64::new(
+                        (std::mem::size_of::<crate::camera::CameraUniform>() as u64).div_ceil(16)
+                            * 16,
+                    )
+                    .unwrap(),
+                ),
+            },
+            count: None,
+        };
+        let buffer_entry = |binding, ty| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Buffer {
+                ty,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let storage = wgpu::BufferBindingType::Storage { read_only: true };
+
+        let velocity_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Velocity Vector Bind Group Layout"),
+                entries: &[
+                    // Camera (Uniform) - Binding 0
+                    camera_entry,
+                    // Particles (Storage) - Binding 1
+                    buffer_entry(1, storage),
+                    // Arrow scaling (Uniform) - Binding 2
+                    buffer_entry(2, wgpu::BufferBindingType::Uniform),
+                ],
+            });
+
+        let field_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Force Field Vector Bind Group Layout"),
+                entries: &[
+                    // Camera (Uniform) - Binding 0
+                    camera_entry,
+                    // Field samples (Storage) - Binding 3
+                    buffer_entry(3, storage),
+                    // Max field magnitude (Storage) - Binding 4
+                    buffer_entry(4, storage),
+                ],
+            });
+
+        let create_pipeline = |label, layout: &wgpu::BindGroupLayout, entry_point| {
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some(label),
+                bind_group_layouts: &[layout],
+                immediate_size: 0,
+            });
+
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::LineList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: None,
+                    unclipped_depth: false,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    conservative: false,
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: false,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: sample_count,
+                    ..Default::default()
+                },
+                multiview_mask: None,
+                cache,
+            })
+        };
+
+        let velocity_pipeline = create_pipeline(
+            "Velocity Vector Pipeline",
+            &velocity_bind_group_layout,
+            "vs_velocity",
+        );
+        let field_pipeline = create_pipeline(
+            "Force Field Vector Pipeline",
+            &field_bind_group_layout,
+            "vs_field",
+        );
+
+        let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Vector Params Buffer"),
+            contents: bytemuck::cast_slice(&[VectorParams {
+                velocity_scale: 1.0,
+                max_length: 10.0,
+                _pad: [0.0; 2],
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self {
+            velocity_pipeline,
+            field_pipeline,
+            velocity_bind_group_layout,
+            field_bind_group_layout,
+            params_buffer,
+        }
+    }
+
+    /// Velocity arrows are `scale` world units per unit speed, capped at `max_length`.
+    pub fn set_velocity_scale(&self, queue: &wgpu::Queue, scale: f32, max_length: f32) {
+        let params = VectorParams {
+            velocity_scale: scale,
+            max_length: max_length.max(1e-3),
+            _pad: [0.0; 2],
+        };
+        queue.write_buffer(&self.params_buffer, 0, bytemuck::cast_slice(&[params]));
+    }
+
+    /// Draw one arrow per particle along its velocity.
+    pub fn render_velocity(
+        &self,
+        device: &wgpu::Device,
+        render_pass: &mut wgpu::RenderPass,
+        camera_buffer: &wgpu::Buffer,
+        particle_buffer: &wgpu::Buffer,
+        particle_count: u32,
+        show_velocity: bool,
+    ) {
+        if !show_velocity || particle_count == 0 {
+            return;
+        }
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Velocity Vector Bind Group"),
+            layout: &self.velocity_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: camera_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.params_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        render_pass.set_pipeline(&self.velocity_pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..VERTICES_PER_ARROW, 0..particle_count);
+    }
+
+    /// Draw one arrow per force field sample in `sample_buffer` (direction of the force, colored
+    /// by magnitude relative to the largest sample).
+    pub fn render_force_field(
+        &self,
+        device: &wgpu::Device,
+        render_pass: &mut wgpu::RenderPass,
+        camera_buffer: &wgpu::Buffer,
+        sample_buffer: &wgpu::Buffer,
+        max_buffer: &wgpu::Buffer,
+        show_force_field: bool,
+    ) {
+        let sample_count = (sample_buffer.size() / FIELD_SAMPLE_SIZE) as u32;
+        if !show_force_field || sample_count == 0 {
+            return;
+        }
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Force Field Vector Bind Group"),
+            layout: &self.field_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: camera_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: sample_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: max_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        render_pass.set_pipeline(&self.field_pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..VERTICES_PER_ARROW, 0..sample_count);
+    }
+}
@@ -0,0 +1,183 @@
+//! Beam mode: collider-style scattering experiments
+//!
+//! Two opposing beams of protons (`uud` triplets) are fired at each other along the z axis.
+//! The beams are offset along x by the impact parameter. Each run of the experiment is a
+//! sequence of events; an event re-seeds the particle buffer with fresh beams, runs for a fixed
+//! number of simulation steps, then records a [`BeamEventStats`] summary.
+
+use glam::Vec3;
+use particle_physics::{ColorCharge, Particle, QUARK_DOWN_MASS, QUARK_UP_MASS};
+
+/// Scenario parameters for a beam experiment
+#[derive(Clone, Copy, Debug)]
+pub struct BeamConfig {
+    /// Kinetic energy per proton (simulation units)
+    pub energy: f32,
+    /// Transverse offset between the two beam axes
+    pub impact_parameter: f32,
+    /// Protons per beam
+    pub protons_per_beam: usize,
+    /// Radius of each beam's cross-section
+    pub beam_radius: f32,
+    /// Initial distance between the two beam fronts
+    pub separation: f32,
+    /// Simulation steps per event before statistics are recorded
+    pub steps_per_event: u32,
+    /// Number of events in a run
+    pub event_count: u32,
+}
+
+impl Default for BeamConfig {
+    fn default() -> Self {
+        Self {
+            energy: 1.0,
+            impact_parameter: 0.0,
+            protons_per_beam: 64,
+            beam_radius: 4.0,
+            separation: 20.0,
+            steps_per_event: 600,
+            event_count: 10,
+        }
+    }
+}
+
+/// Summary of a single beam event, recorded once the event has run its course
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BeamEventStats {
+    pub event: u32,
+    pub energy: f32,
+    pub impact_parameter: f32,
+    pub hadrons: u32,
+    pub protons: u32,
+    pub neutrons: u32,
+    pub other_hadrons: u32,
+    /// Photons emitted during the event (sum over the emission spectrum)
+    pub photons: u32,
+}
+
+/// A running beam experiment: tracks event progress and collects per-event statistics
+#[derive(Clone, Debug)]
+pub struct BeamExperiment {
+    pub config: BeamConfig,
+    pub events: Vec<BeamEventStats>,
+    event: u32,
+    steps: u32,
+}
+
+impl BeamExperiment {
+    pub fn new(config: BeamConfig) -> Self {
+        Self {
+            config,
+            events: Vec::with_capacity(config.event_count as usize),
+            event: 0,
+            steps: 0,
+        }
+    }
+
+    /// Index of the event currently running (0-based)
+    pub fn current_event(&self) -> u32 {
+        self.event
+    }
+
+    /// Steps run so far in the current event
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    /// True once every event of the run has been recorded
+    pub fn is_finished(&self) -> bool {
+        self.event >= self.config.event_count
+    }
+
+    /// Generate the particle buffer for the current event, padded with dormant slots to `capacity`.
+    pub fn generate_event(&self, capacity: usize) -> Vec<Particle> {
+        generate_beams(&self.config, self.event, capacity)
+    }
+
+    /// Count one simulation step; returns true when the current event has run its course.
+    pub fn advance(&mut self) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        self.steps += 1;
+        self.steps >= self.config.steps_per_event
+    }
+
+    /// Record the statistics of the current event and move on to the next one.
+    pub fn finish_event(&mut self, mut stats: BeamEventStats) {
+        stats.event = self.event;
+        stats.energy = self.config.energy;
+        stats.impact_parameter = self.config.impact_parameter;
+        self.events.push(stats);
+        self.event += 1;
+        self.steps = 0;
+    }
+}
+
+/// Build two opposing proton beams for `event` (the event index seeds the transverse jitter).
+///
+/// Beam A travels +z from `z = -separation / 2`, offset by `+b / 2` along x; beam B mirrors it.
+/// Remaining slots up to `capacity` are dormant so string breaking can still spawn pairs.
+pub fn generate_beams(config: &BeamConfig, event: u32, capacity: usize) -> Vec<Particle> {
+    let mut particles = Vec::with_capacity(capacity);
+
+    // Constituent mass of a proton (uud)
+    let proton_mass = 2.0 * QUARK_UP_MASS + QUARK_DOWN_MASS;
+    let speed = (2.0 * config.energy.max(0.0) / proton_mass).sqrt();
+    let quark_offset = 0.3;
+
+    for (beam, direction) in [(0u32, 1.0f32), (1u32, -1.0f32)] {
+        let axis_x = direction * config.impact_parameter * 0.5;
+        let front_z = -direction * config.separation * 0.5;
+
+        for i in 0..config.protons_per_beam {
+            if particles.len() + 3 > capacity {
+                break;
+            }
+
+            // Sunflower spiral over the beam cross-section, staggered along the beam axis
+            let seed = hash(event, beam * 0x10000 + i as u32);
+            let r = config.beam_radius * ((i as f32 + 0.5) / config.protons_per_beam as f32).sqrt();
+            let theta = i as f32 * 2.399_963 + seed * std::f32::consts::TAU;
+            let depth = (i % 4) as f32 * 1.5 + seed;
+            let center = Vec3::new(
+                axis_x + r * theta.cos(),
+                r * theta.sin(),
+                front_z - direction * depth,
+            );
+
+            let quarks = [
+                Particle::new_up_quark(
+                    center + Vec3::new(quark_offset, 0.0, 0.0),
+                    ColorCharge::Red,
+                ),
+                Particle::new_up_quark(
+                    center + Vec3::new(-0.5 * quark_offset, 0.866 * quark_offset, 0.0),
+                    ColorCharge::Green,
+                ),
+                Particle::new_down_quark(
+                    center + Vec3::new(-0.5 * quark_offset, -0.866 * quark_offset, 0.0),
+                    ColorCharge::Blue,
+                ),
+            ];
+            for mut quark in quarks {
+                quark.velocity[2] = direction * speed;
+                particles.push(quark);
+            }
+        }
+    }
+
+    particles.resize(capacity, Particle::new_dormant());
+    particles
+}
+
+/// Deterministic hash in [0, 1) so events are reproducible but not identical
+fn hash(a: u32, b: u32) -> f32 {
+    let mut x = a.wrapping_mul(0x9e37_79b9) ^ b.wrapping_mul(0x85eb_ca6b);
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846c_a68b);
+    x ^= x >> 16;
+    (x >> 8) as f32 / (1u32 << 24) as f32
+}
@@ -0,0 +1,34 @@
+//! Simulation boundary: escape accounting and re-injection
+//!
+//! The integration pass counts every particle that crosses the boundary sphere
+//! (`PhysicsParams::boundary`) into a per-species atomic counter. With re-injection enabled the
+//! particle is put back on the boundary with an inward thermal velocity instead of flying off.
+
+/// Size in bytes of the escape counter buffer (one `u32` per species)
+pub const ESCAPE_COUNTS_SIZE: u64 = 16;
+
+/// Cumulative boundary crossings per species, as read back from the GPU
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EscapeCounts {
+    pub up: u32,
+    pub down: u32,
+    pub electron: u32,
+    pub gluon: u32,
+}
+
+impl EscapeCounts {
+    /// Decode the mapped counter buffer (4 little-endian `u32` values, indexed by particle type).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            up: value(0),
+            down: value(1),
+            electron: value(2),
+            gluon: value(3),
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.up + self.down + self.electron + self.gluon
+    }
+}
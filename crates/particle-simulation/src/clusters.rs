@@ -0,0 +1,50 @@
+//! Cluster identification (connected components of the nucleon bond graph)
+//!
+//! Two nucleons are bonded when their centers are within the nucleon binding range, the same
+//! criterion nucleus detection uses. Unlike nuclei, clusters have no `MAX_NUCLEONS` cap, so
+//! nuclei-of-nuclei and larger aggregates show up in the size distribution.
+
+/// Number of cluster size bins (bin `i` counts clusters of `i + 1` nucleons, the last bin
+/// everything at least `CLUSTER_SIZE_BINS` large)
+pub const CLUSTER_SIZE_BINS: usize = 32;
+
+/// Size in bytes of the cluster stats buffer (4x `u32` header + size bins)
+pub const CLUSTER_STATS_SIZE: u64 = (4 + CLUSTER_SIZE_BINS as u64) * 4;
+
+/// Label propagation + pointer jumping rounds per analysis. Each round at least doubles the
+/// distance a label travels, so chains up to `2^CLUSTER_ROUNDS` nucleons converge.
+pub const CLUSTER_ROUNDS: u32 = 10;
+
+/// Cluster size distribution, as read back from the GPU
+#[derive(Clone, Copy, Debug)]
+pub struct ClusterStats {
+    /// Clusters with at least two nucleons
+    pub clusters: u32,
+    /// Nucleons in the largest cluster
+    pub largest: u32,
+    /// Cluster count per size (see `CLUSTER_SIZE_BINS`)
+    pub sizes: [u32; CLUSTER_SIZE_BINS],
+}
+
+impl Default for ClusterStats {
+    fn default() -> Self {
+        Self {
+            clusters: 0,
+            largest: 0,
+            sizes: [0; CLUSTER_SIZE_BINS],
+        }
+    }
+}
+
+impl ClusterStats {
+    /// Decode the mapped stats buffer (little-endian `u32` values: clusters, largest, 2 padding,
+    /// then the size bins).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            clusters: value(0),
+            largest: value(1),
+            sizes: std::array::from_fn(|i| value(4 + i)),
+        }
+    }
+}
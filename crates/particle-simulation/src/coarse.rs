@@ -0,0 +1,72 @@
+//! Coarse-graining LOD: distant particle clusters become super-particles
+//!
+//! Free particles far from the camera merge pairwise (same species, color and cohort tag) into a
+//! super-particle carrying their total mass and charge at their center of mass and momentum.
+//! Every absorbed member remembers its offset and relative velocity, so when the camera comes
+//! back within the split distance the merges are undone in reverse order. Absorbed members are
+//! flagged dormant, so the rest of the pipeline simply skips them.
+
+use bytemuck::{Pod, Zeroable};
+
+/// Size in bytes of the coarse-graining stats buffer (4x `u32`)
+pub const COARSE_STATS_SIZE: u64 = 16;
+
+/// Size in bytes of one per-particle membership link (matches WGSL `CoarseLink`)
+pub const COARSE_LINK_SIZE: u64 = 48;
+
+/// Coarse-graining parameters (uniform, matches WGSL)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CoarseParams {
+    /// xyz = camera position, w = merge distance (particles beyond it may merge)
+    pub camera: [f32; 4],
+    /// x = split distance (super-particles closer than this split), y = merge radius,
+    /// z = max members per super-particle, w = enabled (0/1; disabling splits everything)
+    pub config: [f32; 4],
+}
+
+impl Default for CoarseParams {
+    fn default() -> Self {
+        Self {
+            camera: [0.0, 0.0, 0.0, 120.0],
+            config: [80.0, 1.5, 8.0, 0.0],
+        }
+    }
+}
+
+impl CoarseParams {
+    pub fn enabled(&self) -> bool {
+        self.config[3] > 0.5
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.config[3] = if enabled { 1.0 } else { 0.0 };
+    }
+
+    /// Update the camera position the merge/split distances are measured from.
+    pub fn set_camera(&mut self, position: [f32; 3]) {
+        self.camera[0] = position[0];
+        self.camera[1] = position[1];
+        self.camera[2] = position[2];
+    }
+}
+
+/// Current coarse-graining state, as read back from the GPU
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CoarseStats {
+    /// Active particles standing in for at least one absorbed member
+    pub super_particles: u32,
+    /// Particles currently absorbed into a super-particle
+    pub absorbed: u32,
+}
+
+impl CoarseStats {
+    /// Decode the mapped stats buffer (little-endian `u32` values: super-particles, absorbed).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            super_particles: value(0),
+            absorbed: value(1),
+        }
+    }
+}
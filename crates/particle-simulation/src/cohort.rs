@@ -0,0 +1,46 @@
+//! Particle tagging and cohort tracking
+//!
+//! Particles carry a cohort tag in `data.z` (0 = untagged). The tag pass stamps a tag onto every
+//! particle inside a sphere; the stats pass counts where the tagged cohort currently is (free,
+//! bound in a hadron, or part of a nucleus) so its fate can be followed over time.
+
+use bytemuck::{Pod, Zeroable};
+
+/// Size in bytes of the cohort stats buffer (4x `u32`)
+pub const COHORT_STATS_SIZE: u64 = 16;
+
+/// Tag pass parameters (uniform, matches WGSL)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
+pub struct TagParams {
+    /// xyz = sphere center, w = sphere radius
+    pub sphere: [f32; 4],
+    /// x = tag to apply / track, y/z/w = padding
+    pub tag: [u32; 4],
+}
+
+/// Where the tracked cohort currently is, as read back from the GPU
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CohortStats {
+    /// Particles carrying the tracked tag
+    pub total: u32,
+    /// Tagged particles not bound in any hadron
+    pub free: u32,
+    /// Tagged particles bound in a hadron that is not part of a nucleus
+    pub in_hadrons: u32,
+    /// Tagged particles whose hadron belongs to a nucleus
+    pub in_nuclei: u32,
+}
+
+impl CohortStats {
+    /// Decode the mapped stats buffer (4 little-endian `u32` values).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            total: value(0),
+            free: value(1),
+            in_hadrons: value(2),
+            in_nuclei: value(3),
+        }
+    }
+}
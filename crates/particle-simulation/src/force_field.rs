@@ -0,0 +1,59 @@
+//! Force field slice (debug visualization)
+//!
+//! A small compute pass evaluates the force a probe particle would feel at each point of a
+//! `FORCE_FIELD_GRID` x `FORCE_FIELD_GRID` grid on a plane, summed over every live particle.
+//! Only the long-range forces that do not depend on the probe's flavour or color are included
+//! (gravity, electromagnetism, weak); the strong force needs a color and is left out, and the
+//! probe has no species, so coupling multipliers are not applied.
+
+use bytemuck::{Pod, Zeroable};
+
+/// Samples per side of the slice grid (must match `force_field.wgsl`)
+pub const FORCE_FIELD_GRID: u32 = 32;
+
+/// Size in bytes of one field sample (2x `vec4<f32>`, matches WGSL `FieldSample`)
+pub const FORCE_FIELD_SAMPLE_SIZE: u64 = 32;
+
+/// Slice placement and probe (uniform, matches WGSL `FieldParams`)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ForceFieldParams {
+    /// xyz = slice center, w = half extent along each axis
+    pub center: [f32; 4],
+    /// xyz = first in-plane axis (unit), w = padding
+    pub axis_u: [f32; 4],
+    /// xyz = second in-plane axis (unit), w = padding
+    pub axis_v: [f32; 4],
+    /// x = probe charge (e), y = probe mass, z/w = padding
+    pub probe: [f32; 4],
+}
+
+impl Default for ForceFieldParams {
+    fn default() -> Self {
+        Self {
+            center: [0.0, 0.0, 0.0, 50.0],
+            axis_u: [1.0, 0.0, 0.0, 0.0],
+            axis_v: [0.0, 1.0, 0.0, 0.0],
+            probe: [1.0, 1.0, 0.0, 0.0],
+        }
+    }
+}
+
+impl ForceFieldParams {
+    /// Slice through `center` spanned by `axis_u`/`axis_v` (e.g. the camera's right/up vectors,
+    /// so the slice faces the viewer), `half_extent` from the center along each axis.
+    pub fn slice(center: [f32; 3], axis_u: [f32; 3], axis_v: [f32; 3], half_extent: f32) -> Self {
+        Self {
+            center: [center[0], center[1], center[2], half_extent],
+            axis_u: [axis_u[0], axis_u[1], axis_u[2], 0.0],
+            axis_v: [axis_v[0], axis_v[1], axis_v[2], 0.0],
+            ..Default::default()
+        }
+    }
+
+    /// Set the probe's charge (in e) and mass.
+    pub fn with_probe(mut self, charge: f32, mass: f32) -> Self {
+        self.probe = [charge, mass, 0.0, 0.0];
+        self
+    }
+}
@@ -0,0 +1,126 @@
+//! GPU-computed diagnostics histograms
+//!
+//! The histogram pass bins particle speeds, pair separations inside hadrons and hadron masses
+//! into a single `u32` storage buffer using atomic increments. The CPU reads the buffer back
+//! and decodes it with [`Histograms::from_bytes`].
+//!
+//! A second entry point fills a 2D charge/mass map ("mass spectrometer") over free particles and
+//! hadrons, decoded with [`ChargeMassMap::from_bytes`].
+
+use bytemuck::{Pod, Zeroable};
+
+/// Number of bins per histogram (must match `histogram.wgsl`)
+pub const HISTOGRAM_BINS: usize = 32;
+
+/// Number of histograms computed by the pass
+pub const HISTOGRAM_COUNT: usize = 3;
+
+/// Size in bytes of the histogram storage buffer
+pub const HISTOGRAM_BUFFER_SIZE: u64 = (HISTOGRAM_BINS * HISTOGRAM_COUNT * 4) as u64;
+
+/// Charge rows in the charge/mass map: charges -2..=+2 in steps of e/3
+pub const CHARGE_BINS: usize = 13;
+
+/// Mass columns in the charge/mass map
+pub const MASS_BINS: usize = 32;
+
+/// Size in bytes of the charge/mass map storage buffer
+pub const CHARGE_MASS_BUFFER_SIZE: u64 = (CHARGE_BINS * MASS_BINS * 4) as u64;
+
+/// Value ranges used to map samples into bins (uniform, matches WGSL)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct HistogramRanges {
+    // x: max speed, y: max pair separation, z: max hadron mass, w: max mass (charge/mass map)
+    pub ranges: [f32; 4],
+}
+
+impl Default for HistogramRanges {
+    fn default() -> Self {
+        Self {
+            ranges: [
+                100.0, // max speed
+                1.0,   // max pair separation (matches default breakup distance)
+                1.0,   // max hadron mass (constituent masses + internal kinetic energy)
+                0.016, // max mass in the charge/mass map (constituent masses, neutron ~0.012)
+            ],
+        }
+    }
+}
+
+/// Decoded histogram bins, as read back from the GPU
+#[derive(Clone, Debug)]
+pub struct Histograms {
+    pub speed: [u32; HISTOGRAM_BINS],
+    pub separation: [u32; HISTOGRAM_BINS],
+    pub mass: [u32; HISTOGRAM_BINS],
+}
+
+impl Default for Histograms {
+    fn default() -> Self {
+        Self {
+            speed: [0; HISTOGRAM_BINS],
+            separation: [0; HISTOGRAM_BINS],
+            mass: [0; HISTOGRAM_BINS],
+        }
+    }
+}
+
+impl Histograms {
+    /// Decode the mapped histogram buffer (little-endian `u32` bins).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut histograms = Self::default();
+        let mut bins = bytes
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()));
+
+        for target in [
+            &mut histograms.speed,
+            &mut histograms.separation,
+            &mut histograms.mass,
+        ] {
+            for (slot, value) in target.iter_mut().zip(bins.by_ref()) {
+                *slot = value;
+            }
+        }
+
+        histograms
+    }
+}
+
+/// Population of free particles and hadrons binned by net charge (rows) and mass (columns)
+#[derive(Clone, Debug)]
+pub struct ChargeMassMap {
+    pub counts: [[u32; MASS_BINS]; CHARGE_BINS],
+}
+
+impl Default for ChargeMassMap {
+    fn default() -> Self {
+        Self {
+            counts: [[0; MASS_BINS]; CHARGE_BINS],
+        }
+    }
+}
+
+impl ChargeMassMap {
+    /// Decode the mapped charge/mass buffer (row-major little-endian `u32` bins).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut map = Self::default();
+        let mut bins = bytes
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()));
+
+        for row in map.counts.iter_mut() {
+            for (slot, value) in row.iter_mut().zip(bins.by_ref()) {
+                *slot = value;
+            }
+        }
+
+        map
+    }
+
+    /// Net charge (in units of e) represented by a row.
+    pub fn row_charge(row: usize) -> f32 {
+        (row as f32 - (CHARGE_BINS / 2) as f32) / 3.0
+    }
+}
@@ -2,8 +2,30 @@
 //!
 //! GPU-based N-body simulation using compute shaders for the four fundamental forces.
 
+pub mod beam;
+pub mod boundary;
+pub mod clusters;
+pub mod coarse;
+pub mod cohort;
+pub mod force_field;
+pub mod histogram;
 pub mod params;
+pub mod photons;
+pub mod selection;
 pub mod simulation;
+pub mod statistics;
+pub mod trails;
 
+pub use beam::*;
+pub use boundary::*;
+pub use clusters::*;
+pub use coarse::*;
+pub use cohort::*;
+pub use force_field::*;
+pub use histogram::*;
 pub use params::*;
+pub use photons::*;
+pub use selection::*;
 pub use simulation::*;
+pub use statistics::*;
+pub use trails::*;
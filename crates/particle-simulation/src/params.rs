@@ -1,6 +1,37 @@
 //! Physics parameters for runtime tuning
 
 use bytemuck::{Pod, Zeroable};
+use particle_physics::ParticleType;
+
+/// Number of particle species in the coupling matrix (up, down, electron, gluon)
+pub const COUPLING_SPECIES: usize = 4;
+
+/// Fundamental forces that can be scaled through the coupling matrix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Force {
+    Gravity = 0,
+    Electromagnetic = 1,
+    Strong = 2,
+    Weak = 3,
+}
+
+impl Force {
+    pub const ALL: [Force; 4] = [
+        Force::Gravity,
+        Force::Electromagnetic,
+        Force::Strong,
+        Force::Weak,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Force::Gravity => "Gravity",
+            Force::Electromagnetic => "Electromagnetic",
+            Force::Strong => "Strong",
+            Force::Weak => "Weak",
+        }
+    }
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -22,7 +53,8 @@ pub struct PhysicsParams {
     pub integration: [f32; 4],
 
     // Group 5: Nucleon Physics
-    // x: binding_strength, y: binding_range, z: exclusion_strength, w: exclusion_radius
+    // x: padding (see `residual_strong`), y: binding_range (nucleus detection),
+    // z: exclusion_strength, w: exclusion_radius
     pub nucleon: [f32; 4],
 
     // Group 6: Electron Physics
@@ -32,6 +64,62 @@ pub struct PhysicsParams {
     // Group 7: Hadron Formation & Confinement
     // x: binding_distance, y: breakup_distance, z: confinement_range_mult, w: confinement_strength_mult
     pub hadron: [f32; 4],
+
+    // Group 8: Residual strong force (Yukawa potential between confirmed nucleons)
+    // x: strength, y: range, z: cutoff (x range), w: padding
+    pub residual_strong: [f32; 4],
+
+    // Group 9: String breaking (linear confinement potential)
+    // x: string_tension, y: pair_threshold (energy needed to create a quark/antiquark pair),
+    // z: enabled (0/1), w: padding
+    pub string_breaking: [f32; 4],
+
+    // Group 10: Electron energy levels (Bohr-like, per nucleus)
+    // x: bohr_radius (r_n = a0 * n^2 / Z), y: rydberg_energy (E_n = -Ry * Z^2 / n^2),
+    // z: max_level, w: spectrum_max_energy (photon energy mapped to the last spectrum bin)
+    pub electron_levels: [f32; 4],
+
+    // Group 11: Species coupling matrix
+    // One 4x4 block per force (gravity, EM, strong, weak); row `force * 4 + species_a`,
+    // column `species_b`. Species index = particle type (up, down, electron, gluon).
+    // Each entry multiplies the force strength for that pair; 1.0 = unmodified physics.
+    pub coupling: [[f32; 4]; 16],
+
+    // Group 12: Simulation boundary
+    // x: radius (particles crossing it are counted as escaped; <= 0 disables),
+    // y: reinject (0/1, put escaped particles back on the boundary), z: reinject temperature,
+    // w: padding
+    pub boundary: [f32; 4],
+
+    // Group 13: Per-force enable flags (indexed by `Force`; 0 = off, 1 = on)
+    pub force_enabled: [f32; 4],
+
+    // Group 14: Per-force softening lengths (indexed by `Force`)
+    // Pair distances are evaluated as r_eff^2 = r^2 + eps^2 to tame the 1/r^2 singularities.
+    pub force_softening: [f32; 4],
+
+    // Group 15: Hadron decay (exponential, rolled per step with the frame seed)
+    // x: meson_lifetime, y: exotic_baryon_lifetime (uuu/ddd), z: enabled (0/1),
+    // w: kick_speed (speed given to decay products)
+    pub decay: [f32; 4],
+
+    // Group 16: Degeneracy pressure (Pauli, identical fermions only)
+    // x: strength, y: smoothing radius, z: density threshold (kernel-weighted neighbours of the
+    // same flavour and color), w: enabled (0/1)
+    // Pressure follows the non-relativistic degenerate gas, P ~ n^(5/3), so the per-particle
+    // push grows as (n - threshold)^(2/3) along the density gradient.
+    pub degeneracy: [f32; 4],
+
+    // Group 17: Color flow (gluon exchange rotates quark color charge)
+    // x: rate (radians per unit gluon exposure per unit time), y: gluon range (exposure falls
+    // off as exp(-r / range)), z: enabled (0/1), w: padding
+    pub color_flow: [f32; 4],
+
+    // Group 18: Vacuum fluctuations (short-lived pairs spawned into empty space from the pair pool)
+    // x: pair rate (expected pairs per unit time), y: mean lifetime (annihilation while both
+    // partners are free), z: clearance (no active particle may be closer to the spawn point),
+    // w: enabled (0/1)
+    pub vacuum: [f32; 4],
 }
 
 impl Default for PhysicsParams {
@@ -62,7 +150,7 @@ impl Default for PhysicsParams {
                 1.5,    // nucleon_damping
             ],
             nucleon: [
-                100.0, // binding_strength
+                0.0,   // padding (residual strong strength lives in `residual_strong`)
                 2.2,   // binding_range
                 130.0, // exclusion_strength
                 1.3,   // exclusion_radius
@@ -79,6 +167,98 @@ impl Default for PhysicsParams {
                 2.0, // confinement_range_mult (range multiplier for free quarks, default 1.2x)
                 2.0, // confinement_strength_mult (strength multiplier for free quarks, default 1.5x)
             ],
+            residual_strong: [
+                100.0, // strength
+                2.2,   // range
+                3.0,   // cutoff (x range)
+                0.0,   // padding
+            ],
+            string_breaking: [
+                1.0,  // string_tension (energy per unit stretch past binding distance)
+                0.05, // pair_threshold
+                1.0,  // enabled
+                0.0,  // padding
+            ],
+            electron_levels: [
+                1.0, // bohr_radius
+                1.0, // rydberg_energy
+                4.0, // max_level
+                4.0, // spectrum_max_energy
+            ],
+            coupling: [[1.0; 4]; 16],
+            boundary: [
+                150.0, // radius
+                0.0,   // reinject
+                0.01,  // temperature (thermal speed = sqrt(T / m) per axis)
+                0.0,   // padding
+            ],
+            force_enabled: [1.0; 4],
+            force_softening: [
+                0.0, // gravity
+                0.2, // electromagnetic (previously a hardcoded saturation distance)
+                0.0, // strong (short-range Coulomb-like term)
+                0.0, // weak
+            ],
+            decay: [
+                0.5, // meson_lifetime (simulation time)
+                1.0, // exotic_baryon_lifetime
+                1.0, // enabled
+                2.0, // kick_speed
+            ],
+            degeneracy: [
+                20.0, // strength
+                0.5,  // smoothing radius
+                2.0,  // density threshold
+                0.0,  // enabled (off: only matters for ultra-compressed scenarios)
+            ],
+            color_flow: [
+                20.0, // rate
+                1.5,  // gluon range
+                0.0,  // enabled
+                0.0,  // padding
+            ],
+            vacuum: [
+                2.0, // pair rate
+                1.0, // mean lifetime
+                3.0, // clearance
+                0.0, // enabled
+            ],
         }
     }
 }
+
+impl PhysicsParams {
+    /// Coupling multiplier for `force` between species `a` and `b`.
+    pub fn coupling(&self, force: Force, a: ParticleType, b: ParticleType) -> f32 {
+        self.coupling[force as usize * COUPLING_SPECIES + a as usize][b as usize]
+    }
+
+    /// Whether `force` is evaluated at all by the force pass.
+    pub fn force_enabled(&self, force: Force) -> bool {
+        self.force_enabled[force as usize] > 0.5
+    }
+
+    /// Enable or disable `force` entirely (shaders skip disabled forces instead of evaluating
+    /// them with zero strength).
+    pub fn set_force_enabled(&mut self, force: Force, enabled: bool) {
+        self.force_enabled[force as usize] = if enabled { 1.0 } else { 0.0 };
+    }
+
+    /// Softening length for `force`.
+    pub fn softening(&self, force: Force) -> f32 {
+        self.force_softening[force as usize]
+    }
+
+    /// Set the softening length for `force` (clamped to be non-negative).
+    pub fn set_softening(&mut self, force: Force, length: f32) {
+        self.force_softening[force as usize] = length.max(0.0);
+    }
+
+    /// Set the coupling multiplier for `force` between species `a` and `b`.
+    /// The matrix is kept symmetric so forces stay equal and opposite.
+    pub fn set_coupling(&mut self, force: Force, a: ParticleType, b: ParticleType, value: f32) {
+        let block = force as usize * COUPLING_SPECIES;
+        self.coupling[block + a as usize][b as usize] = value;
+        self.coupling[block + b as usize][a as usize] = value;
+    }
+}
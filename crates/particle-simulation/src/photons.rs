@@ -0,0 +1,52 @@
+//! Electron energy levels and emitted photons
+//!
+//! Electrons near a nucleus are assigned a Bohr-like level `n` (stored in `color_and_flags.w`).
+//! When an electron drops to a lower level it emits a photon with energy
+//! `Ry * Z^2 * (1/n_lo^2 - 1/n_hi^2)`. Photons go into a fixed-size ring buffer for rendering,
+//! and their energies are binned into a cumulative spectrum that the CPU reads back.
+
+use bytemuck::{Pod, Zeroable};
+
+/// Number of photon slots in the ring buffer (must match `electron_levels.wgsl`)
+pub const PHOTON_CAPACITY: usize = 1024;
+
+/// Number of bins in the emission spectrum (must match `electron_levels.wgsl`)
+pub const SPECTRUM_BINS: usize = 32;
+
+/// Size in bytes of the spectrum storage buffer
+pub const SPECTRUM_BUFFER_SIZE: u64 = (SPECTRUM_BINS * 4) as u64;
+
+/// Emitted photon (GPU layout, matches WGSL)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Photon {
+    /// xyz = emission position, w = energy (0 = empty slot)
+    pub position: [f32; 4],
+    /// x = emission time, y = upper level, z = lower level, w = normalized energy (color)
+    pub info: [f32; 4],
+}
+
+/// Cumulative emission spectrum, as read back from the GPU
+#[derive(Clone, Debug)]
+pub struct Spectrum {
+    pub bins: [u32; SPECTRUM_BINS],
+}
+
+impl Default for Spectrum {
+    fn default() -> Self {
+        Self {
+            bins: [0; SPECTRUM_BINS],
+        }
+    }
+}
+
+impl Spectrum {
+    /// Decode the mapped spectrum buffer (little-endian `u32` bins).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut spectrum = Self::default();
+        for (slot, b) in spectrum.bins.iter_mut().zip(bytes.chunks_exact(4)) {
+            *slot = u32::from_le_bytes(b.try_into().unwrap());
+        }
+        spectrum
+    }
+}
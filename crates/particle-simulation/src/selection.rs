@@ -0,0 +1,157 @@
+//! Selection: the resolved single-selection target and multi-entity selection sets
+
+use bytemuck::{Pod, Zeroable};
+
+/// Size in bytes of the selection target buffer (2x `vec4<f32>`)
+pub const SELECTION_TARGET_SIZE: u64 = 32;
+
+/// What the selected packed ID resolved to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionKind {
+    None,
+    Particle,
+    Hadron,
+    Nucleus,
+}
+
+/// Selected entity center, bounding radius and velocity, as read back from the GPU
+#[derive(Clone, Copy, Debug)]
+pub struct SelectionTarget {
+    pub kind: SelectionKind,
+    /// World-space center
+    pub center: [f32; 3],
+    /// Bounding radius (particle size, hadron or nucleus shell radius)
+    pub radius: f32,
+    /// Velocity in simulation units per unit simulation time
+    pub velocity: [f32; 3],
+}
+
+impl SelectionTarget {
+    /// Decode the mapped target buffer (8 little-endian `f32` values: center xyz, kind,
+    /// velocity xyz, radius).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| f32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        let kind = match value(3).round() as i32 {
+            1 => SelectionKind::Particle,
+            2 => SelectionKind::Hadron,
+            3 => SelectionKind::Nucleus,
+            _ => SelectionKind::None,
+        };
+        Self {
+            kind,
+            center: [value(0), value(1), value(2)],
+            radius: value(7),
+            velocity: [value(4), value(5), value(6)],
+        }
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.kind != SelectionKind::None
+    }
+}
+
+/// Entities the measurement tool resolves at once (distance between two, angle at the second of
+/// three)
+pub const MEASURE_CAPACITY: usize = 3;
+
+/// Size in bytes of the measurement target buffer (`MEASURE_CAPACITY` targets)
+pub const MEASURE_TARGETS_SIZE: u64 = MEASURE_CAPACITY as u64 * SELECTION_TARGET_SIZE;
+
+/// Maximum number of entities in a selection set
+pub const SELECTION_SET_CAPACITY: usize = 64;
+
+/// Size in bytes of the selection set stats buffer (4x `u32`)
+pub const SELECTION_SET_STATS_SIZE: u64 = 16;
+
+/// A group of selected entities (packed pick IDs, uniform, matches WGSL `SelectionSet`)
+///
+/// IDs use the picking encoding: particle index + 1, `0x80000000 | (hadron + 1)` or
+/// `0x40000000 | (anchor hadron + 1)` for nuclei.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SelectionSet {
+    /// x = number of IDs, y/z/w = padding
+    count: [u32; 4],
+    /// IDs packed four per row (uniform arrays need a 16-byte stride)
+    ids: [[u32; 4]; SELECTION_SET_CAPACITY / 4],
+}
+
+impl Default for SelectionSet {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl SelectionSet {
+    pub fn len(&self) -> usize {
+        self.count[0] as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..self.len()).map(|i| self.ids[i / 4][i % 4])
+    }
+
+    pub fn contains(&self, id: u32) -> bool {
+        self.iter().any(|other| other == id)
+    }
+
+    /// Add `id` if it is missing, remove it otherwise. Returns false when the set is full.
+    pub fn toggle(&mut self, id: u32) -> bool {
+        if id == 0 {
+            return true;
+        }
+        let existing = self.iter().position(|other| other == id);
+        if let Some(i) = existing {
+            // Swap-remove with the last entry
+            let last = self.len() - 1;
+            self.ids[i / 4][i % 4] = self.ids[last / 4][last % 4];
+            self.ids[last / 4][last % 4] = 0;
+            self.count[0] -= 1;
+            return true;
+        }
+        if self.len() >= SELECTION_SET_CAPACITY {
+            return false;
+        }
+        let i = self.len();
+        self.ids[i / 4][i % 4] = id;
+        self.count[0] += 1;
+        true
+    }
+
+    /// Add `id` unless it is already present. Returns false when the set is full.
+    pub fn insert(&mut self, id: u32) -> bool {
+        id == 0 || self.contains(id) || self.toggle(id)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Aggregate statistics over every particle covered by the selection set
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SelectionSetStats {
+    /// Particles selected directly or through their hadron/nucleus
+    pub particles: u32,
+    pub quarks: u32,
+    pub electrons: u32,
+    /// Net charge in units of e/3
+    pub charge_thirds: i32,
+}
+
+impl SelectionSetStats {
+    /// Decode the mapped stats buffer (little-endian: particles, quarks, electrons, charge/3).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            particles: value(0),
+            quarks: value(1),
+            electrons: value(2),
+            charge_thirds: value(3) as i32,
+        }
+    }
+}
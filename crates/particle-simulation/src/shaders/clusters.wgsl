@@ -0,0 +1,166 @@
+// Compute shaders for cluster identification (connected components over nucleon bonds)
+// `init_main`:      every valid nucleon labels itself, everything else is unlabeled
+// `propagate_main`: hook each nucleon's root onto the smallest label among its bonded neighbours
+// `jump_main`:      pointer jumping (label = label of label) to flatten the label trees
+// `count_main`:     add each nucleon to its root's size
+// `stats_main`:     bin the root sizes
+//
+// Propagate/jump run `CLUSTER_ROUNDS` times; labels only ever decrease, so a round that finds
+// nothing to do leaves them unchanged.
+//
+// Stats layout (u32, must match Rust `ClusterStats`):
+// [0] clusters (>= 2 nucleons), [1] largest cluster, [2..3] padding, [4..] size bins
+
+const CLUSTER_SIZE_BINS: u32 = 32u;
+const NO_LABEL: u32 = 0xFFFFFFFFu;
+
+const HADRON_PROTON: u32 = 1u;
+const HADRON_NEUTRON: u32 = 2u;
+
+struct Hadron {
+    indices_type: vec4<u32>, // x=p1, y=p2, z=p3, w=type_id
+    center: vec4<f32>,       // xyz = center of mass, w = radius
+    velocity: vec4<f32>,     // xyz = velocity, w = nucleus_id
+}
+
+struct HadronCounter {
+    counters: vec4<u32>, // [0] total hadrons (counter range; may include invalid slots)
+}
+
+struct PhysicsParams {
+    constants: vec4<f32>,
+    strong_force: vec4<f32>,
+    repulsion: vec4<f32>,
+    integration: vec4<f32>,
+    nucleon: vec4<f32>, // x: padding, y: binding_range, z: exclusion_strength, w: exclusion_radius
+}
+
+@group(0) @binding(0)
+var<storage, read> hadrons: array<Hadron>;
+
+@group(0) @binding(1)
+var<storage, read> hadron_counter: HadronCounter;
+
+@group(0) @binding(2)
+var<uniform> params: PhysicsParams;
+
+@group(0) @binding(3)
+var<storage, read_write> labels: array<atomic<u32>>;
+
+@group(0) @binding(4)
+var<storage, read_write> sizes: array<atomic<u32>>;
+
+@group(0) @binding(5)
+var<storage, read_write> stats: array<atomic<u32>, 36>;
+
+fn num_hadrons() -> u32 {
+    return min(hadron_counter.counters.x, arrayLength(&hadrons));
+}
+
+fn is_nucleon(index: u32) -> bool {
+    let type_id = hadrons[index].indices_type.w;
+    return type_id == HADRON_PROTON || type_id == HADRON_NEUTRON;
+}
+
+@compute @workgroup_size(256)
+fn init_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&labels)) {
+        return;
+    }
+
+    if (index < num_hadrons() && is_nucleon(index)) {
+        atomicStore(&labels[index], index);
+    } else {
+        atomicStore(&labels[index], NO_LABEL);
+    }
+    atomicStore(&sizes[index], 0u);
+}
+
+@compute @workgroup_size(256)
+fn propagate_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    let count = num_hadrons();
+    if (index >= count) {
+        return;
+    }
+
+    let label = atomicLoad(&labels[index]);
+    if (label == NO_LABEL) {
+        return;
+    }
+
+    let binding_sq = params.nucleon.y * params.nucleon.y;
+    let center = hadrons[index].center.xyz;
+    var smallest = label;
+    for (var j = 0u; j < count; j++) {
+        let other = atomicLoad(&labels[j]);
+        if (other >= smallest) {
+            continue;
+        }
+        let d = hadrons[j].center.xyz - center;
+        if (dot(d, d) <= binding_sq) {
+            smallest = other;
+        }
+    }
+
+    if (smallest < label) {
+        // Hook the whole tree (its root) as well as this nucleon
+        atomicMin(&labels[label], smallest);
+        atomicMin(&labels[index], smallest);
+    }
+}
+
+@compute @workgroup_size(256)
+fn jump_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= num_hadrons()) {
+        return;
+    }
+
+    let label = atomicLoad(&labels[index]);
+    if (label == NO_LABEL) {
+        return;
+    }
+    atomicMin(&labels[index], atomicLoad(&labels[label]));
+}
+
+@compute @workgroup_size(256)
+fn count_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= num_hadrons()) {
+        return;
+    }
+
+    var root = atomicLoad(&labels[index]);
+    if (root == NO_LABEL) {
+        return;
+    }
+    // Labels only point to smaller indices, so this terminates; flattened trees take one step
+    loop {
+        let next = atomicLoad(&labels[root]);
+        if (next == root) {
+            break;
+        }
+        root = next;
+    }
+    atomicAdd(&sizes[root], 1u);
+}
+
+@compute @workgroup_size(256)
+fn stats_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= num_hadrons()) {
+        return;
+    }
+
+    let size = atomicLoad(&sizes[index]);
+    if (size == 0u) {
+        return;
+    }
+    if (size >= 2u) {
+        atomicAdd(&stats[0], 1u);
+    }
+    atomicMax(&stats[1], size);
+    atomicAdd(&stats[4u + min(size, CLUSTER_SIZE_BINS) - 1u], 1u);
+}
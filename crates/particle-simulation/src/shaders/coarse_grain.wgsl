@@ -0,0 +1,253 @@
+// Compute shaders for coarse-graining LOD (distant particle clusters -> super-particles)
+// `release_main`: super-particles near the camera (or all of them when coarse-graining is off)
+//                 hand back their members; each member's restored state is staged in its link
+// `restore_main`: members staged for release become active particles again
+// `merge_main`:   free particles far from the camera merge pairwise into super-particles
+// `stats_main`:   count super-particles and absorbed members
+//
+// A merge conserves mass, charge, center of mass and momentum. The absorbed member stores its
+// offset and velocity relative to the new center of mass, so releasing members in reverse
+// order (last merged first) is the exact inverse for an undisturbed cluster. Super-particles
+// carry `FLAG_SUPER` while they have members, which keeps them out of hadrons, decays and the
+// pair pool (those would change the aggregate's mass or charge, or recycle its slot).
+//
+// Stats layout (4x u32, must match Rust `CoarseStats`):
+// [0] super-particles, [1] absorbed members
+
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+const FLAG_ABSORBED: u32 = 2u;
+const FLAG_SUPER: u32 = 4u;
+
+// Link parent value for a member released this frame, waiting for `restore_main`
+const RELEASE_PENDING: u32 = 0xFFFFFFFFu;
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag, w = vacuum partner + 1
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+// Per-particle membership (must match COARSE_LINK_SIZE)
+struct CoarseLink {
+    offset: vec4<f32>,       // xyz = offset from the parent's center of mass, w = own size before absorption
+    rel_velocity: vec4<f32>, // xyz = velocity relative to the parent, w = base size as a super-particle
+    links: vec4<u32>,        // x = parent + 1 (0 = none), y = last member + 1, z = previous sibling + 1,
+                             // w = member count including itself (0 = plain particle)
+}
+
+struct CoarseParams {
+    camera: vec4<f32>, // xyz = camera position, w = merge distance
+    config: vec4<f32>, // x = split distance, y = merge radius, z = max members, w = enabled
+}
+
+@group(0) @binding(0)
+var<storage, read_write> particles: array<Particle>;
+
+@group(0) @binding(1)
+var<storage, read_write> links: array<CoarseLink>;
+
+@group(0) @binding(2)
+var<uniform> coarse: CoarseParams;
+
+@group(0) @binding(3)
+var<storage, read_write> locks: array<atomic<u32>>;
+
+@group(0) @binding(4)
+var<storage, read_write> stats: array<atomic<u32>, 4>;
+
+fn is_dormant(p: Particle) -> bool {
+    return (p.color_and_flags.y & FLAG_DORMANT) != 0u;
+}
+
+fn member_count(index: u32) -> u32 {
+    return max(links[index].links.w, 1u);
+}
+
+// Only free, active particles beyond the merge distance take part
+// (no hadron membership, no bound electron level, not already absorbed, no vacuum pair partner).
+fn can_merge(index: u32, p: Particle) -> bool {
+    if (is_dormant(p) || links[index].links.x != 0u || p.data.w != 0.0) {
+        return false;
+    }
+    if (p.color_and_flags.z != 0u || p.color_and_flags.w != 0u || p.velocity.w <= 0.0) {
+        return false;
+    }
+    return distance(p.position.xyz, coarse.camera.xyz) > coarse.camera.w;
+}
+
+// Same species, color and cohort tag, so the super-particle still means something
+fn compatible(a: Particle, b: Particle) -> bool {
+    return u32(a.position.w) == u32(b.position.w)
+        && a.color_and_flags.x == b.color_and_flags.x
+        && u32(a.data.z) == u32(b.data.z);
+}
+
+@compute @workgroup_size(256)
+fn release_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    // Only active super-particles release (absorbed ones wait until their parent lets go)
+    let link = links[index];
+    if (link.links.y == 0u || link.links.x != 0u) {
+        return;
+    }
+
+    var p = particles[index];
+    let enabled = coarse.config.w > 0.5;
+    if (enabled && distance(p.position.xyz, coarse.camera.xyz) >= coarse.config.x) {
+        return;
+    }
+
+    // Undo the merges last-to-first. Members are dormant, so their own invocations never touch
+    // their particle slot in this pass; only their link is written here.
+    var member = link.links.y;
+    loop {
+        if (member == 0u) {
+            break;
+        }
+        let m = member - 1u;
+        let member_link = links[m];
+        let member_mass = particles[m].velocity.w;
+
+        let position = p.position.xyz + member_link.offset.xyz;
+        let velocity = p.velocity.xyz + member_link.rel_velocity.xyz;
+
+        let rest = p.velocity.w - member_mass;
+        if (rest > 0.0) {
+            let w = member_mass / rest;
+            p.position = vec4<f32>(p.position.xyz - member_link.offset.xyz * w, p.position.w);
+            p.velocity = vec4<f32>(p.velocity.xyz - member_link.rel_velocity.xyz * w, rest);
+        }
+        p.data.x -= particles[m].data.x;
+
+        links[m].offset = vec4<f32>(position, member_link.offset.w);
+        links[m].rel_velocity = vec4<f32>(velocity, member_link.rel_velocity.w);
+        links[m].links.x = RELEASE_PENDING;
+        links[m].links.z = 0u;
+
+        member = member_link.links.z;
+    }
+
+    p.data.y = link.rel_velocity.w;
+    p.color_and_flags.y &= ~FLAG_SUPER;
+    particles[index] = p;
+    links[index].links.y = 0u;
+    links[index].links.w = 0u;
+}
+
+@compute @workgroup_size(256)
+fn restore_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let link = links[index];
+    if (link.links.x != RELEASE_PENDING) {
+        return;
+    }
+
+    // Staged absolute position/velocity; mass, charge and type were never touched
+    particles[index].position = vec4<f32>(link.offset.xyz, particles[index].position.w);
+    particles[index].velocity = vec4<f32>(link.rel_velocity.xyz, particles[index].velocity.w);
+    particles[index].data.y = link.offset.w;
+    particles[index].color_and_flags.y &= ~(FLAG_DORMANT | FLAG_ABSORBED);
+    links[index].links.x = 0u;
+}
+
+@compute @workgroup_size(256)
+fn merge_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    let num_particles = arrayLength(&particles);
+    if (index >= num_particles || coarse.config.w < 0.5) {
+        return;
+    }
+
+    let p = particles[index];
+    let max_members = u32(coarse.config.z);
+    let own_members = member_count(index);
+    if (own_members >= max_members || !can_merge(index, p)) {
+        return;
+    }
+
+    // Nearest compatible partner with a higher index (the lower index always leads, so two
+    // particles never pick each other and contend for the same pair of locks)
+    let radius = coarse.config.y;
+    var best = 0xFFFFFFFFu;
+    var best_dist_sq = radius * radius;
+    for (var j = index + 1u; j < num_particles; j++) {
+        let q = particles[j];
+        if (!compatible(p, q) || !can_merge(j, q)) {
+            continue;
+        }
+        if (own_members + member_count(j) > max_members) {
+            continue;
+        }
+        let d = q.position.xyz - p.position.xyz;
+        let dist_sq = dot(d, d);
+        if (dist_sq < best_dist_sq) {
+            best_dist_sq = dist_sq;
+            best = j;
+        }
+    }
+    if (best == 0xFFFFFFFFu) {
+        return;
+    }
+
+    // Lock self, then the partner; a failed partner lock just skips the merge this frame
+    if (!atomicCompareExchangeWeak(&locks[index], 0u, 1u).exchanged) {
+        return;
+    }
+    if (!atomicCompareExchangeWeak(&locks[best], 0u, 1u).exchanged) {
+        return;
+    }
+
+    let a = particles[index];
+    let b = particles[best];
+    let mass = a.velocity.w + b.velocity.w;
+    let center = (a.position.xyz * a.velocity.w + b.position.xyz * b.velocity.w) / mass;
+    let velocity = (a.velocity.xyz * a.velocity.w + b.velocity.xyz * b.velocity.w) / mass;
+
+    var link = links[index];
+    if (link.links.w == 0u) {
+        link.rel_velocity.w = a.data.y; // remember the plain size for when it splits again
+    }
+
+    // Push the partner onto this super-particle's member list
+    links[best].offset = vec4<f32>(b.position.xyz - center, b.data.y);
+    links[best].rel_velocity = vec4<f32>(b.velocity.xyz - velocity, links[best].rel_velocity.w);
+    links[best].links.x = index + 1u;
+    links[best].links.z = link.links.y;
+    link.links.y = best + 1u;
+    link.links.w = own_members + member_count(best);
+    links[index] = link;
+
+    particles[best].data.y = 0.0;
+    particles[best].color_and_flags.y |= FLAG_DORMANT | FLAG_ABSORBED;
+
+    particles[index].position = vec4<f32>(center, a.position.w);
+    particles[index].velocity = vec4<f32>(velocity, mass);
+    particles[index].data.x = a.data.x + b.data.x;
+    particles[index].color_and_flags.y |= FLAG_SUPER;
+    // Grow with the member count so aggregates stay visible (volume ~ members)
+    particles[index].data.y = link.rel_velocity.w * pow(f32(link.links.w), 1.0 / 3.0);
+}
+
+@compute @workgroup_size(256)
+fn stats_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    if ((particles[index].color_and_flags.y & FLAG_ABSORBED) != 0u) {
+        _ = atomicAdd(&stats[1], 1u);
+    } else if (links[index].links.y != 0u) {
+        _ = atomicAdd(&stats[0], 1u);
+    }
+}
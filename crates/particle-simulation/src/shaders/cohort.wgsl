@@ -0,0 +1,95 @@
+// Compute shaders for particle tagging and cohort tracking
+// `tag_main`:   stamp `tag` onto every particle inside the sphere (data.z holds the tag)
+// `stats_main`: count where particles carrying `tag` currently are
+//
+// Stats layout (4x u32, must match Rust `CohortStats`):
+// [0] total tagged, [1] free, [2] in hadrons (not in a nucleus), [3] in nuclei
+
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+struct Hadron {
+    indices_type: vec4<u32>, // x=p1, y=p2, z=p3, w=type_id
+    center: vec4<f32>,       // xyz = center of mass, w = radius
+    velocity: vec4<f32>,     // xyz = velocity, w = nucleus_id (as f32, 0 = unbound)
+}
+
+struct HadronCounter {
+    counters: vec4<u32>, // [0] total hadrons (counter range; may include invalid slots)
+}
+
+struct TagParams {
+    sphere: vec4<f32>, // xyz = center, w = radius
+    tag: vec4<u32>,    // x = tag to apply / track
+}
+
+@group(0) @binding(0)
+var<storage, read_write> particles: array<Particle>;
+
+@group(0) @binding(1)
+var<storage, read> hadrons: array<Hadron>;
+
+@group(0) @binding(2)
+var<storage, read> hadron_counter: HadronCounter;
+
+@group(0) @binding(3)
+var<uniform> tag_params: TagParams;
+
+@group(0) @binding(4)
+var<storage, read_write> stats: array<atomic<u32>, 4>;
+
+@compute @workgroup_size(256)
+fn tag_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let p = particles[index];
+    if ((p.color_and_flags.y & FLAG_DORMANT) != 0u) {
+        return;
+    }
+
+    if (distance(p.position.xyz, tag_params.sphere.xyz) <= tag_params.sphere.w) {
+        particles[index].data.z = f32(tag_params.tag.x);
+    }
+}
+
+@compute @workgroup_size(256)
+fn stats_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let p = particles[index];
+    if (tag_params.tag.x == 0u || u32(p.data.z) != tag_params.tag.x) {
+        return;
+    }
+
+    _ = atomicAdd(&stats[0], 1u);
+
+    // hadron_id is 1-indexed (0 = free); treat stale/invalid slots as free
+    let hadron_id = p.color_and_flags.z;
+    let num_hadrons = min(hadron_counter.counters.x, arrayLength(&hadrons));
+    if (hadron_id == 0u || hadron_id - 1u >= num_hadrons) {
+        _ = atomicAdd(&stats[1], 1u);
+        return;
+    }
+
+    let h = hadrons[hadron_id - 1u];
+    if (h.indices_type.w == 0xFFFFFFFFu) {
+        _ = atomicAdd(&stats[1], 1u);
+    } else if (u32(h.velocity.w) != 0u) {
+        _ = atomicAdd(&stats[3], 1u);
+    } else {
+        _ = atomicAdd(&stats[2], 1u);
+    }
+}
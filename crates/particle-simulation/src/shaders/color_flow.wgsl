@@ -0,0 +1,203 @@
+// Compute shader for color flow (gluon exchange rotating quark color charge)
+//
+// Each quark carries a continuous color 3-vector next to its discrete color index. Gluon
+// exposure (sum of exp(-r / range) over nearby gluons) rotates the vector about the neutral
+// (1,1,1) axis, cycling red -> green -> blue; the discrete color follows the largest
+// component (anti-quarks stay anti: index + 3), so the strong force and hadron detection see
+// the rotated charge.
+//
+// Bound quarks rotate with their hadron: the exposure is evaluated at the hadron center, and
+// constituents snap to the same phase when they bind, so every constituent rotates identically
+// and the hadron stays color-neutral.
+//
+// Color vector layout (vec4 per particle): xyz = weights, w = sync marker
+// (discrete color + 1, +8 while bound; 0 = never synced). Whenever another pass changes the
+// discrete color (string breaking, decays) the marker no longer matches and the vector is
+// re-seeded from the discrete color.
+
+const TYPE_GLUON: u32 = 3u;
+const FLAG_DORMANT: u32 = 1u;
+const BOUND_MARKER: u32 = 8u;
+
+struct PhysicsParams {
+    constants: vec4<f32>,
+    strong_force: vec4<f32>,
+    repulsion: vec4<f32>,
+    integration: vec4<f32>,     // x: dt, y: damping, z: time/seed, w: nucleon_damping
+    nucleon: vec4<f32>,
+    electron: vec4<f32>,
+    hadron: vec4<f32>,
+    residual_strong: vec4<f32>,
+    string_breaking: vec4<f32>,
+    electron_levels: vec4<f32>,
+    coupling: array<vec4<f32>, 16>,
+    boundary: vec4<f32>,
+    force_enabled: vec4<f32>,
+    force_softening: vec4<f32>,
+    decay: vec4<f32>,
+    degeneracy: vec4<f32>,
+    color_flow: vec4<f32>,      // x: rate (radians per unit exposure per time), y: gluon range, z: enabled
+}
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+struct Hadron {
+    indices_type: vec4<u32>, // x=p1, y=p2, z=p3, w=type_id
+    center: vec4<f32>,       // xyz = center of mass, w = radius
+    velocity: vec4<f32>,     // xyz = velocity, w = nucleus_id
+}
+
+struct HadronCounter {
+    counters: vec4<u32>, // [0] total hadrons (counter range; may include invalid slots)
+}
+
+@group(0) @binding(0)
+var<storage, read_write> particles: array<Particle>;
+
+@group(0) @binding(1)
+var<storage, read_write> colors: array<vec4<f32>>;
+
+@group(0) @binding(2)
+var<storage, read> hadrons: array<Hadron>;
+
+@group(0) @binding(3)
+var<storage, read> hadron_counter: HadronCounter;
+
+@group(0) @binding(4)
+var<uniform> params: PhysicsParams;
+
+fn basis(component: u32) -> vec3<f32> {
+    var v = vec3<f32>(0.0);
+    v[component] = 1.0;
+    return v;
+}
+
+// Rotate about the color-neutral axis (1,1,1)/sqrt(3); keeps the component sum and length
+fn rotate_color(v: vec3<f32>, angle: f32) -> vec3<f32> {
+    let k = vec3<f32>(0.57735027);
+    let c = cos(angle);
+    let s = sin(angle);
+    return v * c + cross(k, v) * s + k * dot(k, v) * (1.0 - c);
+}
+
+// Project back onto the circle through the three basis vectors (drift from repeated rotation)
+fn renormalize(v: vec3<f32>) -> vec3<f32> {
+    let center = vec3<f32>(1.0 / 3.0);
+    let d = v - center;
+    let len = length(d);
+    if (len < 1e-6) {
+        return basis(0u);
+    }
+    return center + d * (0.81649658 / len); // radius sqrt(2/3)
+}
+
+fn dominant_component(v: vec3<f32>) -> u32 {
+    if (v.x >= v.y && v.x >= v.z) {
+        return 0u;
+    }
+    if (v.y >= v.z) {
+        return 1u;
+    }
+    return 2u;
+}
+
+fn gluon_exposure(at: vec3<f32>) -> f32 {
+    let range = params.color_flow.y;
+    if (range <= 0.0) {
+        return 0.0;
+    }
+
+    var exposure = 0.0;
+    let num_particles = arrayLength(&particles);
+    for (var i = 0u; i < num_particles; i++) {
+        let g = particles[i];
+        if (u32(g.position.w) != TYPE_GLUON || (g.color_and_flags.y & FLAG_DORMANT) != 0u) {
+            continue;
+        }
+        let r = distance(g.position.xyz, at);
+        if (r < range * 3.0) {
+            exposure += exp(-r / range);
+        }
+    }
+    return exposure;
+}
+
+// Center of the valid hadron this quark belongs to (w = 1), or w = 0 when free
+fn hadron_center(index: u32, hadron_id: u32) -> vec4<f32> {
+    if (hadron_id == 0u) {
+        return vec4<f32>(0.0);
+    }
+    let h_idx = hadron_id - 1u;
+    if (h_idx >= min(hadron_counter.counters.x, arrayLength(&hadrons))) {
+        return vec4<f32>(0.0);
+    }
+    let h = hadrons[h_idx];
+    if (h.indices_type.w == 0xFFFFFFFFu) {
+        return vec4<f32>(0.0);
+    }
+    if (h.indices_type.x != index && h.indices_type.y != index && h.indices_type.z != index) {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>(h.center.xyz, 1.0);
+}
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let p = particles[index];
+    let particle_type = u32(p.position.w);
+    if (particle_type > 1u || (p.color_and_flags.y & FLAG_DORMANT) != 0u) {
+        return;
+    }
+
+    let color = p.color_and_flags.x;
+    let anti = color >= 3u;
+    let component = color % 3u;
+
+    var v = colors[index].xyz;
+    var marker = u32(colors[index].w);
+
+    // Re-seed when the discrete color was changed elsewhere (or never synced)
+    if ((marker & (BOUND_MARKER - 1u)) != color + 1u) {
+        v = basis(component);
+        marker = color + 1u;
+    }
+
+    let hadron = hadron_center(index, p.color_and_flags.z);
+    let bound = hadron.w > 0.5;
+    if (bound && marker < BOUND_MARKER) {
+        // Just bound: snap to the pure color so all constituents share one phase
+        v = basis(component);
+        marker += BOUND_MARKER;
+    } else if (!bound && marker >= BOUND_MARKER) {
+        marker -= BOUND_MARKER;
+    }
+
+    if (params.color_flow.z > 0.5) {
+        var at = p.position.xyz;
+        if (bound) {
+            at = hadron.xyz;
+        }
+        let angle = params.color_flow.x * params.integration.x * gluon_exposure(at);
+        if (angle != 0.0) {
+            v = renormalize(rotate_color(v, angle));
+
+            let new_color = dominant_component(v) + select(0u, 3u, anti);
+            if (new_color != color) {
+                particles[index].color_and_flags.x = new_color;
+                marker = new_color + 1u + select(0u, BOUND_MARKER, bound);
+            }
+        }
+    }
+
+    colors[index] = vec4<f32>(v, f32(marker));
+}
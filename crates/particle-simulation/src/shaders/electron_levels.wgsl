@@ -0,0 +1,174 @@
+// Compute shader for electron energy levels and photon emission
+// Assigns each electron a Bohr-like level around its nearest nucleus and emits a photon whenever
+// the electron drops to a lower level.
+//
+// Level model (toy Bohr atom):
+//   r_n = a0 * n^2 / Z        (orbit radius of level n)
+//   E_n = -Ry * Z^2 / n^2     (level energy)
+//   photon energy for n_hi -> n_lo = Ry * Z^2 * (1/n_lo^2 - 1/n_hi^2)
+//
+// The current level lives in the electron's `color_and_flags.w` (0 = unbound).
+
+// Must match Rust `PHOTON_CAPACITY` / `SPECTRUM_BINS`
+const PHOTON_CAPACITY: u32 = 1024u;
+const SPECTRUM_BINS: u32 = 32u;
+
+const MAX_NUCLEONS: u32 = 16u;
+const TYPE_ELECTRON: u32 = 2u;
+
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+
+// How far past the continuous level boundary an electron must move before its level changes.
+// Prevents boundary jitter from producing a stream of photons.
+const LEVEL_HYSTERESIS: f32 = 0.75;
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+struct Nucleus {
+    hadron_indices: array<u32, MAX_NUCLEONS>,
+    nucleon_count: u32,
+    proton_count: u32,
+    neutron_count: u32,
+    type_id: u32,        // Atomic number (Z) or 0xFFFFFFFF for invalid
+    center: vec4<f32>,   // xyz = center of mass, w = radius
+    velocity: vec4<f32>,
+}
+
+struct NucleusCounter {
+    count: u32,
+    _pad: vec3<u32>,
+}
+
+struct Photon {
+    position: vec4<f32>, // xyz = emission position, w = energy (0 = empty slot)
+    info: vec4<f32>,     // x = emission time, y = upper level, z = lower level, w = normalized energy
+}
+
+struct PhotonCounter {
+    emitted: atomic<u32>, // total photons emitted (ring buffer write cursor)
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+}
+
+struct PhysicsParams {
+    constants: vec4<f32>,
+    strong_force: vec4<f32>,
+    repulsion: vec4<f32>,
+    integration: vec4<f32>, // x: dt, y: damping, z: time/seed, w: nucleon_damping
+    nucleon: vec4<f32>,
+    electron: vec4<f32>,
+    hadron: vec4<f32>,
+    residual_strong: vec4<f32>,
+    string_breaking: vec4<f32>,
+    electron_levels: vec4<f32>, // x: bohr_radius, y: rydberg_energy, z: max_level, w: spectrum_max_energy
+}
+
+@group(0) @binding(0)
+var<storage, read_write> particles: array<Particle>;
+
+@group(0) @binding(1)
+var<storage, read> nuclei: array<Nucleus>;
+
+@group(0) @binding(2)
+var<storage, read> nucleus_counter: NucleusCounter;
+
+@group(0) @binding(3)
+var<storage, read_write> photons: array<Photon>;
+
+@group(0) @binding(4)
+var<storage, read_write> photon_counter: PhotonCounter;
+
+@group(0) @binding(5)
+var<storage, read_write> spectrum: array<atomic<u32>>;
+
+@group(0) @binding(6)
+var<uniform> params: PhysicsParams;
+
+fn level_energy(z: f32, n: u32) -> f32 {
+    let nf = f32(n);
+    return params.electron_levels.y * z * z / (nf * nf);
+}
+
+fn emit_photon(position: vec3<f32>, energy: f32, upper: u32, lower: u32) {
+    let t = clamp(energy / max(params.electron_levels.w, 1e-6), 0.0, 1.0);
+
+    let slot = atomicAdd(&photon_counter.emitted, 1u) % PHOTON_CAPACITY;
+    photons[slot].position = vec4<f32>(position, energy);
+    photons[slot].info = vec4<f32>(params.integration.z, f32(upper), f32(lower), t);
+
+    // Energies above the range land in the last bin so they remain visible.
+    let bin = min(u32(t * f32(SPECTRUM_BINS)), SPECTRUM_BINS - 1u);
+    _ = atomicAdd(&spectrum[bin], 1u);
+}
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    let num_particles = arrayLength(&particles);
+
+    if (index >= num_particles) {
+        return;
+    }
+
+    let p = particles[index];
+    if (u32(p.position.w) != TYPE_ELECTRON || (p.color_and_flags.y & FLAG_DORMANT) != 0u) {
+        return;
+    }
+
+    let a0 = params.electron_levels.x;
+    let max_level = u32(max(params.electron_levels.z, 1.0));
+    if (a0 <= 0.0) {
+        return;
+    }
+
+    // Nearest nucleus in units of its own level structure (r * Z / a0 = n^2)
+    let num_nuclei = min(nucleus_counter.count, arrayLength(&nuclei));
+    var best_n_sq = 1e30;
+    var best_z = 0.0;
+
+    for (var i = 0u; i < num_nuclei; i++) {
+        let nucleus = nuclei[i];
+        if (nucleus.type_id == 0xFFFFFFFFu || nucleus.proton_count == 0u) {
+            continue;
+        }
+
+        let z = f32(nucleus.proton_count);
+        let n_sq = distance(p.position.xyz, nucleus.center.xyz) * z / a0;
+        if (n_sq < best_n_sq) {
+            best_n_sq = n_sq;
+            best_z = z;
+        }
+    }
+
+    let previous = p.color_and_flags.w;
+
+    // Continuous level; beyond the outermost level (+ hysteresis) the electron is unbound.
+    let n_cont = sqrt(best_n_sq);
+    var level = 0u;
+    if (best_z > 0.0 && n_cont < f32(max_level) + 0.5 + LEVEL_HYSTERESIS) {
+        if (previous != 0u && abs(n_cont - f32(previous)) < LEVEL_HYSTERESIS) {
+            level = previous;
+        } else {
+            level = clamp(u32(round(n_cont)), 1u, max_level);
+        }
+    }
+
+    if (level == previous) {
+        return;
+    }
+
+    // Downward transition within a bound atom: emit a photon carrying the energy difference.
+    if (previous != 0u && level != 0u && level < previous) {
+        let energy = level_energy(best_z, level) - level_energy(best_z, previous);
+        emit_photon(p.position.xyz, energy, previous, level);
+    }
+
+    particles[index].color_and_flags.w = level;
+}
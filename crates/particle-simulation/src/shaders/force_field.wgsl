@@ -0,0 +1,139 @@
+// Compute shader: sample the force field on a planar grid (debug visualization)
+//
+// Each invocation is one grid point. It sums the force a probe particle (charge/mass from
+// `field.probe`) would feel there from every live particle, using the same softened gravity,
+// electromagnetic and weak force laws as `forces.wgsl` (the strong force needs a color, so it
+// is left out). `field_max` tracks the largest magnitude so the renderer can scale colors.
+//
+// Sample layout (must match Rust `FORCE_FIELD_SAMPLE_SIZE`):
+// position: xyz = grid point, w = arrow length (80% of the grid spacing)
+// force:    xyz = force on the probe, w = magnitude
+
+const FORCE_FIELD_GRID: u32 = 32u;
+const FLAG_DORMANT: u32 = 1u;
+const TYPE_GLUON: u32 = 3u;
+
+const FORCE_GRAVITY: u32 = 0u;
+const FORCE_EM: u32 = 1u;
+const FORCE_WEAK: u32 = 3u;
+
+struct PhysicsParams {
+    constants: vec4<f32>,    // x: G, y: K_electric, z: G_weak, w: weak_force_range
+    strong_force: vec4<f32>,
+    repulsion: vec4<f32>,    // x: core_repulsion, y: core_radius, z: softening, w: max_force
+    integration: vec4<f32>,
+    nucleon: vec4<f32>,
+    electron: vec4<f32>,
+    hadron: vec4<f32>,
+    residual_strong: vec4<f32>,
+    string_breaking: vec4<f32>,
+    electron_levels: vec4<f32>,
+    coupling: array<vec4<f32>, 16>,
+    boundary: vec4<f32>,
+    force_enabled: vec4<f32>,   // per force (gravity, EM, strong, weak): 0 = off, 1 = on
+    force_softening: vec4<f32>, // per force softening length (r_eff^2 = r^2 + eps^2)
+}
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag, w = vacuum partner + 1
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+struct FieldParams {
+    center: vec4<f32>, // xyz = slice center, w = half extent
+    axis_u: vec4<f32>,
+    axis_v: vec4<f32>,
+    probe: vec4<f32>,  // x = charge, y = mass
+}
+
+struct FieldSample {
+    position: vec4<f32>,
+    force: vec4<f32>,
+}
+
+@group(0) @binding(0)
+var<storage, read> particles: array<Particle>;
+
+@group(0) @binding(1)
+var<uniform> params: PhysicsParams;
+
+@group(0) @binding(2)
+var<uniform> field: FieldParams;
+
+@group(0) @binding(3)
+var<storage, read_write> samples: array<FieldSample>;
+
+// Largest sample magnitude (f32 bit pattern; non-negative floats order like u32)
+@group(0) @binding(4)
+var<storage, read_write> field_max: atomic<u32>;
+
+fn force_enabled(force: u32) -> bool {
+    return params.force_enabled[force] > 0.5;
+}
+
+fn softened_r_sq(force: u32, r_sq: f32) -> f32 {
+    let eps = params.force_softening[force];
+    return r_sq + eps * eps;
+}
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= FORCE_FIELD_GRID * FORCE_FIELD_GRID) {
+        return;
+    }
+
+    let half_extent = field.center.w;
+    let spacing = 2.0 * half_extent / f32(FORCE_FIELD_GRID);
+    let cell = vec2<f32>(f32(index % FORCE_FIELD_GRID), f32(index / FORCE_FIELD_GRID));
+    let offset = (cell + 0.5) * spacing - half_extent;
+    let point = field.center.xyz + field.axis_u.xyz * offset.x + field.axis_v.xyz * offset.y;
+
+    let probe_charge = field.probe.x;
+    let probe_mass = field.probe.y;
+    let gravity = force_enabled(FORCE_GRAVITY);
+    let em = force_enabled(FORCE_EM);
+    let weak = force_enabled(FORCE_WEAK);
+
+    var force = vec3<f32>(0.0);
+    for (var i = 0u; i < arrayLength(&particles); i++) {
+        let p = particles[i];
+        if ((p.color_and_flags.y & FLAG_DORMANT) != 0u) {
+            continue;
+        }
+
+        let r_vec = p.position.xyz - point;
+        let r_sq = dot(r_vec, r_vec);
+        if (r_sq < 1e-8) {
+            continue;
+        }
+        let r = sqrt(r_sq);
+        let dir = r_vec / r;
+
+        if (gravity) {
+            force += dir * params.constants.x * probe_mass * p.velocity.w
+                / softened_r_sq(FORCE_GRAVITY, r_sq);
+        }
+        if (em) {
+            // Like charges repel (away from the source), opposite charges attract
+            let charge_product = probe_charge * p.data.x;
+            force -= dir * params.constants.y * charge_product / softened_r_sq(FORCE_EM, r_sq);
+        }
+        if (weak && u32(p.position.w) != TYPE_GLUON && r <= params.constants.w * 3.0) {
+            force += dir * params.constants.z * exp(-r / params.constants.w)
+                / softened_r_sq(FORCE_WEAK, r_sq);
+        }
+    }
+
+    // Same cap the simulation applies
+    let magnitude = length(force);
+    if (magnitude > params.repulsion.w) {
+        force = force / magnitude * params.repulsion.w;
+    }
+    let clamped = min(magnitude, params.repulsion.w);
+
+    samples[index] = FieldSample(vec4<f32>(point, spacing * 0.8), vec4<f32>(force, clamped));
+    atomicMax(&field_max, bitcast<u32>(clamped));
+}
@@ -6,11 +6,29 @@ struct PhysicsParams {
     strong_force: vec4<f32>, // x: strong_short_range, y: strong_confinement, z: strong_range, w: padding
     repulsion: vec4<f32>,    // x: core_repulsion, y: core_radius, z: softening, w: max_force
     integration: vec4<f32>,  // x: dt, y: damping, z: time/seed, w: nucleon_damping
-    nucleon: vec4<f32>,      // x: binding_strength, y: binding_range, z: exclusion_strength, w: exclusion_radius
+    nucleon: vec4<f32>,      // x: padding, y: binding_range (detection), z: exclusion_strength, w: exclusion_radius
     electron: vec4<f32>,     // x: exclusion_strength, y: exclusion_radius, z: padding, w: padding
     hadron: vec4<f32>,       // x: binding_distance, y: breakup_distance, z: confinement_range_mult, w: confinement_strength_mult
+    residual_strong: vec4<f32>, // x: strength, y: range, z: cutoff (x range), w: padding
+    string_breaking: vec4<f32>, // x: string_tension, y: pair_threshold, z: enabled, w: padding
+    electron_levels: vec4<f32>, // x: bohr_radius, y: rydberg_energy, z: max_level, w: spectrum_max_energy
+    coupling: array<vec4<f32>, 16>, // row = force * 4 + species_a, column = species_b
+    boundary: vec4<f32>,        // x: radius, y: reinject, z: temperature, w: padding
+    force_enabled: vec4<f32>,   // per force (gravity, EM, strong, weak): 0 = off, 1 = on
+    force_softening: vec4<f32>, // per force softening length (r_eff^2 = r^2 + eps^2)
+    decay: vec4<f32>,           // x: meson_lifetime, y: exotic_baryon_lifetime, z: enabled, w: kick_speed
+    degeneracy: vec4<f32>,      // x: strength, y: radius, z: density threshold, w: enabled
 }
 
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+
+// Force indices into the coupling matrix (must match Rust `Force`)
+const FORCE_GRAVITY: u32 = 0u;
+const FORCE_EM: u32 = 1u;
+const FORCE_STRONG: u32 = 2u;
+const FORCE_WEAK: u32 = 3u;
+
 @group(0) @binding(2)
 var<uniform> params: PhysicsParams;
 
@@ -56,6 +74,19 @@ var<storage, read> hadrons: array<Hadron>;
 @group(0) @binding(4)
 var<storage, read> hadron_counter: HadronCounter;
 
+// Packed force sources: the N-body loop reads source particles from this 32-byte mirror
+// (written by `pack_main` each step) instead of the 64-byte particle buffer.
+// Positions stay f32; mass/charge and size/tag are stored as f16 pairs.
+override PACKED_SOURCES: bool = false;
+
+struct ForceSource {
+    position: vec4<f32>, // xyz = position, w = particle_type (as f32)
+    packed: vec4<u32>,   // x = f16 (mass, charge), y = color | flags << 16, z = hadron_id, w = f16 (size, tag)
+}
+
+@group(0) @binding(5)
+var<storage, read_write> sources: array<ForceSource>;
+
 // (hadron debug counters removed)
 
 // Treat invalid/out-of-range hadron_id as "free".
@@ -79,6 +110,11 @@ fn is_valid_hadron_id(hadron_id: u32) -> bool {
     return hadrons[h_idx].indices_type.w != 0xFFFFFFFFu;
 }
 
+// Dormant pool slots are parked until string breaking spawns into them
+fn is_dormant(p: Particle) -> bool {
+    return (p.color_and_flags.y & FLAG_DORMANT) != 0u;
+}
+
 // Check if particle is a quark
 fn is_quark(particle_type_f: f32) -> bool {
     let particle_type = u32(particle_type_f);
@@ -125,6 +161,26 @@ fn color_charges_attract(c1: u32, c2: u32) -> bool {
     return false;
 }
 
+fn force_enabled(force: u32) -> bool {
+    return params.force_enabled[force] > 0.5;
+}
+
+// Softened squared distance for a force
+fn softened_r_sq(force: u32, r_sq: f32) -> f32 {
+    let eps = params.force_softening[force];
+    return r_sq + eps * eps;
+}
+
+// Species coupling multiplier for a force (species index = particle type)
+fn coupling(force: u32, type1_f: f32, type2_f: f32) -> f32 {
+    let t1 = u32(type1_f);
+    let t2 = u32(type2_f);
+    if (t1 > 3u || t2 > 3u) {
+        return 1.0;
+    }
+    return params.coupling[force * 4u + t1][t2];
+}
+
 // Helper to clamp force magnitude
 fn clamp_force(f: vec3<f32>) -> vec3<f32> {
     let len = length(f);
@@ -136,7 +192,8 @@ fn clamp_force(f: vec3<f32>) -> vec3<f32> {
 
 // Calculate gravitational force
 fn gravitational_force(p1: Particle, p2: Particle, r_vec: vec3<f32>, r_sq: f32) -> vec3<f32> {
-    let force_mag = params.constants.x * p1.velocity.w * p2.velocity.w / r_sq; // mass in .w
+    let force_mag = params.constants.x * p1.velocity.w * p2.velocity.w
+        / softened_r_sq(FORCE_GRAVITY, r_sq); // mass in .w
     return normalize(r_vec) * force_mag;
 }
 
@@ -147,10 +204,9 @@ fn gravitational_force(p1: Particle, p2: Particle, r_vec: vec3<f32>, r_sq: f32)
 fn electromagnetic_force(p1: Particle, p2: Particle, r_vec: vec3<f32>, r_sq: f32) -> vec3<f32> {
     let charge_product = p1.data.x * p2.data.x; // charge in data.x
 
-    // Smooth saturation using Yukawa-like modification: r_eff = sqrt(r^2 + r_sat^2)
+    // Smooth saturation using Yukawa-like modification: r_eff = sqrt(r^2 + eps^2)
     // This prevents discontinuities and oscillations at close range
-    let saturation_dist = 0.2; // Smaller value for tighter saturation
-    let effective_r_sq = r_sq + saturation_dist * saturation_dist;
+    let effective_r_sq = softened_r_sq(FORCE_EM, r_sq);
 
     let force_mag = params.constants.y * abs(charge_product) / effective_r_sq;
 
@@ -253,7 +309,7 @@ fn strong_force(p1: Particle, p2: Particle, r_vec: vec3<f32>, r: f32) -> vec4<f3
 
     // Cornell potential: -a/r² + b (Force magnitude)
     // Significantly enhanced for free quarks to model confinement
-    let short_range = params.strong_force.x / (r * r);
+    let short_range = params.strong_force.x / softened_r_sq(FORCE_STRONG, r * r);
     let confinement = params.strong_force.y;
     let force_mag = color_factor * (short_range + confinement) * strength_multiplier;
 
@@ -272,61 +328,123 @@ fn weak_force(p1: Particle, p2: Particle, r_vec: vec3<f32>, r: f32, r_sq: f32) -
     }
 
     let exp_term = exp(-r / params.constants.w);
-    let force_mag = params.constants.z * exp_term / r_sq;
+    let force_mag = params.constants.z * exp_term / softened_r_sq(FORCE_WEAK, r_sq);
 
     return normalize(r_vec) * force_mag;
 }
 
-// Calculate nucleon-nucleon forces (Residual Strong + Exclusion)
-fn nucleon_force(h1: Hadron, h2: Hadron) -> vec3<f32> {
+// Confirmed proton/neutron (valid slot, type_id 1 or 2)
+fn is_nucleon(h: Hadron) -> bool {
+    return h.indices_type.w == 1u || h.indices_type.w == 2u;
+}
+
+// Hadron-hadron exclusion (hard sphere / Pauli)
+// Acts between any two valid hadrons when they overlap.
+fn hadron_exclusion_force(h1: Hadron, h2: Hadron) -> vec3<f32> {
     let r_vec = h2.center.xyz - h1.center.xyz;
-    let r_sq = dot(r_vec, r_vec);
-    let r = sqrt(r_sq);
+    let r = length(r_vec);
 
     if (r < 0.001) { return vec3<f32>(0.0); }
 
-    var f = vec3<f32>(0.0);
-    let dir = normalize(r_vec);
-
-    // 1. Exclusion Force (Hard Sphere / Pauli)
-    // Acts when hadrons overlap.
-    let combined_radius = h1.center.w + h2.center.w;
-    let exclusion_radius = combined_radius * params.nucleon.w;
-
-    if (r < exclusion_radius) {
-        let overlap = exclusion_radius - r;
-        // Quadratic repulsion for stiffness
-        let push = params.nucleon.z * overlap * (1.0 + overlap);
-        f -= dir * push;
+    let exclusion_radius = (h1.center.w + h2.center.w) * params.nucleon.w;
+    if (r >= exclusion_radius) { return vec3<f32>(0.0); }
+
+    let overlap = exclusion_radius - r;
+    // Quadratic repulsion for stiffness
+    let push = params.nucleon.z * overlap * (1.0 + overlap);
+    return -normalize(r_vec) * push;
+}
+
+// Residual strong force (Yukawa) between confirmed nucleons.
+// This is what actually binds nuclei; nucleus detection only labels the result.
+fn residual_strong_force(h1: Hadron, h2: Hadron) -> vec3<f32> {
+    let r_vec = h2.center.xyz - h1.center.xyz;
+    let r = length(r_vec);
+    let range = params.residual_strong.y;
+
+    if (r < 0.001 || range <= 0.0 || r > range * params.residual_strong.z) {
+        return vec3<f32>(0.0);
     }
 
+    let dir = r_vec / r;
+    var f = vec3<f32>(0.0);
+
     // Damping throughout binding range to stabilize nuclei
-    if (r < params.nucleon.y * 3.0) {
-        let v_rel = h2.velocity.xyz - h1.velocity.xyz;
-        let v_closing = dot(v_rel, dir);
-        if (v_closing < 0.0) { // Moving towards each other
-            let damping_strength = params.integration.w;
-            f += dir * v_closing * damping_strength;
-        }
+    let v_rel = h2.velocity.xyz - h1.velocity.xyz;
+    let v_closing = dot(v_rel, dir);
+    if (v_closing < 0.0) { // Moving towards each other
+        f += dir * v_closing * params.integration.w;
     }
 
-    // 2. Residual Strong Force (Yukawa)
-    // Attractive at short range.
-    if (r < params.nucleon.y * 3.0) {
-        let exp_term = exp(-r / params.nucleon.y);
-        // Cap minimum distance for attraction calculation to avoid singularity
-        let eff_r_sq = max(r * r, 0.5);
-        let pull = params.nucleon.x * exp_term / eff_r_sq;
+    let exp_term = exp(-r / range);
+    // Cap minimum distance for attraction calculation to avoid singularity
+    let eff_r_sq = max(r * r, 0.5);
+    let pull = params.residual_strong.x * exp_term / eff_r_sq;
 
-        // Dampen attraction inside exclusion zone to prevent instability
-        let damp = smoothstep(exclusion_radius * 0.5, exclusion_radius, r);
+    // Dampen attraction inside exclusion zone to prevent instability
+    let exclusion_radius = (h1.center.w + h2.center.w) * params.nucleon.w;
+    let damp = smoothstep(exclusion_radius * 0.5, exclusion_radius, r);
 
-        f += dir * pull * damp;
-    }
+    f += dir * pull * damp;
 
     return f;
 }
 
+// Identical fermions: same flavour and color (electrons have no color, so any two electrons).
+// Gluons are bosons and never contribute to degeneracy pressure.
+fn identical_fermions(p1: Particle, p2: Particle) -> bool {
+    if (is_gluon(p1.position.w) || u32(p1.position.w) != u32(p2.position.w)) {
+        return false;
+    }
+    return is_electron(p1.position.w) || p1.color_and_flags.x == p2.color_and_flags.x;
+}
+
+// Degeneracy pressure from the kernel-weighted density of identical fermions around a particle.
+// Kernel W(r) = (1 - r/h)^2, so n counts close neighbours ~1 and fades to 0 at the radius h.
+// P ~ n^(5/3) gives a push of (n - threshold)^(2/3) against the density gradient.
+fn degeneracy_force(density: f32, density_gradient: vec3<f32>) -> vec3<f32> {
+    let excess = density - params.degeneracy.z;
+    if (excess <= 0.0) {
+        return vec3<f32>(0.0);
+    }
+    return -params.degeneracy.x * pow(excess, 2.0 / 3.0) * density_gradient;
+}
+
+// Source particle `i` as seen by the N-body loop (velocity is never read there, so it stays zero)
+fn load_source(i: u32) -> Particle {
+    if (!PACKED_SOURCES) {
+        return particles[i];
+    }
+    let s = sources[i];
+    let mass_charge = unpack2x16float(s.packed.x);
+    let size_tag = unpack2x16float(s.packed.w);
+    var p: Particle;
+    p.position = s.position;
+    p.velocity = vec4<f32>(0.0, 0.0, 0.0, mass_charge.x);
+    p.data = vec4<f32>(mass_charge.y, size_tag.x, size_tag.y, 0.0);
+    p.color_and_flags = vec4<u32>(s.packed.y & 0xFFFFu, s.packed.y >> 16u, s.packed.z, 0u);
+    return p;
+}
+
+@compute @workgroup_size(256)
+fn pack_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let p = particles[index];
+    sources[index] = ForceSource(
+        p.position,
+        vec4<u32>(
+            pack2x16float(vec2<f32>(p.velocity.w, p.data.x)),
+            (p.color_and_flags.x & 0xFFFFu) | (p.color_and_flags.y << 16u),
+            p.color_and_flags.z,
+            pack2x16float(p.data.yz),
+        ),
+    );
+}
+
 @compute @workgroup_size(256)
 fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     let index = global_id.x;
@@ -336,6 +454,12 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
         return;
     }
 
+    // Dormant pool slots neither feel nor exert forces
+    if (is_dormant(particles[index])) {
+        forces[index].force = vec3<f32>(0.0);
+        return;
+    }
+
     // Scrub invalid hadron_id references for this particle (local, per-invocation).
     // This MUST happen before taking the particle snapshot (`let p1 = ...`) so subsequent logic
     // uses a consistent view for free/bound checks and confinement multipliers.
@@ -366,16 +490,38 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     var total_force = vec3<f32>(0.0, 0.0, 0.0);
     var total_potential = 0.0;
 
+    // Identical-fermion density (and its gradient w.r.t. p1) for degeneracy pressure
+    let degeneracy_enabled = params.degeneracy.w > 0.5 && params.degeneracy.y > 0.0;
+    var fermion_density = 0.0;
+    var fermion_density_gradient = vec3<f32>(0.0);
+
     // Calculate forces from all other particles (N-body)
     for (var i = 0u; i < num_particles; i = i + 1u) {
         if i == index {
             continue;
         }
 
-        let p2 = particles[i];
+        let p2 = load_source(i);
+        if (is_dormant(p2)) {
+            continue;
+        }
+
         let r_vec = p2.position.xyz - p1.position.xyz; // Use .xyz for position
         let r_sq = dot(r_vec, r_vec);
 
+        // Degeneracy density is accumulated before the softening cut-off: coincident particles
+        // are exactly the case it has to resolve.
+        if (degeneracy_enabled && r_sq < params.degeneracy.y * params.degeneracy.y
+            && identical_fermions(p1, p2)) {
+            let h = params.degeneracy.y;
+            let q = 1.0 - sqrt(r_sq) / h;
+            fermion_density += q * q;
+            if (r_sq > 1e-8) {
+                // dW/dx1 points toward the neighbour (density rises in that direction)
+                fermion_density_gradient += normalize(r_vec) * (2.0 * q / h);
+            }
+        }
+
         if r_sq < params.repulsion.z * params.repulsion.z {
             continue;
         }
@@ -389,10 +535,13 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
         // Sum all four fundamental forces
         var f = vec3<f32>(0.0, 0.0, 0.0);
-        f += gravitational_force(p1, p2, r_vec, r_sq);
+        if (force_enabled(FORCE_GRAVITY)) {
+            f += gravitational_force(p1, p2, r_vec, r_sq)
+                * coupling(FORCE_GRAVITY, p1.position.w, p2.position.w);
+        }
 
         // Electromagnetic force: Complex shielding rules
-        var skip_em = false;
+        var skip_em = !force_enabled(FORCE_EM);
 
         // Skip electron-quark interactions (electrons only see hadrons)
         if ((p1_is_electron && p2_is_quark) || (p1_is_quark && p2_is_electron)) {
@@ -401,7 +550,7 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
         // Skip quark-quark EM unless both free or in same hadron
         // Quarks in hadrons are shielded - only the hadron's net charge matters
-        if (p1_is_quark && p2_is_quark) {
+        if (!skip_em && p1_is_quark && p2_is_quark) {
             // NOTE: hadron_id on particles is 1-indexed (0u means "not in a hadron")
             // hadron_id is 1-indexed: 0u = not in hadron, otherwise (hadron_index + 1)
             let p1_hadron_id = p1.color_and_flags.z;
@@ -420,18 +569,29 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
         }
 
         if (!skip_em) {
-            f += electromagnetic_force(p1, p2, r_vec, r_sq);
+            f += electromagnetic_force(p1, p2, r_vec, r_sq)
+                * coupling(FORCE_EM, p1.position.w, p2.position.w);
         }
 
-        let strong = strong_force(p1, p2, r_vec, r);
-        f += strong.xyz;
-        total_potential += strong.w;
+        if (force_enabled(FORCE_STRONG)) {
+            let strong = strong_force(p1, p2, r_vec, r)
+                * coupling(FORCE_STRONG, p1.position.w, p2.position.w);
+            f += strong.xyz;
+            total_potential += strong.w;
+        }
 
-        f += weak_force(p1, p2, r_vec, r, r_sq);
+        if (force_enabled(FORCE_WEAK)) {
+            f += weak_force(p1, p2, r_vec, r, r_sq)
+                * coupling(FORCE_WEAK, p1.position.w, p2.position.w);
+        }
 
         total_force += clamp_force(f);
     }
 
+    if (degeneracy_enabled) {
+        total_force += clamp_force(degeneracy_force(fermion_density, fermion_density_gradient));
+    }
+
     // Electron-Hadron Exclusion (electrons repelled from nucleus centers)
     // This keeps electrons in shells AROUND nuclei, not between nucleons
     // Electron-Hadron Electromagnetism + Exclusion
@@ -458,14 +618,22 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
             let q_hadron = hadron_net_charge(hadron);
 
             // Skip near-neutral hadrons (e.g. neutrons) for stability/perf.
-            if (abs(q_hadron) > 0.01) {
+            if (force_enabled(FORCE_EM) && abs(q_hadron) > 0.01) {
                 var hadron_particle: Particle;
                 hadron_particle.position = vec4<f32>(hadron.center.xyz, 0.0);
                 hadron_particle.velocity = vec4<f32>(hadron.velocity.xyz, 0.0);
                 hadron_particle.data = vec4<f32>(q_hadron, 0.0, 0.0, 0.0);
                 hadron_particle.color_and_flags = vec4<u32>(0u, 0u, 0u, 0u);
 
-                total_force += electromagnetic_force(p1, hadron_particle, r_vec_hadron, r_sq_hadron);
+                // Hadrons couple like their constituent quarks: average the electron-up and
+                // electron-down EM couplings.
+                let hadron_coupling = 0.5 * (
+                    coupling(FORCE_EM, p1.position.w, 0.0) +
+                    coupling(FORCE_EM, p1.position.w, 1.0)
+                );
+
+                total_force += electromagnetic_force(p1, hadron_particle, r_vec_hadron, r_sq_hadron)
+                    * hadron_coupling;
             }
 
             // 2) Exclusion radius scales with hadron size
@@ -488,6 +656,7 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
         // Find my hadron
         for (var h = 0u; h < num_hadrons; h++) {
             let hadron = hadrons[h];
+            if (hadron.indices_type.w == 0xFFFFFFFFu) { continue; }
             if (hadron.indices_type.x == index ||
                 hadron.indices_type.y == index ||
                 hadron.indices_type.z == index) {
@@ -504,7 +673,14 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
                 if (i32(h) == my_hadron_idx) { continue; }
 
                 let other_hadron = hadrons[h];
-                hadron_force += nucleon_force(my_hadron, other_hadron);
+                if (other_hadron.indices_type.w == 0xFFFFFFFFu) { continue; }
+
+                hadron_force += hadron_exclusion_force(my_hadron, other_hadron);
+
+                // Residual strong force is the strong force leaking out of nucleons
+                if (force_enabled(FORCE_STRONG) && is_nucleon(my_hadron) && is_nucleon(other_hadron)) {
+                    hadron_force += residual_strong_force(my_hadron, other_hadron);
+                }
             }
 
             // Distribute force to constituents
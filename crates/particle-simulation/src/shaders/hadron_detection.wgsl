@@ -1,5 +1,13 @@
 // Compute shader for detecting hadron formation (Baryons and Mesons)
-// This runs as a separate pass after the physics integration
+// This runs as a separate pass after the physics integration.
+//
+// Detection is split into three dispatches so overlapping triads are resolved by binding energy
+// instead of by whichever invocation grabs its atomic locks first:
+// `candidate_main`: every free quark proposes its best triplet (or meson pair) and its Cornell
+//                   binding energy; each member records the best (lowest) energy proposed to it
+// `claim_main`:     leaders whose energy is the best for all of their members claim them; ties
+//                   between equal-energy candidates go to the lowest leader index
+// `main`:           leaders that won every member commit the hadron
 
 // Constants (must match Rust and other shaders)
 const STRONG_RANGE: f32 = 3.0;
@@ -8,6 +16,10 @@ const STRONG_RANGE: f32 = 3.0;
 const TYPE_QUARK_UP: u32 = 0u;
 const TYPE_QUARK_DOWN: u32 = 1u;
 
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+const FLAG_SUPER: u32 = 4u;
+
 // Color Charges
 const COLOR_RED: u32 = 0u;
 const COLOR_GREEN: u32 = 1u;
@@ -87,7 +99,7 @@ fn bump_hadron_type_counter(type_id: u32) {
 
 struct PhysicsParams {
     constants: vec4<f32>,
-    strong_force: vec4<f32>,
+    strong_force: vec4<f32>, // x: strong_short_range, y: strong_confinement, z: strong_range, w: padding
     repulsion: vec4<f32>,
     integration: vec4<f32>,
     nucleon: vec4<f32>,
@@ -104,12 +116,21 @@ var<storage, read_write> hadrons: array<Hadron>;
 @group(0) @binding(2)
 var<storage, read_write> counter: HadronCounter;
 
-@group(0) @binding(3)
-var<storage, read_write> locks: array<atomic<u32>>;
-
 @group(0) @binding(4)
 var<uniform> params: PhysicsParams;
 
+// Per-particle proposal + claim slots (cleared every frame before `candidate_main`)
+struct HadronCandidate {
+    members: vec4<u32>,        // x, y, z = constituents (z unused for mesons), w = hadron type + 1 (0 = none)
+    energy: f32,               // Cornell binding energy of the proposal (lower = more tightly bound)
+    best_score: atomic<u32>,   // best score (see `energy_score`) proposed to this particle as a member
+    best_leader: atomic<u32>,  // ~leader index among proposals with `best_score` (max = lowest index)
+    _padding: u32,
+}
+
+@group(0) @binding(6)
+var<storage, read_write> candidates: array<HadronCandidate>;
+
 fn get_dist_sq(p1_idx: u32, p2_idx: u32) -> f32 {
     let pos1 = particles[p1_idx].position.xyz;
     let pos2 = particles[p2_idx].position.xyz;
@@ -122,6 +143,10 @@ fn get_dist(p1_idx: u32, p2_idx: u32) -> f32 {
 }
 
 fn is_quark(p_idx: u32) -> bool {
+    // Dormant pool slots and coarse-grained super-particles never take part in hadrons
+    if ((particles[p_idx].color_and_flags.y & (FLAG_DORMANT | FLAG_SUPER)) != 0u) {
+        return false;
+    }
     let t = u32(particles[p_idx].position.w);
     return t == TYPE_QUARK_UP || t == TYPE_QUARK_DOWN;
 }
@@ -202,255 +227,296 @@ fn identify_baryon(p1: u32, p2: u32, p3: u32) -> u32 {
     return HADRON_BARYON_OTHER;
 }
 
-@compute @workgroup_size(256)
-fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
-    let index = global_id.x;
-    let num_particles = arrayLength(&particles);
+// Cornell potential between two constituents: -a/r (short range) + k*r (confinement)
+fn pair_energy(a: u32, b: u32) -> f32 {
+    let r = max(get_dist(a, b), 0.05);
+    return -params.strong_force.x / r + params.strong_force.y * r;
+}
 
-    if (index >= num_particles) {
-        return;
+// Order-preserving map from energy to an atomic-friendly score: lower energy => higher score,
+// and 0 never occurs for finite energies, so a cleared slot means "no proposal".
+fn energy_score(energy: f32) -> u32 {
+    let bits = bitcast<u32>(energy);
+    var ordered = bits | 0x80000000u;
+    if ((bits & 0x80000000u) != 0u) {
+        ordered = ~bits;
     }
+    return ~ordered;
+}
 
-    // Only Quarks can initiate a search
-    if (!is_quark(index)) {
-        return;
+fn member_count(candidate: vec4<u32>) -> u32 {
+    if (candidate.w == HADRON_MESON + 1u) {
+        return 2u;
     }
+    return 3u;
+}
 
-    // Skip quarks that are already bound to a hadron
-    if (is_bound(index)) {
-        return;
+// Best baryon proposal for a free quark: strict color-singlet triplets only,
+// (Red, Green, Blue) or (AntiRed, AntiGreen, AntiBlue). Mixed-sign triplets are NOT allowed.
+fn baryon_candidate(index: u32) -> vec4<u32> {
+    let num_particles = arrayLength(&particles);
+    let my_color = get_color(index);
+    let binding_dist = params.hadron.x;
+    let binding_sq = binding_dist * binding_dist;
+
+    // Strict sign rule:
+    // - If I'm a primary color, I only look for other primaries.
+    // - If I'm an anti-color, I only look for other anti-colors.
+    let want_primary = is_primary_color(my_color);
+    let want_anti = is_anti_color(my_color);
+    if (!want_primary && !want_anti) {
+        return vec4<u32>(0u);
     }
 
-    let my_color = get_color(index);
+    // Determine the exact two missing colors within the same sign set.
+    var need_color_1: u32 = 0u;
+    var need_color_2: u32 = 0u;
+
+    if (want_primary) {
+        if (my_color == COLOR_RED) {
+            need_color_1 = COLOR_GREEN;
+            need_color_2 = COLOR_BLUE;
+        } else if (my_color == COLOR_GREEN) {
+            need_color_1 = COLOR_RED;
+            need_color_2 = COLOR_BLUE;
+        } else { // COLOR_BLUE
+            need_color_1 = COLOR_RED;
+            need_color_2 = COLOR_GREEN;
+        }
+    } else { // want_anti
+        if (my_color == COLOR_ANTI_RED) {
+            need_color_1 = COLOR_ANTI_GREEN;
+            need_color_2 = COLOR_ANTI_BLUE;
+        } else if (my_color == COLOR_ANTI_GREEN) {
+            need_color_1 = COLOR_ANTI_RED;
+            need_color_2 = COLOR_ANTI_BLUE;
+        } else { // COLOR_ANTI_BLUE
+            need_color_1 = COLOR_ANTI_RED;
+            need_color_2 = COLOR_ANTI_GREEN;
+        }
+    }
 
-    // STRATEGY:
-    // - Baryons: ONLY form from strict color-singlet triplets:
-    //   (Red, Green, Blue) or (AntiRed, AntiGreen, AntiBlue).
-    //   Mixed-sign triplets are NOT allowed.
-    // - Mesons: still use (color + anti-color) pairing.
-
-    var found_baryon = false;
-
-    // --- BARYON SEARCH (strict RGB or AntiRGB only) ---
-    {
-        let binding_dist = params.hadron.x;
-        let binding_sq = binding_dist * binding_dist;
-
-        // Strict sign rule:
-        // - If I'm a primary color, I only look for other primaries.
-        // - If I'm an anti-color, I only look for other anti-colors.
-        let want_primary = is_primary_color(my_color);
-        let want_anti = is_anti_color(my_color);
-
-        // If neither (shouldn't happen for quarks), skip.
-        if (!want_primary && !want_anti) {
-            // no-op
-        } else {
-            // Determine the exact two missing colors within the same sign set.
-            var need_color_1: u32 = 0u;
-            var need_color_2: u32 = 0u;
-
-            if (want_primary) {
-                if (my_color == COLOR_RED) {
-                    need_color_1 = COLOR_GREEN;
-                    need_color_2 = COLOR_BLUE;
-                } else if (my_color == COLOR_GREEN) {
-                    need_color_1 = COLOR_RED;
-                    need_color_2 = COLOR_BLUE;
-                } else { // COLOR_BLUE
-                    need_color_1 = COLOR_RED;
-                    need_color_2 = COLOR_GREEN;
-                }
-            } else { // want_anti
-                if (my_color == COLOR_ANTI_RED) {
-                    need_color_1 = COLOR_ANTI_GREEN;
-                    need_color_2 = COLOR_ANTI_BLUE;
-                } else if (my_color == COLOR_ANTI_GREEN) {
-                    need_color_1 = COLOR_ANTI_RED;
-                    need_color_2 = COLOR_ANTI_BLUE;
-                } else { // COLOR_ANTI_BLUE
-                    need_color_1 = COLOR_ANTI_RED;
-                    need_color_2 = COLOR_ANTI_GREEN;
-                }
-            }
+    var closest_1 = 0xFFFFFFFFu;
+    var closest_2 = 0xFFFFFFFFu;
+    var min_dist_sq_1 = binding_sq;
+    var min_dist_sq_2 = binding_sq;
 
-            var closest_1 = 0xFFFFFFFFu;
-            var closest_2 = 0xFFFFFFFFu;
-            var min_dist_sq_1 = binding_sq;
-            var min_dist_sq_2 = binding_sq;
-
-            for (var i = 0u; i < num_particles; i++) {
-                if (i == index || !is_quark(i) || is_bound(i)) { continue; }
-
-                let d_sq = get_dist_sq(index, i);
-                if (d_sq > binding_sq) { continue; }
-
-                let c = get_color(i);
-
-                if (c == need_color_1) {
-                    if (d_sq < min_dist_sq_1) {
-                        min_dist_sq_1 = d_sq;
-                        closest_1 = i;
-                    }
-                } else if (c == need_color_2) {
-                    if (d_sq < min_dist_sq_2) {
-                        min_dist_sq_2 = d_sq;
-                        closest_2 = i;
-                    }
-                }
-            }
+    for (var i = 0u; i < num_particles; i++) {
+        if (i == index || !is_quark(i) || is_bound(i)) { continue; }
+
+        let d_sq = get_dist_sq(index, i);
+        if (d_sq > binding_sq) { continue; }
 
-        if (closest_1 != 0xFFFFFFFFu && closest_2 != 0xFFFFFFFFu) {
-            // Ensure the two partners are also close to each other
-            let d_12_sq = get_dist_sq(closest_1, closest_2);
-            if (d_12_sq < binding_sq) {
-                // Ensure the triplet is strictly colorless (RGB or AntiRGB)
-                let c1 = my_color;
-                let c2 = get_color(closest_1);
-                let c3 = get_color(closest_2);
-
-                if (is_colorless_triplet(c1, c2, c3)) {
-                    // Try to acquire locks on the three quarks
-                    let l1 = atomicCompareExchangeWeak(&locks[index], 0u, 1u).exchanged;
-                    var l2 = false;
-                    var l3 = false;
-
-                    if (l1) {
-                        l2 = atomicCompareExchangeWeak(&locks[closest_1], 0u, 1u).exchanged;
-                        if (l2) {
-                            l3 = atomicCompareExchangeWeak(&locks[closest_2], 0u, 1u).exchanged;
-                        }
-                    }
-
-                    if (l1 && l2 && l3) {
-                        let h_idx = find_free_slot();
-                        if (h_idx != 0xFFFFFFFFu) {
-                            let p1 = particles[index];
-                            let p2 = particles[closest_1];
-                            let p3 = particles[closest_2];
-
-                            let center = (p1.position.xyz + p2.position.xyz + p3.position.xyz) / 3.0;
-                            let velocity = (p1.velocity.xyz + p2.velocity.xyz + p3.velocity.xyz) / 3.0;
-
-                            let r1 = distance(center, p1.position.xyz);
-                            let r2 = distance(center, p2.position.xyz);
-                            let r3 = distance(center, p3.position.xyz);
-                            let radius = max(r1, max(r2, r3)) + 0.2;
-
-                            let baryon_type_id = identify_baryon(index, closest_1, closest_2);
-
-                            var h: Hadron;
-                            h.indices_type = vec4<u32>(
-                                index,
-                                closest_1,
-                                closest_2,
-                                baryon_type_id
-                            );
-                            h.center = vec4<f32>(center, radius);
-                            h.velocity = vec4<f32>(velocity, 0.0);
-
-                            hadrons[h_idx] = h;
-
-                            // Increment per-type counters
-                            bump_hadron_type_counter(baryon_type_id);
-
-                            // Set hadron_id on constituent particles (1-indexed)
-                            particles[index].color_and_flags.z = h_idx + 1u;
-                            particles[closest_1].color_and_flags.z = h_idx + 1u;
-                            particles[closest_2].color_and_flags.z = h_idx + 1u;
-
-                            found_baryon = true;
-                        } else {
-                            // Slot allocation failed: release locks to avoid "stuck" quarks.
-                            atomicStore(&locks[index], 0u);
-                            atomicStore(&locks[closest_1], 0u);
-                            atomicStore(&locks[closest_2], 0u);
-                        }
-                    } else {
-                        if (l1) { atomicStore(&locks[index], 0u); }
-                        if (l2) { atomicStore(&locks[closest_1], 0u); }
-                        if (l3) { atomicStore(&locks[closest_2], 0u); }
-                    }
-                }
+        let c = get_color(i);
+
+        if (c == need_color_1) {
+            if (d_sq < min_dist_sq_1) {
+                min_dist_sq_1 = d_sq;
+                closest_1 = i;
+            }
+        } else if (c == need_color_2) {
+            if (d_sq < min_dist_sq_2) {
+                min_dist_sq_2 = d_sq;
+                closest_2 = i;
             }
         }
     }
 
-        // (obsolete) RGB-only baryon detection block removed:
-        // baryon formation is now handled by the generalized colorless-triplet search above.
+    if (closest_1 == 0xFFFFFFFFu || closest_2 == 0xFFFFFFFFu) {
+        return vec4<u32>(0u);
+    }
+
+    // Ensure the two partners are also close to each other
+    if (get_dist_sq(closest_1, closest_2) >= binding_sq) {
+        return vec4<u32>(0u);
+    }
+
+    // Ensure the triplet is strictly colorless (RGB or AntiRGB)
+    if (!is_colorless_triplet(my_color, get_color(closest_1), get_color(closest_2))) {
+        return vec4<u32>(0u);
+    }
+
+    return vec4<u32>(index, closest_1, closest_2, identify_baryon(index, closest_1, closest_2) + 1u);
+}
+
+// Best meson proposal: leaders are Red, Green, Blue looking for AntiRed, AntiGreen, AntiBlue.
+fn meson_candidate(index: u32) -> vec4<u32> {
+    let num_particles = arrayLength(&particles);
+    let my_color = get_color(index);
+    if (my_color > COLOR_BLUE) {
+        return vec4<u32>(0u);
     }
 
-    // --- MESON SEARCH (Color looks for Anti-Color) ---
-    // Only run if we didn't just form a baryon.
-    // Leaders: Red, Green, Blue (looking for AntiRed, AntiGreen, AntiBlue)
-    if (!found_baryon && my_color <= COLOR_BLUE) {
-        let target_anti = my_color + 3u; // Red(0)->AntiRed(3), etc.
-        let binding_dist = params.hadron.x;
+    let target_anti = my_color + 3u; // Red(0)->AntiRed(3), etc.
+    let binding_dist = params.hadron.x;
 
-        var closest_anti = 0xFFFFFFFFu;
-        var min_dist_sq = binding_dist * binding_dist;
+    var closest_anti = 0xFFFFFFFFu;
+    var min_dist_sq = binding_dist * binding_dist;
 
-        for (var i = 0u; i < num_particles; i++) {
-            if (i == index || !is_quark(i) || is_bound(i)) { continue; }
+    for (var i = 0u; i < num_particles; i++) {
+        if (i == index || !is_quark(i) || is_bound(i)) { continue; }
 
-            let c = get_color(i);
-            if (c == target_anti) {
-                let d_sq = get_dist_sq(index, i);
-                if (d_sq < min_dist_sq) {
-                    min_dist_sq = d_sq;
-                    closest_anti = i;
-                }
+        let c = get_color(i);
+        if (c == target_anti) {
+            let d_sq = get_dist_sq(index, i);
+            if (d_sq < min_dist_sq) {
+                min_dist_sq = d_sq;
+                closest_anti = i;
             }
         }
+    }
 
-        if (closest_anti != 0xFFFFFFFFu) {
-            // Try to acquire locks
-            let l1 = atomicCompareExchangeWeak(&locks[index], 0u, 1u).exchanged;
-            var l2 = false;
+    if (closest_anti == 0xFFFFFFFFu) {
+        return vec4<u32>(0u);
+    }
+    return vec4<u32>(index, closest_anti, 0xFFFFFFFFu, HADRON_MESON + 1u);
+}
 
-            if (l1) {
-                l2 = atomicCompareExchangeWeak(&locks[closest_anti], 0u, 1u).exchanged;
-            }
+@compute @workgroup_size(256)
+fn candidate_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
 
-            if (l1 && l2) {
-                // Found a Meson! Find a free hadron slot
-                let h_idx = find_free_slot();
-                if (h_idx != 0xFFFFFFFFu) {
-                    let p1 = particles[index];
-                    let p2 = particles[closest_anti];
-
-                    let center = (p1.position.xyz + p2.position.xyz) / 2.0;
-                    let velocity = (p1.velocity.xyz + p2.velocity.xyz) / 2.0;
-                    let radius = distance(center, p1.position.xyz) + 0.2;
-
-                    var h: Hadron;
-                    h.indices_type = vec4<u32>(
-                        index,
-                        closest_anti,
-                        0xFFFFFFFFu,
-                        HADRON_MESON
-                    );
-                    h.center = vec4<f32>(center, radius);
-                    h.velocity = vec4<f32>(velocity, 0.0);
-
-                    hadrons[h_idx] = h;
-
-                    // Increment per-type counters (mesons count as "other")
-                    bump_hadron_type_counter(HADRON_MESON);
-
-                    // Set hadron_id on constituent particles (1-indexed)
-                    particles[index].color_and_flags.z = h_idx + 1u;
-                    particles[closest_anti].color_and_flags.z = h_idx + 1u;
-                } else {
-                    // Slot allocation failed: release locks to avoid "stuck" quarks.
-                    atomicStore(&locks[index], 0u);
-                    atomicStore(&locks[closest_anti], 0u);
-                }
-            } else {
-                // Failed, release locks
-                if (l1) { atomicStore(&locks[index], 0u); }
-                if (l2) { atomicStore(&locks[closest_anti], 0u); }
-            }
+    // Only free quarks propose
+    if (!is_quark(index) || is_bound(index)) {
+        return;
+    }
+
+    // Baryons take priority; a meson is only proposed when no colorless triplet is in reach.
+    var candidate = baryon_candidate(index);
+    var energy = 0.0;
+    if (candidate.w != 0u) {
+        energy = pair_energy(candidate.x, candidate.y)
+            + pair_energy(candidate.x, candidate.z)
+            + pair_energy(candidate.y, candidate.z);
+    } else {
+        candidate = meson_candidate(index);
+        if (candidate.w == 0u) {
+            return;
         }
+        energy = pair_energy(candidate.x, candidate.y);
+    }
+
+    candidates[index].members = candidate;
+    candidates[index].energy = energy;
+
+    let score = energy_score(energy);
+    _ = atomicMax(&candidates[candidate.x].best_score, score);
+    _ = atomicMax(&candidates[candidate.y].best_score, score);
+    if (member_count(candidate) == 3u) {
+        _ = atomicMax(&candidates[candidate.z].best_score, score);
+    }
+}
+
+// True when the leader's proposal is the best one made to `member`
+fn holds_best_score(member: u32, score: u32) -> bool {
+    return atomicLoad(&candidates[member].best_score) == score;
+}
+
+@compute @workgroup_size(256)
+fn claim_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let candidate = candidates[index].members;
+    if (candidate.w == 0u) {
+        return;
+    }
+
+    let score = energy_score(candidates[index].energy);
+    let triplet = member_count(candidate) == 3u;
+    if (!holds_best_score(candidate.x, score) || !holds_best_score(candidate.y, score)) {
+        return;
+    }
+    if (triplet && !holds_best_score(candidate.z, score)) {
+        return;
+    }
+
+    // Equal-energy proposals (e.g. the same triplet found by each of its quarks) go to the
+    // lowest leader index
+    _ = atomicMax(&candidates[candidate.x].best_leader, ~index);
+    _ = atomicMax(&candidates[candidate.y].best_leader, ~index);
+    if (triplet) {
+        _ = atomicMax(&candidates[candidate.z].best_leader, ~index);
+    }
+}
+
+fn holds_claim(member: u32, index: u32) -> bool {
+    return atomicLoad(&candidates[member].best_leader) == ~index;
+}
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let candidate = candidates[index].members;
+    if (candidate.w == 0u) {
+        return;
+    }
+
+    let score = energy_score(candidates[index].energy);
+    let triplet = member_count(candidate) == 3u;
+    if (!holds_best_score(candidate.x, score) || !holds_claim(candidate.x, index)) {
+        return;
+    }
+    if (!holds_best_score(candidate.y, score) || !holds_claim(candidate.y, index)) {
+        return;
+    }
+    if (triplet && (!holds_best_score(candidate.z, score) || !holds_claim(candidate.z, index))) {
+        return;
+    }
+
+    // Every member is exclusively ours now
+    let h_idx = find_free_slot();
+    if (h_idx == 0xFFFFFFFFu) {
+        return;
+    }
+
+    let type_id = candidate.w - 1u;
+    let p1 = particles[candidate.x];
+    let p2 = particles[candidate.y];
+
+    var h: Hadron;
+    h.indices_type = vec4<u32>(candidate.x, candidate.y, candidate.z, type_id);
+
+    if (triplet) {
+        let p3 = particles[candidate.z];
+
+        let center = (p1.position.xyz + p2.position.xyz + p3.position.xyz) / 3.0;
+        let velocity = (p1.velocity.xyz + p2.velocity.xyz + p3.velocity.xyz) / 3.0;
+
+        let r1 = distance(center, p1.position.xyz);
+        let r2 = distance(center, p2.position.xyz);
+        let r3 = distance(center, p3.position.xyz);
+        let radius = max(r1, max(r2, r3)) + 0.2;
+
+        h.center = vec4<f32>(center, radius);
+        h.velocity = vec4<f32>(velocity, 0.0);
+    } else {
+        let center = (p1.position.xyz + p2.position.xyz) / 2.0;
+        let velocity = (p1.velocity.xyz + p2.velocity.xyz) / 2.0;
+        let radius = distance(center, p1.position.xyz) + 0.2;
+
+        h.center = vec4<f32>(center, radius);
+        h.velocity = vec4<f32>(velocity, 0.0);
+    }
+
+    hadrons[h_idx] = h;
+
+    // Increment per-type counters (mesons count as "other")
+    bump_hadron_type_counter(type_id);
+
+    // Set hadron_id on constituent particles (1-indexed)
+    particles[candidate.x].color_and_flags.z = h_idx + 1u;
+    particles[candidate.y].color_and_flags.z = h_idx + 1u;
+    if (triplet) {
+        particles[candidate.z].color_and_flags.z = h_idx + 1u;
     }
 }
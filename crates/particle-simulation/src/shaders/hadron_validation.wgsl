@@ -1,14 +1,53 @@
 // Compute shader for validating existing hadrons
-// Checks if constituent quarks are still bound, breaks up hadrons if not
+// Checks if constituent quarks are still bound, breaks up hadrons if not.
+// When an overstretched string carries enough energy, it breaks by spawning a quark/antiquark
+// pair into dormant pool slots (string breaking) instead of just releasing the quarks.
+//
+// Also hosts the hadron decay entry points (run after validation, in their own pass):
+// - `baryon_decay_main`: exotic baryons (uuu/ddd) flip one quark flavor and emit a lepton
+//   drawn from the pair pool (pops only)
+// - `meson_decay_main`: mesons annihilate into leptons in place; a leftover slot is returned
+//   to the pair pool (pushes only)
+//
+// And the vacuum fluctuation entry points (same pass, after the decays):
+// - `vacuum_expire_main`: free vacuum pairs annihilate back into the pool (pushes only)
+// - `vacuum_spawn_main`: spawn new pairs in empty regions (pops only)
 
 // Particle Types
 const TYPE_QUARK_UP: u32 = 0u;
 const TYPE_QUARK_DOWN: u32 = 1u;
+const TYPE_ELECTRON: u32 = 2u;
+
+// Hadron types (indices_type.w)
+const HADRON_MESON: u32 = 0u;
+const HADRON_BARYON_OTHER: u32 = 3u;
+
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+const FLAG_SUPER: u32 = 4u;
+
+// Quark properties (must match particle-physics constants)
+const QUARK_UP_MASS: f32 = 0.0023;
+const QUARK_DOWN_MASS: f32 = 0.0048;
+const QUARK_UP_CHARGE: f32 = 0.6666667;
+const QUARK_DOWN_CHARGE: f32 = -0.3333333;
+const QUARK_SIZE: f32 = 0.03;
+
+// Lepton properties (must match particle-physics constants); positrons are electrons with +1 charge
+const ELECTRON_MASS: f32 = 0.000511;
+const ELECTRON_SIZE: f32 = 0.03;
+
+// Vacuum fluctuations: spawn attempts per step (one `vacuum_spawn_main` workgroup), the spawn
+// region radius when no boundary is set, pair separation and speed
+const VACUUM_ATTEMPTS: u32 = 64u;
+const VACUUM_REGION: f32 = 50.0;
+const VACUUM_SEPARATION: f32 = 0.2;
+const VACUUM_SPEED: f32 = 0.1;
 
 struct Particle {
     position: vec4<f32>,        // xyz = position, w = particle_type
     velocity: vec4<f32>,        // xyz = velocity, w = mass
-    data: vec4<f32>,            // x = charge, y = size
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag, w = vacuum partner + 1 (0 = real)
     color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = padding
 }
 
@@ -35,6 +74,25 @@ struct PhysicsParams {
     nucleon: vec4<f32>,
     electron: vec4<f32>,
     hadron: vec4<f32>, // x: binding_distance, y: breakup_distance, z: confinement_range_mult, w: confinement_strength_mult
+    residual_strong: vec4<f32>,
+    string_breaking: vec4<f32>, // x: string_tension, y: pair_threshold, z: enabled, w: padding
+    electron_levels: vec4<f32>,
+    coupling: array<vec4<f32>, 16>,
+    boundary: vec4<f32>,
+    force_enabled: vec4<f32>,
+    force_softening: vec4<f32>,
+    decay: vec4<f32>, // x: meson_lifetime, y: exotic_baryon_lifetime, z: enabled, w: kick_speed
+    degeneracy: vec4<f32>,
+    color_flow: vec4<f32>,
+    vacuum: vec4<f32>, // x: pair rate (per unit time), y: mean lifetime, z: clearance, w: enabled
+}
+
+struct PairPool {
+    available: atomic<u32>,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+    indices: array<u32>, // dormant particle slots; [0, available) are free
 }
 
 @group(0) @binding(0)
@@ -52,6 +110,9 @@ var<storage, read_write> locks: array<atomic<u32>>;
 @group(0) @binding(4)
 var<uniform> params: PhysicsParams;
 
+@group(0) @binding(5)
+var<storage, read_write> pair_pool: PairPool;
+
 fn get_dist_sq(p1_idx: u32, p2_idx: u32) -> f32 {
     let pos1 = particles[p1_idx].position.xyz;
     let pos2 = particles[p2_idx].position.xyz;
@@ -59,11 +120,118 @@ fn get_dist_sq(p1_idx: u32, p2_idx: u32) -> f32 {
     return dot(diff, diff);
 }
 
+// Super-particles never count as quarks, so a hadron holding one breaks up before it can decay
+// or break its string
 fn is_quark(p_idx: u32) -> bool {
+    if ((particles[p_idx].color_and_flags.y & (FLAG_DORMANT | FLAG_SUPER)) != 0u) {
+        return false;
+    }
     let t = u32(particles[p_idx].position.w);
     return t == TYPE_QUARK_UP || t == TYPE_QUARK_DOWN;
 }
 
+// Red/Green/Blue <-> AntiRed/AntiGreen/AntiBlue
+fn anti_color(c: u32) -> u32 {
+    if (c < 3u) {
+        return c + 3u;
+    }
+    return c - 3u;
+}
+
+fn hash(x: u32) -> u32 {
+    var h = x;
+    h ^= h >> 16u;
+    h *= 0x7feb352du;
+    h ^= h >> 15u;
+    h *= 0x846ca68bu;
+    h ^= h >> 16u;
+    return h;
+}
+
+// Claim `count` dormant slots; returns the index of the first one in `pair_pool.indices`
+// or 0xFFFFFFFF when the pool is exhausted (or heavily contended).
+fn pool_pop(count: u32) -> u32 {
+    for (var attempt = 0u; attempt < 8u; attempt++) {
+        let available = atomicLoad(&pair_pool.available);
+        if (available < count) {
+            return 0xFFFFFFFFu;
+        }
+        let result = atomicCompareExchangeWeak(&pair_pool.available, available, available - count);
+        if (result.exchanged) {
+            return available - count;
+        }
+    }
+    return 0xFFFFFFFFu;
+}
+
+fn pool_pop_pair() -> u32 {
+    return pool_pop(2u);
+}
+
+// Anticolored quarks are antiquarks and carry the opposite charge.
+fn spawn_quark(slot: u32, flavor: u32, color: u32, position: vec3<f32>, velocity: vec3<f32>) {
+    var mass = QUARK_UP_MASS;
+    var charge = QUARK_UP_CHARGE;
+    if (flavor == TYPE_QUARK_DOWN) {
+        mass = QUARK_DOWN_MASS;
+        charge = QUARK_DOWN_CHARGE;
+    }
+    if (color >= 3u) {
+        charge = -charge;
+    }
+
+    var p: Particle;
+    p.position = vec4<f32>(position, f32(flavor));
+    p.velocity = vec4<f32>(velocity, mass);
+    p.data = vec4<f32>(charge, QUARK_SIZE, 0.0, 0.0);
+    p.color_and_flags = vec4<u32>(color, 0u, 0u, 0u);
+    particles[slot] = p;
+}
+
+// Linear confinement: the string between `a` and `b` stores
+// `string_tension * (d - binding_distance)`. If that exceeds the pair threshold, the string breaks
+// into a quark/antiquark pair: one partner next to each end, so both ends can re-bind.
+// Near `a` we spawn anti(color_a), near `b` color_a, which keeps color and charge balanced.
+fn try_break_string(a: u32, b: u32) {
+    if (params.string_breaking.z < 0.5) {
+        return;
+    }
+
+    let pa = particles[a];
+    let pb = particles[b];
+    let d = distance(pa.position.xyz, pb.position.xyz);
+    let energy = params.string_breaking.x * max(d - params.hadron.x, 0.0);
+    if (energy < params.string_breaking.y || d < 0.001) {
+        return;
+    }
+
+    let base = pool_pop_pair();
+    if (base == 0xFFFFFFFFu) {
+        return;
+    }
+
+    let dir = (pb.position.xyz - pa.position.xyz) / d;
+    let offset = min(params.hadron.x * 0.5, d * 0.25);
+    let seed = (a * 0x9e3779b9u) ^ b ^ bitcast<u32>(params.integration.z);
+    let flavor = hash(seed) & 1u; // TYPE_QUARK_UP or TYPE_QUARK_DOWN
+    let color_a = pa.color_and_flags.x;
+
+    spawn_quark(
+        pair_pool.indices[base],
+        flavor,
+        anti_color(color_a),
+        pa.position.xyz + dir * offset,
+        pa.velocity.xyz,
+    );
+    spawn_quark(
+        pair_pool.indices[base + 1u],
+        flavor,
+        color_a,
+        pb.position.xyz - dir * offset,
+        pb.velocity.xyz,
+    );
+}
+
 // Mark hadron as invalid
 fn invalidate_hadron(h_idx: u32) {
     let h = hadrons[h_idx];
@@ -135,16 +303,25 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     let breakup_dist = params.hadron.y;
     let breakup_sq = breakup_dist * breakup_dist;
 
-    if (d12_sq > breakup_sq) {
-        invalidate_hadron(h_idx);
-        return;
-    }
-
-    if (!is_meson) {
+    if (is_meson) {
+        if (d12_sq > breakup_sq) {
+            try_break_string(p1, p2);
+            invalidate_hadron(h_idx);
+            return;
+        }
+    } else {
         let d13_sq = get_dist_sq(p1, p3);
         let d23_sq = get_dist_sq(p2, p3);
 
-        if (d13_sq > breakup_sq || d23_sq > breakup_sq) {
+        if (d12_sq > breakup_sq || d13_sq > breakup_sq || d23_sq > breakup_sq) {
+            // Break the most stretched string
+            if (d12_sq >= d13_sq && d12_sq >= d23_sq) {
+                try_break_string(p1, p2);
+            } else if (d13_sq >= d23_sq) {
+                try_break_string(p1, p3);
+            } else {
+                try_break_string(p2, p3);
+            }
             invalidate_hadron(h_idx);
             return;
         }
@@ -171,3 +348,263 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
         hadrons[h_idx].velocity = vec4<f32>(velocity, 0.0);
     }
 }
+
+// Probability that a hadron with mean lifetime `lifetime` decays during this step
+// (exponential decay, rolled with the per-frame seed).
+fn decays_this_step(h_idx: u32, lifetime: f32) -> bool {
+    if (params.decay.z < 0.5 || lifetime <= 0.0) {
+        return false;
+    }
+    let p = 1.0 - exp(-params.integration.x / lifetime);
+    let roll = f32(hash(h_idx ^ bitcast<u32>(params.integration.z)) >> 8u) / 16777216.0;
+    return roll < p;
+}
+
+// Random unit vector for decay kicks
+fn random_direction(seed: u32) -> vec3<f32> {
+    let z = f32(hash(seed) >> 8u) / 8388608.0 - 1.0;
+    let phi = f32(hash(seed ^ 0x68bc21ebu) >> 8u) / 16777216.0 * 6.2831853;
+    let s = sqrt(max(1.0 - z * z, 0.0));
+    return vec3<f32>(s * cos(phi), s * sin(phi), z);
+}
+
+fn quark_charge(flavor: u32, color: u32) -> f32 {
+    var charge = QUARK_UP_CHARGE;
+    if (flavor == TYPE_QUARK_DOWN) {
+        charge = QUARK_DOWN_CHARGE;
+    }
+    if (color >= 3u) {
+        charge = -charge;
+    }
+    return charge;
+}
+
+// Turn a particle slot into a lepton (electron for charge -1, positron for +1).
+// The cohort tag is kept so decay products stay in their cohort.
+fn make_lepton(slot: u32, charge: f32, position: vec3<f32>, velocity: vec3<f32>) {
+    var p = particles[slot];
+    p.position = vec4<f32>(position, f32(TYPE_ELECTRON));
+    p.velocity = vec4<f32>(velocity, ELECTRON_MASS);
+    p.data = vec4<f32>(charge, ELECTRON_SIZE, p.data.z, 0.0);
+    p.color_and_flags = vec4<u32>(0u, 0u, 0u, 0u);
+    particles[slot] = p;
+}
+
+// Return a slot to the pair pool (only called from push-only entry points).
+fn pool_push(slot: u32) {
+    var p = particles[slot];
+    p.data = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    p.velocity = vec4<f32>(0.0, 0.0, 0.0, p.velocity.w);
+    p.color_and_flags = vec4<u32>(0u, FLAG_DORMANT, 0u, 0u);
+    particles[slot] = p;
+
+    let top = atomicAdd(&pair_pool.available, 1u);
+    pair_pool.indices[top] = slot;
+}
+
+// Exotic baryon weak decay: flip one quark flavor towards a nucleon (uuu -> uud, ddd -> udd)
+// and emit a lepton carrying away the charge difference.
+@compute @workgroup_size(256)
+fn baryon_decay_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let h_idx = global_id.x;
+    if (h_idx >= atomicLoad(&counter.counters[0])) {
+        return;
+    }
+
+    let h = hadrons[h_idx];
+    if (h.indices_type.w != HADRON_BARYON_OTHER || !decays_this_step(h_idx, params.decay.y)) {
+        return;
+    }
+
+    let q = h.indices_type.xyz;
+    let num_particles = arrayLength(&particles);
+    if (q.x >= num_particles || q.y >= num_particles || q.z >= num_particles) {
+        return;
+    }
+    if (!is_quark(q.x) || !is_quark(q.y) || !is_quark(q.z)) {
+        return;
+    }
+
+    // All three constituents share a flavor for exotic baryons; flip the first one
+    let slot = q.x;
+    let flavor = u32(particles[slot].position.w);
+    let color = particles[slot].color_and_flags.x;
+    var new_flavor = TYPE_QUARK_UP;
+    var new_mass = QUARK_UP_MASS;
+    if (flavor == TYPE_QUARK_UP) {
+        new_flavor = TYPE_QUARK_DOWN;
+        new_mass = QUARK_DOWN_MASS;
+    }
+    let lepton_charge = quark_charge(flavor, color) - quark_charge(new_flavor, color);
+
+    // No free slot for the lepton: defer the decay rather than violate charge conservation
+    let base = pool_pop(1u);
+    if (base == 0xFFFFFFFFu) {
+        return;
+    }
+
+    particles[slot].position.w = f32(new_flavor);
+    particles[slot].velocity.w = new_mass;
+    particles[slot].data.x = quark_charge(new_flavor, color);
+
+    let seed = h_idx ^ bitcast<u32>(params.integration.z);
+    let dir = random_direction(seed);
+    let lepton = pair_pool.indices[base];
+    make_lepton(
+        lepton,
+        round(lepton_charge),
+        h.center.xyz + dir * h.center.w,
+        h.velocity.xyz + dir * params.decay.w,
+    );
+    particles[lepton].data.z = particles[slot].data.z;
+
+    // Re-detection classifies the remaining triplet as a nucleon
+    invalidate_hadron(h_idx);
+}
+
+// Meson decay: the quark/antiquark pair annihilates into leptons. A neutral meson becomes an
+// electron/positron pair; a charged meson becomes a single lepton and frees the other slot.
+@compute @workgroup_size(256)
+fn meson_decay_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let h_idx = global_id.x;
+    if (h_idx >= atomicLoad(&counter.counters[0])) {
+        return;
+    }
+
+    let h = hadrons[h_idx];
+    if (h.indices_type.w != HADRON_MESON || !decays_this_step(h_idx, params.decay.x)) {
+        return;
+    }
+
+    let a = h.indices_type.x;
+    let b = h.indices_type.y;
+    let num_particles = arrayLength(&particles);
+    if (a >= num_particles || b >= num_particles || !is_quark(a) || !is_quark(b)) {
+        return;
+    }
+
+    let charge = round(particles[a].data.x + particles[b].data.x);
+    invalidate_hadron(h_idx);
+
+    let seed = h_idx ^ bitcast<u32>(params.integration.z);
+    let dir = random_direction(seed);
+    let kick = dir * params.decay.w;
+
+    if (abs(charge) < 0.5) {
+        make_lepton(a, -1.0, particles[a].position.xyz, h.velocity.xyz + kick);
+        make_lepton(b, 1.0, particles[b].position.xyz, h.velocity.xyz - kick);
+    } else {
+        make_lepton(a, charge, h.center.xyz, h.velocity.xyz + kick);
+        pool_push(b);
+    }
+}
+
+// A vacuum particle has gone "real" once it is bound (in a hadron or on an electron level)
+fn is_bound(slot: u32) -> bool {
+    let flags = particles[slot].color_and_flags;
+    return flags.z != 0u || (u32(particles[slot].position.w) == TYPE_ELECTRON && flags.w != 0u);
+}
+
+// Vacuum pairs annihilate with mean lifetime `vacuum.y` while both partners are still free. The
+// lower slot of each pair handles both partners; once either is bound the pair becomes real
+// (markers cleared), so charge and color stay balanced either way.
+@compute @workgroup_size(256)
+fn vacuum_expire_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    let num_particles = arrayLength(&particles);
+    if (index >= num_particles) {
+        return;
+    }
+
+    let p = particles[index];
+    if (p.data.w < 0.5 || (p.color_and_flags.y & (FLAG_DORMANT | FLAG_SUPER)) != 0u) {
+        return;
+    }
+
+    let partner = u32(p.data.w) - 1u;
+    let paired = partner < num_particles
+        && (particles[partner].color_and_flags.y & FLAG_DORMANT) == 0u
+        && u32(particles[partner].data.w) == index + 1u;
+    if (!paired) {
+        particles[index].data.w = 0.0;
+        return;
+    }
+    if (partner < index) {
+        return;
+    }
+
+    if (is_bound(index) || is_bound(partner)) {
+        particles[index].data.w = 0.0;
+        particles[partner].data.w = 0.0;
+        return;
+    }
+
+    let lifetime = params.vacuum.y;
+    if (lifetime <= 0.0) {
+        return;
+    }
+    let roll = f32(hash(index ^ 0x5bd1e995u ^ bitcast<u32>(params.integration.z)) >> 8u) / 16777216.0;
+    if (roll < 1.0 - exp(-params.integration.x / lifetime)) {
+        pool_push(index);
+        pool_push(partner);
+    }
+}
+
+// Each attempt fires with probability `rate * dt / VACUUM_ATTEMPTS`, picks a random point in the
+// spawn region and spawns a pair there if no active particle is within the clearance radius.
+// Half the pairs are electron/positron, half quark/antiquark of a random flavor and color.
+@compute @workgroup_size(64)
+fn vacuum_spawn_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let attempt = global_id.x;
+    if (params.vacuum.w < 0.5 || attempt >= VACUUM_ATTEMPTS) {
+        return;
+    }
+
+    let seed = hash(attempt * 0x9e3779b9u ^ bitcast<u32>(params.integration.z));
+    let chance = params.vacuum.x * params.integration.x / f32(VACUUM_ATTEMPTS);
+    if (f32(seed >> 8u) / 16777216.0 >= chance) {
+        return;
+    }
+
+    var region = VACUUM_REGION;
+    if (params.boundary.x > 0.0) {
+        region = params.boundary.x;
+    }
+    let radius = region * pow(f32(hash(seed ^ 0x27d4eb2fu) >> 8u) / 16777216.0, 1.0 / 3.0);
+    let center = random_direction(seed ^ 0x165667b1u) * radius;
+
+    let clearance_sq = params.vacuum.z * params.vacuum.z;
+    let num_particles = arrayLength(&particles);
+    for (var i = 0u; i < num_particles; i++) {
+        if ((particles[i].color_and_flags.y & FLAG_DORMANT) != 0u) {
+            continue;
+        }
+        let d = particles[i].position.xyz - center;
+        if (dot(d, d) < clearance_sq) {
+            return;
+        }
+    }
+
+    let base = pool_pop_pair();
+    if (base == 0xFFFFFFFFu) {
+        return;
+    }
+
+    let a = pair_pool.indices[base];
+    let b = pair_pool.indices[base + 1u];
+    let dir = random_direction(seed ^ 0xd3a2646cu);
+    let offset = dir * (VACUUM_SEPARATION * 0.5);
+    let velocity = dir * VACUUM_SPEED;
+    let kind = hash(seed ^ 0xfd7046c5u);
+    if ((kind & 1u) == 0u) {
+        make_lepton(a, -1.0, center - offset, -velocity);
+        make_lepton(b, 1.0, center + offset, velocity);
+    } else {
+        let flavor = (kind >> 1u) & 1u; // TYPE_QUARK_UP or TYPE_QUARK_DOWN
+        let color = ((kind >> 2u) & 0xFFFFu) % 3u;
+        spawn_quark(a, flavor, color, center - offset, -velocity);
+        spawn_quark(b, flavor, anti_color(color), center + offset, velocity);
+    }
+    particles[a].data.w = f32(b + 1u);
+    particles[b].data.w = f32(a + 1u);
+}
@@ -0,0 +1,206 @@
+// Compute shader for diagnostics histograms
+// Bins particle speeds, intra-hadron pair separations and hadron masses with atomic increments.
+//
+// Output layout (HISTOGRAM_COUNT * HISTOGRAM_BINS u32 values, must match Rust `Histograms`):
+// [0 .. BINS)          particle speed
+// [BINS .. 2*BINS)     pair separation inside hadrons
+// [2*BINS .. 3*BINS)   hadron mass (constituent masses + internal kinetic energy)
+//
+// `charge_mass_main` writes a CHARGE_BINS x MASS_BINS map (row-major, must match Rust
+// `ChargeMassMap`) into the same binding. It counts every free particle and every hadron
+// (as one object with its net charge and constituent mass), like a mass spectrometer would.
+
+const HISTOGRAM_BINS: u32 = 32u;
+
+const HISTOGRAM_SPEED: u32 = 0u;
+const HISTOGRAM_SEPARATION: u32 = 1u;
+const HISTOGRAM_MASS: u32 = 2u;
+
+const CHARGE_BINS: u32 = 13u; // -2..=+2 in steps of e/3
+const MASS_BINS: u32 = 32u;
+
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = padding
+}
+
+struct Hadron {
+    indices_type: vec4<u32>, // x=p1, y=p2, z=p3, w=type_id
+    center: vec4<f32>,       // xyz = center of mass, w = radius
+    velocity: vec4<f32>,     // xyz = velocity, w = padding
+}
+
+struct HadronCounter {
+    // [0] total hadrons (counter range; may include invalid slots)
+    // [1] protons, [2] neutrons, [3] other
+    counters: vec4<u32>,
+}
+
+struct HistogramParams {
+    // x: max speed, y: max pair separation, z: max hadron mass, w: max mass (charge/mass map)
+    ranges: vec4<f32>,
+}
+
+@group(0) @binding(0)
+var<storage, read> particles: array<Particle>;
+
+@group(0) @binding(1)
+var<storage, read> hadrons: array<Hadron>;
+
+@group(0) @binding(2)
+var<storage, read> hadron_counter: HadronCounter;
+
+@group(0) @binding(3)
+var<storage, read_write> bins: array<atomic<u32>>;
+
+@group(0) @binding(4)
+var<uniform> histogram_params: HistogramParams;
+
+// Values above the range land in the last bin so outliers remain visible.
+fn record(histogram: u32, value: f32, max_value: f32) {
+    if (max_value <= 0.0) {
+        return;
+    }
+
+    let t = clamp(value / max_value, 0.0, 1.0);
+    let bin = min(u32(t * f32(HISTOGRAM_BINS)), HISTOGRAM_BINS - 1u);
+    _ = atomicAdd(&bins[histogram * HISTOGRAM_BINS + bin], 1u);
+}
+
+fn kinetic_energy(p: Particle, frame_velocity: vec3<f32>) -> f32 {
+    let v = p.velocity.xyz - frame_velocity;
+    return 0.5 * p.velocity.w * dot(v, v);
+}
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    let num_particles = arrayLength(&particles);
+
+    // Particle speed (dormant pool slots excluded)
+    if (index < num_particles && (particles[index].color_and_flags.y & FLAG_DORMANT) == 0u) {
+        record(HISTOGRAM_SPEED, length(particles[index].velocity.xyz), histogram_params.ranges.x);
+    }
+
+    // Hadron separation + mass
+    let num_hadrons = min(hadron_counter.counters.x, arrayLength(&hadrons));
+    if (index >= num_hadrons) {
+        return;
+    }
+
+    let h = hadrons[index];
+    if (h.indices_type.w == 0xFFFFFFFFu) {
+        return;
+    }
+
+    let is_meson = h.indices_type.w == 0u;
+    if (h.indices_type.x >= num_particles || h.indices_type.y >= num_particles) {
+        return;
+    }
+    if (!is_meson && h.indices_type.z >= num_particles) {
+        return;
+    }
+
+    let p1 = particles[h.indices_type.x];
+    let p2 = particles[h.indices_type.y];
+    let frame_velocity = h.velocity.xyz;
+
+    record(
+        HISTOGRAM_SEPARATION,
+        distance(p1.position.xyz, p2.position.xyz),
+        histogram_params.ranges.y,
+    );
+
+    var mass = p1.velocity.w + p2.velocity.w
+        + kinetic_energy(p1, frame_velocity)
+        + kinetic_energy(p2, frame_velocity);
+
+    if (!is_meson) {
+        let p3 = particles[h.indices_type.z];
+
+        record(
+            HISTOGRAM_SEPARATION,
+            distance(p1.position.xyz, p3.position.xyz),
+            histogram_params.ranges.y,
+        );
+        record(
+            HISTOGRAM_SEPARATION,
+            distance(p2.position.xyz, p3.position.xyz),
+            histogram_params.ranges.y,
+        );
+
+        mass += p3.velocity.w + kinetic_energy(p3, frame_velocity);
+    }
+
+    record(HISTOGRAM_MASS, mass, histogram_params.ranges.z);
+}
+
+fn record_charge_mass(charge: f32, mass: f32) {
+    let max_mass = histogram_params.ranges.w;
+    if (max_mass <= 0.0) {
+        return;
+    }
+
+    let row = u32(clamp(round(charge * 3.0) + f32(CHARGE_BINS / 2u), 0.0, f32(CHARGE_BINS - 1u)));
+    let t = clamp(mass / max_mass, 0.0, 1.0);
+    let column = min(u32(t * f32(MASS_BINS)), MASS_BINS - 1u);
+    _ = atomicAdd(&bins[row * MASS_BINS + column], 1u);
+}
+
+// Treat stale/out-of-range hadron ids as free (hadron_id is 1-indexed)
+fn is_bound(p: Particle, num_hadrons: u32) -> bool {
+    let hadron_id = p.color_and_flags.z;
+    if (hadron_id == 0u || hadron_id > num_hadrons) {
+        return false;
+    }
+    return hadrons[hadron_id - 1u].indices_type.w != 0xFFFFFFFFu;
+}
+
+@compute @workgroup_size(256)
+fn charge_mass_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    let num_particles = arrayLength(&particles);
+    let num_hadrons = min(hadron_counter.counters.x, arrayLength(&hadrons));
+
+    // Free particles (bound quarks are counted through their hadron below)
+    if (index < num_particles) {
+        let p = particles[index];
+        if ((p.color_and_flags.y & FLAG_DORMANT) == 0u && !is_bound(p, num_hadrons)) {
+            record_charge_mass(p.data.x, p.velocity.w);
+        }
+    }
+
+    if (index >= num_hadrons) {
+        return;
+    }
+
+    let h = hadrons[index];
+    if (h.indices_type.w == 0xFFFFFFFFu) {
+        return;
+    }
+
+    let is_meson = h.indices_type.w == 0u;
+    if (h.indices_type.x >= num_particles || h.indices_type.y >= num_particles) {
+        return;
+    }
+    if (!is_meson && h.indices_type.z >= num_particles) {
+        return;
+    }
+
+    let p1 = particles[h.indices_type.x];
+    let p2 = particles[h.indices_type.y];
+    var charge = p1.data.x + p2.data.x;
+    var mass = p1.velocity.w + p2.velocity.w;
+    if (!is_meson) {
+        let p3 = particles[h.indices_type.z];
+        charge += p3.data.x;
+        mass += p3.velocity.w;
+    }
+
+    record_charge_mass(charge, mass);
+}
@@ -5,7 +5,15 @@ struct PhysicsParams {
     constants: vec4<f32>,    // x: G, y: K_electric, z: G_weak, w: weak_force_range
     strong_force: vec4<f32>, // x: strong_short_range, y: strong_confinement, z: strong_range, w: padding
     repulsion: vec4<f32>,    // x: core_repulsion, y: core_radius, z: softening, w: max_force
-    integration: vec4<f32>,  // x: dt, y: damping, z: padding, w: padding
+    integration: vec4<f32>,  // x: dt, y: damping, z: time/seed, w: nucleon_damping
+    nucleon: vec4<f32>,
+    electron: vec4<f32>,
+    hadron: vec4<f32>,
+    residual_strong: vec4<f32>,
+    string_breaking: vec4<f32>,
+    electron_levels: vec4<f32>,
+    coupling: array<vec4<f32>, 16>,
+    boundary: vec4<f32>,     // x: radius (<= 0 disables), y: reinject (0/1), z: temperature, w: padding
 }
 
 @group(0) @binding(2)
@@ -29,11 +37,97 @@ var<storage, read_write> particles: array<Particle>;
 @group(0) @binding(1)
 var<storage, read> forces: array<Force>;
 
+// Cumulative boundary crossings, indexed by particle type
+@group(0) @binding(3)
+var<storage, read_write> escape_counts: array<atomic<u32>, 4>;
+
+// Trail ring buffer: TRAIL_CAPACITY samples per particle (xyz = position, w = link to the
+// previous sample: 0 = empty, 1 = starts a new run, 2 = continues the run)
+const TRAIL_CAPACITY: u32 = 64u;
+// Jumps longer than this between two steps (re-injection, pool respawn) break the trail
+const TRAIL_BREAK_DISTANCE: f32 = 10.0;
+
+struct TrailParams {
+    length: u32,
+    head: u32,
+    enabled: u32,
+    _pad: u32,
+}
+
+@group(0) @binding(4)
+var<storage, read_write> trails: array<vec4<f32>>;
+
+@group(0) @binding(5)
+var<uniform> trail_params: TrailParams;
+
+fn record_trail(index: u32, position: vec3<f32>) {
+    let base = index * TRAIL_CAPACITY;
+    let length = trail_params.length;
+    let head = trail_params.head;
+    let previous = trails[base + (head + length - 1u) % length];
+    let continues = previous.w > 0.0 && distance(previous.xyz, position) < TRAIL_BREAK_DISTANCE;
+    trails[base + head] = vec4<f32>(position, select(1.0, 2.0, continues));
+}
+
 // Simple pseudo-random number generator
 fn rand(seed: vec2<f32>) -> f32 {
     return fract(sin(dot(seed, vec2<f32>(12.9898, 78.233))) * 43758.5453);
 }
 
+// Particle flags (color_and_flags.y, must match particle-physics)
+const FLAG_DORMANT: u32 = 1u;
+
+fn hash(x: u32) -> u32 {
+    var h = x;
+    h ^= h >> 16u;
+    h *= 0x7feb352du;
+    h ^= h >> 15u;
+    h *= 0x846ca68bu;
+    h ^= h >> 16u;
+    return h;
+}
+
+// Uniform in (0, 1]
+fn hash01(x: u32) -> f32 {
+    return (f32(hash(x) >> 8u) + 1.0) / 16777216.0;
+}
+
+// Standard normal sample (Box-Muller)
+fn gaussian(seed: u32) -> f32 {
+    let u1 = hash01(seed);
+    let u2 = hash01(seed ^ 0x68bc21ebu);
+    return sqrt(-2.0 * log(u1)) * cos(6.2831853 * u2);
+}
+
+// Count a particle that just crossed the boundary; optionally put it back on the boundary
+// sphere at a random point with an inward Maxwell-Boltzmann velocity (sigma = sqrt(T / m)).
+fn handle_escape(index: u32, particle: ptr<function, Particle>) {
+    let species = min(u32((*particle).position.w), 3u);
+    _ = atomicAdd(&escape_counts[species], 1u);
+
+    if (params.boundary.y < 0.5) {
+        return;
+    }
+
+    let seed = hash(index ^ bitcast<u32>(params.integration.z));
+    let dir = normalize(vec3<f32>(
+        gaussian(seed),
+        gaussian(seed + 1u),
+        gaussian(seed + 2u),
+    ) + vec3<f32>(1e-6, 0.0, 0.0));
+
+    let sigma = sqrt(max(params.boundary.z, 0.0) / max((*particle).velocity.w, 1e-6));
+    var v = sigma * vec3<f32>(gaussian(seed + 3u), gaussian(seed + 4u), gaussian(seed + 5u));
+    // Point the radial component inward so the particle re-enters the volume
+    let radial = dot(v, dir);
+    if (radial > 0.0) {
+        v -= 2.0 * radial * dir;
+    }
+
+    (*particle).position = vec4<f32>(dir * params.boundary.x * 0.99, (*particle).position.w);
+    (*particle).velocity = vec4<f32>(v, (*particle).velocity.w);
+}
+
 fn is_quark(particle_type_f: f32) -> bool {
     let particle_type = u32(particle_type_f);
     return particle_type == 0u || particle_type == 1u; // QuarkUp or QuarkDown
@@ -49,6 +143,11 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     }
 
     var particle = particles[index];
+
+    // Dormant pool slots stay parked until string breaking spawns into them
+    if ((particle.color_and_flags.y & FLAG_DORMANT) != 0u) {
+        return;
+    }
     let force = forces[index].force;
 
     // F = ma, so a = F/m (mass in velocity.w)
@@ -81,12 +180,23 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     let new_position = particle.position.xyz + damped_velocity * params.integration.x;
 
     // Update particle (preserve .w components)
+    let old_radius = length(particle.position.xyz);
     particle.position = vec4<f32>(new_position, particle.position.w);
     particle.velocity = vec4<f32>(damped_velocity, mass);
 
+    // Boundary crossing (outward only, so each escape is counted once)
+    let boundary_radius = params.boundary.x;
+    if (boundary_radius > 0.0 && old_radius <= boundary_radius && length(new_position) > boundary_radius) {
+        handle_escape(index, &particle);
+    }
+
     // Color charge is FIXED - it's a conserved quantum number like electric charge
     // Quarks don't randomly change color. They find each other via the strong force
     // and form color-neutral hadrons based on their fixed color charges.
 
+    if (trail_params.enabled != 0u) {
+        record_trail(index, particle.position.xyz);
+    }
+
     particles[index] = particle;
 }
@@ -36,7 +36,7 @@ struct PhysicsParams {
     strong_force: vec4<f32>,
     repulsion: vec4<f32>,
     integration: vec4<f32>,
-    nucleon: vec4<f32>, // x: binding_strength, y: binding_range, z: exclusion_strength, w: exclusion_radius
+    nucleon: vec4<f32>, // x: padding, y: binding_range, z: exclusion_strength, w: exclusion_radius
     electron: vec4<f32>,
     hadron: vec4<f32>,
 }
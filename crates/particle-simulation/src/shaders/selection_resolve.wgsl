@@ -2,7 +2,7 @@
 //
 // This is intended to be run after the GPU picking pass has produced a packed u32 ID.
 // The CPU writes that ID into `selection.id`, then dispatches this shader with 1 invocation.
-// The shader writes the selected entity center into `selection_target.target`.
+// The shader writes the selected entity center, bounding radius and velocity into `selection_target`.
 //
 // ID encoding convention (must match picking shader):
 // - 0                          => no selection
@@ -10,14 +10,19 @@
 // - 0x80000000 | (hadron_index + 1) => hadron selection
 // - 0x40000000 | (anchor_hadron_index + 1) => nucleus selection
 //
-// Output encoding:
-// - selection_target.target.xyz = selected world-space center
-// - selection_target.target.w   = kind (0.0 = none, 1.0 = particle, 2.0 = hadron, 3.0 = nucleus)
+// Output encoding (must match Rust `SelectionTarget`):
+// - selection_target.value.xyz  = selected world-space center
+// - selection_target.value.w    = kind (0.0 = none, 1.0 = particle, 2.0 = hadron, 3.0 = nucleus)
+// - selection_target.extent.xyz = velocity
+// - selection_target.extent.w   = bounding radius (particle size, hadron/nucleus shell radius)
 //
 // Notes:
 // - Particles are addressed directly by index.
 // - Hadrons resolve to the hadron center (`hadron.center.xyz`).
 // - We validate indices and invalid hadron slots (`type_id == 0xFFFFFFFFu`).
+//
+// `measure_main` resolves the measurement tool's IDs (`measure.ids`, 0 = unused) the same way
+// into `measure_targets`, one invocation per ID.
 
 struct Particle {
     position: vec4<f32>,        // xyz = position, w = particle_type (as f32)
@@ -53,6 +58,14 @@ struct Selection {
 
 struct SelectionTarget {
     value: vec4<f32>,
+    extent: vec4<f32>,
+}
+
+// Must match `MEASURE_CAPACITY` in `selection.rs`
+const MEASURE_CAPACITY: u32 = 3u;
+
+struct MeasureIds {
+    ids: vec4<u32>, // xyz = packed IDs, w = padding
 }
 
 @group(0) @binding(0)
@@ -75,17 +88,33 @@ const KIND_NUCLEUS: f32 = 3.0;
 @group(0) @binding(4)
 var<storage, read> nuclei: array<Nucleus>;
 
-fn write_none() {
-    selection_target.value = vec4<f32>(0.0, 0.0, 0.0, KIND_NONE);
+@group(0) @binding(5)
+var<uniform> measure: MeasureIds;
+
+@group(0) @binding(6)
+var<storage, read_write> measure_targets: array<SelectionTarget, MEASURE_CAPACITY>;
+
+fn none_target() -> SelectionTarget {
+    return SelectionTarget(vec4<f32>(0.0, 0.0, 0.0, KIND_NONE), vec4<f32>(0.0));
+}
+
+fn make_target(center: vec3<f32>, kind: f32, velocity: vec3<f32>, radius: f32) -> SelectionTarget {
+    return SelectionTarget(vec4<f32>(center, kind), vec4<f32>(velocity, radius));
 }
 
 @compute @workgroup_size(1)
 fn main() {
-    let raw_id = selection.id;
+    selection_target = resolve(selection.id);
+}
+
+@compute @workgroup_size(MEASURE_CAPACITY)
+fn measure_main(@builtin(local_invocation_index) index: u32) {
+    measure_targets[index] = resolve(measure.ids[index]);
+}
 
+fn resolve(raw_id: u32) -> SelectionTarget {
     if (raw_id == 0u) {
-        write_none();
-        return;
+        return none_target();
     }
 
     let is_hadron = (raw_id & 0x80000000u) != 0u;
@@ -99,8 +128,7 @@ fn main() {
     }
 
     if (idx_1 == 0u) {
-        write_none();
-        return;
+        return none_target();
     }
 
     let idx0 = idx_1 - 1u;
@@ -109,13 +137,11 @@ fn main() {
         // Particle selection
         let n = arrayLength(&particles);
         if (idx0 >= n) {
-            write_none();
-            return;
+            return none_target();
         }
 
         let p = particles[idx0];
-        selection_target.value = vec4<f32>(p.position.xyz, KIND_PARTICLE);
-        return;
+        return make_target(p.position.xyz, KIND_PARTICLE, p.velocity.xyz, p.data.y);
     }
 
     if (is_nucleus) {
@@ -135,30 +161,26 @@ fn main() {
                 }
 
                 if (nuc.hadron_indices[i] == anchor_hadron_index) {
-                    selection_target.value = vec4<f32>(nuc.center.xyz, KIND_NUCLEUS);
-                    return;
+                    return make_target(nuc.center.xyz, KIND_NUCLEUS, nuc.velocity.xyz, nuc.center.w);
                 }
             }
         }
 
-        write_none();
-        return;
+        return none_target();
     }
 
     // Hadron selection
     let h_n = arrayLength(&hadrons);
     if (idx0 >= h_n) {
-        write_none();
-        return;
+        return none_target();
     }
 
     let h = hadrons[idx0];
 
     // Invalid slot sentinel
     if (h.indices_type.w == 0xFFFFFFFFu) {
-        write_none();
-        return;
+        return none_target();
     }
 
-    selection_target.value = vec4<f32>(h.center.xyz, KIND_HADRON);
+    return make_target(h.center.xyz, KIND_HADRON, h.velocity.xyz, h.center.w);
 }
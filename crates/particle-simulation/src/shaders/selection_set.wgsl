@@ -0,0 +1,99 @@
+// Compute shader: expand a selection set (packed pick IDs) into a per-particle mask + stats.
+//
+// A particle is covered when its own ID is in the set, its hadron is, or its hadron belongs
+// to a nucleus whose anchor hadron is (see `selection_resolve.wgsl` for the ID encoding).
+// The renderer reads the mask to highlight the group.
+//
+// Stats layout (4x u32, must match Rust `SelectionSetStats`):
+// [0] particles, [1] quarks, [2] electrons, [3] net charge in e/3 (i32 bit pattern)
+
+const SELECTION_SET_CAPACITY: u32 = 64u;
+const FLAG_DORMANT: u32 = 1u;
+const TYPE_ELECTRON: u32 = 2u;
+const HADRON_BIT: u32 = 0x80000000u;
+const NUCLEUS_BIT: u32 = 0x40000000u;
+
+struct Particle {
+    position: vec4<f32>,        // xyz = position, w = particle_type
+    velocity: vec4<f32>,        // xyz = velocity, w = mass
+    data: vec4<f32>,            // x = charge, y = size, z = cohort tag, w = vacuum partner + 1
+    color_and_flags: vec4<u32>, // x = color_charge, y = flags, z = hadron_id, w = electron level
+}
+
+struct Hadron {
+    indices_type: vec4<u32>, // x=p1, y=p2, z=p3, w=type_id
+    center: vec4<f32>,       // xyz = center of mass, w = radius
+    velocity: vec4<f32>,     // xyz = velocity, w = nucleus_id (as f32, 0 = unbound)
+}
+
+struct SelectionSet {
+    count: vec4<u32>, // x = number of IDs
+    ids: array<vec4<u32>, 16>,
+}
+
+@group(0) @binding(0)
+var<storage, read> particles: array<Particle>;
+
+@group(0) @binding(1)
+var<storage, read> hadrons: array<Hadron>;
+
+@group(0) @binding(2)
+var<uniform> selection_set: SelectionSet;
+
+@group(0) @binding(3)
+var<storage, read_write> mask: array<u32>;
+
+@group(0) @binding(4)
+var<storage, read_write> stats: array<atomic<u32>, 4>;
+
+fn hadron_valid(h_idx: u32) -> bool {
+    return h_idx < arrayLength(&hadrons) && hadrons[h_idx].indices_type.w != 0xFFFFFFFFu;
+}
+
+fn covers(id: u32, index: u32, hadron_id: u32) -> bool {
+    if ((id & HADRON_BIT) != 0u) {
+        return hadron_id != 0u && (id & ~HADRON_BIT) == hadron_id;
+    }
+    if ((id & NUCLEUS_BIT) != 0u) {
+        let anchor = (id & ~NUCLEUS_BIT) - 1u;
+        if (hadron_id == 0u || !hadron_valid(anchor) || !hadron_valid(hadron_id - 1u)) {
+            return false;
+        }
+        let nucleus_id = u32(hadrons[hadron_id - 1u].velocity.w);
+        return nucleus_id != 0u && u32(hadrons[anchor].velocity.w) == nucleus_id;
+    }
+    return id == index + 1u;
+}
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&particles)) {
+        return;
+    }
+
+    let p = particles[index];
+    var selected = false;
+    if ((p.color_and_flags.y & FLAG_DORMANT) == 0u) {
+        let count = min(selection_set.count.x, SELECTION_SET_CAPACITY);
+        for (var i = 0u; i < count; i++) {
+            if (covers(selection_set.ids[i / 4u][i % 4u], index, p.color_and_flags.z)) {
+                selected = true;
+                break;
+            }
+        }
+    }
+
+    mask[index] = select(0u, 1u, selected);
+    if (!selected) {
+        return;
+    }
+
+    atomicAdd(&stats[0], 1u);
+    if (u32(p.position.w) <= 1u) {
+        atomicAdd(&stats[1], 1u);
+    } else if (u32(p.position.w) == TYPE_ELECTRON) {
+        atomicAdd(&stats[2], 1u);
+    }
+    atomicAdd(&stats[3], bitcast<u32>(i32(round(p.data.x * 3.0))));
+}
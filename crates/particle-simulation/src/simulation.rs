@@ -5,11 +5,24 @@
 //! never find reusable slots and may treat untouched slots as valid hadrons. We initialize all hadron slots as
 //! invalid on startup to make slot reuse reliable.
 
-use crate::PhysicsParams;
+use crate::{
+    CoarseParams, ForceFieldParams, HistogramRanges, Photon, PhysicsParams, SelectionSet,
+    Statistics, StatisticsStaging, TagParams, TrailParams, CHARGE_MASS_BUFFER_SIZE, CLUSTER_ROUNDS,
+    CLUSTER_STATS_SIZE, COARSE_LINK_SIZE, COARSE_STATS_SIZE, COHORT_STATS_SIZE, ESCAPE_COUNTS_SIZE,
+    FORCE_FIELD_GRID, FORCE_FIELD_SAMPLE_SIZE, HISTOGRAM_BUFFER_SIZE, MEASURE_CAPACITY,
+    MEASURE_TARGETS_SIZE, PHOTON_CAPACITY, SELECTION_SET_STATS_SIZE, SELECTION_TARGET_SIZE,
+    SPECTRUM_BUFFER_SIZE, TRAIL_CAPACITY, TRAIL_SAMPLE_SIZE,
+};
 use bytemuck::{Pod, Zeroable};
 use particle_physics::{Hadron, Nucleus, Particle, MAX_NUCLEONS};
 use wgpu::util::DeviceExt;
 
+/// Size in bytes of one hadron detection proposal/claim slot (matches WGSL `HadronCandidate`)
+const HADRON_CANDIDATE_SIZE: u64 = 32;
+
+/// Size in bytes of one packed force source (matches WGSL `ForceSource`)
+const FORCE_SOURCE_SIZE: u64 = 32;
+
 /// Force accumulator structure (matches WGSL)
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
@@ -18,6 +31,39 @@ struct Force {
     _padding: f32,
 }
 
+/// Copies of the buffers the renderers read, taken at the end of a step.
+///
+/// With two of them the renderer reads the snapshot of the frame it is drawing while the next
+/// step writes the live buffers and the other snapshot, so the two submissions share no writes
+/// and the backend is free to overlap them.
+struct RenderSnapshot {
+    particles: wgpu::Buffer,
+    hadrons: wgpu::Buffer,
+    hadron_counter: wgpu::Buffer,
+    colors: wgpu::Buffer,
+    selection_mask: wgpu::Buffer,
+}
+
+impl RenderSnapshot {
+    fn new(device: &wgpu::Device, sources: [&wgpu::Buffer; 5], label: &str) -> Self {
+        let copy_of = |source: &wgpu::Buffer, name: &str| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(&format!("{label} {name}")),
+                size: source.size(),
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            })
+        };
+        Self {
+            particles: copy_of(sources[0], "Particles"),
+            hadrons: copy_of(sources[1], "Hadrons"),
+            hadron_counter: copy_of(sources[2], "Hadron Counter"),
+            colors: copy_of(sources[3], "Colors"),
+            selection_mask: copy_of(sources[4], "Selection Mask"),
+        }
+    }
+}
+
 /// GPU-based particle physics simulation
 pub struct ParticleSimulation {
     device: wgpu::Device,
@@ -25,24 +71,107 @@ pub struct ParticleSimulation {
 
     // Buffers
     particle_buffer: wgpu::Buffer,
-    _force_buffer: wgpu::Buffer,
+    force_buffer: wgpu::Buffer,
     hadron_buffer: wgpu::Buffer,
     hadron_count_buffer: wgpu::Buffer,
     nucleus_buffer: wgpu::Buffer,
     nucleus_count_buffer: wgpu::Buffer,
     locks_buffer: wgpu::Buffer,
+    hadron_candidates_buffer: wgpu::Buffer,
+    pair_pool_buffer: wgpu::Buffer,
+    escape_counts_buffer: wgpu::Buffer,
     params_buffer: wgpu::Buffer,
 
+    // Trails (ring of recent positions per particle, written by the integration pass)
+    trail_buffer: wgpu::Buffer,
+    trail_params_buffer: wgpu::Buffer,
+    trail_params: TrailParams,
+
     // Selection (GPU resolve)
     selection_id_buffer: wgpu::Buffer,
     selection_target_buffer: wgpu::Buffer,
     selection_pipeline: wgpu::ComputePipeline,
     selection_bind_group: wgpu::BindGroup,
+    // Measurement tool: up to `MEASURE_CAPACITY` IDs resolved like the selection
+    measure_id_buffer: wgpu::Buffer,
+    measure_target_buffer: wgpu::Buffer,
+    measure_pipeline: wgpu::ComputePipeline,
+
+    // Selection set (multi-entity selection -> per-particle mask + stats)
+    selection_set_buffer: wgpu::Buffer,
+    selection_mask_buffer: wgpu::Buffer,
+    selection_set_stats_buffer: wgpu::Buffer,
+    selection_set_pipeline: wgpu::ComputePipeline,
+    selection_set_bind_group: wgpu::BindGroup,
+
+    // Diagnostics histograms
+    histogram_buffer: wgpu::Buffer,
+    histogram_ranges_buffer: wgpu::Buffer,
+    histogram_pipeline: wgpu::ComputePipeline,
+    histogram_bind_group: wgpu::BindGroup,
+
+    // Charge/mass map ("mass spectrometer"), shares the histogram shader + layout
+    charge_mass_buffer: wgpu::Buffer,
+    charge_mass_pipeline: wgpu::ComputePipeline,
+    charge_mass_bind_group: wgpu::BindGroup,
+
+    // Electron energy levels + emitted photons
+    photon_buffer: wgpu::Buffer,
+    photon_counter_buffer: wgpu::Buffer,
+    spectrum_buffer: wgpu::Buffer,
+    electron_levels_pipeline: wgpu::ComputePipeline,
+    electron_levels_bind_group: wgpu::BindGroup,
+
+    // Particle tagging + cohort tracking
+    tag_params_buffer: wgpu::Buffer,
+    cohort_stats_buffer: wgpu::Buffer,
+    tag_pipeline: wgpu::ComputePipeline,
+    cohort_stats_pipeline: wgpu::ComputePipeline,
+    cohort_bind_group: wgpu::BindGroup,
+
+    // Color flow (continuous quark color vectors rotated by gluon exchange)
+    color_buffer: wgpu::Buffer,
+    color_flow_pipeline: wgpu::ComputePipeline,
+    color_flow_bind_group: wgpu::BindGroup,
+
+    // Coarse-graining LOD (super-particles for distant clusters)
+    coarse_links_buffer: wgpu::Buffer,
+    coarse_params_buffer: wgpu::Buffer,
+    coarse_stats_buffer: wgpu::Buffer,
+    coarse_release_pipeline: wgpu::ComputePipeline,
+    coarse_restore_pipeline: wgpu::ComputePipeline,
+    coarse_merge_pipeline: wgpu::ComputePipeline,
+    coarse_stats_pipeline: wgpu::ComputePipeline,
+    coarse_bind_group: wgpu::BindGroup,
+
+    // Cluster identification (connected components of the nucleon bond graph)
+    cluster_stats_buffer: wgpu::Buffer,
+    cluster_init_pipeline: wgpu::ComputePipeline,
+    cluster_propagate_pipeline: wgpu::ComputePipeline,
+    cluster_jump_pipeline: wgpu::ComputePipeline,
+    cluster_count_pipeline: wgpu::ComputePipeline,
+    cluster_stats_pipeline: wgpu::ComputePipeline,
+    cluster_bind_group: wgpu::BindGroup,
+
+    // Force field slice (debug visualization, run on demand)
+    force_field_params_buffer: wgpu::Buffer,
+    force_field_buffer: wgpu::Buffer,
+    force_field_max_buffer: wgpu::Buffer,
+    force_field_pipeline: wgpu::ComputePipeline,
+    force_field_bind_group: wgpu::BindGroup,
 
     // Compute pipelines
     force_pipeline: wgpu::ComputePipeline,
+    force_packed_pipeline: wgpu::ComputePipeline,
+    pack_sources_pipeline: wgpu::ComputePipeline,
     integrate_pipeline: wgpu::ComputePipeline,
     hadron_validation_pipeline: wgpu::ComputePipeline,
+    baryon_decay_pipeline: wgpu::ComputePipeline,
+    meson_decay_pipeline: wgpu::ComputePipeline,
+    vacuum_expire_pipeline: wgpu::ComputePipeline,
+    vacuum_spawn_pipeline: wgpu::ComputePipeline,
+    hadron_candidate_pipeline: wgpu::ComputePipeline,
+    hadron_claim_pipeline: wgpu::ComputePipeline,
     hadron_pipeline: wgpu::ComputePipeline,
     nucleus_pipeline: wgpu::ComputePipeline,
     nucleus_reset_pipeline: wgpu::ComputePipeline,
@@ -53,6 +182,19 @@ pub struct ParticleSimulation {
     hadron_bind_group: wgpu::BindGroup,
     nucleus_bind_group: wgpu::BindGroup,
 
+    // Frame overlap: double-buffered render snapshots + the last two step submissions
+    frame_overlap: bool,
+    render_snapshots: [RenderSnapshot; 2],
+    snapshot_index: usize,
+    snapshot_ready: bool,
+    step_submissions: [Option<wgpu::SubmissionIndex>; 2],
+
+    // Statistics readback
+    statistics_staging: StatisticsStaging,
+
+    // Force pass reads f16-packed sources instead of full particles
+    packed_force_sources: bool,
+
     particle_count: u32,
     nucleus_capacity: u32,
 }
@@ -85,6 +227,14 @@ impl ParticleSimulation {
             usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
         });
 
+        // Packed copy of the fields the N-body loop reads (see `set_packed_force_sources`)
+        let force_source_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Force Source Buffer"),
+            size: FORCE_SOURCE_SIZE * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+
         // Create hadron buffer.
         //
         // Important: The WGSL side treats `indices_type.w == 0xFFFFFFFFu` as "invalid hadron slot".
@@ -95,21 +245,12 @@ impl ParticleSimulation {
         let hadron_size = std::mem::size_of::<Hadron>() as u64;
         let _hadron_buffer_size = hadron_size * particles.len() as u64;
 
-        let invalid_hadrons: Vec<Hadron> = (0..particles.len())
-            .map(|_| Hadron {
-                p1: 0,
-                p2: 0,
-                p3: 0,
-                type_id: 0xFFFF_FFFF,
-                center: [0.0; 4],
-                velocity: [0.0; 4],
-            })
-            .collect();
-
         let hadron_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Hadron Buffer"),
-            contents: bytemuck::cast_slice(&invalid_hadrons),
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            contents: bytemuck::cast_slice(&invalid_hadrons(particles.len())),
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
         });
 
         // Create hadron counter buffer.
@@ -136,22 +277,12 @@ impl ParticleSimulation {
         // Nuclei can contain up to MAX_NUCLEONS hadrons. We'll allocate space for up to
         // particles.len() / 4 potential nuclei (rough estimate).
         let max_nuclei = particles.len() / 4;
-        let invalid_nuclei: Vec<Nucleus> = (0..max_nuclei)
-            .map(|_| Nucleus {
-                hadron_indices: [0xFFFF_FFFF; MAX_NUCLEONS],
-                nucleon_count: 0,
-                proton_count: 0,
-                neutron_count: 0,
-                type_id: 0xFFFF_FFFF,
-                center: [0.0; 4],
-                velocity: [0.0; 4],
-            })
-            .collect();
-
         let nucleus_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Nucleus Buffer"),
-            contents: bytemuck::cast_slice(&invalid_nuclei),
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            contents: bytemuck::cast_slice(&invalid_nuclei(max_nuclei)),
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
         });
 
         // Create nucleus counter buffer (single u32 + padding)
@@ -173,6 +304,43 @@ impl ParticleSimulation {
             mapped_at_creation: false,
         });
 
+        // Hadron detection proposals (one slot per particle, cleared before every detection pass)
+        let hadron_candidates_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Hadron Candidates Buffer"),
+            size: HADRON_CANDIDATE_SIZE * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Create pair pool buffer (dormant slots available to string breaking).
+        //
+        // Layout: [0] available count (atomic), [1..4) padding, [4..) dormant particle indices.
+        // String breaking pops two indices off the end per spawned quark/antiquark pair.
+        //
+        // The buffer is sized for every particle slot so `reset_particles` can refill it with a
+        // different dormant set.
+        let mut pair_pool = pair_pool(particles);
+        log::info!("Pair pool: {} dormant slots", pair_pool[0]);
+        pair_pool.resize(4 + particles.len().max(1), 0);
+
+        let pair_pool_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Pair Pool Buffer"),
+            contents: bytemuck::cast_slice(&pair_pool),
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+        });
+
+        // Escape counter: boundary crossings per species (up, down, electron, gluon)
+        let escape_counts_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Escape Counts Buffer"),
+            size: ESCAPE_COUNTS_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
         // Create params buffer
         let params = PhysicsParams::default();
         let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -190,17 +358,218 @@ impl ParticleSimulation {
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        // selection_target_buffer layout: vec4<f32> (16 bytes)
-        // xyz = selected center, w = kind (0 none, 1 particle, 2 hadron)
+        // selection_target_buffer layout: 2x vec4<f32> (see `SelectionTarget`)
+        // [0] xyz = selected center, w = kind (0 none, 1 particle, 2 hadron, 3 nucleus)
+        // [1] xyz = velocity, w = bounding radius
         let selection_target_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Selection Target Buffer"),
-            size: 16,
+            size: SELECTION_TARGET_SIZE,
             usage: wgpu::BufferUsages::STORAGE
                 | wgpu::BufferUsages::COPY_SRC
                 | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
 
+        // Measurement IDs (uniform vec4<u32>, 0 = unused) and their resolved targets
+        let measure_id_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Measure ID Buffer"),
+            contents: bytemuck::cast_slice(&[0u32; 4]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let measure_target_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Measure Target Buffer"),
+            size: MEASURE_TARGETS_SIZE,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Diagnostics histogram bins (atomic u32 counters, cleared before every histogram pass)
+        let histogram_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Histogram Buffer"),
+            size: HISTOGRAM_BUFFER_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let charge_mass_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Charge Mass Buffer"),
+            size: CHARGE_MASS_BUFFER_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let histogram_ranges_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Histogram Ranges Buffer"),
+                contents: bytemuck::cast_slice(&[HistogramRanges::default()]),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+
+        // Photon ring buffer (zeroed slots have energy 0 and are skipped by the renderer)
+        let photon_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Photon Buffer"),
+            contents: bytemuck::cast_slice(&[Photon::zeroed(); PHOTON_CAPACITY]),
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        });
+
+        // Photon counter: [0] total emitted (ring buffer write cursor), [1..4) padding
+        let photon_counter_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Photon Counter Buffer"),
+            size: 16,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Cumulative emission spectrum (atomic u32 bins)
+        let spectrum_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Spectrum Buffer"),
+            size: SPECTRUM_BUFFER_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Tag pass parameters (sphere + tag); also selects the cohort counted by the stats pass
+        let tag_params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Tag Params Buffer"),
+            contents: bytemuck::cast_slice(&[TagParams::default()]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Cohort stats (4x atomic u32, cleared before every stats pass)
+        let cohort_stats_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cohort Stats Buffer"),
+            size: COHORT_STATS_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Trail ring buffer (TRAIL_CAPACITY samples per particle) + recording state
+        let trail_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Trail Buffer"),
+            size: TRAIL_SAMPLE_SIZE * TRAIL_CAPACITY as u64 * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let trail_params = TrailParams {
+            length: 32,
+            ..Default::default()
+        };
+        let trail_params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Trail Params Buffer"),
+            contents: bytemuck::cast_slice(&[trail_params]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Continuous color vectors (xyz = weights, w = sync marker). Zeroed slots are seeded from
+        // the discrete color by the first color flow pass.
+        let color_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Color Buffer"),
+            size: 16 * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Coarse-graining membership links (one per particle, zeroed = plain particle)
+        let coarse_links_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Coarse Links Buffer"),
+            size: COARSE_LINK_SIZE * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let coarse_params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Coarse Params Buffer"),
+            contents: bytemuck::cast_slice(&[CoarseParams::default()]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Coarse-graining stats (4x atomic u32, cleared before every stats pass)
+        let coarse_stats_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Coarse Stats Buffer"),
+            size: COARSE_STATS_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Selection set (uniform IDs), per-particle mask (1 = covered) and stats
+        let selection_set_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Selection Set Buffer"),
+            contents: bytemuck::cast_slice(&[SelectionSet::default()]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let selection_mask_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Selection Mask Buffer"),
+            size: 4 * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let selection_set_stats_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Selection Set Stats Buffer"),
+            size: SELECTION_SET_STATS_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Cluster labels + per-root sizes (one slot per hadron slot)
+        let cluster_labels_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cluster Labels Buffer"),
+            size: 4 * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        let cluster_sizes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cluster Sizes Buffer"),
+            size: 4 * particles.len().max(1) as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+
+        // Cluster stats (atomic u32 header + size bins, cleared before every analysis)
+        let cluster_stats_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cluster Stats Buffer"),
+            size: CLUSTER_STATS_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        // Force field slice: probe placement (uniform), grid samples and max magnitude
+        let force_field_params_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Force Field Params Buffer"),
+                contents: bytemuck::cast_slice(&[ForceFieldParams::default()]),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+        let force_field_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Force Field Buffer"),
+            size: FORCE_FIELD_SAMPLE_SIZE * (FORCE_FIELD_GRID * FORCE_FIELD_GRID) as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        let force_field_max_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Force Field Max Buffer"),
+            size: 4,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         log::info!("Buffers created");
 
         // Load compute shaders
@@ -241,6 +610,46 @@ impl ParticleSimulation {
             source: wgpu::ShaderSource::Wgsl(include_str!("shaders/selection_resolve.wgsl").into()),
         });
 
+        let histogram_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Histogram Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/histogram.wgsl").into()),
+        });
+
+        let cohort_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Cohort Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/cohort.wgsl").into()),
+        });
+
+        let electron_levels_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Electron Levels Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/electron_levels.wgsl").into()),
+        });
+
+        let color_flow_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Color Flow Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/color_flow.wgsl").into()),
+        });
+
+        let coarse_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Coarse Graining Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/coarse_grain.wgsl").into()),
+        });
+
+        let selection_set_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Selection Set Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/selection_set.wgsl").into()),
+        });
+
+        let cluster_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Cluster Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/clusters.wgsl").into()),
+        });
+
+        let force_field_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Force Field Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/force_field.wgsl").into()),
+        });
+
         log::info!("Shaders loaded");
 
         // Create bind group layout for force computation
@@ -300,6 +709,17 @@ impl ParticleSimulation {
                         },
                         count: None,
                     },
+                    // Packed force sources (written by `pack_main`, read by the N-body loop)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
                 ],
             });
 
@@ -338,6 +758,38 @@ impl ParticleSimulation {
                         },
                         count: None,
                     },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Trails (storage, read_write)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Trail params (uniform)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
                 ],
             });
 
@@ -347,6 +799,8 @@ impl ParticleSimulation {
         // 2: hadrons (storage, read)
         // 3: selection target (storage, write)
         // 4: nuclei (storage, read)
+        // 5: measurement ids (uniform)
+        // 6: measurement targets (storage, write)
         let selection_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("Selection Bind Group Layout"),
@@ -401,6 +855,26 @@ impl ParticleSimulation {
                         },
                         count: None,
                     },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 6,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
                 ],
             });
 
@@ -459,6 +933,28 @@ impl ParticleSimulation {
                         },
                         count: None,
                     },
+                    // Pair pool (validation only: string breaking spawns)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Hadron candidates (detection only: proposals + claims)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 6,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
                 ],
             });
 
@@ -530,26 +1026,584 @@ impl ParticleSimulation {
                 ],
             });
 
-        log::info!("Bind group layouts created");
-
-        // Create compute pipelines
-        log::info!("Creating force pipeline layout...");
-        let force_pipeline_layout =
-            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-                label: Some("Force Pipeline Layout"),
-                bind_group_layouts: &[&force_bind_group_layout],
-                immediate_size: 0,
-            });
-
-        log::info!("Creating force pipeline...");
-        let force_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
-            label: Some("Force Pipeline"),
-            layout: Some(&force_pipeline_layout),
-            module: &force_shader,
+        // Bind group layout for diagnostics histograms:
+        // 0: particles (storage, read)
+        // 1: hadrons (storage, read)
+        // 2: hadron counter (storage, read)
+        // 3: histogram bins (storage, atomic write)
+        // 4: histogram ranges (uniform)
+        let histogram_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Histogram Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        // Bind group layout for electron levels / photon emission:
+        // 0: particles (storage, read_write: level in color_and_flags.w)
+        // 1: nuclei (storage, read)
+        // 2: nucleus counter (storage, read)
+        // 3: photons (storage, read_write)
+        // 4: photon counter (storage, atomic)
+        // 5: spectrum bins (storage, atomic)
+        // 6: params (uniform)
+        let electron_levels_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Electron Levels Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 6,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        // Bind group layout for tagging / cohort stats:
+        // 0: particles (storage, read_write: tag in data.z)
+        // 1: hadrons (storage, read)
+        // 2: hadron counter (storage, read)
+        // 3: tag params (uniform)
+        // 4: cohort stats (storage, atomic)
+        let cohort_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Cohort Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        // Color flow bind group layout:
+        // 0: particles (storage, read_write: discrete color follows the vector)
+        // 1: color vectors (storage, read_write)
+        // 2: hadrons (storage, read)
+        // 3: hadron counter (storage, read)
+        // 4: params (uniform)
+        let color_flow_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Color Flow Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        // Selection set bind group layout:
+        // 0: particles (storage, read)
+        // 1: hadrons (storage, read)
+        // 2: selection set (uniform)
+        // 3: mask (storage, read_write)
+        // 4: stats (storage, read_write)
+        let selection_set_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Selection Set Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        // Cluster bind group layout:
+        // 0: hadrons (storage, read)
+        // 1: hadron counter (storage, read)
+        // 2: params (uniform)
+        // 3: labels (storage, read_write)
+        // 4: sizes (storage, read_write)
+        // 5: stats (storage, read_write)
+        let cluster_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Cluster Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        // Force field bind group layout:
+        // 0: particles (storage, read)
+        // 1: params (uniform)
+        // 2: field params (uniform)
+        // 3: samples (storage, read_write)
+        // 4: max magnitude (storage, atomic)
+        let force_field_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Force Field Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        // Coarse-graining bind group layout:
+        // 0: particles (storage, read_write)
+        // 1: membership links (storage, read_write)
+        // 2: coarse params (uniform)
+        // 3: locks (storage, atomic)
+        // 4: coarse stats (storage, atomic)
+        let coarse_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Coarse Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        log::info!("Bind group layouts created");
+
+        // Create compute pipelines
+        log::info!("Creating force pipeline layout...");
+        let force_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Force Pipeline Layout"),
+                bind_group_layouts: &[&force_bind_group_layout],
+                immediate_size: 0,
+            });
+
+        log::info!("Creating force pipeline...");
+        let force_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Force Pipeline"),
+            layout: Some(&force_pipeline_layout),
+            module: &force_shader,
             entry_point: Some("main"),
             compilation_options: Default::default(),
             cache: None,
         });
+        let force_packed_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Force Pipeline (Packed Sources)"),
+                layout: Some(&force_pipeline_layout),
+                module: &force_shader,
+                entry_point: Some("main"),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &[("PACKED_SOURCES", 1.0)],
+                    ..Default::default()
+                },
+                cache: None,
+            });
+        let pack_sources_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Pack Force Sources Pipeline"),
+                layout: Some(&force_pipeline_layout),
+                module: &force_shader,
+                entry_point: Some("pack_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
 
         log::debug!("Creating selection pipeline layout...");
         let selection_pipeline_layout =
@@ -568,6 +1622,14 @@ impl ParticleSimulation {
             compilation_options: Default::default(),
             cache: None,
         });
+        let measure_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Measure Pipeline"),
+            layout: Some(&selection_pipeline_layout),
+            module: &selection_shader,
+            entry_point: Some("measure_main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
 
         log::info!("Creating integrate pipeline layout...");
         let integrate_pipeline_layout =
@@ -606,7 +1668,63 @@ impl ParticleSimulation {
                 cache: None,
             });
 
-        log::info!("Creating hadron pipeline...");
+        log::info!("Creating hadron decay pipelines...");
+        let baryon_decay_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Baryon Decay Pipeline"),
+                layout: Some(&hadron_pipeline_layout),
+                module: &hadron_validation_shader,
+                entry_point: Some("baryon_decay_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let meson_decay_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Meson Decay Pipeline"),
+                layout: Some(&hadron_pipeline_layout),
+                module: &hadron_validation_shader,
+                entry_point: Some("meson_decay_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let vacuum_expire_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Vacuum Expire Pipeline"),
+                layout: Some(&hadron_pipeline_layout),
+                module: &hadron_validation_shader,
+                entry_point: Some("vacuum_expire_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let vacuum_spawn_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Vacuum Spawn Pipeline"),
+                layout: Some(&hadron_pipeline_layout),
+                module: &hadron_validation_shader,
+                entry_point: Some("vacuum_spawn_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::info!("Creating hadron detection pipelines...");
+        let hadron_candidate_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Hadron Candidate Pipeline"),
+                layout: Some(&hadron_pipeline_layout),
+                module: &hadron_shader,
+                entry_point: Some("candidate_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let hadron_claim_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Hadron Claim Pipeline"),
+                layout: Some(&hadron_pipeline_layout),
+                module: &hadron_shader,
+                entry_point: Some("claim_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
         let hadron_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
             label: Some("Hadron Pipeline"),
             layout: Some(&hadron_pipeline_layout),
@@ -634,13 +1752,238 @@ impl ParticleSimulation {
             cache: None,
         });
 
-        log::info!("Creating nucleus reset pipeline...");
-        let nucleus_reset_pipeline =
+        log::info!("Creating nucleus reset pipeline...");
+        let nucleus_reset_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Nucleus Reset Pipeline"),
+                layout: Some(&nucleus_pipeline_layout),
+                module: &nucleus_reset_shader,
+                entry_point: Some("reset_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating histogram pipeline layout...");
+        let histogram_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Histogram Pipeline Layout"),
+                bind_group_layouts: &[&histogram_bind_group_layout],
+                immediate_size: 0,
+            });
+
+        log::debug!("Creating histogram pipeline...");
+        let histogram_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Histogram Pipeline"),
+            layout: Some(&histogram_pipeline_layout),
+            module: &histogram_shader,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        log::debug!("Creating charge/mass pipeline...");
+        let charge_mass_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Charge Mass Pipeline"),
+                layout: Some(&histogram_pipeline_layout),
+                module: &histogram_shader,
+                entry_point: Some("charge_mass_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating electron levels pipeline layout...");
+        let electron_levels_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Electron Levels Pipeline Layout"),
+                bind_group_layouts: &[&electron_levels_bind_group_layout],
+                immediate_size: 0,
+            });
+
+        log::debug!("Creating electron levels pipeline...");
+        let electron_levels_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Electron Levels Pipeline"),
+                layout: Some(&electron_levels_pipeline_layout),
+                module: &electron_levels_shader,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating cohort pipeline layout...");
+        let cohort_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Cohort Pipeline Layout"),
+                bind_group_layouts: &[&cohort_bind_group_layout],
+                immediate_size: 0,
+            });
+
+        log::debug!("Creating tag pipeline...");
+        let tag_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Tag Pipeline"),
+            layout: Some(&cohort_pipeline_layout),
+            module: &cohort_shader,
+            entry_point: Some("tag_main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        log::debug!("Creating cohort stats pipeline...");
+        let cohort_stats_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Cohort Stats Pipeline"),
+                layout: Some(&cohort_pipeline_layout),
+                module: &cohort_shader,
+                entry_point: Some("stats_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating color flow pipeline...");
+        let color_flow_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Color Flow Pipeline Layout"),
+                bind_group_layouts: &[&color_flow_bind_group_layout],
+                immediate_size: 0,
+            });
+        let color_flow_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Color Flow Pipeline"),
+                layout: Some(&color_flow_pipeline_layout),
+                module: &color_flow_shader,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating coarse-graining pipelines...");
+        let coarse_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Coarse Pipeline Layout"),
+                bind_group_layouts: &[&coarse_bind_group_layout],
+                immediate_size: 0,
+            });
+        let coarse_release_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Coarse Release Pipeline"),
+                layout: Some(&coarse_pipeline_layout),
+                module: &coarse_shader,
+                entry_point: Some("release_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let coarse_restore_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Coarse Restore Pipeline"),
+                layout: Some(&coarse_pipeline_layout),
+                module: &coarse_shader,
+                entry_point: Some("restore_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let coarse_merge_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Coarse Merge Pipeline"),
+                layout: Some(&coarse_pipeline_layout),
+                module: &coarse_shader,
+                entry_point: Some("merge_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let coarse_stats_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Coarse Stats Pipeline"),
+                layout: Some(&coarse_pipeline_layout),
+                module: &coarse_shader,
+                entry_point: Some("stats_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating selection set pipeline...");
+        let selection_set_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Selection Set Pipeline Layout"),
+                bind_group_layouts: &[&selection_set_bind_group_layout],
+                immediate_size: 0,
+            });
+        let selection_set_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Selection Set Pipeline"),
+                layout: Some(&selection_set_pipeline_layout),
+                module: &selection_set_shader,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating cluster pipelines...");
+        let cluster_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Cluster Pipeline Layout"),
+                bind_group_layouts: &[&cluster_bind_group_layout],
+                immediate_size: 0,
+            });
+        let cluster_init_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Cluster Init Pipeline"),
+                layout: Some(&cluster_pipeline_layout),
+                module: &cluster_shader,
+                entry_point: Some("init_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let cluster_propagate_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Cluster Propagate Pipeline"),
+                layout: Some(&cluster_pipeline_layout),
+                module: &cluster_shader,
+                entry_point: Some("propagate_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let cluster_jump_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Cluster Jump Pipeline"),
+                layout: Some(&cluster_pipeline_layout),
+                module: &cluster_shader,
+                entry_point: Some("jump_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let cluster_count_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Cluster Count Pipeline"),
+                layout: Some(&cluster_pipeline_layout),
+                module: &cluster_shader,
+                entry_point: Some("count_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let cluster_stats_pipeline =
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Cluster Stats Pipeline"),
+                layout: Some(&cluster_pipeline_layout),
+                module: &cluster_shader,
+                entry_point: Some("stats_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        log::debug!("Creating force field pipeline...");
+        let force_field_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Force Field Pipeline Layout"),
+                bind_group_layouts: &[&force_field_bind_group_layout],
+                immediate_size: 0,
+            });
+        let force_field_pipeline =
             device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
-                label: Some("Nucleus Reset Pipeline"),
-                layout: Some(&nucleus_pipeline_layout),
-                module: &nucleus_reset_shader,
-                entry_point: Some("reset_main"),
+                label: Some("Force Field Pipeline"),
+                layout: Some(&force_field_pipeline_layout),
+                module: &force_field_shader,
+                entry_point: Some("main"),
                 compilation_options: Default::default(),
                 cache: None,
             });
@@ -672,6 +2015,10 @@ impl ParticleSimulation {
                     binding: 4,
                     resource: hadron_count_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: force_source_buffer.as_entire_binding(),
+                },
             ],
         });
 
@@ -699,6 +2046,14 @@ impl ParticleSimulation {
                     binding: 4,
                     resource: nucleus_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: measure_id_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: measure_target_buffer.as_entire_binding(),
+                },
             ],
         });
 
@@ -718,12 +2073,291 @@ impl ParticleSimulation {
                     binding: 2,
                     resource: params_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: escape_counts_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: trail_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: trail_params_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let hadron_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Hadron Bind Group"),
+            layout: &hadron_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: hadron_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: locks_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: pair_pool_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: hadron_candidates_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let nucleus_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Nucleus Bind Group"),
+            layout: &nucleus_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: nucleus_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: nucleus_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: locks_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: hadron_count_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let histogram_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Histogram Bind Group"),
+            layout: &histogram_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: hadron_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: histogram_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: histogram_ranges_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let charge_mass_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Charge Mass Bind Group"),
+            layout: &histogram_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: hadron_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: charge_mass_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: histogram_ranges_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let electron_levels_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Electron Levels Bind Group"),
+            layout: &electron_levels_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: nucleus_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: nucleus_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: photon_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: photon_counter_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: spectrum_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: params_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let cohort_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Cohort Bind Group"),
+            layout: &cohort_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: hadron_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: tag_params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: cohort_stats_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let color_flow_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Color Flow Bind Group"),
+            layout: &color_flow_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: color_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: hadron_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: params_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let selection_set_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Selection Set Bind Group"),
+            layout: &selection_set_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: particle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: selection_set_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: selection_mask_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: selection_set_stats_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let cluster_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Cluster Bind Group"),
+            layout: &cluster_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: hadron_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: hadron_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: cluster_labels_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: cluster_sizes_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: cluster_stats_buffer.as_entire_binding(),
+                },
             ],
         });
 
-        let hadron_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Hadron Bind Group"),
-            layout: &hadron_bind_group_layout,
+        let force_field_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Force Field Bind Group"),
+            layout: &force_field_bind_group_layout,
             entries: &[
                 wgpu::BindGroupEntry {
                     binding: 0,
@@ -731,38 +2365,38 @@ impl ParticleSimulation {
                 },
                 wgpu::BindGroupEntry {
                     binding: 1,
-                    resource: hadron_buffer.as_entire_binding(),
+                    resource: params_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 2,
-                    resource: hadron_count_buffer.as_entire_binding(),
+                    resource: force_field_params_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 3,
-                    resource: locks_buffer.as_entire_binding(),
+                    resource: force_field_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 4,
-                    resource: params_buffer.as_entire_binding(),
+                    resource: force_field_max_buffer.as_entire_binding(),
                 },
             ],
         });
 
-        let nucleus_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Nucleus Bind Group"),
-            layout: &nucleus_bind_group_layout,
+        let coarse_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Coarse Bind Group"),
+            layout: &coarse_bind_group_layout,
             entries: &[
                 wgpu::BindGroupEntry {
                     binding: 0,
-                    resource: hadron_buffer.as_entire_binding(),
+                    resource: particle_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 1,
-                    resource: nucleus_buffer.as_entire_binding(),
+                    resource: coarse_links_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 2,
-                    resource: nucleus_count_buffer.as_entire_binding(),
+                    resource: coarse_params_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 3,
@@ -770,37 +2404,110 @@ impl ParticleSimulation {
                 },
                 wgpu::BindGroupEntry {
                     binding: 4,
-                    resource: params_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 5,
-                    resource: hadron_count_buffer.as_entire_binding(),
+                    resource: coarse_stats_buffer.as_entire_binding(),
                 },
             ],
         });
 
         log::info!("Bind groups created");
 
+        let snapshot_sources = [
+            &particle_buffer,
+            &hadron_buffer,
+            &hadron_count_buffer,
+            &color_buffer,
+            &selection_mask_buffer,
+        ];
+        let render_snapshots = [
+            RenderSnapshot::new(&device, snapshot_sources, "Render Snapshot A"),
+            RenderSnapshot::new(&device, snapshot_sources, "Render Snapshot B"),
+        ];
+        let statistics_staging = StatisticsStaging::new(&device);
+
         Self {
             device,
             queue,
             particle_buffer,
-            _force_buffer: force_buffer,
+            force_buffer,
             hadron_buffer,
             hadron_count_buffer,
             nucleus_buffer,
             nucleus_count_buffer,
             locks_buffer,
+            hadron_candidates_buffer,
+            pair_pool_buffer,
+            escape_counts_buffer,
+            trail_buffer,
+            trail_params_buffer,
+            trail_params,
             params_buffer,
 
             selection_id_buffer,
             selection_target_buffer,
             selection_pipeline,
             selection_bind_group,
-
+            measure_id_buffer,
+            measure_target_buffer,
+            measure_pipeline,
+            selection_set_buffer,
+            selection_mask_buffer,
+            selection_set_stats_buffer,
+            selection_set_pipeline,
+            selection_set_bind_group,
+
+            histogram_buffer,
+            histogram_ranges_buffer,
+            histogram_pipeline,
+            histogram_bind_group,
+            charge_mass_buffer,
+            charge_mass_pipeline,
+            charge_mass_bind_group,
+            photon_buffer,
+            photon_counter_buffer,
+            spectrum_buffer,
+            electron_levels_pipeline,
+            electron_levels_bind_group,
+            tag_params_buffer,
+            cohort_stats_buffer,
+            tag_pipeline,
+            cohort_stats_pipeline,
+            cohort_bind_group,
+            color_buffer,
+            color_flow_pipeline,
+            color_flow_bind_group,
+            coarse_links_buffer,
+            coarse_params_buffer,
+            coarse_stats_buffer,
+            coarse_release_pipeline,
+            coarse_restore_pipeline,
+            coarse_merge_pipeline,
+            coarse_stats_pipeline,
+            coarse_bind_group,
+
+            cluster_stats_buffer,
+            cluster_init_pipeline,
+            cluster_propagate_pipeline,
+            cluster_jump_pipeline,
+            cluster_count_pipeline,
+            cluster_stats_pipeline,
+            cluster_bind_group,
+
+            force_field_params_buffer,
+            force_field_buffer,
+            force_field_max_buffer,
+            force_field_pipeline,
+            force_field_bind_group,
             force_pipeline,
+            force_packed_pipeline,
+            pack_sources_pipeline,
             integrate_pipeline,
             hadron_validation_pipeline,
+            baryon_decay_pipeline,
+            meson_decay_pipeline,
+            vacuum_expire_pipeline,
+            vacuum_spawn_pipeline,
+            hadron_candidate_pipeline,
+            hadron_claim_pipeline,
             hadron_pipeline,
             nucleus_pipeline,
             nucleus_reset_pipeline,
@@ -808,13 +2515,36 @@ impl ParticleSimulation {
             integrate_bind_group,
             hadron_bind_group,
             nucleus_bind_group,
+            frame_overlap: false,
+            render_snapshots,
+            snapshot_index: 0,
+            snapshot_ready: false,
+            step_submissions: [None, None],
+            statistics_staging,
+            packed_force_sources: false,
             particle_count,
             nucleus_capacity: max_nuclei as u32,
         }
     }
 
     /// Step the simulation forward by one timestep
-    pub fn step(&self) {
+    ///
+    /// With frame overlap enabled the step ends by copying the render-facing buffers into the next
+    /// snapshot, and the CPU waits for the step before last, so at most two steps are in flight.
+    pub fn step(&mut self) -> wgpu::SubmissionIndex {
+        // WebGPU cannot block on a submission; the browser's queue bounds the work in flight
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.frame_overlap {
+            if let Some(index) = self.step_submissions[0].take() {
+                self.device
+                    .poll(wgpu::PollType::Wait {
+                        submission_index: Some(index),
+                        timeout: None,
+                    })
+                    .unwrap();
+            }
+        }
+
         let mut encoder = self
             .device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -824,18 +2554,50 @@ impl ParticleSimulation {
         // Calculate workgroup count (256 threads per workgroup)
         let workgroup_count = (self.particle_count + 255) / 256;
 
+        // Step 0: Coarse-graining LOD. Split super-particles near the camera first (release stages
+        // the members, restore re-activates them), then merge free particles far away.
+        {
+            encoder.clear_buffer(&self.locks_buffer, 0, None);
+
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Coarse Graining Pass"),
+                timestamp_writes: None,
+            });
+            compute_pass.set_bind_group(0, &self.coarse_bind_group, &[]);
+            compute_pass.set_pipeline(&self.coarse_release_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+            compute_pass.set_pipeline(&self.coarse_restore_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+            compute_pass.set_pipeline(&self.coarse_merge_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+        }
+
         // Step 1: Compute forces
         {
             let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("Force Compute Pass"),
                 timestamp_writes: None,
             });
-            compute_pass.set_pipeline(&self.force_pipeline);
             compute_pass.set_bind_group(0, &self.force_bind_group, &[]);
+            if self.packed_force_sources {
+                compute_pass.set_pipeline(&self.pack_sources_pipeline);
+                compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+                compute_pass.set_pipeline(&self.force_packed_pipeline);
+            } else {
+                compute_pass.set_pipeline(&self.force_pipeline);
+            }
             compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
         }
 
-        // Step 2: Integrate motion
+        // Step 2: Integrate motion (also appends to the trails when enabled)
+        if self.trail_params.enabled != 0 {
+            self.trail_params.head = (self.trail_params.head + 1) % self.trail_params.length;
+            self.queue.write_buffer(
+                &self.trail_params_buffer,
+                0,
+                bytemuck::cast_slice(&[self.trail_params]),
+            );
+        }
         {
             let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("Integration Compute Pass"),
@@ -846,6 +2608,17 @@ impl ParticleSimulation {
             compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
         }
 
+        // Step 2b: Color flow (rotates quark colors before validation/detection look at them)
+        {
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Color Flow Pass"),
+                timestamp_writes: None,
+            });
+            compute_pass.set_pipeline(&self.color_flow_pipeline);
+            compute_pass.set_bind_group(0, &self.color_flow_bind_group, &[]);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+        }
+
         // Step 3: Validate existing hadrons
         {
             let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
@@ -857,17 +2630,40 @@ impl ParticleSimulation {
             compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
         }
 
-        // Step 4: Detect new hadrons
+        // Step 3b: Decay unstable hadrons and vacuum pairs (baryon decays and vacuum spawns
+        // only pop the pair pool, meson decays and vacuum annihilation only push, so each runs
+        // as a separate dispatch)
         {
-            // Reset locks (hadron count persists now)
-            encoder.clear_buffer(&self.locks_buffer, 0, None);
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Hadron Decay Pass"),
+                timestamp_writes: None,
+            });
+            compute_pass.set_bind_group(0, &self.hadron_bind_group, &[]);
+            compute_pass.set_pipeline(&self.baryon_decay_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+            compute_pass.set_pipeline(&self.meson_decay_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+            compute_pass.set_pipeline(&self.vacuum_expire_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+            compute_pass.set_pipeline(&self.vacuum_spawn_pipeline);
+            compute_pass.dispatch_workgroups(1, 1, 1);
+        }
+
+        // Step 4: Detect new hadrons (propose -> claim by binding energy -> commit)
+        {
+            // Reset proposals (hadron count persists now)
+            encoder.clear_buffer(&self.hadron_candidates_buffer, 0, None);
 
             let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("Hadron Detection Pass"),
                 timestamp_writes: None,
             });
-            compute_pass.set_pipeline(&self.hadron_pipeline);
             compute_pass.set_bind_group(0, &self.hadron_bind_group, &[]);
+            compute_pass.set_pipeline(&self.hadron_candidate_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+            compute_pass.set_pipeline(&self.hadron_claim_pipeline);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+            compute_pass.set_pipeline(&self.hadron_pipeline);
             compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
         }
 
@@ -903,6 +2699,116 @@ impl ParticleSimulation {
             compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
         }
 
+        // Step 7: Electron energy levels + photon emission (needs this frame's nuclei)
+        {
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Electron Levels Pass"),
+                timestamp_writes: None,
+            });
+            compute_pass.set_pipeline(&self.electron_levels_pipeline);
+            compute_pass.set_bind_group(0, &self.electron_levels_bind_group, &[]);
+            compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+        }
+
+        // Step 7b: Refresh the selection set mask (membership follows hadrons/nuclei)
+        self.encode_selection_set(&mut encoder);
+
+        // Step 8: Snapshot for the renderers (frame overlap only)
+        if self.frame_overlap {
+            let next = 1 - self.snapshot_index;
+            let snapshot = &self.render_snapshots[next];
+            for (source, target) in [
+                (&self.particle_buffer, &snapshot.particles),
+                (&self.hadron_buffer, &snapshot.hadrons),
+                (&self.hadron_count_buffer, &snapshot.hadron_counter),
+                (&self.color_buffer, &snapshot.colors),
+                (&self.selection_mask_buffer, &snapshot.selection_mask),
+            ] {
+                encoder.copy_buffer_to_buffer(source, 0, target, 0, source.size());
+            }
+            self.snapshot_index = next;
+            self.snapshot_ready = true;
+        }
+
+        let index = self.queue.submit(std::iter::once(encoder.finish()));
+        self.step_submissions = [self.step_submissions[1].take(), Some(index.clone())];
+        index
+    }
+
+    /// Let the next step run while the renderers draw the previous one.
+    ///
+    /// The renderers must then read the `render_*` buffers, which lag the live simulation
+    /// buffers by the steps still in flight. Enabling takes effect from the next step; until
+    /// then the `render_*` getters keep returning the live buffers.
+    pub fn set_frame_overlap(&mut self, enabled: bool) {
+        if enabled != self.frame_overlap {
+            self.frame_overlap = enabled;
+            self.step_submissions = [None, None];
+            self.snapshot_ready = false;
+        }
+    }
+
+    pub fn frame_overlap(&self) -> bool {
+        self.frame_overlap
+    }
+
+    /// Read source particles in the force pass from a packed mirror (f32 position, f16
+    /// mass/charge/size/tag), halving the bytes the O(N²) loop fetches per pair.
+    ///
+    /// Only the core WGSL `pack2x16float` builtins are used, so no device feature is needed.
+    /// Charges and masses lose precision beyond ~3 significant digits in force sums.
+    pub fn set_packed_force_sources(&mut self, enabled: bool) {
+        self.packed_force_sources = enabled;
+    }
+
+    pub fn packed_force_sources(&self) -> bool {
+        self.packed_force_sources
+    }
+
+    /// Replace every particle and clear all derived state (hadrons, nuclei, pair pool, photons).
+    ///
+    /// `particles` must have the same length the simulation was created with; scenario
+    /// generators pad with dormant slots to keep the buffer size fixed.
+    pub fn reset_particles(&self, particles: &[Particle]) {
+        assert_eq!(
+            particles.len(),
+            self.particle_count as usize,
+            "reset_particles must keep the particle count"
+        );
+
+        self.queue
+            .write_buffer(&self.particle_buffer, 0, bytemuck::cast_slice(particles));
+        self.queue.write_buffer(
+            &self.hadron_buffer,
+            0,
+            bytemuck::cast_slice(&invalid_hadrons(particles.len())),
+        );
+        self.queue.write_buffer(
+            &self.nucleus_buffer,
+            0,
+            bytemuck::cast_slice(&invalid_nuclei(self.nucleus_capacity as usize)),
+        );
+        self.queue.write_buffer(
+            &self.pair_pool_buffer,
+            0,
+            bytemuck::cast_slice(&pair_pool(particles)),
+        );
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Reset Encoder"),
+            });
+        encoder.clear_buffer(&self.hadron_count_buffer, 0, None);
+        encoder.clear_buffer(&self.nucleus_count_buffer, 0, None);
+        encoder.clear_buffer(&self.locks_buffer, 0, None);
+        encoder.clear_buffer(&self.force_buffer, 0, None);
+        encoder.clear_buffer(&self.photon_counter_buffer, 0, None);
+        encoder.clear_buffer(&self.spectrum_buffer, 0, None);
+        encoder.clear_buffer(&self.escape_counts_buffer, 0, None);
+        encoder.clear_buffer(&self.coarse_links_buffer, 0, None);
+        encoder.clear_buffer(&self.color_buffer, 0, None);
+        encoder.clear_buffer(&self.trail_buffer, 0, None);
         self.queue.submit(std::iter::once(encoder.finish()));
     }
 
@@ -928,7 +2834,8 @@ impl ParticleSimulation {
 
     /// Run the selection resolve compute pass (1 invocation).
     ///
-    /// This writes the selected entity center into `selection_target_buffer`.
+    /// This writes the selected entity center, radius and velocity into `selection_target_buffer`
+    /// (decode with `SelectionTarget::from_bytes`).
     pub fn encode_selection_resolve(&self, encoder: &mut wgpu::CommandEncoder) {
         let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
             label: Some("Selection Resolve Pass"),
@@ -939,6 +2846,387 @@ impl ParticleSimulation {
         pass.dispatch_workgroups(1, 1, 1);
     }
 
+    /// Set the packed IDs the measurement tool tracks (at most `MEASURE_CAPACITY`, same encoding
+    /// as `set_selected_id`).
+    pub fn set_measure_ids(&self, ids: &[u32]) {
+        let mut data = [0u32; 4];
+        for (slot, &id) in data.iter_mut().zip(ids.iter().take(MEASURE_CAPACITY)) {
+            *slot = id;
+        }
+        self.queue
+            .write_buffer(&self.measure_id_buffer, 0, bytemuck::cast_slice(&data));
+    }
+
+    /// Resolve the measurement IDs into `measure_target_buffer` (`MEASURE_CAPACITY` targets of
+    /// `SELECTION_TARGET_SIZE` bytes, decode each with `SelectionTarget::from_bytes`).
+    pub fn encode_measure_resolve(&self, encoder: &mut wgpu::CommandEncoder) {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Measure Resolve Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.measure_pipeline);
+        pass.set_bind_group(0, &self.selection_bind_group, &[]);
+        pass.dispatch_workgroups(1, 1, 1);
+    }
+
+    pub fn measure_target_buffer(&self) -> &wgpu::Buffer {
+        &self.measure_target_buffer
+    }
+
+    /// Replace the selection set and refresh its mask right away (also while paused).
+    pub fn update_selection_set(&self, set: &SelectionSet) {
+        self.queue
+            .write_buffer(&self.selection_set_buffer, 0, bytemuck::cast_slice(&[*set]));
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Selection Set Encoder"),
+            });
+        self.encode_selection_set(&mut encoder);
+        if let Some(snapshot) = self.render_snapshot() {
+            encoder.copy_buffer_to_buffer(
+                &self.selection_mask_buffer,
+                0,
+                &snapshot.selection_mask,
+                0,
+                self.selection_mask_buffer.size(),
+            );
+        }
+        self.queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Expand the selection set into the per-particle mask and accumulate its stats.
+    fn encode_selection_set(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.selection_set_stats_buffer, 0, None);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Selection Set Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.selection_set_pipeline);
+        pass.set_bind_group(0, &self.selection_set_bind_group, &[]);
+        pass.dispatch_workgroups(self.particle_count.div_ceil(256), 1, 1);
+    }
+
+    /// Run the statistics passes and read back every counter and histogram.
+    ///
+    /// Blocks until the GPU has finished, so call it every few frames rather than every frame.
+    pub fn read_statistics(&self) -> Statistics {
+        let staging = &self.statistics_staging;
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Readback Encoder"),
+            });
+
+        self.encode_histograms(&mut encoder);
+        self.encode_charge_mass(&mut encoder);
+        self.encode_cohort_stats(&mut encoder);
+        self.encode_coarse_stats(&mut encoder);
+        self.encode_cluster_stats(&mut encoder);
+        for (source, destination) in [
+            (&self.hadron_count_buffer, &staging.hadrons),
+            (&self.histogram_buffer, &staging.histograms),
+            (&self.charge_mass_buffer, &staging.charge_mass),
+            (&self.spectrum_buffer, &staging.spectrum),
+            (&self.cohort_stats_buffer, &staging.cohort),
+            (&self.coarse_stats_buffer, &staging.coarse),
+            (&self.cluster_stats_buffer, &staging.clusters),
+            (&self.selection_set_stats_buffer, &staging.selection_set),
+            (&self.escape_counts_buffer, &staging.escapes),
+        ] {
+            encoder.copy_buffer_to_buffer(source, 0, destination, 0, destination.size());
+        }
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+        staging.read(&self.device)
+    }
+
+    /// Get the selection set stats buffer for readback (decode with `SelectionSetStats`).
+    pub fn selection_set_stats_buffer(&self) -> &wgpu::Buffer {
+        &self.selection_set_stats_buffer
+    }
+
+    /// Run the diagnostics histogram pass.
+    ///
+    /// Clears the bins and re-accumulates them from the current particle/hadron state. Copy
+    /// `histogram_buffer` into a staging buffer afterwards and decode with `Histograms::from_bytes`.
+    pub fn encode_histograms(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.histogram_buffer, 0, None);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Histogram Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.histogram_pipeline);
+        pass.set_bind_group(0, &self.histogram_bind_group, &[]);
+        // Hadron slots are allocated per particle, so one invocation per particle covers both.
+        pass.dispatch_workgroups(self.particle_count.div_ceil(256), 1, 1);
+    }
+
+    /// Run the charge/mass map pass over free particles and hadrons.
+    ///
+    /// Copy `charge_mass_buffer` into a staging buffer afterwards and decode with
+    /// `ChargeMassMap::from_bytes`.
+    pub fn encode_charge_mass(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.charge_mass_buffer, 0, None);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Charge Mass Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.charge_mass_pipeline);
+        pass.set_bind_group(0, &self.charge_mass_bind_group, &[]);
+        pass.dispatch_workgroups(self.particle_count.div_ceil(256), 1, 1);
+    }
+
+    /// Get the charge/mass map buffer for readback.
+    pub fn charge_mass_buffer(&self) -> &wgpu::Buffer {
+        &self.charge_mass_buffer
+    }
+
+    /// Get the pair pool buffer (first `u32` = dormant slots still available to string breaking).
+    pub fn pair_pool_buffer(&self) -> &wgpu::Buffer {
+        &self.pair_pool_buffer
+    }
+
+    /// Record particle trails of `length` samples (clamped to 2..=`TRAIL_CAPACITY`).
+    ///
+    /// Turning trails on or changing the length clears the recorded history, since the ring
+    /// layout depends on the length.
+    pub fn set_trails(&mut self, enabled: bool, length: u32) {
+        let length = length.clamp(2, TRAIL_CAPACITY);
+        if (self.trail_params.enabled != 0) == enabled && self.trail_params.length == length {
+            return;
+        }
+
+        if enabled {
+            let mut encoder = self
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("Trail Reset Encoder"),
+                });
+            encoder.clear_buffer(&self.trail_buffer, 0, None);
+            self.queue.submit(std::iter::once(encoder.finish()));
+        }
+
+        self.trail_params = TrailParams {
+            length,
+            head: 0,
+            enabled: enabled as u32,
+            _pad: 0,
+        };
+        self.queue.write_buffer(
+            &self.trail_params_buffer,
+            0,
+            bytemuck::cast_slice(&[self.trail_params]),
+        );
+    }
+
+    /// Current trail recording state (`enabled`, `length` and the newest slot `head`)
+    pub fn trail_params(&self) -> TrailParams {
+        self.trail_params
+    }
+
+    /// Trail ring buffer (`TRAIL_CAPACITY` `vec4<f32>` samples per particle). Always the live
+    /// buffer, also with frame overlap (snapshotting it every step would cost more than it saves).
+    pub fn trail_buffer(&self) -> &wgpu::Buffer {
+        &self.trail_buffer
+    }
+
+    /// Trail recording state uniform (`TrailParams`)
+    pub fn trail_params_buffer(&self) -> &wgpu::Buffer {
+        &self.trail_params_buffer
+    }
+
+    /// Get the escape counter buffer for readback (`EscapeCounts::from_bytes`).
+    pub fn escape_counts_buffer(&self) -> &wgpu::Buffer {
+        &self.escape_counts_buffer
+    }
+
+    /// Tag every particle within `radius` of `center` with `tag` and track that cohort.
+    ///
+    /// Later `encode_cohort_stats` calls count where particles carrying `tag` ended up.
+    pub fn tag_sphere(&self, center: [f32; 3], radius: f32, tag: u32) {
+        let params = TagParams {
+            sphere: [center[0], center[1], center[2], radius],
+            tag: [tag, 0, 0, 0],
+        };
+        self.queue
+            .write_buffer(&self.tag_params_buffer, 0, bytemuck::cast_slice(&[params]));
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Tag Encoder"),
+            });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Tag Pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.tag_pipeline);
+            pass.set_bind_group(0, &self.cohort_bind_group, &[]);
+            pass.dispatch_workgroups(self.particle_count.div_ceil(256), 1, 1);
+        }
+        self.queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Run the cohort stats pass for the most recently tagged cohort.
+    ///
+    /// Copy `cohort_stats_buffer` into a staging buffer afterwards and decode with
+    /// `CohortStats::from_bytes`.
+    pub fn encode_cohort_stats(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.cohort_stats_buffer, 0, None);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Cohort Stats Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.cohort_stats_pipeline);
+        pass.set_bind_group(0, &self.cohort_bind_group, &[]);
+        pass.dispatch_workgroups(self.particle_count.div_ceil(256), 1, 1);
+    }
+
+    /// Get the cohort stats buffer for readback.
+    pub fn cohort_stats_buffer(&self) -> &wgpu::Buffer {
+        &self.cohort_stats_buffer
+    }
+
+    /// Get the continuous color vector buffer (`vec4<f32>` per particle, xyz = color weights).
+    pub fn color_buffer(&self) -> &wgpu::Buffer {
+        &self.color_buffer
+    }
+
+    /// Update the coarse-graining parameters (camera position + merge/split distances).
+    ///
+    /// Called every frame since the distances are measured from the moving camera.
+    pub fn update_coarse_params(&self, params: &CoarseParams) {
+        self.queue.write_buffer(
+            &self.coarse_params_buffer,
+            0,
+            bytemuck::cast_slice(&[*params]),
+        );
+    }
+
+    /// Run the coarse-graining stats pass.
+    ///
+    /// Copy `coarse_stats_buffer` into a staging buffer afterwards and decode with
+    /// `CoarseStats::from_bytes`.
+    pub fn encode_coarse_stats(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.coarse_stats_buffer, 0, None);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Coarse Stats Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.coarse_stats_pipeline);
+        pass.set_bind_group(0, &self.coarse_bind_group, &[]);
+        pass.dispatch_workgroups(self.particle_count.div_ceil(256), 1, 1);
+    }
+
+    /// Get the coarse-graining stats buffer for readback.
+    pub fn coarse_stats_buffer(&self) -> &wgpu::Buffer {
+        &self.coarse_stats_buffer
+    }
+
+    /// Run the cluster identification passes (connected components over nucleon bonds).
+    ///
+    /// Copy `cluster_stats_buffer` into a staging buffer afterwards and decode with
+    /// `ClusterStats::from_bytes`.
+    pub fn encode_cluster_stats(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.cluster_stats_buffer, 0, None);
+
+        let workgroup_count = self.particle_count.div_ceil(256);
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Cluster Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_bind_group(0, &self.cluster_bind_group, &[]);
+        pass.set_pipeline(&self.cluster_init_pipeline);
+        pass.dispatch_workgroups(workgroup_count, 1, 1);
+        for _ in 0..CLUSTER_ROUNDS {
+            pass.set_pipeline(&self.cluster_propagate_pipeline);
+            pass.dispatch_workgroups(workgroup_count, 1, 1);
+            pass.set_pipeline(&self.cluster_jump_pipeline);
+            pass.dispatch_workgroups(workgroup_count, 1, 1);
+        }
+        pass.set_pipeline(&self.cluster_count_pipeline);
+        pass.dispatch_workgroups(workgroup_count, 1, 1);
+        pass.set_pipeline(&self.cluster_stats_pipeline);
+        pass.dispatch_workgroups(workgroup_count, 1, 1);
+    }
+
+    /// Get the cluster stats buffer for readback.
+    pub fn cluster_stats_buffer(&self) -> &wgpu::Buffer {
+        &self.cluster_stats_buffer
+    }
+
+    /// Sample the force field on the slice described by `field` (debug visualization).
+    ///
+    /// Cost is `FORCE_FIELD_GRID`² x particle count, so only encode it while the slice is shown.
+    pub fn encode_force_field(&self, encoder: &mut wgpu::CommandEncoder, field: &ForceFieldParams) {
+        self.queue.write_buffer(
+            &self.force_field_params_buffer,
+            0,
+            bytemuck::cast_slice(&[*field]),
+        );
+        encoder.clear_buffer(&self.force_field_max_buffer, 0, None);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Force Field Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.force_field_pipeline);
+        pass.set_bind_group(0, &self.force_field_bind_group, &[]);
+        pass.dispatch_workgroups((FORCE_FIELD_GRID * FORCE_FIELD_GRID).div_ceil(64), 1, 1);
+    }
+
+    /// Get the force field samples (`FORCE_FIELD_GRID`² x `FORCE_FIELD_SAMPLE_SIZE` bytes).
+    pub fn force_field_buffer(&self) -> &wgpu::Buffer {
+        &self.force_field_buffer
+    }
+
+    /// Get the largest sampled force magnitude (one `u32` holding the `f32` bit pattern).
+    pub fn force_field_max_buffer(&self) -> &wgpu::Buffer {
+        &self.force_field_max_buffer
+    }
+
+    /// Get the photon ring buffer (`PHOTON_CAPACITY` slots) for rendering.
+    pub fn photon_buffer(&self) -> &wgpu::Buffer {
+        &self.photon_buffer
+    }
+
+    /// Get the photon counter buffer (first `u32` = total photons emitted).
+    pub fn photon_counter_buffer(&self) -> &wgpu::Buffer {
+        &self.photon_counter_buffer
+    }
+
+    /// Get the cumulative emission spectrum buffer for readback (`Spectrum::from_bytes`).
+    pub fn spectrum_buffer(&self) -> &wgpu::Buffer {
+        &self.spectrum_buffer
+    }
+
+    /// Reset the cumulative emission spectrum.
+    pub fn clear_spectrum(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.spectrum_buffer, 0, None);
+    }
+
+    /// Get the histogram buffer for readback.
+    pub fn histogram_buffer(&self) -> &wgpu::Buffer {
+        &self.histogram_buffer
+    }
+
+    /// Update the value ranges mapped onto the histogram bins.
+    pub fn update_histogram_ranges(&self, ranges: &HistogramRanges) {
+        self.queue.write_buffer(
+            &self.histogram_ranges_buffer,
+            0,
+            bytemuck::cast_slice(&[*ranges]),
+        );
+    }
+
     /// Get the selection target buffer for readback.
     pub fn selection_target_buffer(&self) -> &wgpu::Buffer {
         &self.selection_target_buffer
@@ -963,6 +3251,41 @@ impl ParticleSimulation {
         &self.hadron_count_buffer
     }
 
+    fn render_snapshot(&self) -> Option<&RenderSnapshot> {
+        (self.frame_overlap && self.snapshot_ready)
+            .then(|| &self.render_snapshots[self.snapshot_index])
+    }
+
+    /// Particle buffer for drawing (the last step's snapshot when frame overlap is enabled)
+    pub fn render_particle_buffer(&self) -> &wgpu::Buffer {
+        self.render_snapshot()
+            .map_or(&self.particle_buffer, |s| &s.particles)
+    }
+
+    /// Hadron buffer for drawing (the last step's snapshot when frame overlap is enabled)
+    pub fn render_hadron_buffer(&self) -> &wgpu::Buffer {
+        self.render_snapshot()
+            .map_or(&self.hadron_buffer, |s| &s.hadrons)
+    }
+
+    /// Hadron counter for drawing (the last step's snapshot when frame overlap is enabled)
+    pub fn render_hadron_count_buffer(&self) -> &wgpu::Buffer {
+        self.render_snapshot()
+            .map_or(&self.hadron_count_buffer, |s| &s.hadron_counter)
+    }
+
+    /// Selection set mask for drawing (`u32` per particle, 1 = in the selection set)
+    pub fn render_selection_mask_buffer(&self) -> &wgpu::Buffer {
+        self.render_snapshot()
+            .map_or(&self.selection_mask_buffer, |s| &s.selection_mask)
+    }
+
+    /// Color vectors for drawing (the last step's snapshot when frame overlap is enabled)
+    pub fn render_color_buffer(&self) -> &wgpu::Buffer {
+        self.render_snapshot()
+            .map_or(&self.color_buffer, |s| &s.colors)
+    }
+
     /// Get reference to nucleus buffer.
     pub fn nucleus_buffer(&self) -> &wgpu::Buffer {
         &self.nucleus_buffer
@@ -979,3 +3302,50 @@ impl ParticleSimulation {
             .write_buffer(&self.params_buffer, 0, bytemuck::cast_slice(&[*params]));
     }
 }
+
+/// All hadron slots marked invalid (`type_id == 0xFFFFFFFF`)
+fn invalid_hadrons(count: usize) -> Vec<Hadron> {
+    (0..count)
+        .map(|_| Hadron {
+            p1: 0,
+            p2: 0,
+            p3: 0,
+            type_id: 0xFFFF_FFFF,
+            center: [0.0; 4],
+            velocity: [0.0; 4],
+        })
+        .collect()
+}
+
+/// All nucleus slots marked invalid (`type_id == 0xFFFFFFFF`)
+fn invalid_nuclei(count: usize) -> Vec<Nucleus> {
+    (0..count)
+        .map(|_| Nucleus {
+            hadron_indices: [0xFFFF_FFFF; MAX_NUCLEONS],
+            nucleon_count: 0,
+            proton_count: 0,
+            neutron_count: 0,
+            type_id: 0xFFFF_FFFF,
+            center: [0.0; 4],
+            velocity: [0.0; 4],
+        })
+        .collect()
+}
+
+/// Pair pool contents: [available, pad x3, dormant particle indices...]
+fn pair_pool(particles: &[Particle]) -> Vec<u32> {
+    let mut pool: Vec<u32> = vec![0; 4];
+    pool.extend(
+        particles
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_dormant())
+            .map(|(i, _)| i as u32),
+    );
+    pool[0] = (pool.len() - 4) as u32;
+    // Runtime-sized arrays need at least one element
+    if pool.len() == 4 {
+        pool.push(0);
+    }
+    pool
+}
@@ -0,0 +1,134 @@
+//! Periodic statistics readback
+//!
+//! `ParticleSimulation::read_statistics` runs the statistics passes, copies every counter and
+//! histogram buffer into its own staging buffer and decodes them all after a single blocking
+//! poll, so the app only has to copy the result into its UI state.
+
+use crate::{
+    ChargeMassMap, ClusterStats, CoarseStats, CohortStats, EscapeCounts, Histograms,
+    SelectionSetStats, Spectrum, CHARGE_MASS_BUFFER_SIZE, CLUSTER_STATS_SIZE, COARSE_STATS_SIZE,
+    COHORT_STATS_SIZE, ESCAPE_COUNTS_SIZE, HISTOGRAM_BUFFER_SIZE, SELECTION_SET_STATS_SIZE,
+    SPECTRUM_BUFFER_SIZE,
+};
+
+/// Size in bytes of the hadron counter buffer (4x `u32`)
+pub const HADRON_COUNTS_SIZE: u64 = 16;
+
+/// Hadron counters, as read back from the GPU
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HadronCounts {
+    pub total: u32,
+    pub protons: u32,
+    pub neutrons: u32,
+    pub other: u32,
+}
+
+impl HadronCounts {
+    /// Decode the mapped counter buffer (4 little-endian `u32` values:
+    /// total, protons, neutrons, other).
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            total: value(0),
+            protons: value(1),
+            neutrons: value(2),
+            other: value(3),
+        }
+    }
+}
+
+/// Everything the statistics panel shows, decoded from one readback
+#[derive(Clone, Debug)]
+pub struct Statistics {
+    pub hadrons: HadronCounts,
+    pub histograms: Histograms,
+    pub charge_mass: ChargeMassMap,
+    pub spectrum: Spectrum,
+    pub cohort: CohortStats,
+    pub coarse: CoarseStats,
+    pub clusters: ClusterStats,
+    pub selection_set: SelectionSetStats,
+    pub escapes: EscapeCounts,
+}
+
+/// Staging buffers for `ParticleSimulation::read_statistics`, one per source buffer
+pub(crate) struct StatisticsStaging {
+    pub(crate) hadrons: wgpu::Buffer,
+    pub(crate) histograms: wgpu::Buffer,
+    pub(crate) charge_mass: wgpu::Buffer,
+    pub(crate) spectrum: wgpu::Buffer,
+    pub(crate) cohort: wgpu::Buffer,
+    pub(crate) coarse: wgpu::Buffer,
+    pub(crate) clusters: wgpu::Buffer,
+    pub(crate) selection_set: wgpu::Buffer,
+    pub(crate) escapes: wgpu::Buffer,
+}
+
+impl StatisticsStaging {
+    pub(crate) fn new(device: &wgpu::Device) -> Self {
+        let staging = |label: &str, size: u64| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            })
+        };
+        Self {
+            hadrons: staging("Hadron Count Staging Buffer", HADRON_COUNTS_SIZE),
+            histograms: staging("Histogram Staging Buffer", HISTOGRAM_BUFFER_SIZE),
+            charge_mass: staging("Charge Mass Staging Buffer", CHARGE_MASS_BUFFER_SIZE),
+            spectrum: staging("Spectrum Staging Buffer", SPECTRUM_BUFFER_SIZE),
+            cohort: staging("Cohort Staging Buffer", COHORT_STATS_SIZE),
+            coarse: staging("Coarse Staging Buffer", COARSE_STATS_SIZE),
+            clusters: staging("Cluster Staging Buffer", CLUSTER_STATS_SIZE),
+            selection_set: staging("Selection Set Staging Buffer", SELECTION_SET_STATS_SIZE),
+            escapes: staging("Escape Staging Buffer", ESCAPE_COUNTS_SIZE),
+        }
+    }
+
+    /// Map every staging buffer, wait for the GPU and decode the contents.
+    pub(crate) fn read(&self, device: &wgpu::Device) -> Statistics {
+        let buffers = [
+            &self.hadrons,
+            &self.histograms,
+            &self.charge_mass,
+            &self.spectrum,
+            &self.cohort,
+            &self.coarse,
+            &self.clusters,
+            &self.selection_set,
+            &self.escapes,
+        ];
+        for buffer in buffers {
+            buffer.slice(..).map_async(wgpu::MapMode::Read, |_| {});
+        }
+        // TODO: Convert to async ring buffer to avoid blocking GPU pipeline
+        // See: https://toji.dev/webgpu-best-practices/buffer-uploads
+        device
+            .poll(wgpu::PollType::Wait {
+                submission_index: None,
+                timeout: None,
+            })
+            .unwrap();
+
+        fn decode<T>(buffer: &wgpu::Buffer, from_bytes: fn(&[u8]) -> T) -> T {
+            from_bytes(&buffer.slice(..).get_mapped_range())
+        }
+        let statistics = Statistics {
+            hadrons: decode(&self.hadrons, HadronCounts::from_bytes),
+            histograms: decode(&self.histograms, Histograms::from_bytes),
+            charge_mass: decode(&self.charge_mass, ChargeMassMap::from_bytes),
+            spectrum: decode(&self.spectrum, Spectrum::from_bytes),
+            cohort: decode(&self.cohort, CohortStats::from_bytes),
+            coarse: decode(&self.coarse, CoarseStats::from_bytes),
+            clusters: decode(&self.clusters, ClusterStats::from_bytes),
+            selection_set: decode(&self.selection_set, SelectionSetStats::from_bytes),
+            escapes: decode(&self.escapes, EscapeCounts::from_bytes),
+        };
+        for buffer in buffers {
+            buffer.unmap();
+        }
+        statistics
+    }
+}
@@ -0,0 +1,27 @@
+//! Particle trails
+//!
+//! The integration pass appends each particle's new position to a per-particle ring buffer of
+//! `TRAIL_CAPACITY` slots (only the first `length` slots are used). `w` of each sample says
+//! how it connects to the one before it, so jumps (boundary re-injection, spawns into old pool
+//! slots) break the polyline instead of drawing a streak across the scene.
+
+use bytemuck::{Pod, Zeroable};
+
+/// Samples per particle in the trail buffer (must match `integrate.wgsl` and `trail.wgsl`)
+pub const TRAIL_CAPACITY: u32 = 64;
+
+/// Size in bytes of one trail sample (`vec4<f32>`: xyz = position, w = link)
+pub const TRAIL_SAMPLE_SIZE: u64 = 16;
+
+/// Trail recording state (uniform, matches WGSL `TrailParams`)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Pod, Zeroable)]
+pub struct TrailParams {
+    /// Samples in use per particle (2..=`TRAIL_CAPACITY`)
+    pub length: u32,
+    /// Slot the newest sample was written to
+    pub head: u32,
+    /// Record samples this step (0/1)
+    pub enabled: u32,
+    pub _pad: u32,
+}
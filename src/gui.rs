@@ -3,7 +3,7 @@
 use winit::event::WindowEvent;
 
 use astra_gui::{
-    catppuccin::mocha, Content, CornerShape, DebugOptions, FullOutput as AstraFullOutput,
+    catppuccin::mocha, Color, Content, CornerShape, DebugOptions, FullOutput as AstraFullOutput,
     HorizontalAlign, Layout, Node, Place, Size, Spacing, Stroke, Style, TextContent, VerticalAlign,
 };
 use astra_gui_interactive::{
@@ -13,10 +13,293 @@ use astra_gui_interactive::{
 };
 use astra_gui_text::Engine as TextEngine;
 use astra_gui_wgpu::{EventDispatcher, InputState, InteractiveStateManager, TargetedEvent};
-use particle_simulation::PhysicsParams;
+use particle_physics::ParticleType;
+use particle_renderer::{
+    ImpostorShape, LodSettings, MinimapCorner, PassTiming, RecordingFormat, ScaleBar,
+    SpeciesImpostor, IMPOSTOR_SPECIES,
+};
+use particle_simulation::{
+    BeamConfig, BeamEventStats, ChargeMassMap, ClusterStats, CoarseParams, CoarseStats,
+    CohortStats, EscapeCounts, Force, Histograms, PhysicsParams, SelectionSetStats, Spectrum,
+};
 
 use crate::gui_data::{element_name, element_symbol};
 
+/// Collapsible groups of the physics panel: (collapsible id, title). Only the first starts open.
+const PHYSICS_SECTIONS: [(&str, &str); 15] = [
+    ("phys_section_forces", "Forces"),
+    ("phys_section_strong_force", "Strong Force"),
+    ("phys_section_repulsion", "Repulsion"),
+    ("phys_section_integration", "Integration"),
+    (
+        "phys_section_residual_strong_force",
+        "Residual Strong Force",
+    ),
+    ("phys_section_nucleon_physics", "Nucleon Physics"),
+    ("phys_section_electron_physics", "Electron Physics"),
+    ("phys_section_hadron_formation", "Hadron Formation"),
+    ("phys_section_string_breaking", "String Breaking"),
+    ("phys_section_boundary", "Boundary"),
+    ("phys_section_hadron_decay", "Hadron Decay"),
+    ("phys_section_degeneracy_pressure", "Degeneracy Pressure"),
+    ("phys_section_color_flow", "Color Flow"),
+    ("phys_section_vacuum_fluctuations", "Vacuum Fluctuations"),
+    ("phys_section_per_force", "Per-Force Settings"),
+];
+
+/// Force selector buttons for the coupling matrix editor: (button id, label, force).
+const COUPLING_FORCE_BUTTONS: [(&str, &str, Force); 4] = [
+    ("phys_coupling_force_gravity", "Gravity", Force::Gravity),
+    ("phys_coupling_force_em", "EM", Force::Electromagnetic),
+    ("phys_coupling_force_strong", "Strong", Force::Strong),
+    ("phys_coupling_force_weak", "Weak", Force::Weak),
+];
+
+/// Root zoom of the UI tree (lpx -> surface pixels)
+const UI_ZOOM: f32 = 1.5;
+/// Root padding in lpx
+const UI_PADDING: f32 = 12.0;
+/// Space below the scale bar in lpx (keeps it clear of the hover tooltip)
+const SCALE_BAR_MARGIN: f32 = 48.0;
+
+/// Scene anti-aliasing buttons: (button id, label, MSAA sample count).
+const MSAA_BUTTONS: [(&str, &str, u32); 4] = [
+    ("msaa_1", "Off", 1),
+    ("msaa_2", "2x", 2),
+    ("msaa_4", "4x", 4),
+    ("msaa_8", "8x", 8),
+];
+
+/// Present mode buttons: (button id, label, mode). The auto modes work everywhere; the others
+/// are listed when the surface supports them.
+const PRESENT_MODE_BUTTONS: [(&str, &str, wgpu::PresentMode); 5] = [
+    ("present_auto_vsync", "Vsync", wgpu::PresentMode::AutoVsync),
+    (
+        "present_auto_no_vsync",
+        "No vsync",
+        wgpu::PresentMode::AutoNoVsync,
+    ),
+    ("present_fifo", "Fifo", wgpu::PresentMode::Fifo),
+    ("present_mailbox", "Mailbox", wgpu::PresentMode::Mailbox),
+    (
+        "present_immediate",
+        "Immediate",
+        wgpu::PresentMode::Immediate,
+    ),
+];
+
+/// Render scale buttons: (button id, label, scene resolution in percent of the window).
+const RENDER_SCALE_BUTTONS: [(&str, &str, u32); 4] = [
+    ("render_scale_50", "50%", 50),
+    ("render_scale_67", "67%", 67),
+    ("render_scale_85", "85%", 85),
+    ("render_scale_100", "100%", 100),
+];
+
+/// Screenshot resolution buttons: (button id, label, multiple of the window resolution).
+const SCREENSHOT_SCALE_BUTTONS: [(&str, &str, u32); 3] = [
+    ("screenshot_scale_1", "1x", 1),
+    ("screenshot_scale_2", "2x", 2),
+    ("screenshot_scale_4", "4x", 4),
+];
+
+/// Camera bookmark slots: one jump button id per bookmark (the app stops saving when full).
+pub const CAMERA_BOOKMARK_BUTTONS: [&str; 8] = [
+    "camera_bookmark_0",
+    "camera_bookmark_1",
+    "camera_bookmark_2",
+    "camera_bookmark_3",
+    "camera_bookmark_4",
+    "camera_bookmark_5",
+    "camera_bookmark_6",
+    "camera_bookmark_7",
+];
+
+/// Impostor species buttons: (button id, label), indexed by particle type.
+const IMPOSTOR_SPECIES_BUTTONS: [(&str, &str); IMPOSTOR_SPECIES] = [
+    ("impostor_species_up", "Up"),
+    ("impostor_species_down", "Down"),
+    ("impostor_species_electron", "Electron"),
+    ("impostor_species_gluon", "Gluon"),
+];
+
+/// Impostor shape buttons for the selected species: (near button id, far button id, shape).
+const IMPOSTOR_SHAPE_BUTTONS: [(&str, &str, ImpostorShape); 3] = [
+    (
+        "impostor_near_sphere",
+        "impostor_far_sphere",
+        ImpostorShape::Sphere,
+    ),
+    (
+        "impostor_near_glow",
+        "impostor_far_glow",
+        ImpostorShape::SoftGlow,
+    ),
+    (
+        "impostor_near_point",
+        "impostor_far_point",
+        ImpostorShape::Point,
+    ),
+];
+
+/// Recording output buttons: (button id, label, format).
+const RECORDING_FORMAT_BUTTONS: [(&str, &str, RecordingFormat); 3] = [
+    ("record_format_png", "PNG", RecordingFormat::PngSequence),
+    ("record_format_y4m", "y4m", RecordingFormat::Y4m),
+    ("record_format_ffmpeg", "ffmpeg", RecordingFormat::Ffmpeg),
+];
+
+/// Minimap placement buttons: (button id, label, corner).
+const MINIMAP_CORNER_BUTTONS: [(&str, &str, MinimapCorner); 4] = [
+    ("minimap_top_left", "TL", MinimapCorner::TopLeft),
+    ("minimap_top_right", "TR", MinimapCorner::TopRight),
+    ("minimap_bottom_left", "BL", MinimapCorner::BottomLeft),
+    ("minimap_bottom_right", "BR", MinimapCorner::BottomRight),
+];
+
+/// Unique species pairs of the (symmetric) coupling matrix: (label, slider id, value id, a, b).
+const COUPLING_PAIRS: [(&str, &str, &str, ParticleType, ParticleType); 10] = [
+    (
+        "up - up",
+        "phys_coupling_uu",
+        "phys_coupling_uu_value",
+        ParticleType::QuarkUp,
+        ParticleType::QuarkUp,
+    ),
+    (
+        "up - down",
+        "phys_coupling_ud",
+        "phys_coupling_ud_value",
+        ParticleType::QuarkUp,
+        ParticleType::QuarkDown,
+    ),
+    (
+        "up - e",
+        "phys_coupling_ue",
+        "phys_coupling_ue_value",
+        ParticleType::QuarkUp,
+        ParticleType::Electron,
+    ),
+    (
+        "up - gluon",
+        "phys_coupling_ug",
+        "phys_coupling_ug_value",
+        ParticleType::QuarkUp,
+        ParticleType::Gluon,
+    ),
+    (
+        "down - down",
+        "phys_coupling_dd",
+        "phys_coupling_dd_value",
+        ParticleType::QuarkDown,
+        ParticleType::QuarkDown,
+    ),
+    (
+        "down - e",
+        "phys_coupling_de",
+        "phys_coupling_de_value",
+        ParticleType::QuarkDown,
+        ParticleType::Electron,
+    ),
+    (
+        "down - gluon",
+        "phys_coupling_dg",
+        "phys_coupling_dg_value",
+        ParticleType::QuarkDown,
+        ParticleType::Gluon,
+    ),
+    (
+        "e - e",
+        "phys_coupling_ee",
+        "phys_coupling_ee_value",
+        ParticleType::Electron,
+        ParticleType::Electron,
+    ),
+    (
+        "e - gluon",
+        "phys_coupling_eg",
+        "phys_coupling_eg_value",
+        ParticleType::Electron,
+        ParticleType::Gluon,
+    ),
+    (
+        "gluon - gluon",
+        "phys_coupling_gg",
+        "phys_coupling_gg_value",
+        ParticleType::Gluon,
+        ParticleType::Gluon,
+    ),
+];
+
+/// Entity under the cursor, decoded by the app from the hover pick readback
+#[derive(Clone, Copy, Debug)]
+pub enum HoverInfo {
+    Particle {
+        particle_type: ParticleType,
+        /// Charge in units of e
+        charge: f32,
+        /// Vacuum fluctuation partner that has not become real yet
+        virtual_pair: bool,
+    },
+    Hadron {
+        type_id: u32,
+    },
+    Nucleus,
+}
+
+impl HoverInfo {
+    /// Short name for screen-space labels ("Proton", "Anti-up quark")
+    pub fn label(&self) -> String {
+        match *self {
+            HoverInfo::Particle {
+                particle_type,
+                charge,
+                ..
+            } => {
+                let name = match particle_type {
+                    ParticleType::QuarkUp => "Up quark",
+                    ParticleType::QuarkDown => "Down quark",
+                    ParticleType::Electron => "Electron",
+                    ParticleType::Gluon => "Gluon",
+                    ParticleType::Proton => "Proton",
+                    ParticleType::Neutron => "Neutron",
+                };
+                let anti = match particle_type {
+                    ParticleType::QuarkUp => charge < 0.0,
+                    ParticleType::QuarkDown | ParticleType::Electron => charge > 0.0,
+                    _ => false,
+                };
+                if anti {
+                    format!("Anti-{}", name.to_lowercase())
+                } else {
+                    name.to_string()
+                }
+            }
+            HoverInfo::Hadron { type_id } => match type_id {
+                1 => "Proton",
+                2 => "Neutron",
+                0 => "Meson",
+                _ => "Baryon",
+            }
+            .to_string(),
+            HoverInfo::Nucleus => "Nucleus".to_string(),
+        }
+    }
+}
+
+/// Nuclide label for a nucleus ("He-4")
+pub fn nucleus_label(atomic_number: u32, nucleon_count: u32) -> String {
+    format!("{}-{}", element_symbol(atomic_number), nucleon_count)
+}
+
+/// Text tag for an entity in the 3D view, positioned by the app every frame
+#[derive(Clone, Debug)]
+pub struct EntityLabel {
+    pub text: String,
+    /// Bottom-left corner of the tag in surface pixels (y down); the leader line ends here
+    pub tag: [f32; 2],
+}
+
 /// UI runtime state owned by the app.
 ///
 /// This remains the single source of truth for UI-exposed values during the migration.
@@ -32,6 +315,56 @@ pub struct UiState {
     pub neutron_count: u32,
     pub other_hadron_count: u32,
 
+    // Diagnostics histograms (read back every few frames)
+    pub histograms: Histograms,
+    // GPU time per render pass (read back every few frames; empty without timestamp queries)
+    pub gpu_timings: Vec<PassTiming>,
+    pub gpu_timings_supported: bool,
+    // Charge/mass map of free particles + hadrons (read back every few frames)
+    pub charge_mass: ChargeMassMap,
+    // Cumulative photon emission spectrum (read back every few frames)
+    pub spectrum: Spectrum,
+    // Tracked cohort (read back every few frames; all zero until something is tagged)
+    pub cohort: CohortStats,
+    // Cumulative boundary escapes per species (read back every few frames)
+    pub escapes: EscapeCounts,
+    pub cohort_tag: u32,
+    pub tag_radius: f32,
+    pub tag_requested: bool,
+
+    // Coarse-graining LOD (the app keeps the camera position in sync every frame)
+    pub coarse_params: CoarseParams,
+    // Super-particle counts (read back every few frames)
+    pub coarse_stats: CoarseStats,
+    pub clusters: ClusterStats,
+    // Selection set (Shift + Left Click): entity count and stats over the covered particles
+    pub selection_set_len: usize,
+    pub selection_set: SelectionSetStats,
+    // Hover tooltip (None while nothing is under the cursor)
+    pub hover: Option<HoverInfo>,
+    // Screen-space tags for the selected/hovered entities (the app projects them every frame)
+    pub show_labels: bool,
+    /// Orthographic instead of perspective projection (the camera eases between them)
+    pub orthographic: bool,
+    /// Free-fly (WASD + mouse-look) instead of orbit camera controls
+    pub free_fly: bool,
+    /// Draw the scene in the rest frame of the followed hadron or nucleus
+    pub rest_frame: bool,
+    pub labels: Vec<EntityLabel>,
+
+    // Measurement tool (clicks pick up to three entities while on; the app resolves them every
+    // frame and mirrors the results here)
+    pub measure_mode: bool,
+    pub measure_clear_requested: bool,
+    pub measure_readout: Vec<String>,
+
+    // Beam experiment (the app owns the running experiment and mirrors its progress here)
+    pub beam_config: BeamConfig,
+    pub beam_fire_requested: bool,
+    pub beam_stop_requested: bool,
+    pub beam_progress: Option<(u32, u32)>, // (current event, event count) while running
+    pub beam_events: Vec<BeamEventStats>,
+
     // Selected nucleus info (for atom card UI)
     pub selected_nucleus_atomic_number: Option<u32>, // Z (proton count / type_id)
     pub selected_nucleus_proton_count: Option<u32>,
@@ -43,22 +376,73 @@ pub struct UiState {
     pub show_shells: bool,
     pub show_bonds: bool,
     pub show_nuclei: bool,
+    pub show_photons: bool,
+    /// Composite hadron/nucleus shells with weighted blended OIT instead of in draw order
+    pub oit_shells: bool,
+    pub show_trails: bool,
+    /// Trail samples per particle (2..=64)
+    pub trail_length: u32,
+    pub show_velocity_arrows: bool,
+    /// Velocity arrow length per unit speed
+    pub velocity_arrow_scale: f32,
+    pub show_force_field: bool,
+    /// Half size of the force field slice (world units)
+    pub force_field_extent: f32,
+    /// Reference grid in the XZ plane (cells of one scale bar length)
+    pub show_grid: bool,
+    /// X/Y/Z axes through the world origin
+    pub show_axes: bool,
+    pub show_scale_bar: bool,
+    /// Overhead overview inset with the camera frustum
+    pub show_minimap: bool,
+    pub minimap_corner: MinimapCorner,
+    /// Current scale bar (set by the app every frame)
+    pub scale_bar: Option<ScaleBar>,
+    pub bloom_enabled: bool,
+    pub bloom_intensity: f32,
+    /// HDR particle brightness per unit kinetic energy
+    pub energy_glow: f32,
+    /// MSAA sample count for the 3D scene (1 = off)
+    pub msaa_samples: u32,
+    /// Sample counts the adapter supports (set by the app at startup)
+    pub msaa_sample_counts: Vec<u32>,
+    /// 3D scene resolution in percent of the window (upscaled before the GUI)
+    pub render_scale: u32,
+    /// Surface present mode (the app reconfigures the surface when it changes)
+    pub present_mode: wgpu::PresentMode,
+    /// Present modes the surface supports (set by the app at startup)
+    pub present_modes: Vec<wgpu::PresentMode>,
+    /// Screenshots render at this multiple of the window resolution
+    pub screenshot_scale: u32,
+    pub screenshot_requested: bool,
+    /// Panels live in a separate inspector window (set by the app)
+    pub inspector_detached: bool,
+    pub inspector_toggle_requested: bool,
+    pub recording_format: RecordingFormat,
+    pub recording_toggle_requested: bool,
+    pub recording_frames: Option<u32>, // Frames recorded so far while recording
+
+    // Camera bookmarks and fly-throughs (the app owns the poses and mirrors their names here)
+    pub camera_bookmarks: Vec<String>,
+    pub camera_bookmark_save_requested: bool,
+    pub camera_bookmark_clear_requested: bool,
+    pub camera_bookmark_goto: Option<usize>,
+    pub camera_flythrough_toggle_requested: bool,
+    pub camera_flythrough_progress: Option<f32>, // 0..1 while a fly-through runs
     pub is_paused: bool,
     pub step_one_frame: bool,
     pub steps_to_play: u32,
     pub steps_remaining: u32,
 
     // LOD controls
-    pub lod_shell_fade_start: f32,
-    pub lod_shell_fade_end: f32,
-    pub lod_bound_hadron_fade_start: f32,
-    pub lod_bound_hadron_fade_end: f32,
-    pub lod_bond_fade_start: f32,
-    pub lod_bond_fade_end: f32,
-    pub lod_quark_fade_start: f32,
-    pub lod_quark_fade_end: f32,
-    pub lod_nucleus_fade_start: f32,
-    pub lod_nucleus_fade_end: f32,
+    /// GPU culling of off-screen and sub-pixel particles
+    pub cull_particles: bool,
+    /// Draw dense screen tiles as one aggregated splat each
+    pub density_splats: bool,
+    /// Particles per 16 px tile from which a tile is aggregated
+    pub density_splat_threshold: u32,
+    /// Fade ranges and particle impostors
+    pub lod: LodSettings,
 }
 
 impl Default for UiState {
@@ -73,6 +457,38 @@ impl Default for UiState {
             neutron_count: 0,
             other_hadron_count: 0,
 
+            histograms: Histograms::default(),
+            gpu_timings: Vec::new(),
+            gpu_timings_supported: false,
+            charge_mass: ChargeMassMap::default(),
+            spectrum: Spectrum::default(),
+            cohort: CohortStats::default(),
+            escapes: EscapeCounts::default(),
+            cohort_tag: 0,
+            tag_radius: 5.0,
+            tag_requested: false,
+
+            coarse_params: CoarseParams::default(),
+            coarse_stats: CoarseStats::default(),
+            clusters: ClusterStats::default(),
+            selection_set_len: 0,
+            selection_set: SelectionSetStats::default(),
+            hover: None,
+            show_labels: true,
+            orthographic: false,
+            free_fly: false,
+            rest_frame: false,
+            labels: Vec::new(),
+            measure_mode: false,
+            measure_clear_requested: false,
+            measure_readout: Vec::new(),
+
+            beam_config: BeamConfig::default(),
+            beam_fire_requested: false,
+            beam_stop_requested: false,
+            beam_progress: None,
+            beam_events: Vec::new(),
+
             selected_nucleus_atomic_number: None,
             selected_nucleus_proton_count: None,
             selected_nucleus_neutron_count: None,
@@ -83,21 +499,51 @@ impl Default for UiState {
             show_shells: true,
             show_bonds: true,
             show_nuclei: true,
+            show_photons: true,
+            oit_shells: true,
+            show_trails: false,
+            trail_length: 32,
+            show_velocity_arrows: false,
+            velocity_arrow_scale: 1.0,
+            show_force_field: false,
+            force_field_extent: 50.0,
+            show_grid: false,
+            show_axes: false,
+            show_scale_bar: false,
+            show_minimap: false,
+            minimap_corner: MinimapCorner::default(),
+            scale_bar: None,
+            bloom_enabled: true,
+            bloom_intensity: 0.6,
+            energy_glow: 20.0,
+            msaa_samples: 4,
+            render_scale: 100,
+            present_mode: wgpu::PresentMode::AutoNoVsync,
+            present_modes: Vec::new(),
+            msaa_sample_counts: vec![1, 4],
+            screenshot_scale: 1,
+            screenshot_requested: false,
+            inspector_detached: false,
+            inspector_toggle_requested: false,
+            recording_format: RecordingFormat::default(),
+            recording_toggle_requested: false,
+            recording_frames: None,
+
+            camera_bookmarks: Vec::new(),
+            camera_bookmark_save_requested: false,
+            camera_bookmark_clear_requested: false,
+            camera_bookmark_goto: None,
+            camera_flythrough_toggle_requested: false,
+            camera_flythrough_progress: None,
             is_paused: false,
             step_one_frame: false,
             steps_to_play: 1,
             steps_remaining: 0,
 
-            lod_shell_fade_start: 10.0,
-            lod_shell_fade_end: 30.0,
-            lod_bound_hadron_fade_start: 40.0,
-            lod_bound_hadron_fade_end: 70.0,
-            lod_bond_fade_start: 10.0,
-            lod_bond_fade_end: 30.0,
-            lod_quark_fade_start: 10.0,
-            lod_quark_fade_end: 30.0,
-            lod_nucleus_fade_start: 40.0, // Nuclei appear further out than hadrons
-            lod_nucleus_fade_end: 70.0,
+            cull_particles: true,
+            density_splats: true,
+            density_splat_threshold: 64,
+            lod: LodSettings::default(),
         }
     }
 }
@@ -124,12 +570,44 @@ pub struct Gui {
     physics_panel_expanded: bool,
     time_panel_expanded: bool,
     atom_card_expanded: bool,
+    // Groups inside the physics panel (`PHYSICS_SECTIONS`)
+    physics_sections_expanded: [bool; PHYSICS_SECTIONS.len()],
 
     // Per-widget state (these are required for interactive widgets to behave correctly)
     render_shells: bool,
     render_bonds: bool,
     render_nuclei: bool,
-
+    render_photons: bool,
+    render_oit_shells: bool,
+    render_labels: bool,
+    render_orthographic: bool,
+    render_free_fly: bool,
+    render_rest_frame: bool,
+    render_measure: bool,
+    measure_readout: Vec<String>,
+    render_trails: bool,
+    render_velocity_arrows: bool,
+    render_force_field: bool,
+    render_grid: bool,
+    render_axes: bool,
+    render_scale_bar: bool,
+    render_minimap: bool,
+    minimap_corner: MinimapCorner,
+    render_bloom: bool,
+    msaa_samples: u32,
+    render_scale: u32,
+    present_mode: wgpu::PresentMode,
+    present_modes: Vec<wgpu::PresentMode>,
+    msaa_sample_counts: Vec<u32>,
+    screenshot_scale: u32,
+    inspector_detached: bool,
+    recording_format: RecordingFormat,
+    recording_frames: Option<u32>,
+    camera_bookmarks: Vec<String>,
+    camera_flythrough_progress: Option<f32>,
+
+    render_cull: bool,
+    render_density_splats: bool,
     lod_shell_fade_start: f32,
     lod_shell_fade_end: f32,
     lod_bound_hadron_fade_start: f32,
@@ -140,6 +618,19 @@ pub struct Gui {
     lod_quark_fade_end: f32,
     lod_nucleus_fade_start: f32,
     lod_nucleus_fade_end: f32,
+    lod_impostor_fade_start: f32,
+    lod_impostor_fade_end: f32,
+    /// Species whose impostors the near/far buttons edit (index into `impostors`)
+    impostor_species: usize,
+    impostors: [SpeciesImpostor; IMPOSTOR_SPECIES],
+    cohort_radius: f32,
+    trail_length: f32,
+    density_splat_threshold: f32,
+    velocity_arrow_scale: f32,
+    force_field_extent: f32,
+    bloom_intensity: f32,
+    energy_glow: f32,
+    coarse: CoarseParams,
 
     // slider_with_value per-slider input state
     lod_shell_fade_start_text: String,
@@ -227,11 +718,23 @@ pub struct Gui {
     phys_integration_nucleon_damping_focused: bool,
     phys_integration_nucleon_damping_drag_accumulator: f32,
 
-    phys_nucleon_binding_strength_text: String,
-    phys_nucleon_binding_strength_cursor: usize,
-    phys_nucleon_binding_strength_selection: Option<(usize, usize)>,
-    phys_nucleon_binding_strength_focused: bool,
-    phys_nucleon_binding_strength_drag_accumulator: f32,
+    phys_residual_strength_text: String,
+    phys_residual_strength_cursor: usize,
+    phys_residual_strength_selection: Option<(usize, usize)>,
+    phys_residual_strength_focused: bool,
+    phys_residual_strength_drag_accumulator: f32,
+
+    phys_residual_range_text: String,
+    phys_residual_range_cursor: usize,
+    phys_residual_range_selection: Option<(usize, usize)>,
+    phys_residual_range_focused: bool,
+    phys_residual_range_drag_accumulator: f32,
+
+    phys_residual_cutoff_text: String,
+    phys_residual_cutoff_cursor: usize,
+    phys_residual_cutoff_selection: Option<(usize, usize)>,
+    phys_residual_cutoff_focused: bool,
+    phys_residual_cutoff_drag_accumulator: f32,
 
     phys_nucleon_binding_range_text: String,
     phys_nucleon_binding_range_cursor: usize,
@@ -287,6 +790,99 @@ pub struct Gui {
     phys_hadron_conf_strength_mult_focused: bool,
     phys_hadron_conf_strength_mult_drag_accumulator: f32,
 
+    phys_string_tension_text: String,
+    phys_string_tension_cursor: usize,
+    phys_string_tension_selection: Option<(usize, usize)>,
+    phys_string_tension_focused: bool,
+    phys_string_tension_drag_accumulator: f32,
+
+    phys_string_pair_threshold_text: String,
+    phys_string_pair_threshold_cursor: usize,
+    phys_string_pair_threshold_selection: Option<(usize, usize)>,
+    phys_string_pair_threshold_focused: bool,
+    phys_string_pair_threshold_drag_accumulator: f32,
+
+    phys_boundary_radius_text: String,
+    phys_boundary_radius_cursor: usize,
+    phys_boundary_radius_selection: Option<(usize, usize)>,
+    phys_boundary_radius_focused: bool,
+    phys_boundary_radius_drag_accumulator: f32,
+
+    phys_boundary_temperature_text: String,
+    phys_boundary_temperature_cursor: usize,
+    phys_boundary_temperature_selection: Option<(usize, usize)>,
+    phys_boundary_temperature_focused: bool,
+    phys_boundary_temperature_drag_accumulator: f32,
+
+    phys_decay_meson_lifetime_text: String,
+    phys_decay_meson_lifetime_cursor: usize,
+    phys_decay_meson_lifetime_selection: Option<(usize, usize)>,
+    phys_decay_meson_lifetime_focused: bool,
+    phys_decay_meson_lifetime_drag_accumulator: f32,
+
+    phys_decay_baryon_lifetime_text: String,
+    phys_decay_baryon_lifetime_cursor: usize,
+    phys_decay_baryon_lifetime_selection: Option<(usize, usize)>,
+    phys_decay_baryon_lifetime_focused: bool,
+    phys_decay_baryon_lifetime_drag_accumulator: f32,
+
+    phys_degeneracy_strength_text: String,
+    phys_degeneracy_strength_cursor: usize,
+    phys_degeneracy_strength_selection: Option<(usize, usize)>,
+    phys_degeneracy_strength_focused: bool,
+    phys_degeneracy_strength_drag_accumulator: f32,
+
+    phys_degeneracy_radius_text: String,
+    phys_degeneracy_radius_cursor: usize,
+    phys_degeneracy_radius_selection: Option<(usize, usize)>,
+    phys_degeneracy_radius_focused: bool,
+    phys_degeneracy_radius_drag_accumulator: f32,
+
+    phys_degeneracy_threshold_text: String,
+    phys_degeneracy_threshold_cursor: usize,
+    phys_degeneracy_threshold_selection: Option<(usize, usize)>,
+    phys_degeneracy_threshold_focused: bool,
+    phys_degeneracy_threshold_drag_accumulator: f32,
+    phys_color_flow_rate_text: String,
+    phys_color_flow_rate_cursor: usize,
+    phys_color_flow_rate_selection: Option<(usize, usize)>,
+    phys_color_flow_rate_focused: bool,
+    phys_color_flow_rate_drag_accumulator: f32,
+    phys_color_flow_range_text: String,
+    phys_color_flow_range_cursor: usize,
+    phys_color_flow_range_selection: Option<(usize, usize)>,
+    phys_color_flow_range_focused: bool,
+    phys_color_flow_range_drag_accumulator: f32,
+    phys_vacuum_rate_text: String,
+    phys_vacuum_rate_cursor: usize,
+    phys_vacuum_rate_selection: Option<(usize, usize)>,
+    phys_vacuum_rate_focused: bool,
+    phys_vacuum_rate_drag_accumulator: f32,
+    phys_vacuum_lifetime_text: String,
+    phys_vacuum_lifetime_cursor: usize,
+    phys_vacuum_lifetime_selection: Option<(usize, usize)>,
+    phys_vacuum_lifetime_focused: bool,
+    phys_vacuum_lifetime_drag_accumulator: f32,
+    phys_vacuum_clearance_text: String,
+    phys_vacuum_clearance_cursor: usize,
+    phys_vacuum_clearance_selection: Option<(usize, usize)>,
+    phys_vacuum_clearance_focused: bool,
+    phys_vacuum_clearance_drag_accumulator: f32,
+
+    // Coupling matrix editor: selected force + per-pair slider state (indexed like COUPLING_PAIRS)
+    coupling_force: Force,
+    coupling_text: [String; 10],
+    coupling_cursor: [usize; 10],
+    coupling_selection: [Option<(usize, usize)>; 10],
+    coupling_focused: [bool; 10],
+    coupling_drag_accumulator: [f32; 10],
+
+    force_softening_text: String,
+    force_softening_cursor: usize,
+    force_softening_selection: Option<(usize, usize)>,
+    force_softening_focused: bool,
+    force_softening_drag_accumulator: f32,
+
     lod_shell_fade_end_text: String,
     lod_shell_fade_end_cursor: usize,
     lod_shell_fade_end_selection: Option<(usize, usize)>,
@@ -341,6 +937,83 @@ pub struct Gui {
     lod_nucleus_fade_end_focused: bool,
     lod_nucleus_fade_end_drag_accumulator: f32,
 
+    lod_impostor_fade_start_text: String,
+    lod_impostor_fade_start_cursor: usize,
+    lod_impostor_fade_start_selection: Option<(usize, usize)>,
+    lod_impostor_fade_start_focused: bool,
+    lod_impostor_fade_start_drag_accumulator: f32,
+
+    lod_impostor_fade_end_text: String,
+    lod_impostor_fade_end_cursor: usize,
+    lod_impostor_fade_end_selection: Option<(usize, usize)>,
+    lod_impostor_fade_end_focused: bool,
+    lod_impostor_fade_end_drag_accumulator: f32,
+
+    cohort_radius_text: String,
+    cohort_radius_cursor: usize,
+    cohort_radius_selection: Option<(usize, usize)>,
+    cohort_radius_focused: bool,
+    cohort_radius_drag_accumulator: f32,
+
+    trail_length_text: String,
+    trail_length_cursor: usize,
+    trail_length_selection: Option<(usize, usize)>,
+    trail_length_focused: bool,
+    trail_length_drag_accumulator: f32,
+    density_splat_threshold_text: String,
+    density_splat_threshold_cursor: usize,
+    density_splat_threshold_selection: Option<(usize, usize)>,
+    density_splat_threshold_focused: bool,
+    density_splat_threshold_drag_accumulator: f32,
+
+    velocity_arrow_scale_text: String,
+    velocity_arrow_scale_cursor: usize,
+    velocity_arrow_scale_selection: Option<(usize, usize)>,
+    velocity_arrow_scale_focused: bool,
+    velocity_arrow_scale_drag_accumulator: f32,
+
+    force_field_extent_text: String,
+    force_field_extent_cursor: usize,
+    force_field_extent_selection: Option<(usize, usize)>,
+    force_field_extent_focused: bool,
+    force_field_extent_drag_accumulator: f32,
+
+    bloom_intensity_text: String,
+    bloom_intensity_cursor: usize,
+    bloom_intensity_selection: Option<(usize, usize)>,
+    bloom_intensity_focused: bool,
+    bloom_intensity_drag_accumulator: f32,
+
+    energy_glow_text: String,
+    energy_glow_cursor: usize,
+    energy_glow_selection: Option<(usize, usize)>,
+    energy_glow_focused: bool,
+    energy_glow_drag_accumulator: f32,
+
+    coarse_merge_distance_text: String,
+    coarse_merge_distance_cursor: usize,
+    coarse_merge_distance_selection: Option<(usize, usize)>,
+    coarse_merge_distance_focused: bool,
+    coarse_merge_distance_drag_accumulator: f32,
+
+    coarse_split_distance_text: String,
+    coarse_split_distance_cursor: usize,
+    coarse_split_distance_selection: Option<(usize, usize)>,
+    coarse_split_distance_focused: bool,
+    coarse_split_distance_drag_accumulator: f32,
+
+    coarse_merge_radius_text: String,
+    coarse_merge_radius_cursor: usize,
+    coarse_merge_radius_selection: Option<(usize, usize)>,
+    coarse_merge_radius_focused: bool,
+    coarse_merge_radius_drag_accumulator: f32,
+
+    coarse_max_members_text: String,
+    coarse_max_members_cursor: usize,
+    coarse_max_members_selection: Option<(usize, usize)>,
+    coarse_max_members_focused: bool,
+    coarse_max_members_drag_accumulator: f32,
+
     physics_dt_text: String,
     physics_dt_cursor: usize,
     physics_dt_selection: Option<(usize, usize)>,
@@ -353,6 +1026,20 @@ pub struct Gui {
     time_steps_to_play_focused: bool,
     time_steps_to_play_drag_accumulator: f32,
 
+    beam_energy: f32,
+    beam_energy_text: String,
+    beam_energy_cursor: usize,
+    beam_energy_selection: Option<(usize, usize)>,
+    beam_energy_focused: bool,
+    beam_energy_drag_accumulator: f32,
+
+    beam_impact: f32,
+    beam_impact_text: String,
+    beam_impact_cursor: usize,
+    beam_impact_selection: Option<(usize, usize)>,
+    beam_impact_focused: bool,
+    beam_impact_drag_accumulator: f32,
+
     is_paused: bool,
     steps_to_play: f32,
 
@@ -381,12 +1068,43 @@ impl Gui {
             physics_panel_expanded: false,
             time_panel_expanded: true,
             atom_card_expanded: true,
+            physics_sections_expanded: std::array::from_fn(|i| i == 0),
 
             // Defaults mirror UiState::default() so the UI behaves predictably.
             render_shells: true,
             render_bonds: true,
             render_nuclei: true,
-
+            render_photons: true,
+            render_oit_shells: true,
+            render_labels: true,
+            render_orthographic: false,
+            render_free_fly: false,
+            render_rest_frame: false,
+            render_measure: false,
+            measure_readout: Vec::new(),
+            render_trails: false,
+            render_velocity_arrows: false,
+            render_force_field: false,
+            render_grid: false,
+            render_axes: false,
+            render_scale_bar: false,
+            render_minimap: false,
+            minimap_corner: MinimapCorner::default(),
+            render_bloom: true,
+            msaa_samples: 4,
+            render_scale: 100,
+            present_mode: wgpu::PresentMode::AutoNoVsync,
+            present_modes: Vec::new(),
+            msaa_sample_counts: vec![1, 4],
+            screenshot_scale: 1,
+            inspector_detached: false,
+            recording_format: RecordingFormat::default(),
+            recording_frames: None,
+            camera_bookmarks: Vec::new(),
+            camera_flythrough_progress: None,
+
+            render_cull: true,
+            render_density_splats: true,
             lod_shell_fade_start: 10.0,
             lod_shell_fade_end: 30.0,
             lod_bound_hadron_fade_start: 40.0,
@@ -397,6 +1115,18 @@ impl Gui {
             lod_quark_fade_end: 30.0,
             lod_nucleus_fade_start: 40.0,
             lod_nucleus_fade_end: 70.0,
+            lod_impostor_fade_start: 80.0,
+            lod_impostor_fade_end: 160.0,
+            impostor_species: 0,
+            impostors: [SpeciesImpostor::default(); IMPOSTOR_SPECIES],
+            cohort_radius: 5.0,
+            trail_length: 32.0,
+            density_splat_threshold: 64.0,
+            velocity_arrow_scale: 1.0,
+            force_field_extent: 50.0,
+            bloom_intensity: 0.6,
+            energy_glow: 20.0,
+            coarse: CoarseParams::default(),
 
             lod_shell_fade_start_text: String::new(),
             lod_shell_fade_start_cursor: 0,
@@ -458,6 +1188,83 @@ impl Gui {
             lod_nucleus_fade_end_focused: false,
             lod_nucleus_fade_end_drag_accumulator: 70.0,
 
+            lod_impostor_fade_start_text: String::new(),
+            lod_impostor_fade_start_cursor: 0,
+            lod_impostor_fade_start_selection: None,
+            lod_impostor_fade_start_focused: false,
+            lod_impostor_fade_start_drag_accumulator: 80.0,
+
+            lod_impostor_fade_end_text: String::new(),
+            lod_impostor_fade_end_cursor: 0,
+            lod_impostor_fade_end_selection: None,
+            lod_impostor_fade_end_focused: false,
+            lod_impostor_fade_end_drag_accumulator: 160.0,
+
+            cohort_radius_text: String::new(),
+            cohort_radius_cursor: 0,
+            cohort_radius_selection: None,
+            cohort_radius_focused: false,
+            cohort_radius_drag_accumulator: 5.0,
+
+            trail_length_text: String::new(),
+            trail_length_cursor: 0,
+            trail_length_selection: None,
+            trail_length_focused: false,
+            trail_length_drag_accumulator: 32.0,
+            density_splat_threshold_text: String::new(),
+            density_splat_threshold_cursor: 0,
+            density_splat_threshold_selection: None,
+            density_splat_threshold_focused: false,
+            density_splat_threshold_drag_accumulator: 64.0,
+
+            velocity_arrow_scale_text: String::new(),
+            velocity_arrow_scale_cursor: 0,
+            velocity_arrow_scale_selection: None,
+            velocity_arrow_scale_focused: false,
+            velocity_arrow_scale_drag_accumulator: 1.0,
+
+            force_field_extent_text: String::new(),
+            force_field_extent_cursor: 0,
+            force_field_extent_selection: None,
+            force_field_extent_focused: false,
+            force_field_extent_drag_accumulator: 50.0,
+
+            bloom_intensity_text: String::new(),
+            bloom_intensity_cursor: 0,
+            bloom_intensity_selection: None,
+            bloom_intensity_focused: false,
+            bloom_intensity_drag_accumulator: 0.6,
+
+            energy_glow_text: String::new(),
+            energy_glow_cursor: 0,
+            energy_glow_selection: None,
+            energy_glow_focused: false,
+            energy_glow_drag_accumulator: 20.0,
+
+            coarse_merge_distance_text: String::new(),
+            coarse_merge_distance_cursor: 0,
+            coarse_merge_distance_selection: None,
+            coarse_merge_distance_focused: false,
+            coarse_merge_distance_drag_accumulator: 0.0,
+
+            coarse_split_distance_text: String::new(),
+            coarse_split_distance_cursor: 0,
+            coarse_split_distance_selection: None,
+            coarse_split_distance_focused: false,
+            coarse_split_distance_drag_accumulator: 0.0,
+
+            coarse_merge_radius_text: String::new(),
+            coarse_merge_radius_cursor: 0,
+            coarse_merge_radius_selection: None,
+            coarse_merge_radius_focused: false,
+            coarse_merge_radius_drag_accumulator: 0.0,
+
+            coarse_max_members_text: String::new(),
+            coarse_max_members_cursor: 0,
+            coarse_max_members_selection: None,
+            coarse_max_members_focused: false,
+            coarse_max_members_drag_accumulator: 0.0,
+
             physics_dt_text: String::new(),
             physics_dt_cursor: 0,
             physics_dt_selection: None,
@@ -543,11 +1350,23 @@ impl Gui {
             phys_integration_nucleon_damping_focused: false,
             phys_integration_nucleon_damping_drag_accumulator: 0.0,
 
-            phys_nucleon_binding_strength_text: String::new(),
-            phys_nucleon_binding_strength_cursor: 0,
-            phys_nucleon_binding_strength_selection: None,
-            phys_nucleon_binding_strength_focused: false,
-            phys_nucleon_binding_strength_drag_accumulator: 0.0,
+            phys_residual_strength_text: String::new(),
+            phys_residual_strength_cursor: 0,
+            phys_residual_strength_selection: None,
+            phys_residual_strength_focused: false,
+            phys_residual_strength_drag_accumulator: 0.0,
+
+            phys_residual_range_text: String::new(),
+            phys_residual_range_cursor: 0,
+            phys_residual_range_selection: None,
+            phys_residual_range_focused: false,
+            phys_residual_range_drag_accumulator: 0.0,
+
+            phys_residual_cutoff_text: String::new(),
+            phys_residual_cutoff_cursor: 0,
+            phys_residual_cutoff_selection: None,
+            phys_residual_cutoff_focused: false,
+            phys_residual_cutoff_drag_accumulator: 0.0,
 
             phys_nucleon_binding_range_text: String::new(),
             phys_nucleon_binding_range_cursor: 0,
@@ -603,12 +1422,118 @@ impl Gui {
             phys_hadron_conf_strength_mult_focused: false,
             phys_hadron_conf_strength_mult_drag_accumulator: 0.0,
 
+            phys_string_tension_text: String::new(),
+            phys_string_tension_cursor: 0,
+            phys_string_tension_selection: None,
+            phys_string_tension_focused: false,
+            phys_string_tension_drag_accumulator: 0.0,
+
+            phys_string_pair_threshold_text: String::new(),
+            phys_string_pair_threshold_cursor: 0,
+            phys_string_pair_threshold_selection: None,
+            phys_string_pair_threshold_focused: false,
+            phys_string_pair_threshold_drag_accumulator: 0.0,
+
+            phys_boundary_radius_text: String::new(),
+            phys_boundary_radius_cursor: 0,
+            phys_boundary_radius_selection: None,
+            phys_boundary_radius_focused: false,
+            phys_boundary_radius_drag_accumulator: 0.0,
+
+            phys_boundary_temperature_text: String::new(),
+            phys_boundary_temperature_cursor: 0,
+            phys_boundary_temperature_selection: None,
+            phys_boundary_temperature_focused: false,
+            phys_boundary_temperature_drag_accumulator: 0.0,
+
+            phys_decay_meson_lifetime_text: String::new(),
+            phys_decay_meson_lifetime_cursor: 0,
+            phys_decay_meson_lifetime_selection: None,
+            phys_decay_meson_lifetime_focused: false,
+            phys_decay_meson_lifetime_drag_accumulator: 0.0,
+
+            phys_decay_baryon_lifetime_text: String::new(),
+            phys_decay_baryon_lifetime_cursor: 0,
+            phys_decay_baryon_lifetime_selection: None,
+            phys_decay_baryon_lifetime_focused: false,
+            phys_decay_baryon_lifetime_drag_accumulator: 0.0,
+
+            phys_degeneracy_strength_text: String::new(),
+            phys_degeneracy_strength_cursor: 0,
+            phys_degeneracy_strength_selection: None,
+            phys_degeneracy_strength_focused: false,
+            phys_degeneracy_strength_drag_accumulator: 0.0,
+
+            phys_degeneracy_radius_text: String::new(),
+            phys_degeneracy_radius_cursor: 0,
+            phys_degeneracy_radius_selection: None,
+            phys_degeneracy_radius_focused: false,
+            phys_degeneracy_radius_drag_accumulator: 0.0,
+
+            phys_degeneracy_threshold_text: String::new(),
+            phys_degeneracy_threshold_cursor: 0,
+            phys_degeneracy_threshold_selection: None,
+            phys_degeneracy_threshold_focused: false,
+            phys_degeneracy_threshold_drag_accumulator: 0.0,
+            phys_color_flow_rate_text: String::new(),
+            phys_color_flow_rate_cursor: 0,
+            phys_color_flow_rate_selection: None,
+            phys_color_flow_rate_focused: false,
+            phys_color_flow_rate_drag_accumulator: 0.0,
+            phys_color_flow_range_text: String::new(),
+            phys_color_flow_range_cursor: 0,
+            phys_color_flow_range_selection: None,
+            phys_color_flow_range_focused: false,
+            phys_color_flow_range_drag_accumulator: 0.0,
+            phys_vacuum_rate_text: String::new(),
+            phys_vacuum_rate_cursor: 0,
+            phys_vacuum_rate_selection: None,
+            phys_vacuum_rate_focused: false,
+            phys_vacuum_rate_drag_accumulator: 0.0,
+            phys_vacuum_lifetime_text: String::new(),
+            phys_vacuum_lifetime_cursor: 0,
+            phys_vacuum_lifetime_selection: None,
+            phys_vacuum_lifetime_focused: false,
+            phys_vacuum_lifetime_drag_accumulator: 0.0,
+            phys_vacuum_clearance_text: String::new(),
+            phys_vacuum_clearance_cursor: 0,
+            phys_vacuum_clearance_selection: None,
+            phys_vacuum_clearance_focused: false,
+            phys_vacuum_clearance_drag_accumulator: 0.0,
+
+            coupling_force: Force::Electromagnetic,
+            coupling_text: Default::default(),
+            coupling_cursor: [0; 10],
+            coupling_selection: [None; 10],
+            coupling_focused: [false; 10],
+            coupling_drag_accumulator: [0.0; 10],
+
+            force_softening_text: String::new(),
+            force_softening_cursor: 0,
+            force_softening_selection: None,
+            force_softening_focused: false,
+            force_softening_drag_accumulator: 0.0,
+
             time_steps_to_play_text: String::new(),
             time_steps_to_play_cursor: 0,
             time_steps_to_play_selection: None,
             time_steps_to_play_focused: false,
             time_steps_to_play_drag_accumulator: 1.0,
 
+            beam_energy: 1.0,
+            beam_energy_text: String::new(),
+            beam_energy_cursor: 0,
+            beam_energy_selection: None,
+            beam_energy_focused: false,
+            beam_energy_drag_accumulator: 1.0,
+
+            beam_impact: 0.0,
+            beam_impact_text: String::new(),
+            beam_impact_cursor: 0,
+            beam_impact_selection: None,
+            beam_impact_focused: false,
+            beam_impact_drag_accumulator: 0.0,
+
             is_paused: false,
             steps_to_play: 1.0,
 
@@ -633,6 +1558,12 @@ impl Gui {
         self.ui_consumed_pointer
     }
 
+    /// Whether a value field has keyboard focus (as of the last `build`); the app's keyboard
+    /// shortcuts stand down while it does.
+    pub fn wants_keyboard(&self) -> bool {
+        self.event_dispatcher.focused_node().is_some()
+    }
+
     /// Build a minimal UI overlay node tree.
     ///
     /// Note: this does not use a text measurer yet; sizes are explicit so it stays robust.
@@ -651,20 +1582,64 @@ impl Gui {
         self.render_shells = ui_state.show_shells;
         self.render_bonds = ui_state.show_bonds;
         self.render_nuclei = ui_state.show_nuclei;
-
-        self.lod_shell_fade_start = ui_state.lod_shell_fade_start;
-        self.lod_shell_fade_end = ui_state.lod_shell_fade_end;
-        self.lod_bound_hadron_fade_start = ui_state.lod_bound_hadron_fade_start;
-        self.lod_bound_hadron_fade_end = ui_state.lod_bound_hadron_fade_end;
-        self.lod_bond_fade_start = ui_state.lod_bond_fade_start;
-        self.lod_bond_fade_end = ui_state.lod_bond_fade_end;
-        self.lod_quark_fade_start = ui_state.lod_quark_fade_start;
-        self.lod_quark_fade_end = ui_state.lod_quark_fade_end;
-        self.lod_nucleus_fade_start = ui_state.lod_nucleus_fade_start;
-        self.lod_nucleus_fade_end = ui_state.lod_nucleus_fade_end;
+        self.render_photons = ui_state.show_photons;
+        self.render_oit_shells = ui_state.oit_shells;
+        self.render_labels = ui_state.show_labels;
+        self.render_measure = ui_state.measure_mode;
+        self.measure_readout.clone_from(&ui_state.measure_readout);
+        self.render_orthographic = ui_state.orthographic;
+        self.render_free_fly = ui_state.free_fly;
+        self.render_rest_frame = ui_state.rest_frame;
+        self.render_trails = ui_state.show_trails;
+        self.render_velocity_arrows = ui_state.show_velocity_arrows;
+        self.render_force_field = ui_state.show_force_field;
+        self.render_grid = ui_state.show_grid;
+        self.render_axes = ui_state.show_axes;
+        self.render_scale_bar = ui_state.show_scale_bar;
+        self.render_minimap = ui_state.show_minimap;
+        self.minimap_corner = ui_state.minimap_corner;
+        self.render_bloom = ui_state.bloom_enabled;
+        self.msaa_samples = ui_state.msaa_samples;
+        self.render_scale = ui_state.render_scale;
+        self.present_mode = ui_state.present_mode;
+        self.present_modes.clone_from(&ui_state.present_modes);
+        self.msaa_sample_counts
+            .clone_from(&ui_state.msaa_sample_counts);
+        self.screenshot_scale = ui_state.screenshot_scale;
+        self.inspector_detached = ui_state.inspector_detached;
+        self.recording_format = ui_state.recording_format;
+        self.recording_frames = ui_state.recording_frames;
+        self.camera_bookmarks.clone_from(&ui_state.camera_bookmarks);
+        self.camera_flythrough_progress = ui_state.camera_flythrough_progress;
+
+        self.render_cull = ui_state.cull_particles;
+        self.render_density_splats = ui_state.density_splats;
+        self.density_splat_threshold = ui_state.density_splat_threshold as f32;
+        self.lod_shell_fade_start = ui_state.lod.shell.start;
+        self.lod_shell_fade_end = ui_state.lod.shell.end;
+        self.lod_bound_hadron_fade_start = ui_state.lod.bound_hadron.start;
+        self.lod_bound_hadron_fade_end = ui_state.lod.bound_hadron.end;
+        self.lod_bond_fade_start = ui_state.lod.bond.start;
+        self.lod_bond_fade_end = ui_state.lod.bond.end;
+        self.lod_quark_fade_start = ui_state.lod.quark.start;
+        self.lod_quark_fade_end = ui_state.lod.quark.end;
+        self.lod_nucleus_fade_start = ui_state.lod.nucleus.start;
+        self.lod_nucleus_fade_end = ui_state.lod.nucleus.end;
+        self.lod_impostor_fade_start = ui_state.lod.impostor.start;
+        self.lod_impostor_fade_end = ui_state.lod.impostor.end;
+        self.impostors = ui_state.lod.impostors;
+        self.cohort_radius = ui_state.tag_radius;
+        self.trail_length = ui_state.trail_length as f32;
+        self.velocity_arrow_scale = ui_state.velocity_arrow_scale;
+        self.force_field_extent = ui_state.force_field_extent;
+        self.bloom_intensity = ui_state.bloom_intensity;
+        self.energy_glow = ui_state.energy_glow;
+        self.coarse = ui_state.coarse_params;
 
         self.is_paused = ui_state.is_paused;
         self.steps_to_play = ui_state.steps_to_play as f32;
+        self.beam_energy = ui_state.beam_config.energy;
+        self.beam_impact = ui_state.beam_config.impact_parameter;
 
         self.physics_params_dirty = ui_state.physics_params_dirty;
 
@@ -673,42 +1648,49 @@ impl Gui {
         // Note: during this stage we still treat UiState as the source of truth. We *render* from
         // local widget state (so widgets can be interactive), then we apply events to UiState via
         // `apply_events_to_state(...)` below.
-        let mut root = Node::new()
-            .with_zoom(1.5)
-            .with_id("ui_root")
-            .with_layout_direction(Layout::Stack)
-            .with_width(Size::Fill)
-            .with_height(Size::Fill)
-            .with_padding(Spacing::all(Size::lpx(12.0)))
-            .with_children(vec![
-                // Statistics (top-left)
-                self.stats_panel(ui_state).with_place(Place::Alignment {
+        //
+        // With the inspector detached this window only holds the panels; the 3D window draws the
+        // overlays through `build_overlays`.
+        let detached = ui_state.inspector_detached;
+        let mut children = vec![
+            // Entity labels (behind the panels, anchored to the 3D view)
+            if detached {
+                Node::new()
+            } else {
+                Self::entity_labels(ui_state, window_size)
+            },
+            // Statistics (top-left)
+            self.stats_panel(ui_state).with_place(Place::Alignment {
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Top,
+            }),
+            // Render + LOD (top-right)
+            self.render_lod_panel().with_place(Place::Alignment {
+                h_align: HorizontalAlign::Right,
+                v_align: VerticalAlign::Top,
+            }),
+            // Physics params (bottom-left)
+            self.physics_params_panel(ui_state)
+                .with_place(Place::Alignment {
                     h_align: HorizontalAlign::Left,
-                    v_align: VerticalAlign::Top,
+                    v_align: VerticalAlign::Bottom,
                 }),
-                // Render + LOD (top-right)
-                self.render_lod_panel().with_place(Place::Alignment {
+            // Time controls (bottom-right)
+            self.time_controls_panel(ui_state)
+                .with_place(Place::Alignment {
                     h_align: HorizontalAlign::Right,
-                    v_align: VerticalAlign::Top,
-                }),
-                // Physics params (bottom-left)
-                self.physics_params_panel(ui_state)
-                    .with_place(Place::Alignment {
-                        h_align: HorizontalAlign::Left,
-                        v_align: VerticalAlign::Bottom,
-                    }),
-                // Time controls (bottom-right)
-                self.time_controls_panel(ui_state)
-                    .with_place(Place::Alignment {
-                        h_align: HorizontalAlign::Right,
-                        v_align: VerticalAlign::Bottom,
-                    }),
-                // Atom card (top-center)
-                self.atom_card(ui_state).with_place(Place::Alignment {
-                    h_align: HorizontalAlign::Center,
-                    v_align: VerticalAlign::Top,
+                    v_align: VerticalAlign::Bottom,
                 }),
-            ]);
+            // Atom card (top-center)
+            self.atom_card(ui_state).with_place(Place::Alignment {
+                h_align: HorizontalAlign::Center,
+                v_align: VerticalAlign::Top,
+            }),
+        ];
+        if !detached {
+            children.extend(Self::bottom_overlays(ui_state));
+        }
+        let mut root = Self::root_node().with_children(children);
 
         // Layout (with measurer) so we can hit-test for interaction.
         //
@@ -757,6 +1739,44 @@ impl Gui {
         output
     }
 
+    /// Non-interactive overlays of the 3D window (labels, hover tooltip, scale bar) while the
+    /// panels are detached into the inspector window.
+    pub fn build_overlays(&mut self, ui_state: &UiState, window_size: [f32; 2]) -> AstraFullOutput {
+        let mut children = vec![Self::entity_labels(ui_state, window_size)];
+        children.extend(Self::bottom_overlays(ui_state));
+        let mut root = Self::root_node().with_children(children);
+
+        let window_rect = astra_gui::Rect::new([0.0, 0.0], [window_size[0], window_size[1]]);
+        root.compute_layout_with_measurer(window_rect, &mut self.text_engine);
+        AstraFullOutput::from_laid_out_node(root, (window_size[0], window_size[1]), None)
+    }
+
+    fn root_node() -> Node {
+        Node::new()
+            .with_zoom(UI_ZOOM)
+            .with_id("ui_root")
+            .with_layout_direction(Layout::Stack)
+            .with_width(Size::Fill)
+            .with_height(Size::Fill)
+            .with_padding(Spacing::all(Size::lpx(UI_PADDING)))
+    }
+
+    /// Hover tooltip and scale bar, stacked at the bottom center of the 3D view.
+    fn bottom_overlays(ui_state: &UiState) -> Vec<Node> {
+        vec![
+            // Hover tooltip (bottom-center)
+            Self::hover_tooltip(ui_state).with_place(Place::Alignment {
+                h_align: HorizontalAlign::Center,
+                v_align: VerticalAlign::Bottom,
+            }),
+            // Scale bar (bottom-center, above the tooltip)
+            Self::scale_bar(ui_state).with_place(Place::Alignment {
+                h_align: HorizontalAlign::Center,
+                v_align: VerticalAlign::Bottom,
+            }),
+        ]
+    }
+
     fn panel_frame() -> Style {
         Style {
             fill_color: Some(mocha::BASE.with_alpha(0.98)),
@@ -796,6 +1816,79 @@ impl Gui {
                 Self::line_text(format!("Protons: {}", ui_state.proton_count)),
                 Self::line_text(format!("Neutrons: {}", ui_state.neutron_count)),
                 Self::line_text(format!("Other: {}", ui_state.other_hadron_count)),
+                Self::line_text(format!(
+                    "Escaped: {} (u {}, d {}, e {}, g {})",
+                    ui_state.escapes.total(),
+                    ui_state.escapes.up,
+                    ui_state.escapes.down,
+                    ui_state.escapes.electron,
+                    ui_state.escapes.gluon,
+                )),
+                Self::line_text(format!(
+                    "Coarse: {} super-particles ({} absorbed)",
+                    ui_state.coarse_stats.super_particles, ui_state.coarse_stats.absorbed,
+                )),
+                Self::line_text(format!(
+                    "Clusters: {} (largest {} nucleons)",
+                    ui_state.clusters.clusters, ui_state.clusters.largest,
+                )),
+                Self::line_text(if ui_state.selection_set_len == 0 {
+                    "Selection set: empty".to_string()
+                } else {
+                    format!(
+                        "Selection set: {} entities ({} particles, {} quarks, {} electrons, charge {})",
+                        ui_state.selection_set_len,
+                        ui_state.selection_set.particles,
+                        ui_state.selection_set.quarks,
+                        ui_state.selection_set.electrons,
+                        format_charge_thirds(ui_state.selection_set.charge_thirds),
+                    )
+                }),
+                Self::line_text(if ui_state.cohort_tag == 0 {
+                    "Cohort: untagged".to_string()
+                } else {
+                    format!(
+                        "Cohort #{}: {} (free {}, hadrons {}, nuclei {})",
+                        ui_state.cohort_tag,
+                        ui_state.cohort.total,
+                        ui_state.cohort.free,
+                        ui_state.cohort.in_hadrons,
+                        ui_state.cohort.in_nuclei,
+                    )
+                }),
+                Self::panel_section_title("Diagnostics"),
+                Self::pass_timing_chart("gpu_pass_timings", ui_state),
+                Self::histogram_chart(
+                    "histogram_speed",
+                    "Particle speed",
+                    &ui_state.histograms.speed,
+                    mocha::BLUE,
+                ),
+                Self::histogram_chart(
+                    "histogram_separation",
+                    "Quark separation",
+                    &ui_state.histograms.separation,
+                    mocha::GREEN,
+                ),
+                Self::histogram_chart(
+                    "histogram_mass",
+                    "Hadron mass",
+                    &ui_state.histograms.mass,
+                    mocha::PEACH,
+                ),
+                Self::histogram_chart(
+                    "histogram_spectrum",
+                    "Emission spectrum",
+                    &ui_state.spectrum.bins,
+                    mocha::MAUVE,
+                ),
+                Self::charge_mass_chart("charge_mass_map", &ui_state.charge_mass),
+                Self::histogram_chart(
+                    "histogram_cluster_sizes",
+                    "Cluster size",
+                    &ui_state.clusters.sizes,
+                    mocha::TEAL,
+                ),
             ]);
 
         Node::new()
@@ -820,6 +1913,196 @@ impl Gui {
             ))
     }
 
+    /// Small bar chart for a diagnostics histogram. Bars are normalized to the fullest bin.
+    fn histogram_chart(id: &'static str, label: &'static str, bins: &[u32], color: Color) -> Node {
+        const CHART_HEIGHT: f32 = 36.0;
+
+        let max = bins.iter().copied().max().unwrap_or(0).max(1) as f32;
+        let total: u32 = bins.iter().sum();
+
+        let bars = bins
+            .iter()
+            .map(|&count| {
+                let height = if count == 0 {
+                    0.0
+                } else {
+                    (count as f32 / max * CHART_HEIGHT).max(1.0)
+                };
+
+                Node::new()
+                    .with_width(Size::lpx(4.0))
+                    .with_height(Size::lpx(CHART_HEIGHT))
+                    .with_layout_direction(Layout::Vertical)
+                    .with_v_align(VerticalAlign::Bottom)
+                    .with_child(
+                        Node::new()
+                            .with_width(Size::Fill)
+                            .with_height(Size::lpx(height))
+                            .with_style(Style {
+                                fill_color: Some(color),
+                                ..Default::default()
+                            }),
+                    )
+            })
+            .collect();
+
+        Node::new()
+            .with_id(id)
+            .with_layout_direction(Layout::Vertical)
+            .with_gap(Size::lpx(2.0))
+            .with_children(vec![
+                Self::line_text(format!("{label} (n={total})")),
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(1.0))
+                    .with_children(bars),
+            ])
+    }
+
+    /// Stacked bar of the GPU time per pass with one legend row per pass.
+    fn pass_timing_chart(id: &'static str, ui_state: &UiState) -> Node {
+        const CHART_WIDTH: f32 = 196.0;
+        const CHART_HEIGHT: f32 = 10.0;
+
+        if !ui_state.gpu_timings_supported {
+            return Self::line_text("GPU passes: no timestamp queries");
+        }
+
+        let timings = &ui_state.gpu_timings;
+        let total: f32 = timings.iter().map(|timing| timing.milliseconds).sum();
+        let colors = [
+            mocha::BLUE,
+            mocha::GREEN,
+            mocha::PEACH,
+            mocha::MAUVE,
+            mocha::TEAL,
+            mocha::SUBTEXT1,
+        ];
+        let color = |index: usize| colors[index % colors.len()];
+
+        let segments = timings
+            .iter()
+            .enumerate()
+            .map(|(index, timing)| {
+                Node::new()
+                    .with_width(Size::lpx(
+                        timing.milliseconds / total.max(1e-6) * CHART_WIDTH,
+                    ))
+                    .with_height(Size::lpx(CHART_HEIGHT))
+                    .with_style(Style {
+                        fill_color: Some(color(index)),
+                        ..Default::default()
+                    })
+            })
+            .collect();
+
+        let legend = timings.iter().enumerate().map(|(index, timing)| {
+            Node::new()
+                .with_layout_direction(Layout::Horizontal)
+                .with_gap(Size::lpx(6.0))
+                .with_v_align(VerticalAlign::Center)
+                .with_children(vec![
+                    Node::new()
+                        .with_width(Size::lpx(8.0))
+                        .with_height(Size::lpx(8.0))
+                        .with_style(Style {
+                            fill_color: Some(color(index)),
+                            ..Default::default()
+                        }),
+                    Self::line_text(format!("{} {:.2} ms", timing.label, timing.milliseconds)),
+                ])
+        });
+
+        Node::new()
+            .with_id(id)
+            .with_layout_direction(Layout::Vertical)
+            .with_gap(Size::lpx(2.0))
+            .with_children(
+                [
+                    Self::line_text(format!("GPU passes ({total:.2} ms)")),
+                    Node::new()
+                        .with_layout_direction(Layout::Horizontal)
+                        .with_children(segments),
+                ]
+                .into_iter()
+                .chain(legend)
+                .collect(),
+            )
+    }
+
+    /// Mass spectrometer view: one row per net charge (+2 at the top), mass increasing to the right.
+    fn charge_mass_chart(id: &'static str, map: &ChargeMassMap) -> Node {
+        const CELL: f32 = 4.0;
+
+        let max = map
+            .counts
+            .iter()
+            .flatten()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(1) as f32;
+        let total: u32 = map.counts.iter().flatten().sum();
+
+        let rows = map
+            .counts
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(row, counts)| {
+                let thirds = (ChargeMassMap::row_charge(row) * 3.0).round() as i32;
+                let label = if thirds % 3 == 0 {
+                    format!("{:+}", thirds / 3)
+                } else {
+                    format!("{thirds:+}/3")
+                };
+
+                let cells = counts.iter().map(|&count| {
+                    let alpha = if count == 0 {
+                        0.0
+                    } else {
+                        (count as f32 / max).sqrt().max(0.15)
+                    };
+                    Node::new()
+                        .with_width(Size::lpx(CELL))
+                        .with_height(Size::lpx(CELL))
+                        .with_style(Style {
+                            fill_color: Some(mocha::TEAL.with_alpha(alpha)),
+                            ..Default::default()
+                        })
+                });
+
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(1.0))
+                    .with_v_align(VerticalAlign::Center)
+                    .with_children(
+                        std::iter::once(
+                            Node::new()
+                                .with_width(Size::lpx(32.0))
+                                .with_content(Content::Text(
+                                    TextContent::new(label)
+                                        .with_color(mocha::SUBTEXT0)
+                                        .with_font_size(Size::lpx(8.0)),
+                                )),
+                        )
+                        .chain(cells)
+                        .collect(),
+                    )
+            })
+            .collect();
+
+        Node::new()
+            .with_id(id)
+            .with_layout_direction(Layout::Vertical)
+            .with_gap(Size::lpx(1.0))
+            .with_children(
+                std::iter::once(Self::line_text(format!("Charge vs mass (n={total})")))
+                    .chain(rows)
+                    .collect(),
+            )
+    }
+
     fn panel_section_title(text: impl Into<String>) -> Node {
         Node::new().with_content(Content::Text(
             TextContent::new(text.into())
@@ -881,6 +2164,124 @@ impl Gui {
         Self::labeled_row(label, toggle(id, checked, false, &ToggleStyle::default()))
     }
 
+    /// Bookmark controls plus one jump button per saved view
+    fn camera_path_rows(&self) -> Node {
+        let flying = self.camera_flythrough_progress.is_some();
+        let mut rows = vec![Node::new()
+            .with_layout_direction(Layout::Horizontal)
+            .with_gap(Size::lpx(10.0))
+            .with_children(vec![
+                button(
+                    "camera_bookmark_save",
+                    "Save view",
+                    false,
+                    &ButtonStyle::default(),
+                ),
+                button(
+                    "camera_flythrough",
+                    if flying { "Stop" } else { "Fly through" },
+                    false,
+                    &ButtonStyle::default(),
+                ),
+                button(
+                    "camera_bookmark_clear",
+                    "Clear",
+                    false,
+                    &ButtonStyle::default(),
+                ),
+            ])];
+
+        if self.camera_bookmarks.is_empty() {
+            rows.push(Self::line_text("No saved views"));
+        }
+        rows.extend(
+            CAMERA_BOOKMARK_BUTTONS
+                .iter()
+                .zip(&self.camera_bookmarks)
+                .map(|(&id, name)| button(id, name.as_str(), false, &ButtonStyle::default())),
+        );
+        if let Some(progress) = self.camera_flythrough_progress {
+            rows.push(Self::line_text(format!("Flying: {:.0}%", progress * 100.0)));
+        }
+
+        Node::new()
+            .with_layout_direction(Layout::Vertical)
+            .with_gap(Size::lpx(10.0))
+            .with_children(rows)
+    }
+
+    /// Measure mode toggle, the current results and a clear button
+    fn measure_rows(&self) -> Node {
+        let mut rows = vec![Self::toggle_row(
+            "toggle_measure",
+            "Click to measure",
+            self.render_measure,
+        )];
+        rows.extend(self.measure_readout.iter().map(Self::line_text));
+        if !self.measure_readout.is_empty() {
+            rows.push(button(
+                "measure_clear",
+                "Clear",
+                false,
+                &ButtonStyle::default(),
+            ));
+        }
+
+        Node::new()
+            .with_layout_direction(Layout::Vertical)
+            .with_gap(Size::lpx(10.0))
+            .with_children(rows)
+    }
+
+    /// Species selector plus near and far shape buttons for the selected species
+    fn impostor_rows(&self) -> Node {
+        let selected = self.impostors[self.impostor_species];
+        let shape_buttons = |far: bool| {
+            Node::new()
+                .with_layout_direction(Layout::Horizontal)
+                .with_gap(Size::lpx(10.0))
+                .with_children(
+                    IMPOSTOR_SHAPE_BUTTONS
+                        .iter()
+                        .map(|&(near_id, far_id, shape)| {
+                            let current = if far { selected.far } else { selected.near };
+                            button(
+                                if far { far_id } else { near_id },
+                                shape.label(),
+                                shape == current,
+                                &ButtonStyle::default(),
+                            )
+                        })
+                        .collect(),
+                )
+        };
+
+        Node::new()
+            .with_layout_direction(Layout::Vertical)
+            .with_gap(Size::lpx(10.0))
+            .with_children(vec![
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(10.0))
+                    .with_children(
+                        IMPOSTOR_SPECIES_BUTTONS
+                            .iter()
+                            .enumerate()
+                            .map(|(species, &(id, label))| {
+                                button(
+                                    id,
+                                    label,
+                                    species == self.impostor_species,
+                                    &ButtonStyle::default(),
+                                )
+                            })
+                            .collect(),
+                    ),
+                Self::labeled_row("Near", shape_buttons(false)),
+                Self::labeled_row("Far", shape_buttons(true)),
+            ])
+    }
+
     fn render_lod_panel(&mut self) -> Node {
         // Always render the header; only render the heavy/interactive body when expanded.
         let inner_children = if self.render_lod_panel_expanded {
@@ -889,7 +2290,281 @@ impl Gui {
                 Self::toggle_row("toggle_shells", "Show shells", self.render_shells),
                 Self::toggle_row("toggle_bonds", "Show bonds", self.render_bonds),
                 Self::toggle_row("toggle_nuclei", "Show nuclei", self.render_nuclei),
+                Self::toggle_row("toggle_photons", "Show photons", self.render_photons),
+                Self::toggle_row(
+                    "toggle_oit_shells",
+                    "Order-independent shells",
+                    self.render_oit_shells,
+                ),
+                Self::toggle_row("toggle_labels", "Show labels", self.render_labels),
+                Self::toggle_row(
+                    "toggle_orthographic",
+                    "Orthographic camera",
+                    self.render_orthographic,
+                ),
+                Self::toggle_row(
+                    "toggle_free_fly",
+                    "Free-fly camera (F)",
+                    self.render_free_fly,
+                ),
+                Self::toggle_row(
+                    "toggle_rest_frame",
+                    "Rest frame of followed hadron",
+                    self.render_rest_frame,
+                ),
+                Self::panel_section_title("Bloom"),
+                Self::toggle_row("toggle_bloom", "HDR bloom", self.render_bloom),
+                Self::slider_with_value_row(
+                    "Bloom intensity",
+                    "bloom_intensity",
+                    "bloom_intensity_value",
+                    self.bloom_intensity,
+                    0.0..=2.0,
+                    self.bloom_intensity_focused,
+                    &self.bloom_intensity_text,
+                    self.bloom_intensity_cursor,
+                    self.bloom_intensity_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::slider_with_value_row(
+                    "Energy glow",
+                    "energy_glow",
+                    "energy_glow_value",
+                    self.energy_glow,
+                    0.0..=500.0,
+                    self.energy_glow_focused,
+                    &self.energy_glow_text,
+                    self.energy_glow_cursor,
+                    self.energy_glow_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::panel_section_title("Anti-aliasing"),
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(10.0))
+                    .with_children(
+                        MSAA_BUTTONS
+                            .iter()
+                            .filter(|(_, _, count)| self.msaa_sample_counts.contains(count))
+                            .map(|&(id, label, count)| {
+                                button(
+                                    id,
+                                    label,
+                                    count == self.msaa_samples,
+                                    &ButtonStyle::default(),
+                                )
+                            })
+                            .collect(),
+                    ),
+                Self::panel_section_title("Presentation"),
+                Node::new()
+                    .with_layout_direction(Layout::Vertical)
+                    .with_gap(Size::lpx(6.0))
+                    .with_children(
+                        PRESENT_MODE_BUTTONS
+                            .iter()
+                            .filter(|(_, _, mode)| self.present_modes.contains(mode))
+                            .collect::<Vec<_>>()
+                            .chunks(3)
+                            .map(|row| {
+                                Node::new()
+                                    .with_layout_direction(Layout::Horizontal)
+                                    .with_gap(Size::lpx(10.0))
+                                    .with_children(
+                                        row.iter()
+                                            .map(|&&(id, label, mode)| {
+                                                button(
+                                                    id,
+                                                    label,
+                                                    mode == self.present_mode,
+                                                    &ButtonStyle::default(),
+                                                )
+                                            })
+                                            .collect(),
+                                    )
+                            })
+                            .collect(),
+                    ),
+                Self::panel_section_title("Render scale"),
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(10.0))
+                    .with_children(
+                        RENDER_SCALE_BUTTONS
+                            .iter()
+                            .map(|&(id, label, percent)| {
+                                button(
+                                    id,
+                                    label,
+                                    percent == self.render_scale,
+                                    &ButtonStyle::default(),
+                                )
+                            })
+                            .collect(),
+                    ),
+                Self::panel_section_title("Window"),
+                button(
+                    "inspector_toggle",
+                    if self.inspector_detached {
+                        "Attach panels"
+                    } else {
+                        "Detach panels"
+                    },
+                    false,
+                    &ButtonStyle::default(),
+                ),
+                Self::panel_section_title("Capture"),
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(10.0))
+                    .with_children(
+                        SCREENSHOT_SCALE_BUTTONS
+                            .iter()
+                            .map(|&(id, label, scale)| {
+                                button(
+                                    id,
+                                    label,
+                                    scale == self.screenshot_scale,
+                                    &ButtonStyle::default(),
+                                )
+                            })
+                            .collect(),
+                    ),
+                button(
+                    "screenshot",
+                    "Screenshot (F12)",
+                    false,
+                    &ButtonStyle::default(),
+                ),
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(10.0))
+                    .with_children(
+                        RECORDING_FORMAT_BUTTONS
+                            .iter()
+                            .map(|&(id, label, format)| {
+                                button(
+                                    id,
+                                    label,
+                                    format == self.recording_format,
+                                    &ButtonStyle::default(),
+                                )
+                            })
+                            .collect(),
+                    ),
+                button(
+                    "record_toggle",
+                    if self.recording_frames.is_some() {
+                        "Stop recording"
+                    } else {
+                        "Start recording"
+                    },
+                    false,
+                    &ButtonStyle::default(),
+                ),
+                Self::line_text(match self.recording_frames {
+                    Some(frames) => format!("Recording: {} frames", frames),
+                    None => format!("Records to {}", self.recording_format.label()),
+                }),
+                Self::panel_section_title("Camera Paths"),
+                self.camera_path_rows(),
+                Self::panel_section_title("Measure"),
+                self.measure_rows(),
+                Self::panel_section_title("Trails"),
+                Self::toggle_row("toggle_trails", "Show trails", self.render_trails),
+                Self::slider_with_value_row(
+                    "Trail length",
+                    "trail_length",
+                    "trail_length_value",
+                    self.trail_length,
+                    2.0..=64.0,
+                    self.trail_length_focused,
+                    &self.trail_length_text,
+                    self.trail_length_cursor,
+                    self.trail_length_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::panel_section_title("Vectors"),
+                Self::toggle_row(
+                    "toggle_velocity_arrows",
+                    "Velocity arrows",
+                    self.render_velocity_arrows,
+                ),
+                Self::slider_with_value_row(
+                    "Arrow scale",
+                    "velocity_arrow_scale",
+                    "velocity_arrow_scale_value",
+                    self.velocity_arrow_scale,
+                    0.05..=10.0,
+                    self.velocity_arrow_scale_focused,
+                    &self.velocity_arrow_scale_text,
+                    self.velocity_arrow_scale_cursor,
+                    self.velocity_arrow_scale_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::toggle_row(
+                    "toggle_force_field",
+                    "Force field slice",
+                    self.render_force_field,
+                ),
+                Self::slider_with_value_row(
+                    "Slice half size",
+                    "force_field_extent",
+                    "force_field_extent_value",
+                    self.force_field_extent,
+                    5.0..=500.0,
+                    self.force_field_extent_focused,
+                    &self.force_field_extent_text,
+                    self.force_field_extent_cursor,
+                    self.force_field_extent_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::panel_section_title("Reference"),
+                Self::toggle_row("toggle_grid", "Grid (XZ plane)", self.render_grid),
+                Self::toggle_row("toggle_axes", "World axes", self.render_axes),
+                Self::toggle_row("toggle_scale_bar", "Scale bar (fm)", self.render_scale_bar),
+                Self::toggle_row("toggle_minimap", "Overview minimap", self.render_minimap),
+                Node::new()
+                    .with_layout_direction(Layout::Horizontal)
+                    .with_gap(Size::lpx(10.0))
+                    .with_children(
+                        MINIMAP_CORNER_BUTTONS
+                            .iter()
+                            .map(|&(id, label, corner)| {
+                                button(
+                                    id,
+                                    label,
+                                    corner == self.minimap_corner,
+                                    &ButtonStyle::default(),
+                                )
+                            })
+                            .collect(),
+                    ),
                 Self::panel_section_title("LOD (fade start/end)"),
+                Self::toggle_row("toggle_cull", "Cull off-screen particles", self.render_cull),
+                Self::toggle_row(
+                    "toggle_density_splats",
+                    "Splat dense clusters",
+                    self.render_density_splats,
+                ),
+                Self::slider_with_value_row(
+                    "Splat threshold",
+                    "density_splat_threshold",
+                    "density_splat_threshold_value",
+                    self.density_splat_threshold,
+                    8.0..=512.0,
+                    self.density_splat_threshold_focused,
+                    &self.density_splat_threshold_text,
+                    self.density_splat_threshold_cursor,
+                    self.density_splat_threshold_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
                 Self::slider_with_value_row(
                     "Shell start",
                     "lod_shell_fade_start",
@@ -1020,6 +2695,112 @@ impl Gui {
                     &mut self.text_engine,
                     &mut self.event_dispatcher,
                 ),
+                Self::panel_section_title("Impostors (near -> far)"),
+                Self::slider_with_value_row(
+                    "Crossfade start",
+                    "lod_impostor_fade_start",
+                    "lod_impostor_fade_start_value",
+                    self.lod_impostor_fade_start,
+                    0.0..=500.0,
+                    self.lod_impostor_fade_start_focused,
+                    &self.lod_impostor_fade_start_text,
+                    self.lod_impostor_fade_start_cursor,
+                    self.lod_impostor_fade_start_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::slider_with_value_row(
+                    "Crossfade end",
+                    "lod_impostor_fade_end",
+                    "lod_impostor_fade_end_value",
+                    self.lod_impostor_fade_end,
+                    0.0..=500.0,
+                    self.lod_impostor_fade_end_focused,
+                    &self.lod_impostor_fade_end_text,
+                    self.lod_impostor_fade_end_cursor,
+                    self.lod_impostor_fade_end_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                self.impostor_rows(),
+                Self::panel_section_title("Cohort"),
+                button(
+                    "cohort_tag",
+                    "Tag around selection",
+                    false,
+                    &ButtonStyle::default(),
+                ),
+                Self::slider_with_value_row(
+                    "Tag radius",
+                    "cohort_radius",
+                    "cohort_radius_value",
+                    self.cohort_radius,
+                    0.5..=50.0,
+                    self.cohort_radius_focused,
+                    &self.cohort_radius_text,
+                    self.cohort_radius_cursor,
+                    self.cohort_radius_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::panel_section_title("Coarse Graining"),
+                Self::toggle_row(
+                    "coarse_enabled",
+                    "Merge distant particles",
+                    self.coarse.enabled(),
+                ),
+                Self::slider_with_value_row(
+                    "Merge distance",
+                    "coarse_merge_distance",
+                    "coarse_merge_distance_value",
+                    self.coarse.camera[3],
+                    10.0..=500.0,
+                    self.coarse_merge_distance_focused,
+                    &self.coarse_merge_distance_text,
+                    self.coarse_merge_distance_cursor,
+                    self.coarse_merge_distance_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::slider_with_value_row(
+                    "Split distance",
+                    "coarse_split_distance",
+                    "coarse_split_distance_value",
+                    self.coarse.config[0],
+                    5.0..=500.0,
+                    self.coarse_split_distance_focused,
+                    &self.coarse_split_distance_text,
+                    self.coarse_split_distance_cursor,
+                    self.coarse_split_distance_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::slider_with_value_row(
+                    "Merge radius",
+                    "coarse_merge_radius",
+                    "coarse_merge_radius_value",
+                    self.coarse.config[1],
+                    0.1..=10.0,
+                    self.coarse_merge_radius_focused,
+                    &self.coarse_merge_radius_text,
+                    self.coarse_merge_radius_cursor,
+                    self.coarse_merge_radius_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
+                Self::slider_with_value_row(
+                    "Max members",
+                    "coarse_max_members",
+                    "coarse_max_members_value",
+                    self.coarse.config[2],
+                    2.0..=64.0,
+                    self.coarse_max_members_focused,
+                    &self.coarse_max_members_text,
+                    self.coarse_max_members_cursor,
+                    self.coarse_max_members_selection,
+                    &mut self.text_engine,
+                    &mut self.event_dispatcher,
+                ),
             ]
         } else {
             Vec::new()
@@ -1059,325 +2840,627 @@ impl Gui {
         // Always render the header; only build the heavy/interactive body when expanded.
         let inner_children = if self.physics_panel_expanded {
             vec![
-                Self::panel_section_title("Forces"),
-                // constants: x: G, y: K_electric, z: G_weak, w: weak_force_range
-                Self::slider_with_value_row(
-                    "Gravity (G)",
-                    "phys_constants_g",
-                    "phys_constants_g_value",
-                    params.constants[0],
-                    0.0..=1.0e-9,
-                    self.phys_constants_g_focused,
-                    &self.phys_constants_g_text,
-                    self.phys_constants_g_cursor,
-                    self.phys_constants_g_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Electric (K)",
-                    "phys_constants_k",
-                    "phys_constants_k_value",
-                    params.constants[1],
-                    0.0..=20.0,
-                    self.phys_constants_k_focused,
-                    &self.phys_constants_k_text,
-                    self.phys_constants_k_cursor,
-                    self.phys_constants_k_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Weak (G)",
-                    "phys_constants_gweak",
-                    "phys_constants_gweak_value",
-                    params.constants[2],
-                    0.0..=1.0e-3,
-                    self.phys_constants_gweak_focused,
-                    &self.phys_constants_gweak_text,
-                    self.phys_constants_gweak_cursor,
-                    self.phys_constants_gweak_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Weak range",
-                    "phys_constants_weak_range",
-                    "phys_constants_weak_range_value",
-                    params.constants[3],
-                    0.0..=5.0,
-                    self.phys_constants_weak_range_focused,
-                    &self.phys_constants_weak_range_text,
-                    self.phys_constants_weak_range_cursor,
-                    self.phys_constants_weak_range_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::panel_section_title("Strong Force"),
-                // strong_force: x: strong_short_range, y: strong_confinement, z: strong_range, w: padding
-                Self::slider_with_value_row(
-                    "Short Range",
-                    "phys_strong_short",
-                    "phys_strong_short_value",
-                    params.strong_force[0],
-                    0.0..=5.0,
-                    self.phys_strong_short_focused,
-                    &self.phys_strong_short_text,
-                    self.phys_strong_short_cursor,
-                    self.phys_strong_short_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Confinement",
-                    "phys_strong_confinement",
-                    "phys_strong_confinement_value",
-                    params.strong_force[1],
-                    0.0..=5.0,
-                    self.phys_strong_confinement_focused,
-                    &self.phys_strong_confinement_text,
-                    self.phys_strong_confinement_cursor,
-                    self.phys_strong_confinement_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Range Cutoff",
-                    "phys_strong_range",
-                    "phys_strong_range_value",
-                    params.strong_force[2],
-                    0.0..=10.0,
-                    self.phys_strong_range_focused,
-                    &self.phys_strong_range_text,
-                    self.phys_strong_range_cursor,
-                    self.phys_strong_range_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::panel_section_title("Repulsion"),
-                // repulsion: x: core_repulsion, y: core_radius, z: softening, w: max_force
-                Self::slider_with_value_row(
-                    "Core Strength",
-                    "phys_repulsion_strength",
-                    "phys_repulsion_strength_value",
-                    params.repulsion[0],
-                    0.0..=500.0,
-                    self.phys_repulsion_strength_focused,
-                    &self.phys_repulsion_strength_text,
-                    self.phys_repulsion_strength_cursor,
-                    self.phys_repulsion_strength_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Core Radius",
-                    "phys_repulsion_radius",
-                    "phys_repulsion_radius_value",
-                    params.repulsion[1],
-                    0.0..=1.0,
-                    self.phys_repulsion_radius_focused,
-                    &self.phys_repulsion_radius_text,
-                    self.phys_repulsion_radius_cursor,
-                    self.phys_repulsion_radius_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Softening",
-                    "phys_repulsion_softening",
-                    "phys_repulsion_softening_value",
-                    params.repulsion[2],
-                    0.001..=0.1,
-                    self.phys_repulsion_softening_focused,
-                    &self.phys_repulsion_softening_text,
-                    self.phys_repulsion_softening_cursor,
-                    self.phys_repulsion_softening_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Max Force",
-                    "phys_repulsion_max_force",
-                    "phys_repulsion_max_force_value",
-                    params.repulsion[3],
-                    10.0..=200.0,
-                    self.phys_repulsion_max_force_focused,
-                    &self.phys_repulsion_max_force_text,
-                    self.phys_repulsion_max_force_cursor,
-                    self.phys_repulsion_max_force_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::panel_section_title("Integration"),
-                // integration: x: dt, y: damping, z: time/seed, w: nucleon_damping
-                Self::slider_with_value_row(
-                    "Damping",
-                    "phys_integration_damping",
-                    "phys_integration_damping_value",
-                    params.integration[1],
-                    0.9..=1.0,
-                    self.phys_integration_damping_focused,
-                    &self.phys_integration_damping_text,
-                    self.phys_integration_damping_cursor,
-                    self.phys_integration_damping_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Nucleon damp",
-                    "phys_integration_nucleon_damping",
-                    "phys_integration_nucleon_damping_value",
-                    params.integration[3],
-                    0.0..=5.0,
-                    self.phys_integration_nucleon_damping_focused,
-                    &self.phys_integration_nucleon_damping_text,
-                    self.phys_integration_nucleon_damping_cursor,
-                    self.phys_integration_nucleon_damping_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::panel_section_title("Nucleon Physics"),
-                // nucleon: x: binding_strength, y: binding_range, z: exclusion_strength, w: exclusion_radius
-                Self::slider_with_value_row(
-                    "Bind strength",
-                    "phys_nucleon_binding_strength",
-                    "phys_nucleon_binding_strength_value",
-                    params.nucleon[0],
-                    0.0..=500.0,
-                    self.phys_nucleon_binding_strength_focused,
-                    &self.phys_nucleon_binding_strength_text,
-                    self.phys_nucleon_binding_strength_cursor,
-                    self.phys_nucleon_binding_strength_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Bind range",
-                    "phys_nucleon_binding_range",
-                    "phys_nucleon_binding_range_value",
-                    params.nucleon[1],
-                    0.0..=10.0,
-                    self.phys_nucleon_binding_range_focused,
-                    &self.phys_nucleon_binding_range_text,
-                    self.phys_nucleon_binding_range_cursor,
-                    self.phys_nucleon_binding_range_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Excl strength",
-                    "phys_nucleon_exclusion_strength",
-                    "phys_nucleon_exclusion_strength_value",
-                    params.nucleon[2],
-                    0.0..=500.0,
-                    self.phys_nucleon_exclusion_strength_focused,
-                    &self.phys_nucleon_exclusion_strength_text,
-                    self.phys_nucleon_exclusion_strength_cursor,
-                    self.phys_nucleon_exclusion_strength_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Excl radius",
-                    "phys_nucleon_exclusion_radius",
-                    "phys_nucleon_exclusion_radius_value",
-                    params.nucleon[3],
-                    0.0..=5.0,
-                    self.phys_nucleon_exclusion_radius_focused,
-                    &self.phys_nucleon_exclusion_radius_text,
-                    self.phys_nucleon_exclusion_radius_cursor,
-                    self.phys_nucleon_exclusion_radius_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::panel_section_title("Electron Physics"),
-                // electron: x: exclusion_strength, y: exclusion_radius, z: padding, w: padding
-                Self::slider_with_value_row(
-                    "Excl strength",
-                    "phys_electron_exclusion_strength",
-                    "phys_electron_exclusion_strength_value",
-                    params.electron[0],
-                    0.0..=500.0,
-                    self.phys_electron_exclusion_strength_focused,
-                    &self.phys_electron_exclusion_strength_text,
-                    self.phys_electron_exclusion_strength_cursor,
-                    self.phys_electron_exclusion_strength_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Excl radius",
-                    "phys_electron_exclusion_radius",
-                    "phys_electron_exclusion_radius_value",
-                    params.electron[1],
-                    0.0..=5.0,
-                    self.phys_electron_exclusion_radius_focused,
-                    &self.phys_electron_exclusion_radius_text,
-                    self.phys_electron_exclusion_radius_cursor,
-                    self.phys_electron_exclusion_radius_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::panel_section_title("Hadron Formation"),
-                // hadron: x: binding_distance, y: breakup_distance, z: confinement_range_mult, w: confinement_strength_mult
-                Self::slider_with_value_row(
-                    "Bind dist",
-                    "phys_hadron_binding_distance",
-                    "phys_hadron_binding_distance_value",
-                    params.hadron[0],
-                    0.0..=5.0,
-                    self.phys_hadron_binding_distance_focused,
-                    &self.phys_hadron_binding_distance_text,
-                    self.phys_hadron_binding_distance_cursor,
-                    self.phys_hadron_binding_distance_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Break dist",
-                    "phys_hadron_breakup_distance",
-                    "phys_hadron_breakup_distance_value",
-                    params.hadron[1],
-                    0.0..=5.0,
-                    self.phys_hadron_breakup_distance_focused,
-                    &self.phys_hadron_breakup_distance_text,
-                    self.phys_hadron_breakup_distance_cursor,
-                    self.phys_hadron_breakup_distance_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Conf range",
-                    "phys_hadron_conf_range_mult",
-                    "phys_hadron_conf_range_mult_value",
-                    params.hadron[2],
-                    0.0..=5.0,
-                    self.phys_hadron_conf_range_mult_focused,
-                    &self.phys_hadron_conf_range_mult_text,
-                    self.phys_hadron_conf_range_mult_cursor,
-                    self.phys_hadron_conf_range_mult_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::slider_with_value_row(
-                    "Conf strength",
-                    "phys_hadron_conf_strength_mult",
-                    "phys_hadron_conf_strength_mult_value",
-                    params.hadron[3],
-                    0.0..=5.0,
-                    self.phys_hadron_conf_strength_mult_focused,
-                    &self.phys_hadron_conf_strength_mult_text,
-                    self.phys_hadron_conf_strength_mult_cursor,
-                    self.phys_hadron_conf_strength_mult_selection,
-                    &mut self.text_engine,
-                    &mut self.event_dispatcher,
-                ),
-                Self::line_text(if self.physics_params_dirty {
+                Self::physics_section(0, self.physics_sections_expanded[0], || {
+                    vec![
+                        // constants: x: G, y: K_electric, z: G_weak, w: weak_force_range
+                        Self::slider_with_value_row(
+                            "Gravity (G)",
+                            "phys_constants_g",
+                            "phys_constants_g_value",
+                            params.constants[0],
+                            0.0..=1.0e-9,
+                            self.phys_constants_g_focused,
+                            &self.phys_constants_g_text,
+                            self.phys_constants_g_cursor,
+                            self.phys_constants_g_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Electric (K)",
+                            "phys_constants_k",
+                            "phys_constants_k_value",
+                            params.constants[1],
+                            0.0..=20.0,
+                            self.phys_constants_k_focused,
+                            &self.phys_constants_k_text,
+                            self.phys_constants_k_cursor,
+                            self.phys_constants_k_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Weak (G)",
+                            "phys_constants_gweak",
+                            "phys_constants_gweak_value",
+                            params.constants[2],
+                            0.0..=1.0e-3,
+                            self.phys_constants_gweak_focused,
+                            &self.phys_constants_gweak_text,
+                            self.phys_constants_gweak_cursor,
+                            self.phys_constants_gweak_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Weak range",
+                            "phys_constants_weak_range",
+                            "phys_constants_weak_range_value",
+                            params.constants[3],
+                            0.0..=5.0,
+                            self.phys_constants_weak_range_focused,
+                            &self.phys_constants_weak_range_text,
+                            self.phys_constants_weak_range_cursor,
+                            self.phys_constants_weak_range_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(1, self.physics_sections_expanded[1], || {
+                    vec![
+                        // strong_force: x: strong_short_range, y: strong_confinement, z: strong_range, w: padding
+                        Self::slider_with_value_row(
+                            "Short Range",
+                            "phys_strong_short",
+                            "phys_strong_short_value",
+                            params.strong_force[0],
+                            0.0..=5.0,
+                            self.phys_strong_short_focused,
+                            &self.phys_strong_short_text,
+                            self.phys_strong_short_cursor,
+                            self.phys_strong_short_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Confinement",
+                            "phys_strong_confinement",
+                            "phys_strong_confinement_value",
+                            params.strong_force[1],
+                            0.0..=5.0,
+                            self.phys_strong_confinement_focused,
+                            &self.phys_strong_confinement_text,
+                            self.phys_strong_confinement_cursor,
+                            self.phys_strong_confinement_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Range Cutoff",
+                            "phys_strong_range",
+                            "phys_strong_range_value",
+                            params.strong_force[2],
+                            0.0..=10.0,
+                            self.phys_strong_range_focused,
+                            &self.phys_strong_range_text,
+                            self.phys_strong_range_cursor,
+                            self.phys_strong_range_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(2, self.physics_sections_expanded[2], || {
+                    vec![
+                        // repulsion: x: core_repulsion, y: core_radius, z: softening, w: max_force
+                        Self::slider_with_value_row(
+                            "Core Strength",
+                            "phys_repulsion_strength",
+                            "phys_repulsion_strength_value",
+                            params.repulsion[0],
+                            0.0..=500.0,
+                            self.phys_repulsion_strength_focused,
+                            &self.phys_repulsion_strength_text,
+                            self.phys_repulsion_strength_cursor,
+                            self.phys_repulsion_strength_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Core Radius",
+                            "phys_repulsion_radius",
+                            "phys_repulsion_radius_value",
+                            params.repulsion[1],
+                            0.0..=1.0,
+                            self.phys_repulsion_radius_focused,
+                            &self.phys_repulsion_radius_text,
+                            self.phys_repulsion_radius_cursor,
+                            self.phys_repulsion_radius_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Softening",
+                            "phys_repulsion_softening",
+                            "phys_repulsion_softening_value",
+                            params.repulsion[2],
+                            0.001..=0.1,
+                            self.phys_repulsion_softening_focused,
+                            &self.phys_repulsion_softening_text,
+                            self.phys_repulsion_softening_cursor,
+                            self.phys_repulsion_softening_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Max Force",
+                            "phys_repulsion_max_force",
+                            "phys_repulsion_max_force_value",
+                            params.repulsion[3],
+                            10.0..=200.0,
+                            self.phys_repulsion_max_force_focused,
+                            &self.phys_repulsion_max_force_text,
+                            self.phys_repulsion_max_force_cursor,
+                            self.phys_repulsion_max_force_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(3, self.physics_sections_expanded[3], || {
+                    vec![
+                        // integration: x: dt, y: damping, z: time/seed, w: nucleon_damping
+                        Self::slider_with_value_row(
+                            "Damping",
+                            "phys_integration_damping",
+                            "phys_integration_damping_value",
+                            params.integration[1],
+                            0.9..=1.0,
+                            self.phys_integration_damping_focused,
+                            &self.phys_integration_damping_text,
+                            self.phys_integration_damping_cursor,
+                            self.phys_integration_damping_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Nucleon damp",
+                            "phys_integration_nucleon_damping",
+                            "phys_integration_nucleon_damping_value",
+                            params.integration[3],
+                            0.0..=5.0,
+                            self.phys_integration_nucleon_damping_focused,
+                            &self.phys_integration_nucleon_damping_text,
+                            self.phys_integration_nucleon_damping_cursor,
+                            self.phys_integration_nucleon_damping_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(4, self.physics_sections_expanded[4], || {
+                    vec![
+                        // residual_strong: x: strength, y: range, z: cutoff (x range)
+                        Self::slider_with_value_row(
+                            "Strength",
+                            "phys_residual_strength",
+                            "phys_residual_strength_value",
+                            params.residual_strong[0],
+                            0.0..=500.0,
+                            self.phys_residual_strength_focused,
+                            &self.phys_residual_strength_text,
+                            self.phys_residual_strength_cursor,
+                            self.phys_residual_strength_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Range",
+                            "phys_residual_range",
+                            "phys_residual_range_value",
+                            params.residual_strong[1],
+                            0.1..=10.0,
+                            self.phys_residual_range_focused,
+                            &self.phys_residual_range_text,
+                            self.phys_residual_range_cursor,
+                            self.phys_residual_range_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Cutoff (x range)",
+                            "phys_residual_cutoff",
+                            "phys_residual_cutoff_value",
+                            params.residual_strong[2],
+                            1.0..=6.0,
+                            self.phys_residual_cutoff_focused,
+                            &self.phys_residual_cutoff_text,
+                            self.phys_residual_cutoff_cursor,
+                            self.phys_residual_cutoff_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(5, self.physics_sections_expanded[5], || {
+                    vec![
+                        // nucleon: y: binding_range (detection), z: exclusion_strength, w: exclusion_radius
+                        Self::slider_with_value_row(
+                            "Detect range",
+                            "phys_nucleon_binding_range",
+                            "phys_nucleon_binding_range_value",
+                            params.nucleon[1],
+                            0.0..=10.0,
+                            self.phys_nucleon_binding_range_focused,
+                            &self.phys_nucleon_binding_range_text,
+                            self.phys_nucleon_binding_range_cursor,
+                            self.phys_nucleon_binding_range_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Excl strength",
+                            "phys_nucleon_exclusion_strength",
+                            "phys_nucleon_exclusion_strength_value",
+                            params.nucleon[2],
+                            0.0..=500.0,
+                            self.phys_nucleon_exclusion_strength_focused,
+                            &self.phys_nucleon_exclusion_strength_text,
+                            self.phys_nucleon_exclusion_strength_cursor,
+                            self.phys_nucleon_exclusion_strength_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Excl radius",
+                            "phys_nucleon_exclusion_radius",
+                            "phys_nucleon_exclusion_radius_value",
+                            params.nucleon[3],
+                            0.0..=5.0,
+                            self.phys_nucleon_exclusion_radius_focused,
+                            &self.phys_nucleon_exclusion_radius_text,
+                            self.phys_nucleon_exclusion_radius_cursor,
+                            self.phys_nucleon_exclusion_radius_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(6, self.physics_sections_expanded[6], || {
+                    vec![
+                        // electron: x: exclusion_strength, y: exclusion_radius, z: padding, w: padding
+                        Self::slider_with_value_row(
+                            "Excl strength",
+                            "phys_electron_exclusion_strength",
+                            "phys_electron_exclusion_strength_value",
+                            params.electron[0],
+                            0.0..=500.0,
+                            self.phys_electron_exclusion_strength_focused,
+                            &self.phys_electron_exclusion_strength_text,
+                            self.phys_electron_exclusion_strength_cursor,
+                            self.phys_electron_exclusion_strength_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Excl radius",
+                            "phys_electron_exclusion_radius",
+                            "phys_electron_exclusion_radius_value",
+                            params.electron[1],
+                            0.0..=5.0,
+                            self.phys_electron_exclusion_radius_focused,
+                            &self.phys_electron_exclusion_radius_text,
+                            self.phys_electron_exclusion_radius_cursor,
+                            self.phys_electron_exclusion_radius_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(7, self.physics_sections_expanded[7], || {
+                    vec![
+                        // hadron: x: binding_distance, y: breakup_distance, z: confinement_range_mult, w: confinement_strength_mult
+                        Self::slider_with_value_row(
+                            "Bind dist",
+                            "phys_hadron_binding_distance",
+                            "phys_hadron_binding_distance_value",
+                            params.hadron[0],
+                            0.0..=5.0,
+                            self.phys_hadron_binding_distance_focused,
+                            &self.phys_hadron_binding_distance_text,
+                            self.phys_hadron_binding_distance_cursor,
+                            self.phys_hadron_binding_distance_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Break dist",
+                            "phys_hadron_breakup_distance",
+                            "phys_hadron_breakup_distance_value",
+                            params.hadron[1],
+                            0.0..=5.0,
+                            self.phys_hadron_breakup_distance_focused,
+                            &self.phys_hadron_breakup_distance_text,
+                            self.phys_hadron_breakup_distance_cursor,
+                            self.phys_hadron_breakup_distance_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Conf range",
+                            "phys_hadron_conf_range_mult",
+                            "phys_hadron_conf_range_mult_value",
+                            params.hadron[2],
+                            0.0..=5.0,
+                            self.phys_hadron_conf_range_mult_focused,
+                            &self.phys_hadron_conf_range_mult_text,
+                            self.phys_hadron_conf_range_mult_cursor,
+                            self.phys_hadron_conf_range_mult_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Conf strength",
+                            "phys_hadron_conf_strength_mult",
+                            "phys_hadron_conf_strength_mult_value",
+                            params.hadron[3],
+                            0.0..=5.0,
+                            self.phys_hadron_conf_strength_mult_focused,
+                            &self.phys_hadron_conf_strength_mult_text,
+                            self.phys_hadron_conf_strength_mult_cursor,
+                            self.phys_hadron_conf_strength_mult_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(8, self.physics_sections_expanded[8], || {
+                    vec![
+                        // string_breaking: x: string_tension, y: pair_threshold, z: enabled
+                        Self::toggle_row(
+                            "phys_string_breaking_enabled",
+                            "Pair creation",
+                            params.string_breaking[2] > 0.5,
+                        ),
+                        Self::slider_with_value_row(
+                            "Tension",
+                            "phys_string_tension",
+                            "phys_string_tension_value",
+                            params.string_breaking[0],
+                            0.0..=5.0,
+                            self.phys_string_tension_focused,
+                            &self.phys_string_tension_text,
+                            self.phys_string_tension_cursor,
+                            self.phys_string_tension_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Pair threshold",
+                            "phys_string_pair_threshold",
+                            "phys_string_pair_threshold_value",
+                            params.string_breaking[1],
+                            0.0..=1.0,
+                            self.phys_string_pair_threshold_focused,
+                            &self.phys_string_pair_threshold_text,
+                            self.phys_string_pair_threshold_cursor,
+                            self.phys_string_pair_threshold_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(9, self.physics_sections_expanded[9], || {
+                    vec![
+                        // boundary: x: radius, y: reinject, z: temperature
+                        Self::slider_with_value_row(
+                            "Radius",
+                            "phys_boundary_radius",
+                            "phys_boundary_radius_value",
+                            params.boundary[0],
+                            0.0..=500.0,
+                            self.phys_boundary_radius_focused,
+                            &self.phys_boundary_radius_text,
+                            self.phys_boundary_radius_cursor,
+                            self.phys_boundary_radius_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::toggle_row(
+                            "phys_boundary_reinject",
+                            "Re-inject escapes",
+                            params.boundary[1] > 0.5,
+                        ),
+                        Self::slider_with_value_row(
+                            "Temperature",
+                            "phys_boundary_temperature",
+                            "phys_boundary_temperature_value",
+                            params.boundary[2],
+                            0.0..=1.0,
+                            self.phys_boundary_temperature_focused,
+                            &self.phys_boundary_temperature_text,
+                            self.phys_boundary_temperature_cursor,
+                            self.phys_boundary_temperature_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(10, self.physics_sections_expanded[10], || {
+                    vec![
+                        // decay: x: meson_lifetime, y: exotic_baryon_lifetime, z: enabled
+                        Self::toggle_row(
+                            "phys_decay_enabled",
+                            "Decay unstable hadrons",
+                            params.decay[2] > 0.5,
+                        ),
+                        Self::slider_with_value_row(
+                            "Meson lifetime",
+                            "phys_decay_meson_lifetime",
+                            "phys_decay_meson_lifetime_value",
+                            params.decay[0],
+                            0.01..=10.0,
+                            self.phys_decay_meson_lifetime_focused,
+                            &self.phys_decay_meson_lifetime_text,
+                            self.phys_decay_meson_lifetime_cursor,
+                            self.phys_decay_meson_lifetime_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Baryon lifetime",
+                            "phys_decay_baryon_lifetime",
+                            "phys_decay_baryon_lifetime_value",
+                            params.decay[1],
+                            0.01..=10.0,
+                            self.phys_decay_baryon_lifetime_focused,
+                            &self.phys_decay_baryon_lifetime_text,
+                            self.phys_decay_baryon_lifetime_cursor,
+                            self.phys_decay_baryon_lifetime_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(11, self.physics_sections_expanded[11], || {
+                    vec![
+                        // degeneracy: x: strength, y: radius, z: density threshold, w: enabled
+                        Self::toggle_row(
+                            "phys_degeneracy_enabled",
+                            "Pauli pressure at high density",
+                            params.degeneracy[3] > 0.5,
+                        ),
+                        Self::slider_with_value_row(
+                            "Strength",
+                            "phys_degeneracy_strength",
+                            "phys_degeneracy_strength_value",
+                            params.degeneracy[0],
+                            0.0..=100.0,
+                            self.phys_degeneracy_strength_focused,
+                            &self.phys_degeneracy_strength_text,
+                            self.phys_degeneracy_strength_cursor,
+                            self.phys_degeneracy_strength_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Radius",
+                            "phys_degeneracy_radius",
+                            "phys_degeneracy_radius_value",
+                            params.degeneracy[1],
+                            0.05..=5.0,
+                            self.phys_degeneracy_radius_focused,
+                            &self.phys_degeneracy_radius_text,
+                            self.phys_degeneracy_radius_cursor,
+                            self.phys_degeneracy_radius_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Threshold",
+                            "phys_degeneracy_threshold",
+                            "phys_degeneracy_threshold_value",
+                            params.degeneracy[2],
+                            0.0..=20.0,
+                            self.phys_degeneracy_threshold_focused,
+                            &self.phys_degeneracy_threshold_text,
+                            self.phys_degeneracy_threshold_cursor,
+                            self.phys_degeneracy_threshold_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(12, self.physics_sections_expanded[12], || {
+                    vec![
+                        // color_flow: x: rate, y: gluon range, z: enabled
+                        Self::toggle_row(
+                            "phys_color_flow_enabled",
+                            "Gluons rotate quark color",
+                            params.color_flow[2] > 0.5,
+                        ),
+                        Self::slider_with_value_row(
+                            "Rate",
+                            "phys_color_flow_rate",
+                            "phys_color_flow_rate_value",
+                            params.color_flow[0],
+                            0.0..=100.0,
+                            self.phys_color_flow_rate_focused,
+                            &self.phys_color_flow_rate_text,
+                            self.phys_color_flow_rate_cursor,
+                            self.phys_color_flow_rate_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Gluon Range",
+                            "phys_color_flow_range",
+                            "phys_color_flow_range_value",
+                            params.color_flow[1],
+                            0.1..=10.0,
+                            self.phys_color_flow_range_focused,
+                            &self.phys_color_flow_range_text,
+                            self.phys_color_flow_range_cursor,
+                            self.phys_color_flow_range_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                Self::physics_section(13, self.physics_sections_expanded[13], || {
+                    vec![
+                        // vacuum: x: pair rate, y: lifetime, z: clearance, w: enabled
+                        Self::toggle_row(
+                            "phys_vacuum_enabled",
+                            "Spawn short-lived pairs",
+                            params.vacuum[3] > 0.5,
+                        ),
+                        Self::slider_with_value_row(
+                            "Pair Rate",
+                            "phys_vacuum_rate",
+                            "phys_vacuum_rate_value",
+                            params.vacuum[0],
+                            0.0..=50.0,
+                            self.phys_vacuum_rate_focused,
+                            &self.phys_vacuum_rate_text,
+                            self.phys_vacuum_rate_cursor,
+                            self.phys_vacuum_rate_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Lifetime",
+                            "phys_vacuum_lifetime",
+                            "phys_vacuum_lifetime_value",
+                            params.vacuum[1],
+                            0.05..=10.0,
+                            self.phys_vacuum_lifetime_focused,
+                            &self.phys_vacuum_lifetime_text,
+                            self.phys_vacuum_lifetime_cursor,
+                            self.phys_vacuum_lifetime_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                        Self::slider_with_value_row(
+                            "Clearance",
+                            "phys_vacuum_clearance",
+                            "phys_vacuum_clearance_value",
+                            params.vacuum[2],
+                            0.0..=20.0,
+                            self.phys_vacuum_clearance_focused,
+                            &self.phys_vacuum_clearance_text,
+                            self.phys_vacuum_clearance_cursor,
+                            self.phys_vacuum_clearance_selection,
+                            &mut self.text_engine,
+                            &mut self.event_dispatcher,
+                        ),
+                    ]
+                }),
+                // Per-force softening and the species coupling matrix
+                Self::physics_section(14, self.physics_sections_expanded[14], || {
+                    self.coupling_matrix_rows(&params)
+                }),
+            ]
+            .into_iter()
+            .chain(std::iter::once(Self::line_text(
+                if self.physics_params_dirty {
                     "Pending: upload needed"
                 } else {
                     "Synced"
-                }),
-            ]
+                },
+            )))
+            .collect()
         } else {
             Vec::new()
         };
@@ -1410,6 +3493,155 @@ impl Gui {
             ))
     }
 
+    /// One group of the physics panel (`PHYSICS_SECTIONS[index]`); collapsed groups build no
+    /// widgets, like the panels themselves.
+    fn physics_section(index: usize, expanded: bool, children: impl FnOnce() -> Vec<Node>) -> Node {
+        let (id, title) = PHYSICS_SECTIONS[index];
+        let inner = Node::new()
+            .with_layout_direction(Layout::Vertical)
+            .with_gap(Size::lpx(10.0))
+            .with_children(if expanded { children() } else { Vec::new() });
+
+        collapsible(
+            id,
+            title,
+            expanded,
+            false,
+            vec![inner],
+            &CollapsibleStyle::default()
+                .with_title_font_size(14.0)
+                .with_header_padding(Spacing::all(Size::lpx(6.0)))
+                .with_content_padding(Spacing::trbl(
+                    Size::lpx(4.0),
+                    Size::lpx(6.0),
+                    Size::lpx(6.0),
+                    Size::lpx(6.0),
+                )),
+        )
+    }
+
+    /// Species coupling matrix editor: force selector + one slider per unique species pair.
+    fn coupling_matrix_rows(&mut self, params: &PhysicsParams) -> Vec<Node> {
+        let force = self.coupling_force;
+
+        let mut rows = vec![
+            Node::new()
+                .with_layout_direction(Layout::Horizontal)
+                .with_gap(Size::lpx(10.0))
+                .with_children(
+                    COUPLING_FORCE_BUTTONS
+                        .iter()
+                        .map(|&(id, label, f)| {
+                            button(id, label, f == force, &ButtonStyle::default())
+                        })
+                        .collect(),
+                ),
+            Self::toggle_row("phys_force_enabled", "Enabled", params.force_enabled(force)),
+            Self::slider_with_value_row(
+                "Softening",
+                "phys_force_softening",
+                "phys_force_softening_value",
+                params.softening(force),
+                0.0..=2.0,
+                self.force_softening_focused,
+                &self.force_softening_text,
+                self.force_softening_cursor,
+                self.force_softening_selection,
+                &mut self.text_engine,
+                &mut self.event_dispatcher,
+            ),
+            Self::panel_section_title("Coupling Matrix"),
+        ];
+
+        for (i, &(label, slider_id, value_id, a, b)) in COUPLING_PAIRS.iter().enumerate() {
+            rows.push(Self::slider_with_value_row(
+                label,
+                slider_id,
+                value_id,
+                params.coupling(force, a, b),
+                -2.0..=2.0,
+                self.coupling_focused[i],
+                &self.coupling_text[i],
+                self.coupling_cursor[i],
+                self.coupling_selection[i],
+                &mut self.text_engine,
+                &mut self.event_dispatcher,
+            ));
+        }
+
+        rows
+    }
+
+    fn beam_rows(&mut self, ui_state: &UiState) -> Vec<Node> {
+        let running = ui_state.beam_progress.is_some();
+
+        let mut rows = vec![
+            Self::title_text("Beam Experiment"),
+            Self::slider_with_value_row(
+                "Energy",
+                "beam_energy",
+                "beam_energy_value",
+                self.beam_energy,
+                0.0..=10.0,
+                self.beam_energy_focused,
+                &self.beam_energy_text,
+                self.beam_energy_cursor,
+                self.beam_energy_selection,
+                &mut self.text_engine,
+                &mut self.event_dispatcher,
+            ),
+            Self::slider_with_value_row(
+                "Impact b",
+                "beam_impact",
+                "beam_impact_value",
+                self.beam_impact,
+                0.0..=20.0,
+                self.beam_impact_focused,
+                &self.beam_impact_text,
+                self.beam_impact_cursor,
+                self.beam_impact_selection,
+                &mut self.text_engine,
+                &mut self.event_dispatcher,
+            ),
+            button(
+                "beam_fire_toggle",
+                if running { "Stop beams" } else { "Fire beams" },
+                false,
+                &ButtonStyle::default(),
+            ),
+        ];
+
+        if let Some((event, count)) = ui_state.beam_progress {
+            rows.push(Self::line_text(format!("Event {}/{}", event + 1, count)));
+        }
+
+        if let Some(last) = ui_state.beam_events.last() {
+            let n = ui_state.beam_events.len() as f32;
+            let mean = |f: fn(&BeamEventStats) -> u32| {
+                ui_state.beam_events.iter().map(f).sum::<u32>() as f32 / n
+            };
+            rows.push(Self::line_text(format!(
+                "Last (E={:.2}, b={:.2}): {} hadrons, {} p, {} n, {} photons",
+                last.energy,
+                last.impact_parameter,
+                last.hadrons,
+                last.protons,
+                last.neutrons,
+                last.photons,
+            )));
+            rows.push(Self::line_text(format!(
+                "Mean over {} events: {:.1} hadrons, {:.1} p, {:.1} n, {:.1} photons",
+                ui_state.beam_events.len(),
+                mean(|e| e.hadrons),
+                mean(|e| e.protons),
+                mean(|e| e.neutrons),
+                mean(|e| e.photons),
+            )));
+        }
+
+        rows
+    }
+
     fn time_controls_panel(&mut self, ui_state: &UiState) -> Node {
         let steps_remaining = ui_state.steps_remaining;
 
@@ -1457,6 +3689,9 @@ impl Gui {
                 ),
                 Self::line_text(format!("Remaining: {steps_remaining}")),
             ]
+            .into_iter()
+            .chain(self.beam_rows(ui_state))
+            .collect()
         } else {
             Vec::new()
         };
@@ -1506,6 +3741,11 @@ impl Gui {
         if collapsible_clicked("atom_card_collapsible", &self.last_events) {
             self.atom_card_expanded = !self.atom_card_expanded;
         }
+        for (i, &(id, _)) in PHYSICS_SECTIONS.iter().enumerate() {
+            if collapsible_clicked(id, &self.last_events) {
+                self.physics_sections_expanded[i] = !self.physics_sections_expanded[i];
+            }
+        }
 
         // Render toggles
         if toggle_clicked("toggle_shells", &self.last_events) {
@@ -1520,6 +3760,265 @@ impl Gui {
             self.render_nuclei = !self.render_nuclei;
             ui_state.show_nuclei = self.render_nuclei;
         }
+        if toggle_clicked("toggle_photons", &self.last_events) {
+            self.render_photons = !self.render_photons;
+            ui_state.show_photons = self.render_photons;
+        }
+        if toggle_clicked("toggle_oit_shells", &self.last_events) {
+            self.render_oit_shells = !self.render_oit_shells;
+            ui_state.oit_shells = self.render_oit_shells;
+        }
+        if toggle_clicked("toggle_labels", &self.last_events) {
+            self.render_labels = !self.render_labels;
+            ui_state.show_labels = self.render_labels;
+        }
+        if toggle_clicked("toggle_orthographic", &self.last_events) {
+            self.render_orthographic = !self.render_orthographic;
+            ui_state.orthographic = self.render_orthographic;
+        }
+        if toggle_clicked("toggle_free_fly", &self.last_events) {
+            self.render_free_fly = !self.render_free_fly;
+            ui_state.free_fly = self.render_free_fly;
+        }
+        if toggle_clicked("toggle_rest_frame", &self.last_events) {
+            self.render_rest_frame = !self.render_rest_frame;
+            ui_state.rest_frame = self.render_rest_frame;
+        }
+        if toggle_clicked("toggle_cull", &self.last_events) {
+            self.render_cull = !self.render_cull;
+            ui_state.cull_particles = self.render_cull;
+        }
+        if toggle_clicked("toggle_density_splats", &self.last_events) {
+            self.render_density_splats = !self.render_density_splats;
+            ui_state.density_splats = self.render_density_splats;
+        }
+        if slider_with_value_update(
+            "density_splat_threshold",
+            "density_splat_threshold_value",
+            &mut self.density_splat_threshold,
+            &mut self.density_splat_threshold_text,
+            &mut self.density_splat_threshold_cursor,
+            &mut self.density_splat_threshold_selection,
+            &mut self.density_splat_threshold_focused,
+            &mut self.density_splat_threshold_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            8.0..=512.0,
+            0.5,
+            None,
+        ) {
+            ui_state.density_splat_threshold = self.density_splat_threshold.round() as u32;
+        }
+
+        // Scene MSAA (the app rebuilds the scene pipelines when the count changes)
+        for &(id, _, count) in &MSAA_BUTTONS {
+            if button_clicked(id, &self.last_events) {
+                self.msaa_samples = count;
+                ui_state.msaa_samples = count;
+            }
+        }
+
+        // Present mode (the app reconfigures the surface)
+        for &(id, _, mode) in &PRESENT_MODE_BUTTONS {
+            if button_clicked(id, &self.last_events) {
+                self.present_mode = mode;
+                ui_state.present_mode = mode;
+            }
+        }
+
+        // Render scale (the app resizes the scene targets)
+        for &(id, _, percent) in &RENDER_SCALE_BUTTONS {
+            if button_clicked(id, &self.last_events) {
+                self.render_scale = percent;
+                ui_state.render_scale = percent;
+            }
+        }
+
+        // Screenshots (the app re-renders the scene offscreen and writes the PNG)
+        for &(id, _, scale) in &SCREENSHOT_SCALE_BUTTONS {
+            if button_clicked(id, &self.last_events) {
+                self.screenshot_scale = scale;
+                ui_state.screenshot_scale = scale;
+            }
+        }
+        if button_clicked("screenshot", &self.last_events) {
+            ui_state.screenshot_requested = true;
+        }
+        if button_clicked("inspector_toggle", &self.last_events) {
+            ui_state.inspector_toggle_requested = true;
+        }
+
+        // Recording (the app owns the recorder; the format is fixed while it runs)
+        if ui_state.recording_frames.is_none() {
+            for &(id, _, format) in &RECORDING_FORMAT_BUTTONS {
+                if button_clicked(id, &self.last_events) {
+                    self.recording_format = format;
+                    ui_state.recording_format = format;
+                }
+            }
+        }
+        if button_clicked("record_toggle", &self.last_events) {
+            ui_state.recording_toggle_requested = true;
+        }
+
+        // Camera bookmarks (the app captures/applies the poses)
+        if button_clicked("camera_bookmark_save", &self.last_events) {
+            ui_state.camera_bookmark_save_requested = true;
+        }
+        if button_clicked("camera_bookmark_clear", &self.last_events) {
+            ui_state.camera_bookmark_clear_requested = true;
+        }
+        // Measurement tool (the app picks and resolves the entities)
+        if toggle_clicked("toggle_measure", &self.last_events) {
+            self.render_measure = !self.render_measure;
+            ui_state.measure_mode = self.render_measure;
+        }
+        if button_clicked("measure_clear", &self.last_events) {
+            ui_state.measure_clear_requested = true;
+        }
+        if button_clicked("camera_flythrough", &self.last_events) {
+            ui_state.camera_flythrough_toggle_requested = true;
+        }
+        for (index, &id) in CAMERA_BOOKMARK_BUTTONS.iter().enumerate() {
+            if button_clicked(id, &self.last_events) {
+                ui_state.camera_bookmark_goto = Some(index);
+            }
+        }
+
+        // HDR bloom + kinetic energy glow
+        if toggle_clicked("toggle_bloom", &self.last_events) {
+            self.render_bloom = !self.render_bloom;
+            ui_state.bloom_enabled = self.render_bloom;
+        }
+        if slider_with_value_update(
+            "bloom_intensity",
+            "bloom_intensity_value",
+            &mut self.bloom_intensity,
+            &mut self.bloom_intensity_text,
+            &mut self.bloom_intensity_cursor,
+            &mut self.bloom_intensity_selection,
+            &mut self.bloom_intensity_focused,
+            &mut self.bloom_intensity_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=2.0,
+            0.05,
+            None,
+        ) {
+            ui_state.bloom_intensity = self.bloom_intensity;
+        }
+        if slider_with_value_update(
+            "energy_glow",
+            "energy_glow_value",
+            &mut self.energy_glow,
+            &mut self.energy_glow_text,
+            &mut self.energy_glow_cursor,
+            &mut self.energy_glow_selection,
+            &mut self.energy_glow_focused,
+            &mut self.energy_glow_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=500.0,
+            0.05,
+            None,
+        ) {
+            ui_state.energy_glow = self.energy_glow;
+        }
+        if toggle_clicked("toggle_trails", &self.last_events) {
+            self.render_trails = !self.render_trails;
+            ui_state.show_trails = self.render_trails;
+        }
+        if slider_with_value_update(
+            "trail_length",
+            "trail_length_value",
+            &mut self.trail_length,
+            &mut self.trail_length_text,
+            &mut self.trail_length_cursor,
+            &mut self.trail_length_selection,
+            &mut self.trail_length_focused,
+            &mut self.trail_length_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            2.0..=64.0,
+            0.05,
+            None,
+        ) {
+            ui_state.trail_length = self.trail_length.round() as u32;
+        }
+
+        // Debug vectors (the force field pass only runs while the slice is shown)
+        if toggle_clicked("toggle_velocity_arrows", &self.last_events) {
+            self.render_velocity_arrows = !self.render_velocity_arrows;
+            ui_state.show_velocity_arrows = self.render_velocity_arrows;
+        }
+        if slider_with_value_update(
+            "velocity_arrow_scale",
+            "velocity_arrow_scale_value",
+            &mut self.velocity_arrow_scale,
+            &mut self.velocity_arrow_scale_text,
+            &mut self.velocity_arrow_scale_cursor,
+            &mut self.velocity_arrow_scale_selection,
+            &mut self.velocity_arrow_scale_focused,
+            &mut self.velocity_arrow_scale_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.05..=10.0,
+            0.05,
+            None,
+        ) {
+            ui_state.velocity_arrow_scale = self.velocity_arrow_scale;
+        }
+        if toggle_clicked("toggle_force_field", &self.last_events) {
+            self.render_force_field = !self.render_force_field;
+            ui_state.show_force_field = self.render_force_field;
+        }
+
+        // Reference grid, axes and scale bar
+        if toggle_clicked("toggle_grid", &self.last_events) {
+            self.render_grid = !self.render_grid;
+            ui_state.show_grid = self.render_grid;
+        }
+        if toggle_clicked("toggle_axes", &self.last_events) {
+            self.render_axes = !self.render_axes;
+            ui_state.show_axes = self.render_axes;
+        }
+        if toggle_clicked("toggle_scale_bar", &self.last_events) {
+            self.render_scale_bar = !self.render_scale_bar;
+            ui_state.show_scale_bar = self.render_scale_bar;
+        }
+        if toggle_clicked("toggle_minimap", &self.last_events) {
+            self.render_minimap = !self.render_minimap;
+            ui_state.show_minimap = self.render_minimap;
+        }
+        for &(id, _, corner) in &MINIMAP_CORNER_BUTTONS {
+            if button_clicked(id, &self.last_events) {
+                self.minimap_corner = corner;
+                ui_state.minimap_corner = corner;
+            }
+        }
+        if slider_with_value_update(
+            "force_field_extent",
+            "force_field_extent_value",
+            &mut self.force_field_extent,
+            &mut self.force_field_extent_text,
+            &mut self.force_field_extent_cursor,
+            &mut self.force_field_extent_selection,
+            &mut self.force_field_extent_focused,
+            &mut self.force_field_extent_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            5.0..=500.0,
+            0.05,
+            None,
+        ) {
+            ui_state.force_field_extent = self.force_field_extent;
+        }
 
         // LOD sliders (continuous, with drag-value)
         if slider_with_value_update(
@@ -1538,7 +4037,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_shell_fade_start = self.lod_shell_fade_start;
+            ui_state.lod.shell.start = self.lod_shell_fade_start;
         }
 
         if slider_with_value_update(
@@ -1557,7 +4056,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_shell_fade_end = self.lod_shell_fade_end;
+            ui_state.lod.shell.end = self.lod_shell_fade_end;
         }
 
         if slider_with_value_update(
@@ -1576,7 +4075,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_bound_hadron_fade_start = self.lod_bound_hadron_fade_start;
+            ui_state.lod.bound_hadron.start = self.lod_bound_hadron_fade_start;
         }
 
         if slider_with_value_update(
@@ -1595,7 +4094,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_bound_hadron_fade_end = self.lod_bound_hadron_fade_end;
+            ui_state.lod.bound_hadron.end = self.lod_bound_hadron_fade_end;
         }
 
         if slider_with_value_update(
@@ -1614,7 +4113,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_bond_fade_start = self.lod_bond_fade_start;
+            ui_state.lod.bond.start = self.lod_bond_fade_start;
         }
 
         if slider_with_value_update(
@@ -1633,7 +4132,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_bond_fade_end = self.lod_bond_fade_end;
+            ui_state.lod.bond.end = self.lod_bond_fade_end;
         }
 
         if slider_with_value_update(
@@ -1652,7 +4151,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_quark_fade_start = self.lod_quark_fade_start;
+            ui_state.lod.quark.start = self.lod_quark_fade_start;
         }
 
         if slider_with_value_update(
@@ -1671,7 +4170,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_quark_fade_end = self.lod_quark_fade_end;
+            ui_state.lod.quark.end = self.lod_quark_fade_end;
         }
 
         if slider_with_value_update(
@@ -1690,7 +4189,7 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_nucleus_fade_start = self.lod_nucleus_fade_start;
+            ui_state.lod.nucleus.start = self.lod_nucleus_fade_start;
         }
 
         if slider_with_value_update(
@@ -1709,7 +4208,173 @@ impl Gui {
             0.05,
             None,
         ) {
-            ui_state.lod_nucleus_fade_end = self.lod_nucleus_fade_end;
+            ui_state.lod.nucleus.end = self.lod_nucleus_fade_end;
+        }
+
+        // Particle impostors
+        if slider_with_value_update(
+            "lod_impostor_fade_start",
+            "lod_impostor_fade_start_value",
+            &mut self.lod_impostor_fade_start,
+            &mut self.lod_impostor_fade_start_text,
+            &mut self.lod_impostor_fade_start_cursor,
+            &mut self.lod_impostor_fade_start_selection,
+            &mut self.lod_impostor_fade_start_focused,
+            &mut self.lod_impostor_fade_start_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=500.0,
+            0.05,
+            None,
+        ) {
+            ui_state.lod.impostor.start = self.lod_impostor_fade_start;
+        }
+
+        if slider_with_value_update(
+            "lod_impostor_fade_end",
+            "lod_impostor_fade_end_value",
+            &mut self.lod_impostor_fade_end,
+            &mut self.lod_impostor_fade_end_text,
+            &mut self.lod_impostor_fade_end_cursor,
+            &mut self.lod_impostor_fade_end_selection,
+            &mut self.lod_impostor_fade_end_focused,
+            &mut self.lod_impostor_fade_end_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=500.0,
+            0.05,
+            None,
+        ) {
+            ui_state.lod.impostor.end = self.lod_impostor_fade_end;
+        }
+
+        for (species, &(id, _)) in IMPOSTOR_SPECIES_BUTTONS.iter().enumerate() {
+            if button_clicked(id, &self.last_events) {
+                self.impostor_species = species;
+            }
+        }
+        for &(near_id, far_id, shape) in &IMPOSTOR_SHAPE_BUTTONS {
+            let impostor = &mut ui_state.lod.impostors[self.impostor_species];
+            if button_clicked(near_id, &self.last_events) {
+                impostor.near = shape;
+            }
+            if button_clicked(far_id, &self.last_events) {
+                impostor.far = shape;
+            }
+        }
+
+        // Cohort tagging (the app resolves the selection and dispatches the tag pass)
+        if button_clicked("cohort_tag", &self.last_events) {
+            ui_state.tag_requested = true;
+        }
+        if slider_with_value_update(
+            "cohort_radius",
+            "cohort_radius_value",
+            &mut self.cohort_radius,
+            &mut self.cohort_radius_text,
+            &mut self.cohort_radius_cursor,
+            &mut self.cohort_radius_selection,
+            &mut self.cohort_radius_focused,
+            &mut self.cohort_radius_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.5..=50.0,
+            0.05,
+            None,
+        ) {
+            ui_state.tag_radius = self.cohort_radius;
+        }
+
+        // Coarse-graining LOD
+        let mut coarse_changed = false;
+        if toggle_clicked("coarse_enabled", &self.last_events) {
+            let enabled = self.coarse.enabled();
+            self.coarse.set_enabled(!enabled);
+            coarse_changed = true;
+        }
+
+        if slider_with_value_update(
+            "coarse_merge_distance",
+            "coarse_merge_distance_value",
+            &mut self.coarse.camera[3],
+            &mut self.coarse_merge_distance_text,
+            &mut self.coarse_merge_distance_cursor,
+            &mut self.coarse_merge_distance_selection,
+            &mut self.coarse_merge_distance_focused,
+            &mut self.coarse_merge_distance_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            10.0..=500.0,
+            0.5,
+            None,
+        ) {
+            coarse_changed = true;
+        }
+
+        if slider_with_value_update(
+            "coarse_split_distance",
+            "coarse_split_distance_value",
+            &mut self.coarse.config[0],
+            &mut self.coarse_split_distance_text,
+            &mut self.coarse_split_distance_cursor,
+            &mut self.coarse_split_distance_selection,
+            &mut self.coarse_split_distance_focused,
+            &mut self.coarse_split_distance_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            5.0..=500.0,
+            0.5,
+            None,
+        ) {
+            coarse_changed = true;
+        }
+
+        if slider_with_value_update(
+            "coarse_merge_radius",
+            "coarse_merge_radius_value",
+            &mut self.coarse.config[1],
+            &mut self.coarse_merge_radius_text,
+            &mut self.coarse_merge_radius_cursor,
+            &mut self.coarse_merge_radius_selection,
+            &mut self.coarse_merge_radius_focused,
+            &mut self.coarse_merge_radius_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.1..=10.0,
+            0.01,
+            None,
+        ) {
+            coarse_changed = true;
+        }
+
+        if slider_with_value_update(
+            "coarse_max_members",
+            "coarse_max_members_value",
+            &mut self.coarse.config[2],
+            &mut self.coarse_max_members_text,
+            &mut self.coarse_max_members_cursor,
+            &mut self.coarse_max_members_selection,
+            &mut self.coarse_max_members_focused,
+            &mut self.coarse_max_members_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            2.0..=64.0,
+            0.1,
+            None,
+        ) {
+            coarse_changed = true;
+        }
+        if coarse_changed {
+            // Splitting must happen closer than merging, or clusters would flip every frame
+            self.coarse.config[0] = self.coarse.config[0].min(self.coarse.camera[3]);
+            ui_state.coarse_params = self.coarse;
         }
 
         // Time step dt (physics_params.integration.x)
@@ -1761,7 +4426,52 @@ impl Gui {
             0.05,
             Some(1.0),
         ) {
-            ui_state.steps_to_play = self.steps_to_play.round().clamp(1.0, 240.0) as u32;
+            ui_state.steps_to_play = self.steps_to_play.round().clamp(1.0, 240.0) as u32;
+        }
+
+        // Beam experiment (the app regenerates particles when a run starts)
+        if button_clicked("beam_fire_toggle", &self.last_events) {
+            if ui_state.beam_progress.is_some() {
+                ui_state.beam_stop_requested = true;
+            } else {
+                ui_state.beam_fire_requested = true;
+            }
+        }
+        if slider_with_value_update(
+            "beam_energy",
+            "beam_energy_value",
+            &mut self.beam_energy,
+            &mut self.beam_energy_text,
+            &mut self.beam_energy_cursor,
+            &mut self.beam_energy_selection,
+            &mut self.beam_energy_focused,
+            &mut self.beam_energy_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=10.0,
+            0.01,
+            None,
+        ) {
+            ui_state.beam_config.energy = self.beam_energy;
+        }
+        if slider_with_value_update(
+            "beam_impact",
+            "beam_impact_value",
+            &mut self.beam_impact,
+            &mut self.beam_impact_text,
+            &mut self.beam_impact_cursor,
+            &mut self.beam_impact_selection,
+            &mut self.beam_impact_focused,
+            &mut self.beam_impact_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=20.0,
+            0.02,
+            None,
+        ) {
+            ui_state.beam_config.impact_parameter = self.beam_impact;
         }
 
         // Physics controls (write-through to UiState + mark dirty)
@@ -2031,14 +4741,14 @@ impl Gui {
 
         // nucleon: x/y/z/w
         if slider_with_value_update(
-            "phys_nucleon_binding_strength",
-            "phys_nucleon_binding_strength_value",
-            &mut ui_state.physics_params.nucleon[0],
-            &mut self.phys_nucleon_binding_strength_text,
-            &mut self.phys_nucleon_binding_strength_cursor,
-            &mut self.phys_nucleon_binding_strength_selection,
-            &mut self.phys_nucleon_binding_strength_focused,
-            &mut self.phys_nucleon_binding_strength_drag_accumulator,
+            "phys_residual_strength",
+            "phys_residual_strength_value",
+            &mut ui_state.physics_params.residual_strong[0],
+            &mut self.phys_residual_strength_text,
+            &mut self.phys_residual_strength_cursor,
+            &mut self.phys_residual_strength_selection,
+            &mut self.phys_residual_strength_focused,
+            &mut self.phys_residual_strength_drag_accumulator,
             &self.last_events,
             &self.input_state,
             &mut self.event_dispatcher,
@@ -2050,6 +4760,46 @@ impl Gui {
             self.physics_params_dirty = true;
         }
 
+        if slider_with_value_update(
+            "phys_residual_range",
+            "phys_residual_range_value",
+            &mut ui_state.physics_params.residual_strong[1],
+            &mut self.phys_residual_range_text,
+            &mut self.phys_residual_range_cursor,
+            &mut self.phys_residual_range_selection,
+            &mut self.phys_residual_range_focused,
+            &mut self.phys_residual_range_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.1..=10.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_residual_cutoff",
+            "phys_residual_cutoff_value",
+            &mut ui_state.physics_params.residual_strong[2],
+            &mut self.phys_residual_cutoff_text,
+            &mut self.phys_residual_cutoff_cursor,
+            &mut self.phys_residual_cutoff_selection,
+            &mut self.phys_residual_cutoff_focused,
+            &mut self.phys_residual_cutoff_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            1.0..=6.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
         if slider_with_value_update(
             "phys_nucleon_binding_range",
             "phys_nucleon_binding_range_value",
@@ -2231,6 +4981,502 @@ impl Gui {
             ui_state.physics_params_dirty = true;
             self.physics_params_dirty = true;
         }
+
+        if toggle_clicked("phys_string_breaking_enabled", &self.last_events) {
+            let enabled = &mut ui_state.physics_params.string_breaking[2];
+            *enabled = if *enabled > 0.5 { 0.0 } else { 1.0 };
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_string_tension",
+            "phys_string_tension_value",
+            &mut ui_state.physics_params.string_breaking[0],
+            &mut self.phys_string_tension_text,
+            &mut self.phys_string_tension_cursor,
+            &mut self.phys_string_tension_selection,
+            &mut self.phys_string_tension_focused,
+            &mut self.phys_string_tension_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=5.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_string_pair_threshold",
+            "phys_string_pair_threshold_value",
+            &mut ui_state.physics_params.string_breaking[1],
+            &mut self.phys_string_pair_threshold_text,
+            &mut self.phys_string_pair_threshold_cursor,
+            &mut self.phys_string_pair_threshold_selection,
+            &mut self.phys_string_pair_threshold_focused,
+            &mut self.phys_string_pair_threshold_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=1.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_boundary_radius",
+            "phys_boundary_radius_value",
+            &mut ui_state.physics_params.boundary[0],
+            &mut self.phys_boundary_radius_text,
+            &mut self.phys_boundary_radius_cursor,
+            &mut self.phys_boundary_radius_selection,
+            &mut self.phys_boundary_radius_focused,
+            &mut self.phys_boundary_radius_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=500.0,
+            0.5,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if toggle_clicked("phys_boundary_reinject", &self.last_events) {
+            let reinject = &mut ui_state.physics_params.boundary[1];
+            *reinject = if *reinject > 0.5 { 0.0 } else { 1.0 };
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_boundary_temperature",
+            "phys_boundary_temperature_value",
+            &mut ui_state.physics_params.boundary[2],
+            &mut self.phys_boundary_temperature_text,
+            &mut self.phys_boundary_temperature_cursor,
+            &mut self.phys_boundary_temperature_selection,
+            &mut self.phys_boundary_temperature_focused,
+            &mut self.phys_boundary_temperature_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=1.0,
+            0.001,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if toggle_clicked("phys_decay_enabled", &self.last_events) {
+            let enabled = &mut ui_state.physics_params.decay[2];
+            *enabled = if *enabled > 0.5 { 0.0 } else { 1.0 };
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_decay_meson_lifetime",
+            "phys_decay_meson_lifetime_value",
+            &mut ui_state.physics_params.decay[0],
+            &mut self.phys_decay_meson_lifetime_text,
+            &mut self.phys_decay_meson_lifetime_cursor,
+            &mut self.phys_decay_meson_lifetime_selection,
+            &mut self.phys_decay_meson_lifetime_focused,
+            &mut self.phys_decay_meson_lifetime_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.01..=10.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_decay_baryon_lifetime",
+            "phys_decay_baryon_lifetime_value",
+            &mut ui_state.physics_params.decay[1],
+            &mut self.phys_decay_baryon_lifetime_text,
+            &mut self.phys_decay_baryon_lifetime_cursor,
+            &mut self.phys_decay_baryon_lifetime_selection,
+            &mut self.phys_decay_baryon_lifetime_focused,
+            &mut self.phys_decay_baryon_lifetime_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.01..=10.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if toggle_clicked("phys_degeneracy_enabled", &self.last_events) {
+            let enabled = &mut ui_state.physics_params.degeneracy[3];
+            *enabled = if *enabled > 0.5 { 0.0 } else { 1.0 };
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_degeneracy_strength",
+            "phys_degeneracy_strength_value",
+            &mut ui_state.physics_params.degeneracy[0],
+            &mut self.phys_degeneracy_strength_text,
+            &mut self.phys_degeneracy_strength_cursor,
+            &mut self.phys_degeneracy_strength_selection,
+            &mut self.phys_degeneracy_strength_focused,
+            &mut self.phys_degeneracy_strength_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=100.0,
+            0.5,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_degeneracy_radius",
+            "phys_degeneracy_radius_value",
+            &mut ui_state.physics_params.degeneracy[1],
+            &mut self.phys_degeneracy_radius_text,
+            &mut self.phys_degeneracy_radius_cursor,
+            &mut self.phys_degeneracy_radius_selection,
+            &mut self.phys_degeneracy_radius_focused,
+            &mut self.phys_degeneracy_radius_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.05..=5.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_degeneracy_threshold",
+            "phys_degeneracy_threshold_value",
+            &mut ui_state.physics_params.degeneracy[2],
+            &mut self.phys_degeneracy_threshold_text,
+            &mut self.phys_degeneracy_threshold_cursor,
+            &mut self.phys_degeneracy_threshold_selection,
+            &mut self.phys_degeneracy_threshold_focused,
+            &mut self.phys_degeneracy_threshold_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=20.0,
+            0.1,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if toggle_clicked("phys_color_flow_enabled", &self.last_events) {
+            let enabled = &mut ui_state.physics_params.color_flow[2];
+            *enabled = if *enabled > 0.5 { 0.0 } else { 1.0 };
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_color_flow_rate",
+            "phys_color_flow_rate_value",
+            &mut ui_state.physics_params.color_flow[0],
+            &mut self.phys_color_flow_rate_text,
+            &mut self.phys_color_flow_rate_cursor,
+            &mut self.phys_color_flow_rate_selection,
+            &mut self.phys_color_flow_rate_focused,
+            &mut self.phys_color_flow_rate_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=100.0,
+            0.5,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_color_flow_range",
+            "phys_color_flow_range_value",
+            &mut ui_state.physics_params.color_flow[1],
+            &mut self.phys_color_flow_range_text,
+            &mut self.phys_color_flow_range_cursor,
+            &mut self.phys_color_flow_range_selection,
+            &mut self.phys_color_flow_range_focused,
+            &mut self.phys_color_flow_range_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.1..=10.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if toggle_clicked("phys_vacuum_enabled", &self.last_events) {
+            let enabled = &mut ui_state.physics_params.vacuum[3];
+            *enabled = if *enabled > 0.5 { 0.0 } else { 1.0 };
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_vacuum_rate",
+            "phys_vacuum_rate_value",
+            &mut ui_state.physics_params.vacuum[0],
+            &mut self.phys_vacuum_rate_text,
+            &mut self.phys_vacuum_rate_cursor,
+            &mut self.phys_vacuum_rate_selection,
+            &mut self.phys_vacuum_rate_focused,
+            &mut self.phys_vacuum_rate_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=50.0,
+            0.1,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_vacuum_lifetime",
+            "phys_vacuum_lifetime_value",
+            &mut ui_state.physics_params.vacuum[1],
+            &mut self.phys_vacuum_lifetime_text,
+            &mut self.phys_vacuum_lifetime_cursor,
+            &mut self.phys_vacuum_lifetime_selection,
+            &mut self.phys_vacuum_lifetime_focused,
+            &mut self.phys_vacuum_lifetime_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.05..=10.0,
+            0.01,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        if slider_with_value_update(
+            "phys_vacuum_clearance",
+            "phys_vacuum_clearance_value",
+            &mut ui_state.physics_params.vacuum[2],
+            &mut self.phys_vacuum_clearance_text,
+            &mut self.phys_vacuum_clearance_cursor,
+            &mut self.phys_vacuum_clearance_selection,
+            &mut self.phys_vacuum_clearance_focused,
+            &mut self.phys_vacuum_clearance_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=20.0,
+            0.05,
+            None,
+        ) {
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        for &(id, _, force) in &COUPLING_FORCE_BUTTONS {
+            if button_clicked(id, &self.last_events) {
+                self.coupling_force = force;
+            }
+        }
+
+        if toggle_clicked("phys_force_enabled", &self.last_events) {
+            let force = self.coupling_force;
+            let enabled = ui_state.physics_params.force_enabled(force);
+            ui_state.physics_params.set_force_enabled(force, !enabled);
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        let mut softening = ui_state.physics_params.softening(self.coupling_force);
+        if slider_with_value_update(
+            "phys_force_softening",
+            "phys_force_softening_value",
+            &mut softening,
+            &mut self.force_softening_text,
+            &mut self.force_softening_cursor,
+            &mut self.force_softening_selection,
+            &mut self.force_softening_focused,
+            &mut self.force_softening_drag_accumulator,
+            &self.last_events,
+            &self.input_state,
+            &mut self.event_dispatcher,
+            0.0..=2.0,
+            0.005,
+            None,
+        ) {
+            ui_state
+                .physics_params
+                .set_softening(self.coupling_force, softening);
+            ui_state.physics_params_dirty = true;
+            self.physics_params_dirty = true;
+        }
+
+        for (i, &(_, slider_id, value_id, a, b)) in COUPLING_PAIRS.iter().enumerate() {
+            let force = self.coupling_force;
+            let mut value = ui_state.physics_params.coupling(force, a, b);
+            if slider_with_value_update(
+                slider_id,
+                value_id,
+                &mut value,
+                &mut self.coupling_text[i],
+                &mut self.coupling_cursor[i],
+                &mut self.coupling_selection[i],
+                &mut self.coupling_focused[i],
+                &mut self.coupling_drag_accumulator[i],
+                &self.last_events,
+                &self.input_state,
+                &mut self.event_dispatcher,
+                -2.0..=2.0,
+                0.01,
+                None,
+            ) {
+                ui_state.physics_params.set_coupling(force, a, b, value);
+                ui_state.physics_params_dirty = true;
+                self.physics_params_dirty = true;
+            }
+        }
+    }
+
+    fn entity_labels(ui_state: &UiState, window_size: [f32; 2]) -> Node {
+        // Each tag sits in its own full-size layer, pushed to its bottom-left corner with
+        // padding (the root's zoom and padding turn surface pixels into layer lpx).
+        let tags = ui_state
+            .labels
+            .iter()
+            .filter_map(|label| {
+                let left = label.tag[0] / UI_ZOOM - UI_PADDING;
+                let bottom = (window_size[1] - label.tag[1]) / UI_ZOOM - UI_PADDING;
+                (left >= 0.0 && bottom >= 0.0).then(|| {
+                    Node::new()
+                        .with_width(Size::Fill)
+                        .with_height(Size::Fill)
+                        .with_h_align(HorizontalAlign::Left)
+                        .with_v_align(VerticalAlign::Bottom)
+                        .with_padding(Spacing::trbl(
+                            Size::lpx(0.0),
+                            Size::lpx(0.0),
+                            Size::lpx(bottom),
+                            Size::lpx(left),
+                        ))
+                        .with_child(
+                            Node::new()
+                                .with_style(Style {
+                                    fill_color: Some(mocha::BASE.with_alpha(0.85)),
+                                    stroke: Some(Stroke::new(Size::lpx(1.0), mocha::SURFACE2)),
+                                    corner_shape: Some(CornerShape::Round(Size::lpx(8.0))),
+                                    ..Default::default()
+                                })
+                                .with_padding(Spacing::all(Size::lpx(4.0)))
+                                .with_child(Self::line_text(label.text.clone())),
+                        )
+                })
+            })
+            .collect();
+
+        Node::new()
+            .with_id("entity_labels")
+            .with_layout_direction(Layout::Stack)
+            .with_width(Size::Fill)
+            .with_height(Size::Fill)
+            .with_children(tags)
+    }
+
+    fn hover_tooltip(ui_state: &UiState) -> Node {
+        // Bottom-center, only while something is hovered.
+        let Some(hover) = ui_state.hover else {
+            return Node::new().with_id("hover_tooltip_hidden");
+        };
+
+        let text = match hover {
+            HoverInfo::Particle {
+                charge,
+                virtual_pair,
+                ..
+            } => format!(
+                "{}{}, charge {}",
+                hover.label(),
+                if virtual_pair { " (virtual)" } else { "" },
+                format_charge_thirds((charge * 3.0).round() as i32),
+            ),
+            HoverInfo::Hadron { type_id } => match type_id {
+                1 => "Proton, charge +1".to_string(),
+                2 => "Neutron, charge 0".to_string(),
+                0 => "Meson".to_string(),
+                _ => "Baryon".to_string(),
+            },
+            HoverInfo::Nucleus => "Nucleus (click for details)".to_string(),
+        };
+
+        Node::new()
+            .with_id("hover_tooltip")
+            .with_style(Self::panel_frame())
+            .with_padding(Spacing::all(Size::lpx(10.0)))
+            .with_child(Self::line_text(text))
+    }
+
+    fn scale_bar(ui_state: &UiState) -> Node {
+        let Some(bar) = ui_state
+            .scale_bar
+            .as_ref()
+            .filter(|_| ui_state.show_scale_bar)
+        else {
+            return Node::new().with_id("scale_bar_hidden");
+        };
+
+        // The bar is measured in surface pixels; the root zoom scales logical pixels
+        Node::new()
+            .with_id("scale_bar")
+            .with_layout_direction(Layout::Vertical)
+            .with_h_align(HorizontalAlign::Center)
+            .with_gap(Size::lpx(4.0))
+            .with_padding(Spacing::trbl(
+                Size::lpx(0.0),
+                Size::lpx(0.0),
+                Size::lpx(SCALE_BAR_MARGIN),
+                Size::lpx(0.0),
+            ))
+            .with_children(vec![
+                Self::line_text(bar.label.clone()),
+                Node::new()
+                    .with_width(Size::lpx(bar.pixels / UI_ZOOM))
+                    .with_height(Size::lpx(3.0))
+                    .with_style(Style {
+                        fill_color: Some(mocha::TEXT),
+                        corner_shape: Some(CornerShape::Round(Size::lpx(1.5))),
+                        ..Default::default()
+                    }),
+            ])
     }
 
     fn atom_card(&mut self, ui_state: &UiState) -> Node {
@@ -2291,3 +5537,14 @@ impl Gui {
             ))
     }
 }
+
+/// Format a charge given in units of e/3 ("+1", "-2/3", "0")
+fn format_charge_thirds(thirds: i32) -> String {
+    if thirds == 0 {
+        "0".to_string()
+    } else if thirds % 3 == 0 {
+        format!("{:+}", thirds / 3)
+    } else {
+        format!("{:+}/3", thirds)
+    }
+}
@@ -4,31 +4,107 @@
 
 mod gui;
 mod gui_data;
+#[cfg(target_arch = "wasm32")]
+mod web;
 
 use astra_gui::DebugOptions;
 use astra_gui_wgpu::Renderer as AstraRenderer;
 use glam::Vec3;
-use gui::{Gui, UiState};
-use particle_physics::{ColorCharge, Particle};
+use gui::{nucleus_label, EntityLabel, Gui, HoverInfo, UiState, CAMERA_BOOKMARK_BUTTONS};
+use particle_physics::{ColorCharge, Hadron, Particle};
 use particle_renderer::{
-    Camera, GpuPicker, HadronRenderer, NucleusRenderer, ParticleRenderer, PickingRenderer,
+    capture_screenshot, pipeline_cache_features, profiler_features, supported_sample_counts,
+    BloomRenderer, BloomSettings, Camera, CameraMode, CameraPose, CpuPicker, FlyThrough, GpuPicker,
+    GpuProfiler, GridRenderer, HadronRenderer, LabelRenderer, MeasureRenderer, MeasureTool,
+    MinimapRenderer, NucleusRenderer, OitRenderer, ParticleRenderer, PhotonRenderer, PickRay,
+    PickingRenderer, PipelineCache, Recording, ScaleBar, ScreenLabel, TrailRenderer,
+    VectorRenderer, HDR_FORMAT,
+};
+use particle_simulation::{
+    BeamEventStats, BeamExperiment, ForceFieldParams, ParticleSimulation, SelectionKind,
+    SelectionSet, SelectionTarget, PHOTON_CAPACITY, SELECTION_TARGET_SIZE,
 };
-use particle_simulation::ParticleSimulation;
 use rand::Rng;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
 use std::sync::Arc;
-use std::time::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+use web_time::{Instant, SystemTime, UNIX_EPOCH};
 use winit::{
     application::ApplicationHandler,
     event::*,
-    event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
+    event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy},
     keyboard::{KeyCode, PhysicalKey},
     window::{Window, WindowId},
 };
 
 const PARTICLE_COUNT: usize = 8000;
+// Blocking GPU readbacks (statistics, picking, screenshots, recording). WebGPU only maps
+// buffers asynchronously, so the web build renders without them.
+const READBACKS: bool = cfg!(not(target_arch = "wasm32"));
 const SPAWN_RADIUS: f32 = 50.0;
+const PACKED_FORCE_SOURCES_MIN: usize = 4096; // Particle count from which the force pass reads f16-packed sources
 const PARTICLE_SCALE: f32 = 3.0; // Global scale multiplier for visibility
+const SELECTION_FRAME_FILL: f32 = 0.3; // Fraction of the view a followed selection spans
+const MIN_FOLLOW_DISTANCE: f32 = 2.0; // Closest the camera zooms in on a tiny selection
+const CPU_PICKING: bool = false; // Click picking by CPU ray cast over a readback snapshot instead of the ID pass
+const PRESENT_MODE: wgpu::PresentMode = wgpu::PresentMode::AutoNoVsync; // Default, switchable in the render panel
+const MSAA_SAMPLES: u32 = 4; // Default scene anti-aliasing (falls back to 1 if unsupported)
+const LABEL_OFFSET: [f32; 2] = [24.0, -24.0]; // Entity -> label tag corner (surface pixels)
+const MEASURE_LABEL_OFFSET: f32 = 6.0; // Measured point -> value tag corner (surface pixels)
+const VELOCITY_ARROW_MAX_LENGTH: f32 = 20.0; // Longest velocity arrow in the vector view (world units)
+const SCALE_BAR_MAX_PIXELS: f32 = 240.0; // Longest scale bar (and grid cell) on screen (surface pixels)
+const MINIMAP_SIZE: f32 = 220.0; // Side of the overview minimap (surface pixels)
+const MINIMAP_MARGIN: f32 = 16.0; // Minimap -> window corner gap (surface pixels)
+const MINIMAP_EXTENT_MARGIN: f32 = 1.1; // Overview half size per boundary radius
+const INSPECTOR_SIZE: [u32; 2] = [1280, 900]; // Logical size of the detached panel window
+const CAPTURE_DIR: &str = "captures"; // Screenshots (F12) and recordings land here, relative to the working directory
+const PIPELINE_CACHE_DIR: &str = "cache"; // Driver pipeline cache (one file per adapter), relative to the working directory
+const RECORDING_FPS: u32 = 60; // Playback rate of recordings (every rendered frame is recorded)
+const FLYTHROUGH_SEGMENT_SECONDS: f32 = 4.0; // Time between consecutive bookmarks in a fly-through
+const BOOKMARK_JUMP_SECONDS: f32 = 1.5; // Duration of the move to a single bookmark
+
+/// App-level keyboard actions (bound in `SHORTCUTS`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shortcut {
+    Quit,
+    ResetCamera,
+    Screenshot,
+    ToggleFreeFly,
+    TogglePause,
+    StepFrame,
+}
+
+impl Shortcut {
+    /// Whether holding the key repeats the action
+    fn repeats(self) -> bool {
+        self == Shortcut::StepFrame
+    }
+}
+
+/// Key bindings: (key, Ctrl held, action). Each (key, Ctrl) pair appears once; the astra debug
+/// keys (M/P/B/O/R/G/D) only see keys not bound here.
+const SHORTCUTS: [(KeyCode, bool, Shortcut); 7] = [
+    (KeyCode::Escape, false, Shortcut::Quit),
+    (KeyCode::KeyC, false, Shortcut::ResetCamera),
+    (KeyCode::F12, false, Shortcut::Screenshot),
+    (KeyCode::KeyF, false, Shortcut::ToggleFreeFly),
+    (KeyCode::Space, false, Shortcut::TogglePause),
+    (KeyCode::ArrowRight, true, Shortcut::StepFrame),
+    (KeyCode::KeyD, true, Shortcut::StepFrame),
+];
+
+/// Free-fly movement keys and their view-space directions (-z forward)
+const FLY_KEYS: [(KeyCode, Vec3); 6] = [
+    (KeyCode::KeyW, Vec3::NEG_Z),
+    (KeyCode::KeyS, Vec3::Z),
+    (KeyCode::KeyA, Vec3::NEG_X),
+    (KeyCode::KeyD, Vec3::X),
+    (KeyCode::KeyE, Vec3::Y),
+    (KeyCode::KeyQ, Vec3::NEG_Y),
+];
 
 /// Initialize particles with quarks and electrons
 fn initialize_particles() -> Vec<Particle> {
@@ -38,7 +114,7 @@ fn initialize_particles() -> Vec<Particle> {
     let colors = [ColorCharge::Red, ColorCharge::Green, ColorCharge::Blue];
 
     // Create particles: mostly quarks, some electrons
-    for _ in 0..PARTICLE_COUNT {
+    for _ in 0..PARTICLE_COUNT - PAIR_POOL_SIZE {
         // Random position in sphere
         let theta = rng.random::<f32>() * std::f32::consts::TAU;
         let cos_phi = rng.random::<f32>() * 2.0 - 1.0;
@@ -66,6 +142,9 @@ fn initialize_particles() -> Vec<Particle> {
         particles.push(particle);
     }
 
+    // Remaining slots start dormant; string breaking spawns quark/antiquark pairs into them
+    particles.resize(PARTICLE_COUNT, Particle::new_dormant());
+
     log::info!("✓ Initialized {} particles", PARTICLE_COUNT);
     log::info!(
         "  Particle struct size: {} bytes",
@@ -87,34 +166,84 @@ fn initialize_particles() -> Vec<Particle> {
     particles
 }
 
+/// Detached GUI window: the panels move here and the main window keeps the 3D view with its
+/// labels, tooltip and scale bar. Shares the device, queue and simulation with the main window.
+struct Inspector {
+    window: Arc<Window>,
+    surface: wgpu::Surface<'static>,
+    config: wgpu::SurfaceConfiguration,
+    astra_renderer: AstraRenderer,
+}
+
 struct GpuState {
+    // Kept to create the surfaces of further windows (the inspector)
+    instance: wgpu::Instance,
+    adapter: wgpu::Adapter,
     surface: wgpu::Surface<'static>,
     device: wgpu::Device,
     queue: wgpu::Queue,
     config: wgpu::SurfaceConfiguration,
+    /// Scene resolution in percent of the window (the bloom composite upscales it)
+    render_scale: u32,
 
     simulation: ParticleSimulation,
     renderer: ParticleRenderer,
     hadron_renderer: HadronRenderer,
     nucleus_renderer: NucleusRenderer,
+    photon_renderer: PhotonRenderer,
+    trail_renderer: TrailRenderer,
+    vector_renderer: VectorRenderer,
+    grid_renderer: GridRenderer,
+    oit_renderer: OitRenderer,
+    label_renderer: LabelRenderer,
+    measure_renderer: MeasureRenderer,
+    minimap_renderer: MinimapRenderer,
+    bloom_renderer: BloomRenderer,
+    profiler: GpuProfiler,
+    /// Compiled pipelines kept across runs (empty without driver support)
+    pipeline_cache: PipelineCache,
     camera: Camera,
 
     gui: Gui,
     astra_renderer: AstraRenderer,
+    inspector: Option<Inspector>,
     ui_state: UiState,
-    hadron_count_staging_buffer: wgpu::Buffer,
     _nucleus_count_staging_buffer: wgpu::Buffer,
 
     // GPU picking (ID render + 1px readback)
     picker: GpuPicker,
     picking_renderer: PickingRenderer,
+    // CPU ray picking fallback (only with `CPU_PICKING`)
+    cpu_picker: Option<CpuPicker>,
 
     // Camera lock (follow selected entity)
     camera_lock: Option<CameraLock>,
 
     // Selection resolve (GPU -> CPU readback for camera target)
     selection_target_staging_buffer: wgpu::Buffer,
-    selection_target_cached: Option<[f32; 4]>,
+    selection_target_cached: Option<SelectionTarget>,
+    /// What the selection was when it was clicked (from the hover readback), for its label
+    selection_info: Option<HoverInfo>,
+    screen_labels: Vec<ScreenLabel>,
+
+    // Multi-entity selection (Shift + Left Click), highlighted by the particle renderer
+    selection_set: SelectionSet,
+
+    // Measurement tool: picked IDs (resolved every frame) and their current centers
+    measure_tool: MeasureTool,
+
+    // Beam experiment mode (None = free-running simulation)
+    beam_experiment: Option<BeamExperiment>,
+
+    // Free-fly movement keys currently held
+    fly_keys: HashSet<KeyCode>,
+
+    // Saved camera views and the fly-through currently driving the camera (if any)
+    camera_bookmarks: Vec<CameraPose>,
+    fly_through: Option<FlyThrough>,
+
+    // Running frame recording (started/stopped from the render panel)
+    recording: Option<Recording>,
 
     // Selected nucleus readback (for atom card UI)
     nucleus_readback_staging_buffer: wgpu::Buffer,
@@ -149,6 +278,18 @@ enum CameraLock {
     Nucleus { anchor_hadron_index: u32 },
 }
 
+/// File stem for captures: `particles-<unix seconds>-<millis>`
+fn capture_stem() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    format!(
+        "particles-{}-{:03}",
+        timestamp.as_secs(),
+        timestamp.subsec_millis()
+    )
+}
+
 fn decode_pick_id(raw: u32) -> Option<CameraLock> {
     if raw == 0 {
         return None;
@@ -185,6 +326,170 @@ fn decode_pick_id(raw: u32) -> Option<CameraLock> {
 }
 
 impl GpuState {
+    /// Render the picking ID pass into the picker target (whole target, or only the scissor
+    /// rect for hover picks).
+    fn encode_pick_pass(&mut self, encoder: &mut wgpu::CommandEncoder, scissor: Option<[u32; 4]>) {
+        self.picking_renderer.set_scissor(scissor);
+        let scope = self.profiler.begin(encoder, "Picking");
+        self.picking_renderer.render(
+            &self.device,
+            &self.queue,
+            encoder,
+            &self.picker.id_texture_view,
+            &self.camera,
+            self.simulation.particle_buffer(),
+            self.simulation.hadron_buffer(),
+            self.simulation.hadron_count_buffer(),
+            self.simulation.nucleus_buffer(),
+            self.simulation.nucleus_count_buffer(),
+            self.simulation.particle_count(),
+            self.simulation.particle_count(), // max_hadrons == particle_count allocation
+            self.simulation.particle_count() / 4, // match render path's rough max nuclei
+            self.picking_particle_size,
+            self.ui_state.physics_params.integration[2],
+            &self.ui_state.lod,
+        );
+        self.profiler.end(encoder, scope);
+    }
+
+    /// Map a window cursor position to pick-target pixel coordinates (same mapping as click
+    /// picking).
+    fn pick_pixel(&self, window: &Window, (x, y): (f64, f64)) -> (u32, u32) {
+        let size = window.inner_size();
+        let px = (x / size.width.max(1) as f64 * self.config.width as f64)
+            .floor()
+            .clamp(0.0, self.config.width.saturating_sub(1) as f64);
+        let py = (y / size.height.max(1) as f64 * self.config.height as f64)
+            .floor()
+            .clamp(0.0, self.config.height.saturating_sub(1) as f64);
+        (px as u32, py as u32)
+    }
+
+    /// Box select: pick every entity visible inside the rectangle between two pick pixels and
+    /// add them to the selection set.
+    fn box_select(&mut self, a: (u32, u32), b: (u32, u32)) {
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Box Select Encoder"),
+            });
+        self.encode_pick_pass(&mut encoder, None);
+        let ids = self
+            .picker
+            .select_region(&self.device, &self.queue, encoder, a, b);
+
+        let mut added = 0;
+        for &id in &ids {
+            if !self.selection_set.insert(id) {
+                log::warn!(
+                    "Selection set is full, {} of {} boxed entities not added",
+                    ids.len() - added,
+                    ids.len()
+                );
+                break;
+            }
+            added += 1;
+        }
+        log::debug!("box select: {} entities in {:?}..{:?}", ids.len(), a, b);
+        self.simulation.update_selection_set(&self.selection_set);
+    }
+
+    /// Throttled hover picking: land finished readbacks (highlight + tooltip), then start a
+    /// scissored pick around the cursor when one is due. Never blocks on the GPU.
+    fn update_hover(&mut self) {
+        if self.picker.hover_in_flight() {
+            let _ = self.device.poll(wgpu::PollType::Poll);
+        }
+        self.picker.poll_hover();
+
+        // Cleared immediately when the cursor leaves the scene, so refresh every frame
+        let hover = self.picker.hover();
+        self.renderer.set_hovered_id(hover.id);
+        self.ui_state.hover = match decode_pick_id(hover.id) {
+            None => None,
+            Some(CameraLock::Nucleus { .. }) => Some(HoverInfo::Nucleus),
+            Some(CameraLock::Particle { .. }) => self.picker.hover_record().and_then(|record| {
+                let particle: Particle = bytemuck::pod_read_unaligned(record);
+                Some(HoverInfo::Particle {
+                    particle_type: particle.get_type()?,
+                    charge: particle.data[0],
+                    virtual_pair: particle.data[3] != 0.0,
+                })
+            }),
+            Some(CameraLock::Hadron { .. }) => self.picker.hover_record().map(|record| {
+                let hadron: Hadron =
+                    bytemuck::pod_read_unaligned(&record[..std::mem::size_of::<Hadron>()]);
+                HoverInfo::Hadron {
+                    type_id: hadron.type_id,
+                }
+            }),
+        };
+
+        let Some((x, y)) = self.picker.hover_pick_due() else {
+            return;
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Hover Picking Encoder"),
+            });
+        let scissor = self.picker.hover_scissor(x, y);
+        self.encode_pick_pass(&mut encoder, Some(scissor));
+        self.picking_renderer.set_scissor(None);
+
+        // Carry the hovered entity's record along so the tooltip can describe it
+        let record = match decode_pick_id(self.picker.hover().id) {
+            Some(CameraLock::Particle { particle_index }) => Some((
+                self.simulation.particle_buffer(),
+                particle_index as u64 * std::mem::size_of::<Particle>() as u64,
+                std::mem::size_of::<Particle>() as u64,
+            )),
+            Some(CameraLock::Hadron { hadron_index }) => Some((
+                self.simulation.hadron_buffer(),
+                hadron_index as u64 * std::mem::size_of::<Hadron>() as u64,
+                std::mem::size_of::<Hadron>() as u64,
+            )),
+            _ => None,
+        };
+
+        self.picker.encode_read_hover(&mut encoder, x, y, record);
+        self.queue.submit(std::iter::once(encoder.finish()));
+        self.picker.begin_hover_readback();
+    }
+
+    /// Resolve the measured entities and mirror the readout into the UI.
+    fn update_measurement(&mut self) {
+        if std::mem::take(&mut self.ui_state.measure_clear_requested) {
+            self.measure_tool.clear(&self.simulation);
+        }
+        self.measure_tool.update(
+            &self.device,
+            &self.queue,
+            &self.simulation,
+            self.ui_state.measure_mode,
+        );
+        self.ui_state.measure_readout = self.measure_tool.readout().to_vec();
+    }
+
+    /// Tags with the measured distances (at the leg midpoints) and angle (at the vertex).
+    fn update_measure_labels(&mut self) {
+        for (position, text) in self.measure_tool.tags() {
+            if let Some(anchor) =
+                self.camera
+                    .world_to_screen(position, self.config.width, self.config.height)
+            {
+                self.ui_state.labels.push(EntityLabel {
+                    text,
+                    tag: [
+                        anchor.x + MEASURE_LABEL_OFFSET,
+                        anchor.y - MEASURE_LABEL_OFFSET,
+                    ],
+                });
+            }
+        }
+    }
+
     /// Read back nucleus data for the atom card UI.
     /// Searches through nuclei to find the one with the matching anchor hadron index.
     /// Uses a cached staging buffer with dynamic search range (starts at 50, grows to 1000 if needed).
@@ -307,34 +612,74 @@ impl GpuState {
         );
     }
 
-    async fn new(window: Arc<Window>) -> Self {
-        let size = window.inner_size();
-
-        // Create wgpu instance
+    /// Instance, surface and adapter on `backends` (`None` if no adapter can present to the
+    /// window there).
+    async fn request_adapter(
+        window: &Arc<Window>,
+        backends: wgpu::Backends,
+        force_fallback_adapter: bool,
+    ) -> Option<(wgpu::Instance, wgpu::Surface<'static>, wgpu::Adapter)> {
         let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::all(),
+            backends,
             ..Default::default()
         });
-
-        let surface = instance.create_surface(window.clone()).unwrap();
-
-        // Request adapter
+        let surface = instance.create_surface(window.clone()).ok()?;
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: wgpu::PowerPreference::HighPerformance,
                 compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
+                force_fallback_adapter,
             })
             .await
-            .unwrap();
+            .ok()?;
+        Some((instance, surface, adapter))
+    }
+
+    async fn new(window: Arc<Window>, backends: wgpu::Backends) -> Self {
+        let size = window.inner_size();
+
+        // Requested backends first, then any backend, then a software adapter
+        let mut attempts = vec![(backends, false)];
+        if backends != wgpu::Backends::all() {
+            attempts.push((wgpu::Backends::all(), false));
+        }
+        attempts.push((wgpu::Backends::all(), true));
+        let mut found = None;
+        for (attempt_backends, force_fallback_adapter) in attempts {
+            found = Self::request_adapter(&window, attempt_backends, force_fallback_adapter).await;
+            if found.is_some() {
+                break;
+            }
+            log::warn!(
+                "No {}adapter on backends {:?}, falling back",
+                if force_fallback_adapter {
+                    "software "
+                } else {
+                    ""
+                },
+                attempt_backends
+            );
+        }
+        let (instance, surface, adapter) =
+            found.expect("No graphics adapter can present to the window on any backend");
 
-        log::info!("✓ Using GPU: {}", adapter.get_info().name);
+        let adapter_info = adapter.get_info();
+        log::info!(
+            "✓ Using GPU: {} ({:?})",
+            adapter_info.name,
+            adapter_info.backend
+        );
 
         // Create device and queue
         let (device, queue) = adapter
             .request_device(&wgpu::DeviceDescriptor {
                 label: Some("Device"),
-                required_features: wgpu::Features::empty(),
+                // Unlocks MSAA sample counts beyond 1 and 4 where the adapter has them, the
+                // timestamp queries of the GPU pass profiler and the pipeline cache
+                required_features: adapter.features()
+                    & (wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
+                        | profiler_features()
+                        | pipeline_cache_features()),
                 required_limits: wgpu::Limits::default(),
                 memory_hints: wgpu::MemoryHints::default(),
                 experimental_features: wgpu::ExperimentalFeatures::default(),
@@ -357,22 +702,40 @@ impl GpuState {
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::AutoNoVsync,
+            present_mode: PRESENT_MODE,
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
         };
         surface.configure(&device, &config);
+        // The auto modes fall back to Fifo, which every surface supports
+        let mut present_modes = surface_caps.present_modes.clone();
+        present_modes.extend([wgpu::PresentMode::AutoVsync, wgpu::PresentMode::AutoNoVsync]);
 
         // Initialize particles
         let particles = initialize_particles();
 
         // Create simulation
-        let simulation = ParticleSimulation::new(device.clone(), queue.clone(), &particles).await;
+        let mut simulation =
+            ParticleSimulation::new(device.clone(), queue.clone(), &particles).await;
+        simulation.set_frame_overlap(true);
+        // f16 force sources only pay off once the N-body pass is bandwidth bound
+        simulation.set_packed_force_sources(PARTICLE_COUNT >= PACKED_FORCE_SOURCES_MIN);
         log::info!("✓ Simulation initialized");
 
+        // Pipelines compiled on earlier runs (this adapter and driver only)
+        let pipeline_cache =
+            PipelineCache::load(&device, &adapter_info, Path::new(PIPELINE_CACHE_DIR));
+        let cache = pipeline_cache.get();
+
         // Create renderer
-        let renderer = ParticleRenderer::new(&device, &config);
+        let msaa_sample_counts = supported_sample_counts(&adapter, device.features());
+        let msaa_samples = if msaa_sample_counts.contains(&MSAA_SAMPLES) {
+            MSAA_SAMPLES
+        } else {
+            1
+        };
+        let renderer = ParticleRenderer::new(&device, &config, msaa_samples, cache);
         log::info!("✓ Renderer initialized");
 
         // Create hadron renderer
@@ -380,19 +743,66 @@ impl GpuState {
             label: Some("Dummy Layout"),
             entries: &[],
         });
-        let hadron_renderer = HadronRenderer::new(&device, config.format, &dummy_layout);
+        let hadron_renderer =
+            HadronRenderer::new(&device, HDR_FORMAT, &dummy_layout, msaa_samples, cache);
         log::info!("✓ Hadron Renderer initialized");
 
-        let nucleus_renderer = NucleusRenderer::new(&device, config.format, &dummy_layout);
+        let nucleus_renderer =
+            NucleusRenderer::new(&device, HDR_FORMAT, &dummy_layout, msaa_samples, cache);
         log::info!("✓ Nucleus Renderer initialized");
 
+        let photon_renderer = PhotonRenderer::new(&device, HDR_FORMAT, msaa_samples, cache);
+        log::info!("✓ Photon Renderer initialized");
+
+        let trail_renderer = TrailRenderer::new(&device, HDR_FORMAT, msaa_samples, cache);
+        log::info!("✓ Trail Renderer initialized");
+
+        let vector_renderer = VectorRenderer::new(&device, HDR_FORMAT, msaa_samples, cache);
+        log::info!("✓ Vector Renderer initialized");
+
+        let grid_renderer = GridRenderer::new(&device, HDR_FORMAT, msaa_samples, cache);
+        log::info!("✓ Grid Renderer initialized");
+
+        let oit_renderer = OitRenderer::new(&device, &config, msaa_samples, cache);
+        log::info!("✓ OIT Renderer initialized");
+
+        // Leader lines go straight onto the surface, after bloom
+        let label_renderer = LabelRenderer::new(&device, config.format, cache);
+        log::info!("✓ Label Renderer initialized");
+        let measure_renderer = MeasureRenderer::new(&device, config.format, cache);
+        log::info!("✓ Measure Renderer initialized");
+        let minimap_renderer = MinimapRenderer::new(&device, config.format, cache);
+        log::info!("✓ Minimap Renderer initialized");
+
+        // The scene renders into an HDR target; bloom composites it onto the surface
+        let bloom_renderer = BloomRenderer::new(&device, &config, cache);
+        log::info!("✓ Bloom Renderer initialized");
+
+        // Per-pass GPU timings for the diagnostics panel (no-op without timestamp queries)
+        let profiler = GpuProfiler::new(&device, &queue);
+        log::info!(
+            "✓ GPU profiler initialized (timestamps {})",
+            if profiler.is_supported() {
+                "supported"
+            } else {
+                "unsupported"
+            }
+        );
+
         // Create camera
         let camera = Camera::new(size.width, size.height);
 
         // Create GUI (astra-gui placeholder)
         let gui = Gui::new();
         let astra_renderer = AstraRenderer::new(&device, config.format);
-        let ui_state = UiState::default();
+        let ui_state = UiState {
+            msaa_samples,
+            msaa_sample_counts,
+            gpu_timings_supported: profiler.is_supported(),
+            present_mode: PRESENT_MODE,
+            present_modes,
+            ..Default::default()
+        };
 
         // GPU picking:
         // - ID target is RGBA8 (packed u32 ID)
@@ -409,15 +819,17 @@ impl GpuState {
             wgpu::TextureFormat::Depth32Float,
             config.width,
             config.height,
+            cache,
         );
-
-        // Create staging buffer for reading hadron counters:
-        // [total_hadrons, protons, neutrons, other]
-        let hadron_count_staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Hadron Count Staging Buffer"),
-            size: 16,
-            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
+        // Every render pipeline exists now; keep them for the next launch
+        save_pipeline_cache(&pipeline_cache);
+
+        let cpu_picker = CPU_PICKING.then(|| {
+            CpuPicker::new(
+                &device,
+                simulation.particle_buffer().size(),
+                simulation.hadron_buffer().size(),
+            )
         });
 
         // Create staging buffer for reading nucleus counter
@@ -428,14 +840,16 @@ impl GpuState {
             mapped_at_creation: false,
         });
 
-        // Selection target readback (vec4<f32> = 16 bytes)
+        // Selection target readback (center/kind + velocity/radius)
         let selection_target_staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Selection Target Staging Buffer"),
-            size: 16,
+            size: SELECTION_TARGET_SIZE,
             usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
 
+        let measure_tool = MeasureTool::new(&device);
+
         // Selected nucleus readback (for atom card UI)
         // Nucleus struct size: 64 (hadron_indices) + 4*4 (counts/type_id) + 16 (center) + 16 (velocity) = 112 bytes
         let initial_nucleus_capacity = 100u32;
@@ -448,28 +862,57 @@ impl GpuState {
         });
 
         Self {
+            instance,
+            adapter,
             surface,
             device,
             queue,
             config,
+            render_scale: 100,
             simulation,
             renderer,
             hadron_renderer,
             nucleus_renderer,
+            photon_renderer,
+            trail_renderer,
+            vector_renderer,
+            grid_renderer,
+            oit_renderer,
+            label_renderer,
+            measure_renderer,
+            minimap_renderer,
+            profiler,
+            pipeline_cache,
+            bloom_renderer,
             camera,
             gui,
             astra_renderer,
+            inspector: None,
             ui_state,
-            hadron_count_staging_buffer,
             _nucleus_count_staging_buffer,
 
             picker,
             picking_renderer,
+            cpu_picker,
 
             camera_lock: None,
 
             selection_target_staging_buffer,
             selection_target_cached: None,
+            selection_info: None,
+            screen_labels: Vec::new(),
+            selection_set: SelectionSet::default(),
+
+            measure_tool,
+
+            beam_experiment: None,
+
+            fly_keys: HashSet::new(),
+
+            camera_bookmarks: Vec::new(),
+            fly_through: None,
+
+            recording: None,
 
             nucleus_readback_staging_buffer,
             nucleus_readback_capacity: initial_nucleus_capacity,
@@ -493,7 +936,8 @@ impl GpuState {
             self.config.width = new_size.width;
             self.config.height = new_size.height;
             self.surface.configure(&self.device, &self.config);
-            self.renderer.resize(&self.device, &self.config);
+            let config = self.scene_config();
+            self.resize_scene_targets(&config);
             self.camera.resize(new_size.width, new_size.height);
 
             self.picker
@@ -503,139 +947,743 @@ impl GpuState {
         }
     }
 
-    fn render(
-        &mut self,
-        window: &Window,
-        astra_debug_options: &DebugOptions,
-    ) -> Result<(f32, f32), wgpu::SurfaceError> {
-        // Track frame time
-        let now = Instant::now();
-        let frame_time = (now - self.last_frame_time).as_secs_f32() * 1000.0;
-        self.last_frame_time = now;
+    /// Move the GUI panels into `window`; the main window keeps the 3D view and its overlays.
+    fn open_inspector(&mut self, window: Arc<Window>) {
+        let surface = match self.instance.create_surface(window.clone()) {
+            Ok(surface) => surface,
+            Err(e) => {
+                log::error!("Inspector window has no surface: {}", e);
+                return;
+            }
+        };
+        let surface_caps = surface.get_capabilities(&self.adapter);
+        let Some(&first_format) = surface_caps.formats.first() else {
+            log::error!("Inspector surface is not supported by the adapter");
+            return;
+        };
+        let format = surface_caps
+            .formats
+            .iter()
+            .copied()
+            .find(|f| f.is_srgb())
+            .unwrap_or(first_format);
 
-        // Camera reset: smoothly return to origin when requested (press `C`).
-        if let Some(desired) = self.camera_reset_target {
-            // Exponential smoothing (frame-rate independent).
-            // Higher values -> snappier reset.
-            let reset_rate: f32 = 12.0;
-            let dt = (frame_time * 0.001).max(0.0);
-            let t = 1.0 - (-reset_rate * dt).exp();
+        let size = window.inner_size();
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width: size.width.max(1),
+            height: size.height.max(1),
+            // Presented from the main window's frame, so never wait on its own vblank
+            present_mode: wgpu::PresentMode::AutoNoVsync,
+            alpha_mode: surface_caps.alpha_modes[0],
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        surface.configure(&self.device, &config);
 
-            self.camera.target = self.camera.target.lerp(desired, t);
+        self.inspector = Some(Inspector {
+            window,
+            surface,
+            astra_renderer: AstraRenderer::new(&self.device, config.format),
+            config,
+        });
+        self.ui_state.inspector_detached = true;
+        log::info!("✓ Inspector window initialized");
+    }
 
-            // Stop resetting once we're close enough.
-            if (self.camera.target - desired).length() < 0.001 {
-                self.camera.target = desired;
-                self.camera_reset_target = None;
-            }
+    /// Close the inspector window and put the panels back over the 3D view.
+    fn close_inspector(&mut self) {
+        if self.inspector.take().is_some() {
+            self.ui_state.inspector_detached = false;
         }
+    }
 
-        // Camera lock: smoothly follow the selected entity every frame.
-        //
-        // IMPORTANT: particles/hadrons move every simulation step, so a click-time resolved
-        // `selection_target_cached` will go stale. To truly "follow", we must re-run the
-        // selection-resolve compute pass regularly while locked.
-        if self.camera_lock.is_some() {
-            // Re-resolve selection -> target position (GPU compute), then read back vec4<f32>.
-            //
-            // This is intentionally "blockingly" polled for now for correctness; if it ever shows
-            // up in profiles, we can switch to an async ring buffer of readbacks.
-            {
-                let mut resolve_encoder =
-                    self.device
-                        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                            label: Some("Selection Resolve Encoder (per-frame follow)"),
-                        });
-
-                self.simulation
-                    .encode_selection_resolve(&mut resolve_encoder);
+    fn resize_inspector(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
+        if let Some(inspector) = &mut self.inspector {
+            if new_size.width > 0 && new_size.height > 0 {
+                inspector.config.width = new_size.width;
+                inspector.config.height = new_size.height;
+                inspector.surface.configure(&self.device, &inspector.config);
+            }
+        }
+    }
 
-                resolve_encoder.copy_buffer_to_buffer(
-                    self.simulation.selection_target_buffer(),
-                    0,
-                    &self.selection_target_staging_buffer,
-                    0,
-                    16,
-                );
+    fn inspector_window_id(&self) -> Option<WindowId> {
+        self.inspector
+            .as_ref()
+            .map(|inspector| inspector.window.id())
+    }
 
-                self.queue.submit(std::iter::once(resolve_encoder.finish()));
+    /// Reconfigure the surface with `mode` (vsync on/off and friends).
+    fn set_present_mode(&mut self, mode: wgpu::PresentMode) {
+        self.config.present_mode = mode;
+        self.surface.configure(&self.device, &self.config);
+        log::info!("Present mode: {:?}", mode);
+    }
 
-                let slice = self.selection_target_staging_buffer.slice(..);
-                slice.map_async(wgpu::MapMode::Read, |_| {});
-                // TODO: Convert to async ring buffer to avoid blocking GPU pipeline
-                // See: https://toji.dev/webgpu-best-practices/buffer-uploads
-                self.device
-                    .poll(wgpu::PollType::Wait {
-                        submission_index: None,
-                        timeout: None,
-                    })
-                    .unwrap();
+    /// Surface configuration at the scene's render scale (the 3D targets' size)
+    fn scene_config(&self) -> wgpu::SurfaceConfiguration {
+        let scale = |size: u32| (size * self.render_scale / 100).max(1);
+        wgpu::SurfaceConfiguration {
+            width: scale(self.config.width),
+            height: scale(self.config.height),
+            ..self.config.clone()
+        }
+    }
 
-                {
-                    let data = slice.get_mapped_range();
-                    let bytes: &[u8] = &data;
+    /// Render the scene at `percent` of the window resolution.
+    fn set_render_scale(&mut self, percent: u32) {
+        self.render_scale = percent;
+        let config = self.scene_config();
+        self.resize_scene_targets(&config);
+        log::info!(
+            "Render scale: {}% ({}x{})",
+            percent,
+            config.width,
+            config.height
+        );
+    }
 
-                    let x = f32::from_le_bytes(bytes[0..4].try_into().unwrap());
-                    let y = f32::from_le_bytes(bytes[4..8].try_into().unwrap());
-                    let z = f32::from_le_bytes(bytes[8..12].try_into().unwrap());
-                    let w = f32::from_le_bytes(bytes[12..16].try_into().unwrap());
+    /// Resize the offscreen scene targets (depth, HDR/bloom chain, OIT) without touching the
+    /// surface; screenshots use this to render above the window resolution.
+    fn resize_scene_targets(&mut self, config: &wgpu::SurfaceConfiguration) {
+        self.renderer.resize(&self.device, config);
+        self.bloom_renderer.resize(&self.device, config);
+        self.oit_renderer.resize(&self.device, config);
+    }
 
-                    self.selection_target_cached = Some([x, y, z, w]);
+    /// Re-render the scene (without the GUI) at `scale` times the window resolution into an
+    /// offscreen target and write it to `CAPTURE_DIR` as a timestamped PNG.
+    fn capture_screenshot(&mut self, scale: u32) {
+        let (device, queue, config) =
+            (self.device.clone(), self.queue.clone(), self.config.clone());
+        let screenshot = capture_screenshot(
+            &device,
+            &queue,
+            &config,
+            scale,
+            Path::new(CAPTURE_DIR),
+            &capture_stem(),
+            |capture_config, view| {
+                // Screenshots ignore the render scale
+                let resized = capture_config.width != config.width || self.render_scale != 100;
+                if resized {
+                    self.resize_scene_targets(capture_config);
                 }
+                self.render_scene(view);
+                if resized {
+                    let config = self.scene_config();
+                    self.resize_scene_targets(&config);
+                }
+            },
+        );
+        match screenshot {
+            Ok(screenshot) => log::info!(
+                "Screenshot saved to {} ({}x{})",
+                screenshot.path.display(),
+                screenshot.width,
+                screenshot.height
+            ),
+            Err(err) => log::error!("Failed to save screenshot: {}", err),
+        }
+    }
 
-                self.selection_target_staging_buffer.unmap();
+    /// Handle the bookmark requests from the render panel and advance a running fly-through.
+    fn update_camera_path(&mut self, dt: f32) {
+        if std::mem::take(&mut self.ui_state.camera_bookmark_save_requested) {
+            if self.camera_bookmarks.len() < CAMERA_BOOKMARK_BUTTONS.len() {
+                let name = format!("View {}", self.camera_bookmarks.len() + 1);
+                self.camera_bookmarks.push(self.camera.pose(name));
+            } else {
+                log::warn!(
+                    "All {} camera bookmarks are in use; clear them to save more",
+                    CAMERA_BOOKMARK_BUTTONS.len()
+                );
             }
+        }
+        if std::mem::take(&mut self.ui_state.camera_bookmark_clear_requested) {
+            self.camera_bookmarks.clear();
+            self.fly_through = None;
+        }
+        self.ui_state.camera_bookmarks = self
+            .camera_bookmarks
+            .iter()
+            .map(|pose| pose.name.clone())
+            .collect();
 
-            // If a nucleus is locked, also re-read its data every 5 frames to update the atom card
-            if let Some(CameraLock::Nucleus {
-                anchor_hadron_index,
-            }) = self.camera_lock
-            {
-                if self.frame_counter % 5 == 0 {
-                    self.update_selected_nucleus_data(anchor_hadron_index);
-                }
-            }
+        // Paths start at the current view so the camera never jumps
+        if std::mem::take(&mut self.ui_state.camera_flythrough_toggle_requested)
+            && self.fly_through.take().is_none()
+        {
+            let keys = std::iter::once(self.camera.pose("Current"))
+                .chain(self.camera_bookmarks.iter().cloned())
+                .collect();
+            self.start_fly_through(FlyThrough::new(keys, FLYTHROUGH_SEGMENT_SECONDS));
+        }
+        if let Some(pose) = self
+            .ui_state
+            .camera_bookmark_goto
+            .take()
+            .and_then(|index| self.camera_bookmarks.get(index))
+        {
+            let keys = vec![self.camera.pose("Current"), pose.clone()];
+            self.start_fly_through(FlyThrough::new(keys, BOOKMARK_JUMP_SECONDS));
+        }
 
-            if let Some(target) = self.selection_target_cached {
-                // target.w = kind (0 none, 1 particle, 2 hadron, 3 nucleus)
-                // NOTE: The selection-resolve pass only tells us the kind, not the exact radius.
-                // We approximate desired camera distance based on kind. This can be refined later
-                // by adding a resolved "size/radius" output from the compute pass.
-                if target[3] != 0.0 {
-                    let desired = Vec3::new(target[0], target[1], target[2]);
+        if let Some(fly_through) = &mut self.fly_through {
+            fly_through.advance(&mut self.camera, dt);
+            if fly_through.is_finished() {
+                self.fly_through = None;
+            }
+        }
+        self.ui_state.camera_flythrough_progress =
+            self.fly_through.as_ref().map(FlyThrough::progress);
+    }
 
-                    // Exponential smoothing (frame-rate independent).
-                    // Higher values -> snappier camera.
-                    let follow_rate: f32 = 12.0;
-                    let dt = (frame_time * 0.001).max(0.0);
-                    let t = 1.0 - (-follow_rate * dt).exp();
+    fn start_fly_through(&mut self, fly_through: Option<FlyThrough>) {
+        if fly_through.is_some() {
+            // The path drives the camera: release the follow lock and pending smoothing
+            self.camera_lock = None;
+            self.camera_reset_target = None;
+            self.camera_distance_target = None;
+        }
+        self.fly_through = fly_through;
+    }
 
-                    self.camera.target = self.camera.target.lerp(desired, t);
+    /// Start recording at the window resolution, or finish the running recording.
+    fn toggle_recording(&mut self) {
+        if self.recording.is_some() {
+            self.stop_recording();
+            return;
+        }
 
-                    // Smooth distance: zoom in for particles/quarks; stay further for hadrons.
-                    //
-                    // IMPORTANT:
-                    // - only set this ONCE per selection acquisition
-                    // - and never re-arm it after the user manually zooms while locked
-                    //   (otherwise we fight user input).
-                    if self.camera_distance_target.is_none() && !self.camera_zoom_user_override {
-                        let desired_distance = match target[3].round() as i32 {
-                            1 => 5.0,  // particle/quark: close-up
-                            2 => 15.0, // hadron shell: larger, keep more distance
-                            3 => 50.0, // nucleus shell: treat like hadron for now
-                            _ => self.camera.distance,
-                        };
-                        self.camera_distance_target = Some(desired_distance);
-                    }
-                }
+        match Recording::start(
+            &self.device,
+            &self.config,
+            Path::new(CAPTURE_DIR),
+            &capture_stem(),
+            self.ui_state.recording_format,
+            RECORDING_FPS,
+        ) {
+            Ok(recording) => {
+                log::info!(
+                    "Recording {} to {}",
+                    self.ui_state.recording_format.label(),
+                    recording.output().display()
+                );
+                self.recording = Some(recording);
             }
+            Err(err) => log::error!("Failed to start recording: {}", err),
         }
+    }
 
-        // Apply camera zoom smoothing if requested (selection or other systems).
-        if let Some(desired_distance) = self.camera_distance_target {
-            let zoom_rate: f32 = 10.0;
-            let dt = (frame_time * 0.001).max(0.0);
-            let t = 1.0 - (-zoom_rate * dt).exp();
+    /// User zoom (wheel or pinch): scale the orbit distance by `factor`, or the flying speed by
+    /// its inverse in free-fly. Stops the auto-zoom toward a locked selection from re-arming.
+    fn zoom_camera(&mut self, factor: f32) {
+        if self.camera.mode() == CameraMode::FreeFly {
+            self.camera
+                .scale_fly_speed(-factor.max(0.01).ln() / 1.15_f32.ln());
+            return;
+        }
+        if self.camera_lock.is_some() {
+            self.camera_distance_target = None;
+            self.camera_zoom_user_override = true;
+        }
+        self.camera.zoom((factor - 1.0) * self.camera.distance);
+    }
+
+    fn stop_recording(&mut self) {
+        let Some(recording) = self.recording.take() else {
+            return;
+        };
+        let output = recording.output().to_path_buf();
+        match recording.finish() {
+            Ok(frames) => log::info!(
+                "Recording saved to {} ({} frames)",
+                output.display(),
+                frames
+            ),
+            Err(err) => log::error!("Recording {} failed: {}", output.display(), err),
+        }
+    }
+
+    /// Add the frame just presented to the running recording.
+    fn record_frame(&mut self) {
+        let Some(recording) = &mut self.recording else {
+            return;
+        };
+        if let Err(err) = recording.record_frame(
+            &self.device,
+            &self.queue,
+            &self.bloom_renderer,
+            self.config.width,
+            self.config.height,
+        ) {
+            log::warn!("Stopping the recording: {}", err);
+            self.stop_recording();
+        }
+    }
+
+    /// Project the selected and hovered entities to screen-space label tags (leader lines for
+    /// the label renderer, text for the GUI).
+    fn update_labels(&mut self) {
+        self.screen_labels.clear();
+        self.ui_state.labels.clear();
+        if !self.ui_state.show_labels {
+            return;
+        }
+
+        // Labels hang off the top of the entity as seen by the camera
+        let up = self.camera.rotation * Vec3::Y;
+        let mut entities: Vec<(Vec3, String)> = Vec::new();
+
+        if let Some(target) = self.selection_target_cached.filter(|t| t.is_some()) {
+            let (radius, text) = match target.kind {
+                SelectionKind::Particle => (
+                    target.radius * PARTICLE_SCALE,
+                    self.selection_info
+                        .map_or_else(|| "Particle".to_string(), |info| info.label()),
+                ),
+                SelectionKind::Nucleus => (
+                    target.radius,
+                    match (
+                        self.ui_state.selected_nucleus_atomic_number,
+                        self.ui_state.selected_nucleus_nucleon_count,
+                    ) {
+                        (Some(z), Some(a)) => nucleus_label(z, a),
+                        _ => "Nucleus".to_string(),
+                    },
+                ),
+                _ => (
+                    target.radius,
+                    self.selection_info
+                        .map_or_else(|| "Hadron".to_string(), |info| info.label()),
+                ),
+            };
+            entities.push((Vec3::from(target.center) + up * radius, text));
+        }
+
+        // Hovered entity (unless it is the selection); nuclei have no record to place them by
+        let hovered = decode_pick_id(self.picker.hover().id);
+        if hovered != self.camera_lock {
+            let position = match (hovered, self.picker.hover_record()) {
+                (Some(CameraLock::Particle { .. }), Some(record)) => {
+                    let particle: Particle = bytemuck::pod_read_unaligned(record);
+                    Some(
+                        Vec3::from_slice(&particle.position[..3])
+                            + up * particle.data[1] * PARTICLE_SCALE,
+                    )
+                }
+                (Some(CameraLock::Hadron { .. }), Some(record)) => {
+                    let hadron: Hadron =
+                        bytemuck::pod_read_unaligned(&record[..std::mem::size_of::<Hadron>()]);
+                    Some(Vec3::from_slice(&hadron.center[..3]) + up * hadron.center[3])
+                }
+                _ => None,
+            };
+            if let (Some(position), Some(hover)) = (position, self.ui_state.hover) {
+                entities.push((position, hover.label()));
+            }
+        }
+
+        for (position, text) in entities {
+            let Some(anchor) =
+                self.camera
+                    .world_to_screen(position, self.config.width, self.config.height)
+            else {
+                continue;
+            };
+            let tag = [anchor.x + LABEL_OFFSET[0], anchor.y + LABEL_OFFSET[1]];
+            self.screen_labels.push(ScreenLabel {
+                anchor: anchor.to_array(),
+                tag,
+            });
+            self.ui_state.labels.push(EntityLabel { text, tag });
+        }
+    }
+
+    /// Recreate the scene renderers for a new MSAA sample count (pipelines bake it in).
+    fn set_msaa_samples(&mut self, sample_count: u32) {
+        let dummy_layout = self
+            .device
+            .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Dummy Layout"),
+                entries: &[],
+            });
+
+        let config = self.scene_config();
+        let cache = self.pipeline_cache.get();
+        self.renderer = ParticleRenderer::new(&self.device, &config, sample_count, cache);
+        self.hadron_renderer =
+            HadronRenderer::new(&self.device, HDR_FORMAT, &dummy_layout, sample_count, cache);
+        self.nucleus_renderer =
+            NucleusRenderer::new(&self.device, HDR_FORMAT, &dummy_layout, sample_count, cache);
+        self.photon_renderer = PhotonRenderer::new(&self.device, HDR_FORMAT, sample_count, cache);
+        self.trail_renderer = TrailRenderer::new(&self.device, HDR_FORMAT, sample_count, cache);
+        self.vector_renderer = VectorRenderer::new(&self.device, HDR_FORMAT, sample_count, cache);
+        self.grid_renderer = GridRenderer::new(&self.device, HDR_FORMAT, sample_count, cache);
+        self.oit_renderer = OitRenderer::new(&self.device, &config, sample_count, cache);
+        save_pipeline_cache(&self.pipeline_cache);
+        log::info!("Scene anti-aliasing: {}x MSAA", sample_count);
+    }
+
+    /// Draw the 3D scene (particles, hadrons, nuclei, overlays) into the HDR target and tone
+    /// map it onto `target`; the GUI and labels go on top separately.
+    fn render_scene(&mut self, target: &wgpu::TextureView) {
+        self.renderer.render(
+            &self.device,
+            &self.queue,
+            self.bloom_renderer.hdr_view(),
+            &self.camera,
+            self.simulation.render_particle_buffer(),
+            self.simulation.render_hadron_buffer(),
+            self.simulation.render_hadron_count_buffer(),
+            self.simulation.render_color_buffer(),
+            self.simulation.render_selection_mask_buffer(),
+            self.simulation.particle_count(),
+            PARTICLE_SCALE,
+            self.ui_state.physics_params.integration[2],
+            &self.ui_state.lod,
+            &mut self.profiler,
+        );
+
+        // Render Hadrons
+        {
+            let mut encoder = self
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("Hadron Render Encoder"),
+                });
+
+            // Force field slice through the camera target, facing the camera
+            if self.ui_state.show_force_field {
+                let field = ForceFieldParams::slice(
+                    self.camera.target.to_array(),
+                    (self.camera.rotation * Vec3::X).to_array(),
+                    (self.camera.rotation * Vec3::Y).to_array(),
+                    self.ui_state.force_field_extent,
+                );
+                self.simulation.encode_force_field(&mut encoder, &field);
+            }
+            self.vector_renderer.set_velocity_scale(
+                &self.queue,
+                self.ui_state.velocity_arrow_scale,
+                VELOCITY_ARROW_MAX_LENGTH,
+            );
+
+            // Shells go through the OIT accumulation pass instead when it is enabled
+            let oit = self.ui_state.oit_shells
+                && (self.ui_state.show_shells || self.ui_state.show_nuclei);
+
+            {
+                let mut render_pass = self.renderer.begin_scene_pass(
+                    &mut encoder,
+                    "Hadron Render Pass",
+                    self.bloom_renderer.hdr_view(),
+                );
+
+                // Bonds are drawn relaxed at the binding distance, strained at breakup
+                self.hadron_renderer.set_bond_strain_range(
+                    &self.queue,
+                    self.ui_state.physics_params.hadron[0],
+                    self.ui_state.physics_params.hadron[1],
+                );
+                let scope = self.profiler.begin(&mut render_pass, "Hadrons");
+                self.hadron_renderer.render(
+                    &self.device,
+                    &mut render_pass,
+                    &self.renderer.camera_buffer,
+                    self.simulation.render_hadron_buffer(),
+                    self.simulation.render_particle_buffer(),
+                    self.simulation.render_hadron_count_buffer(),
+                    self.simulation.particle_count(),
+                    self.ui_state.show_shells && !oit,
+                    self.ui_state.show_bonds,
+                );
+                self.profiler.end(&mut render_pass, scope);
+
+                // Render nuclei
+                let scope = self.profiler.begin(&mut render_pass, "Nuclei");
+                self.nucleus_renderer.render(
+                    &self.device,
+                    &mut render_pass,
+                    &self.renderer.camera_buffer,
+                    self.simulation.nucleus_buffer(),
+                    self.simulation.nucleus_count_buffer(),
+                    self.simulation.particle_count() / 4, // Rough estimate of max nuclei
+                    self.ui_state.show_nuclei && !oit,
+                );
+                self.profiler.end(&mut render_pass, scope);
+
+                // Render particle trails (fading polylines behind each particle)
+                self.trail_renderer.render(
+                    &self.device,
+                    &mut render_pass,
+                    &self.renderer.camera_buffer,
+                    self.simulation.render_particle_buffer(),
+                    self.simulation.trail_buffer(),
+                    self.simulation.trail_params_buffer(),
+                    self.simulation.particle_count(),
+                    self.simulation.trail_params().length,
+                    self.ui_state.show_trails,
+                );
+
+                // Render debug vectors (velocity arrows, force field slice)
+                self.vector_renderer.render_velocity(
+                    &self.device,
+                    &mut render_pass,
+                    &self.renderer.camera_buffer,
+                    self.simulation.render_particle_buffer(),
+                    self.simulation.particle_count(),
+                    self.ui_state.show_velocity_arrows,
+                );
+                self.vector_renderer.render_force_field(
+                    &self.device,
+                    &mut render_pass,
+                    &self.renderer.camera_buffer,
+                    self.simulation.force_field_buffer(),
+                    self.simulation.force_field_max_buffer(),
+                    self.ui_state.show_force_field,
+                );
+
+                // Reference grid (one scale bar length per cell) and world axes
+                if let Some(bar) = &self.ui_state.scale_bar {
+                    self.grid_renderer.render(
+                        &self.device,
+                        &self.queue,
+                        &mut render_pass,
+                        &self.renderer.camera_buffer,
+                        &self.camera,
+                        bar.length,
+                        self.ui_state.show_grid,
+                        self.ui_state.show_axes,
+                    );
+                }
+            }
+
+            // Order-independent shells: accumulate, then composite in the overlay pass
+            if oit {
+                let mut render_pass = self
+                    .oit_renderer
+                    .begin_accumulate(&mut encoder, &self.renderer.depth_texture);
+
+                // Timed under the same labels as the opaque shells
+                if self.ui_state.show_shells {
+                    let scope = self.profiler.begin(&mut render_pass, "Hadrons");
+                    self.hadron_renderer.render_shells_oit(
+                        &self.device,
+                        &mut render_pass,
+                        &self.renderer.camera_buffer,
+                        self.simulation.render_hadron_buffer(),
+                        self.simulation.render_particle_buffer(),
+                        self.simulation.render_hadron_count_buffer(),
+                        self.simulation.particle_count(),
+                    );
+                    self.profiler.end(&mut render_pass, scope);
+                }
+                if self.ui_state.show_nuclei {
+                    let scope = self.profiler.begin(&mut render_pass, "Nuclei");
+                    self.nucleus_renderer.render_shells_oit(
+                        &self.device,
+                        &mut render_pass,
+                        &self.renderer.camera_buffer,
+                        self.simulation.nucleus_buffer(),
+                        self.simulation.nucleus_count_buffer(),
+                        self.simulation.particle_count() / 4, // Rough estimate of max nuclei
+                    );
+                    self.profiler.end(&mut render_pass, scope);
+                }
+            }
+
+            {
+                let mut render_pass = self.renderer.begin_scene_pass(
+                    &mut encoder,
+                    "Overlay Render Pass",
+                    self.bloom_renderer.hdr_view(),
+                );
+
+                if oit {
+                    self.oit_renderer.composite(&mut render_pass);
+                }
+
+                // Render photon flashes (additive, on top of shells)
+                self.photon_renderer.render(
+                    &self.device,
+                    &mut render_pass,
+                    &self.renderer.camera_buffer,
+                    self.simulation.photon_buffer(),
+                    PHOTON_CAPACITY as u32,
+                    self.ui_state.show_photons,
+                );
+            }
+
+            self.queue.submit(std::iter::once(encoder.finish()));
+        }
+
+        // Bloom + tone map the HDR scene onto the target
+        {
+            let mut encoder = self
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("Bloom Encoder"),
+                });
+            let scope = self.profiler.begin(&mut encoder, "Bloom");
+            self.bloom_renderer.render(&mut encoder, target);
+            self.profiler.end(&mut encoder, scope);
+            self.queue.submit(std::iter::once(encoder.finish()));
+        }
+    }
+
+    fn render(
+        &mut self,
+        window: &Window,
+        astra_debug_options: &DebugOptions,
+    ) -> Result<(f32, f32), wgpu::SurfaceError> {
+        // Track frame time
+        let now = Instant::now();
+        let frame_time = (now - self.last_frame_time).as_secs_f32() * 1000.0;
+        self.last_frame_time = now;
+
+        // Camera reset: smoothly return to origin when requested (press `C`).
+        if let Some(desired) = self.camera_reset_target {
+            // Exponential smoothing (frame-rate independent).
+            // Higher values -> snappier reset.
+            let reset_rate: f32 = 12.0;
+            let dt = (frame_time * 0.001).max(0.0);
+            let t = 1.0 - (-reset_rate * dt).exp();
+
+            self.camera.target = self.camera.target.lerp(desired, t);
+
+            // Stop resetting once we're close enough.
+            if (self.camera.target - desired).length() < 0.001 {
+                self.camera.target = desired;
+                self.camera_reset_target = None;
+            }
+        }
+
+        self.update_measurement();
+
+        // Camera lock: smoothly follow the selected entity every frame.
+        //
+        // IMPORTANT: particles/hadrons move every simulation step, so a click-time resolved
+        // `selection_target_cached` will go stale. To truly "follow", we must re-run the
+        // selection-resolve compute pass regularly while locked.
+        if self.camera_lock.is_some() {
+            // Re-resolve selection -> target position (GPU compute), then read back vec4<f32>.
+            //
+            // This is intentionally "blockingly" polled for now for correctness; if it ever shows
+            // up in profiles, we can switch to an async ring buffer of readbacks.
+            {
+                let mut resolve_encoder =
+                    self.device
+                        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                            label: Some("Selection Resolve Encoder (per-frame follow)"),
+                        });
+
+                self.simulation
+                    .encode_selection_resolve(&mut resolve_encoder);
+
+                resolve_encoder.copy_buffer_to_buffer(
+                    self.simulation.selection_target_buffer(),
+                    0,
+                    &self.selection_target_staging_buffer,
+                    0,
+                    SELECTION_TARGET_SIZE,
+                );
+
+                self.queue.submit(std::iter::once(resolve_encoder.finish()));
+
+                let slice = self.selection_target_staging_buffer.slice(..);
+                slice.map_async(wgpu::MapMode::Read, |_| {});
+                // TODO: Convert to async ring buffer to avoid blocking GPU pipeline
+                // See: https://toji.dev/webgpu-best-practices/buffer-uploads
+                self.device
+                    .poll(wgpu::PollType::Wait {
+                        submission_index: None,
+                        timeout: None,
+                    })
+                    .unwrap();
+
+                {
+                    let data = slice.get_mapped_range();
+                    self.selection_target_cached = Some(SelectionTarget::from_bytes(&data));
+                }
+
+                self.selection_target_staging_buffer.unmap();
+            }
+
+            // If a nucleus is locked, also re-read its data every 5 frames to update the atom card
+            if let Some(CameraLock::Nucleus {
+                anchor_hadron_index,
+            }) = self.camera_lock
+            {
+                if self.frame_counter % 5 == 0 {
+                    self.update_selected_nucleus_data(anchor_hadron_index);
+                }
+            }
+
+            // Rest frame: only for composite objects, whose bulk motion hides the internal one
+            let rest_frame = self.ui_state.rest_frame
+                && matches!(
+                    self.camera_lock,
+                    Some(CameraLock::Hadron { .. } | CameraLock::Nucleus { .. })
+                );
+
+            if let Some(target) = self.selection_target_cached {
+                if target.is_some() {
+                    // Exponential smoothing (frame-rate independent).
+                    // Higher values -> snappier camera.
+                    let follow_rate: f32 = 12.0;
+                    let dt = (frame_time * 0.001).max(0.0);
+                    let t = 1.0 - (-follow_rate * dt).exp();
+
+                    // Steady smoothing lags a moving target by v / follow_rate; lead by that much
+                    // (velocity converted from simulation time to wall-clock time).
+                    let sim_per_second = if self.ui_state.is_paused || dt <= 0.0 {
+                        0.0
+                    } else {
+                        self.ui_state.physics_params.integration[0] / dt
+                    };
+                    let velocity = Vec3::from(target.velocity) * sim_per_second;
+                    let desired = Vec3::from(target.center) + velocity / follow_rate;
+
+                    let center = Vec3::from(target.center);
+                    if rest_frame && self.camera.target.distance(center) <= target.radius {
+                        // Pinned exactly once settled, so the object doesn't wobble with its
+                        // velocity jitter
+                        self.camera.target = center;
+                    } else {
+                        self.camera.target = self.camera.target.lerp(desired, t);
+                    }
+
+                    // Smooth distance: zoom in for particles/quarks; stay further for hadrons.
+                    //
+                    // IMPORTANT:
+                    // - only set this ONCE per selection acquisition
+                    // - and never re-arm it after the user manually zooms while locked
+                    //   (otherwise we fight user input).
+                    if self.camera_distance_target.is_none() && !self.camera_zoom_user_override {
+                        // Particles are drawn scaled up; shells are drawn at their radius
+                        let radius = match target.kind {
+                            SelectionKind::Particle => target.radius * PARTICLE_SCALE,
+                            _ => target.radius,
+                        };
+                        let desired_distance = self
+                            .camera
+                            .framing_distance(radius, SELECTION_FRAME_FILL)
+                            .max(MIN_FOLLOW_DISTANCE);
+                        self.camera_distance_target = Some(desired_distance);
+                    }
+                }
+            }
+        }
+
+        // Apply camera zoom smoothing if requested (selection or other systems).
+        if let Some(desired_distance) = self.camera_distance_target {
+            let zoom_rate: f32 = 10.0;
+            let dt = (frame_time * 0.001).max(0.0);
+            let t = 1.0 - (-zoom_rate * dt).exp();
 
             self.camera.distance =
                 self.camera.distance + (desired_distance - self.camera.distance) * t;
@@ -646,6 +1694,33 @@ impl GpuState {
             }
         }
 
+        // Free-fly movement from the held keys (the mode is toggled with F or in the UI)
+        self.camera.set_mode(if self.ui_state.free_fly {
+            CameraMode::FreeFly
+        } else {
+            CameraMode::Orbit
+        });
+        if self.camera.mode() == CameraMode::FreeFly {
+            let direction: Vec3 = FLY_KEYS
+                .iter()
+                .filter(|(key, _)| self.fly_keys.contains(key))
+                .map(|&(_, direction)| direction)
+                .sum();
+            if direction != Vec3::ZERO {
+                // Flying takes over from following a selection or a path
+                self.camera_lock = None;
+                self.camera_distance_target = None;
+                self.fly_through = None;
+                self.camera.fly(direction, (frame_time * 0.001).max(0.0));
+            }
+        }
+
+        self.update_camera_path((frame_time * 0.001).max(0.0));
+
+        // Perspective <-> orthographic transition
+        self.camera.orthographic = self.ui_state.orthographic;
+        self.camera.update_projection((frame_time * 0.001).max(0.0));
+
         self.frame_times.push_back(frame_time);
         if self.frame_times.len() > 100 {
             self.frame_times.pop_front();
@@ -680,105 +1755,179 @@ impl GpuState {
             self.ui_state.physics_params_dirty = false;
         }
 
-        // Step simulation
-        if !self.ui_state.is_paused || self.ui_state.step_one_frame {
-            self.simulation.step();
-            self.ui_state.step_one_frame = false;
+        // Beam experiment: start/stop runs requested from the time panel
+        if std::mem::take(&mut self.ui_state.beam_fire_requested) {
+            let experiment = BeamExperiment::new(self.ui_state.beam_config);
+            self.simulation
+                .reset_particles(&experiment.generate_event(PARTICLE_COUNT));
+            self.ui_state.beam_events.clear();
+            self.beam_experiment = Some(experiment);
+        }
+        if std::mem::take(&mut self.ui_state.beam_stop_requested) {
+            self.beam_experiment = None;
         }
 
-        // Read back hadron count (only every 10 frames to avoid blocking)
-        if self.frame_counter % 10 == 0 {
-            let mut encoder = self
-                .device
-                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                    label: Some("Readback Encoder"),
-                });
+        // Trails are recorded by the integration pass; cheap no-op when unchanged
+        self.simulation
+            .set_trails(self.ui_state.show_trails, self.ui_state.trail_length);
 
-            encoder.copy_buffer_to_buffer(
-                self.simulation.hadron_count_buffer(),
-                0,
-                &self.hadron_count_staging_buffer,
-                0,
-                16,
-            );
+        // Coarse-graining distances are measured from the camera, so track it every frame
+        self.ui_state
+            .coarse_params
+            .set_camera(self.camera.position().to_array());
+        self.simulation
+            .update_coarse_params(&self.ui_state.coarse_params);
 
-            self.queue.submit(std::iter::once(encoder.finish()));
+        // Step simulation
+        if !self.ui_state.is_paused || self.ui_state.step_one_frame {
+            self.simulation.step();
+            self.ui_state.step_one_frame = false;
 
-            let slice = self.hadron_count_staging_buffer.slice(..);
-            slice.map_async(wgpu::MapMode::Read, |_| {});
-            // TODO: Convert to async ring buffer to avoid blocking GPU pipeline
-            // See: https://toji.dev/webgpu-best-practices/buffer-uploads
-            self.device
-                .poll(wgpu::PollType::Wait {
-                    submission_index: None,
-                    timeout: None,
-                })
-                .unwrap();
+            if let Some(experiment) = self.beam_experiment.as_mut() {
+                if experiment.advance() {
+                    // Counts come from the periodic readback below, so they may lag by a few
+                    // frames; negligible against the event length.
+                    experiment.finish_event(BeamEventStats {
+                        hadrons: self.ui_state.hadron_count,
+                        protons: self.ui_state.proton_count,
+                        neutrons: self.ui_state.neutron_count,
+                        other_hadrons: self.ui_state.other_hadron_count,
+                        photons: self.ui_state.spectrum.bins.iter().sum(),
+                        ..Default::default()
+                    });
+                    self.ui_state.beam_events = experiment.events.clone();
 
+                    if !experiment.is_finished() {
+                        self.simulation
+                            .reset_particles(&experiment.generate_event(PARTICLE_COUNT));
+                    }
+                }
+            }
+            if self
+                .beam_experiment
+                .as_ref()
+                .is_some_and(BeamExperiment::is_finished)
             {
-                let data = slice.get_mapped_range();
-
-                // Layout: 4 little-endian u32 values
-                // [0] total hadrons
-                // [1] protons
-                // [2] neutrons
-                // [3] other
-                let bytes: &[u8] = &data;
-
-                self.ui_state.hadron_count = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
-                self.ui_state.proton_count = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
-                self.ui_state.neutron_count = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
-                self.ui_state.other_hadron_count =
-                    u32::from_le_bytes(bytes[12..16].try_into().unwrap());
+                log::info!(
+                    "Beam experiment finished ({} events)",
+                    self.ui_state.beam_events.len()
+                );
+                self.beam_experiment = None;
+            }
+        }
+        self.ui_state.beam_progress = self
+            .beam_experiment
+            .as_ref()
+            .map(|e| (e.current_event(), e.config.event_count));
+
+        // Tag the cohort around the current selection (requested from the render panel).
+        // The latest tag is the one tracked by the stats pass.
+        if std::mem::take(&mut self.ui_state.tag_requested) {
+            match self.selection_target_cached {
+                Some(target) if target.is_some() => {
+                    self.ui_state.cohort_tag += 1;
+                    self.simulation.tag_sphere(
+                        target.center,
+                        self.ui_state.tag_radius,
+                        self.ui_state.cohort_tag,
+                    );
+                }
+                _ => log::warn!("Cohort tagging needs a selection to center the sphere on"),
             }
-            self.hadron_count_staging_buffer.unmap();
         }
 
+        // Read back statistics (only every 10 frames to avoid blocking)
+        if READBACKS && self.frame_counter % 10 == 0 {
+            let statistics = self.simulation.read_statistics();
+            self.ui_state.hadron_count = statistics.hadrons.total;
+            self.ui_state.proton_count = statistics.hadrons.protons;
+            self.ui_state.neutron_count = statistics.hadrons.neutrons;
+            self.ui_state.other_hadron_count = statistics.hadrons.other;
+            self.ui_state.histograms = statistics.histograms;
+            self.ui_state.charge_mass = statistics.charge_mass;
+            self.ui_state.spectrum = statistics.spectrum;
+            self.ui_state.cohort = statistics.cohort;
+            self.ui_state.coarse_stats = statistics.coarse;
+            self.ui_state.clusters = statistics.clusters;
+            self.ui_state.selection_set = statistics.selection_set;
+            self.ui_state.selection_set_len = self.selection_set.len();
+            self.ui_state.escapes = statistics.escapes;
+        }
+
+        self.update_hover();
+
         // Update UI state
         self.ui_state.fps = fps;
         self.ui_state.frame_time = avg_frame_time;
         self.ui_state.particle_count = PARTICLE_COUNT;
 
-        // Render
+        // Render (the surface can only be reconfigured while no frame is acquired)
+        if self.ui_state.present_mode != self.config.present_mode {
+            self.set_present_mode(self.ui_state.present_mode);
+        }
         let output = self.surface.get_current_texture()?;
         let view = output
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
 
-        self.renderer.render(
-            &self.device,
-            &self.queue,
-            &view,
+        if self.ui_state.msaa_samples != self.renderer.sample_count() {
+            self.set_msaa_samples(self.ui_state.msaa_samples);
+        }
+        if self.ui_state.render_scale != self.render_scale {
+            self.set_render_scale(self.ui_state.render_scale);
+        }
+        self.renderer.set_glow_scale(self.ui_state.energy_glow);
+        self.renderer.set_culling(self.ui_state.cull_particles);
+        self.renderer
+            .set_density_splats(if self.ui_state.density_splats {
+                self.ui_state.density_splat_threshold
+            } else {
+                0
+            });
+        let rest_velocity = match (self.camera_lock, self.selection_target_cached) {
+            (Some(CameraLock::Hadron { .. } | CameraLock::Nucleus { .. }), Some(target))
+                if self.ui_state.rest_frame && target.is_some() =>
+            {
+                Vec3::from(target.velocity)
+            }
+            _ => Vec3::ZERO,
+        };
+        self.renderer
+            .set_rest_frame(rest_velocity, self.ui_state.physics_params.integration[0]);
+        self.ui_state.scale_bar = Some(ScaleBar::fit(
             &self.camera,
-            self.simulation.particle_buffer(),
-            self.simulation.hadron_buffer(),
-            self.simulation.hadron_count_buffer(),
-            self.simulation.particle_count(),
-            PARTICLE_SCALE,
-            self.ui_state.physics_params.integration[2],
-            self.ui_state.lod_shell_fade_start,
-            self.ui_state.lod_shell_fade_end,
-            self.ui_state.lod_bound_hadron_fade_start,
-            self.ui_state.lod_bound_hadron_fade_end,
-            self.ui_state.lod_bond_fade_start,
-            self.ui_state.lod_bond_fade_end,
-            self.ui_state.lod_quark_fade_start,
-            self.ui_state.lod_quark_fade_end,
-            self.ui_state.lod_nucleus_fade_start,
-            self.ui_state.lod_nucleus_fade_end,
+            self.config.height,
+            SCALE_BAR_MAX_PIXELS,
+        ));
+        self.bloom_renderer.set_settings(
+            &self.queue,
+            BloomSettings {
+                enabled: self.ui_state.bloom_enabled,
+                intensity: self.ui_state.bloom_intensity,
+                ..Default::default()
+            },
         );
 
-        // Render Hadrons
+        self.render_scene(&view);
+
+        // Render Astra GUI overlay (astra-gui placeholder)
         {
             let mut encoder = self
                 .device
                 .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                    label: Some("Hadron Render Encoder"),
+                    label: Some("Astra GUI Encoder"),
                 });
 
+            let size = window.inner_size();
+            let window_size = [size.width as f32, size.height as f32];
+
+            self.update_labels();
+            self.update_measure_labels();
+
+            // Leader lines, measurement and minimap share one pass over the tone-mapped frame
             {
                 let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("Hadron Render Pass"),
+                    label: Some("Surface Overlay Pass"),
                     color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                         view: &view,
                         resolve_target: None,
@@ -788,61 +1937,66 @@ impl GpuState {
                         },
                         depth_slice: None,
                     })],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        view: &self.renderer.depth_texture,
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        }),
-                        stencil_ops: None,
-                    }),
+                    depth_stencil_attachment: None,
                     timestamp_writes: None,
                     occlusion_query_set: None,
                     multiview_mask: None,
                 });
 
-                self.hadron_renderer.render(
-                    &self.device,
+                self.label_renderer.render(
+                    &self.queue,
                     &mut render_pass,
-                    &self.renderer.camera_buffer,
-                    self.simulation.hadron_buffer(),
-                    self.simulation.particle_buffer(),
-                    self.simulation.hadron_count_buffer(),
-                    self.simulation.particle_count(),
-                    self.ui_state.show_shells,
-                    self.ui_state.show_bonds,
-                );
-
-                // Render nuclei
-                self.nucleus_renderer.render(
-                    &self.device,
-                    &mut render_pass,
-                    &self.renderer.camera_buffer,
-                    self.simulation.nucleus_buffer(),
-                    self.simulation.nucleus_count_buffer(),
-                    self.simulation.particle_count() / 4, // Rough estimate of max nuclei
-                    self.ui_state.show_nuclei,
+                    &self.screen_labels,
+                    self.config.width,
+                    self.config.height,
                 );
+                if let Some(measurement) = self.measure_tool.measurement() {
+                    self.measure_renderer.render(
+                        &self.queue,
+                        &mut render_pass,
+                        &self.camera,
+                        measurement,
+                        self.config.width,
+                        self.config.height,
+                    );
+                }
+                // Last: it narrows the viewport and scissor to its inset
+                if self.ui_state.show_minimap {
+                    // Frame the boundary sphere (or twice the spawn region when it is disabled)
+                    let boundary_radius = self.ui_state.physics_params.boundary[0];
+                    let extent = if boundary_radius > 0.0 {
+                        boundary_radius.max(SPAWN_RADIUS) * MINIMAP_EXTENT_MARGIN
+                    } else {
+                        SPAWN_RADIUS * 2.0
+                    };
+                    self.minimap_renderer.render(
+                        &self.device,
+                        &self.queue,
+                        &mut render_pass,
+                        &self.camera,
+                        self.simulation.render_particle_buffer(),
+                        self.simulation.particle_count(),
+                        boundary_radius,
+                        extent,
+                        self.ui_state.minimap_corner.viewport(
+                            self.config.width,
+                            self.config.height,
+                            MINIMAP_SIZE,
+                            MINIMAP_MARGIN,
+                        ),
+                    );
+                }
             }
 
-            self.queue.submit(std::iter::once(encoder.finish()));
-        }
-
-        // Render Astra GUI overlay (astra-gui placeholder)
-        {
-            let mut encoder = self
-                .device
-                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                    label: Some("Astra GUI Encoder"),
-                });
-
-            let size = window.inner_size();
-            let window_size = [size.width as f32, size.height as f32];
-
-            let astra_output =
+            // With the panels detached, only the overlays stay over the 3D view
+            let astra_output = if self.inspector.is_some() {
+                self.gui.build_overlays(&self.ui_state, window_size)
+            } else {
                 self.gui
-                    .build(&mut self.ui_state, window_size, *astra_debug_options);
+                    .build(&mut self.ui_state, window_size, *astra_debug_options)
+            };
 
+            let scope = self.profiler.begin(&mut encoder, "GUI");
             self.astra_renderer.render(
                 &self.device,
                 &self.queue,
@@ -852,17 +2006,93 @@ impl GpuState {
                 window_size[1],
                 &astra_output,
             );
+            self.profiler.end(&mut encoder, scope);
+
+            // Detached panels, recorded into the same encoder
+            let mut inspector_frame = None;
+            if let Some(inspector) = &mut self.inspector {
+                match inspector.surface.get_current_texture() {
+                    Ok(frame) => {
+                        let inspector_view = frame
+                            .texture
+                            .create_view(&wgpu::TextureViewDescriptor::default());
+                        let inspector_size = [
+                            inspector.config.width as f32,
+                            inspector.config.height as f32,
+                        ];
+                        let astra_output = self.gui.build(
+                            &mut self.ui_state,
+                            inspector_size,
+                            *astra_debug_options,
+                        );
+
+                        let scope = self.profiler.begin(&mut encoder, "GUI");
+                        inspector.astra_renderer.render(
+                            &self.device,
+                            &self.queue,
+                            &mut encoder,
+                            &inspector_view,
+                            inspector_size[0],
+                            inspector_size[1],
+                            &astra_output,
+                        );
+                        self.profiler.end(&mut encoder, scope);
+                        inspector_frame = Some(frame);
+                    }
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        inspector.surface.configure(&self.device, &inspector.config);
+                    }
+                    Err(e) => log::warn!("Inspector surface: {:?}", e),
+                }
+            }
+
+            self.profiler.resolve(&mut encoder);
 
             self.queue.submit(std::iter::once(encoder.finish()));
+            if let Some(frame) = inspector_frame {
+                frame.present();
+            }
+        }
+
+        // GPU pass timings (every 10 frames, like the statistics readback)
+        if self.frame_counter % 10 == 0 {
+            self.profiler.read_back(&self.device);
+            self.ui_state.gpu_timings = self.profiler.timings().to_vec();
         }
 
         output.present();
+
+        // Screenshot (F12 / UI button) of the frame just presented
+        if std::mem::take(&mut self.ui_state.screenshot_requested) {
+            if READBACKS {
+                self.capture_screenshot(self.ui_state.screenshot_scale);
+            } else {
+                log::warn!("Screenshots are not available in the web build");
+            }
+        }
+
+        // Frame recording (started/stopped from the render panel)
+        if std::mem::take(&mut self.ui_state.recording_toggle_requested) {
+            if READBACKS {
+                self.toggle_recording();
+            } else {
+                log::warn!("Recording is not available in the web build");
+            }
+        }
+        self.record_frame();
+        self.ui_state.recording_frames = self.recording.as_ref().map(Recording::frames);
+
         Ok((fps, avg_frame_time))
     }
 }
 
 struct App {
     window: Option<Arc<Window>>,
+    /// Graphics backends to try first (`--backend`)
+    backends: wgpu::Backends,
+    /// Hands the asynchronously created GPU state back to the event loop (web build)
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    proxy: EventLoopProxy<GpuState>,
     gpu_state: Option<GpuState>,
     mouse_pressed: bool,
     last_mouse_pos: Option<(f64, f64)>,
@@ -870,69 +2100,199 @@ struct App {
     // Picking
     left_mouse_pressed: bool,
     last_cursor_pos: Option<(f64, f64)>,
+    modifiers: winit::keyboard::ModifiersState,
+    // Ctrl + Left drag: box select anchor (window cursor position)
+    box_select_start: Option<(f64, f64)>,
+    // Active camera touches (id, window position), at most two
+    touches: Vec<(u64, (f64, f64))>,
 
     // Astra GUI debug options
     astra_debug_options: DebugOptions,
 }
 
-impl ApplicationHandler for App {
+impl App {
+    fn touch_moved(&mut self, id: u64, position: (f64, f64)) {
+        let Some(index) = self
+            .touches
+            .iter()
+            .position(|&(touch_id, _)| touch_id == id)
+        else {
+            return;
+        };
+        let previous = std::mem::replace(&mut self.touches[index].1, position);
+        let Some(gpu_state) = &mut self.gpu_state else {
+            return;
+        };
+
+        match self.touches[..] {
+            [_] => {
+                let delta_x = (position.0 - previous.0) as f32;
+                let delta_y = (position.1 - previous.1) as f32;
+                gpu_state.camera.rotate(-delta_x * 0.005, delta_y * 0.005);
+            }
+            [_, _] => {
+                let other = self.touches[1 - index].1;
+                let spread = |(x, y): (f64, f64)| ((x - other.0).hypot(y - other.1)) as f32;
+                let (before, after) = (spread(previous), spread(position));
+                if before > 1.0 && after > 1.0 {
+                    // Fingers apart -> closer, like scrolling up
+                    gpu_state.zoom_camera(before / after);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn run_shortcut(&mut self, event_loop: &ActiveEventLoop, shortcut: Shortcut) {
+        if shortcut == Shortcut::Quit {
+            // Let a running recording finish writing (and ffmpeg finalize the mp4)
+            if let Some(gpu_state) = &mut self.gpu_state {
+                gpu_state.stop_recording();
+            }
+            event_loop.exit();
+            return;
+        }
+        let Some(gpu_state) = &mut self.gpu_state else {
+            return;
+        };
+
+        match shortcut {
+            Shortcut::Quit => {}
+            Shortcut::ResetCamera => {
+                // Smooth reset: request a lerped return to origin instead of snapping.
+                gpu_state.camera_reset_target = Some(Vec3::ZERO);
+
+                // Clear selection/lock state so follow doesn't fight the reset.
+                gpu_state.camera_lock = None;
+                gpu_state.selection_target_cached = None;
+                gpu_state.camera_distance_target = None;
+                gpu_state.camera_zoom_user_override = false;
+                gpu_state.simulation.set_selected_id(0);
+
+                gpu_state.selection_set.clear();
+                gpu_state
+                    .simulation
+                    .update_selection_set(&gpu_state.selection_set);
+            }
+            Shortcut::Screenshot => gpu_state.ui_state.screenshot_requested = true,
+            Shortcut::ToggleFreeFly => {
+                gpu_state.ui_state.free_fly = !gpu_state.ui_state.free_fly;
+                gpu_state.fly_keys.clear();
+            }
+            Shortcut::TogglePause => gpu_state.ui_state.is_paused = !gpu_state.ui_state.is_paused,
+            Shortcut::StepFrame => gpu_state.ui_state.step_one_frame = true,
+        }
+    }
+}
+
+impl ApplicationHandler<GpuState> for App {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if self.window.is_none() {
             let window_attributes = Window::default_attributes()
                 .with_title("Particle Physics Simulation")
                 .with_inner_size(winit::dpi::LogicalSize::new(1920, 1080));
+            // In the browser the window is a canvas appended to the page
+            #[cfg(target_arch = "wasm32")]
+            let window_attributes = {
+                use winit::platform::web::WindowAttributesExtWebSys;
+                window_attributes.with_append(true)
+            };
 
             let window = Arc::new(event_loop.create_window(window_attributes).unwrap());
             self.window = Some(window.clone());
-            self.gpu_state = Some(pollster::block_on(GpuState::new(window)));
+
+            // The browser cannot block on the adapter and device requests, so the state arrives
+            // later through `user_event`
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                self.gpu_state = Some(pollster::block_on(GpuState::new(window, self.backends)));
+            }
+            #[cfg(target_arch = "wasm32")]
+            {
+                let proxy = self.proxy.clone();
+                let backends = self.backends;
+                wasm_bindgen_futures::spawn_local(async move {
+                    let gpu_state = GpuState::new(window, backends).await;
+                    if proxy.send_event(gpu_state).is_err() {
+                        log::error!("Event loop closed before the GPU state was ready");
+                    }
+                });
+            }
+        }
+    }
+
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, gpu_state: GpuState) {
+        self.gpu_state = Some(gpu_state);
+        if let Some(window) = &self.window {
+            window.request_redraw();
         }
     }
 
     fn window_event(
         &mut self,
         event_loop: &ActiveEventLoop,
-        _window_id: WindowId,
+        window_id: WindowId,
         event: WindowEvent,
     ) {
+        // The inspector only feeds the GUI; it is drawn with the main window's frame
+        if let Some(gpu_state) = &mut self.gpu_state {
+            if gpu_state.inspector_window_id() == Some(window_id) {
+                gpu_state.gui.handle_event(&event);
+                match event {
+                    WindowEvent::CloseRequested => gpu_state.close_inspector(),
+                    WindowEvent::Resized(physical_size) => {
+                        gpu_state.resize_inspector(physical_size)
+                    }
+                    _ => {}
+                }
+                return;
+            }
+        }
+
         // Feed events into GUI input state first. We'll gate camera/picking based on whether
-        // the UI consumed pointer/scroll input.
-        let ui_consumed = if let Some(gpu_state) = &mut self.gpu_state {
-            gpu_state.gui.handle_event(&event)
-        } else {
-            false
+        // the UI consumed pointer/scroll input (the panels are elsewhere while detached).
+        let ui_consumed = match &mut self.gpu_state {
+            Some(gpu_state) if gpu_state.inspector.is_none() => gpu_state.gui.handle_event(&event),
+            _ => false,
         };
 
-        match event {
-            WindowEvent::CloseRequested
-            | WindowEvent::KeyboardInput {
-                event:
-                    KeyEvent {
-                        physical_key: PhysicalKey::Code(KeyCode::Escape),
-                        ..
-                    },
+        // Typing into a value field must not trigger shortcuts (and Escape cancels the edit
+        // instead of quitting). Releases still go through so held fly keys don't get stuck.
+        if matches!(
+            event,
+            WindowEvent::KeyboardInput {
+                event: KeyEvent {
+                    state: ElementState::Pressed,
+                    ..
+                },
                 ..
-            } => event_loop.exit(),
+            }
+        ) && self
+            .gpu_state
+            .as_ref()
+            .is_some_and(|gpu_state| gpu_state.gui.wants_keyboard())
+        {
+            return;
+        }
+
+        match event {
+            WindowEvent::CloseRequested => self.run_shortcut(event_loop, Shortcut::Quit),
 
+            // App shortcuts (`SHORTCUTS`) win over the fly keys and the astra debug keys
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
-                        physical_key: PhysicalKey::Code(KeyCode::KeyC),
+                        physical_key: PhysicalKey::Code(key_code),
                         state: ElementState::Pressed,
-                        repeat: false,
+                        repeat,
                         ..
                     },
                 ..
-            } => {
-                if let Some(gpu_state) = &mut self.gpu_state {
-                    // Smooth reset: request a lerped return to origin instead of snapping.
-                    gpu_state.camera_reset_target = Some(Vec3::ZERO);
-
-                    // Clear selection/lock state so follow doesn't fight the reset.
-                    gpu_state.camera_lock = None;
-                    gpu_state.selection_target_cached = None;
-                    gpu_state.camera_distance_target = None;
-                    gpu_state.camera_zoom_user_override = false;
-                    gpu_state.simulation.set_selected_id(0);
+            } if shortcut_for(key_code, self.modifiers).is_some() => {
+                if let Some(shortcut) = shortcut_for(key_code, self.modifiers) {
+                    if !repeat || shortcut.repeats() {
+                        self.run_shortcut(event_loop, shortcut);
+                    }
                 }
             }
 
@@ -942,18 +2302,38 @@ impl ApplicationHandler for App {
                 }
             }
 
+            // Free-fly movement keys (take precedence over the astra debug keys while flying)
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
-                        physical_key: PhysicalKey::Code(KeyCode::KeyP),
-                        state: ElementState::Pressed,
-                        repeat: false,
+                        physical_key: PhysicalKey::Code(key_code),
+                        state,
                         ..
                     },
                 ..
-            } => {
-                if let Some(_gpu_state) = &mut self.gpu_state {
-                    log::debug!("picking overlay toggled: (disabled/removed)");
+            } if FLY_KEYS.iter().any(|&(key, _)| key == key_code)
+                && self
+                    .gpu_state
+                    .as_ref()
+                    .is_some_and(|gpu_state| gpu_state.ui_state.free_fly) =>
+            {
+                if let Some(gpu_state) = &mut self.gpu_state {
+                    if state == ElementState::Pressed {
+                        gpu_state.fly_keys.insert(key_code);
+                    } else {
+                        gpu_state.fly_keys.remove(&key_code);
+                    }
+                }
+            }
+
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+            }
+
+            // Releases that happen while another window has focus never arrive
+            WindowEvent::Focused(false) => {
+                if let Some(gpu_state) = &mut self.gpu_state {
+                    gpu_state.fly_keys.clear();
                 }
             }
 
@@ -983,6 +2363,27 @@ impl ApplicationHandler for App {
                 if button == winit::event::MouseButton::Left {
                     self.left_mouse_pressed = state == ElementState::Pressed;
 
+                    // Picking reads the GPU back, which the web build cannot block on
+                    if !READBACKS {
+                        return;
+                    }
+
+                    // Ctrl + Left drag: box select into the selection set (on release)
+                    if state == ElementState::Pressed && self.modifiers.control_key() {
+                        self.box_select_start = self.last_cursor_pos;
+                        return;
+                    }
+                    if let Some(start) = self.box_select_start.take() {
+                        if let (Some(end), Some(window), Some(gpu_state)) =
+                            (self.last_cursor_pos, &self.window, &mut self.gpu_state)
+                        {
+                            let a = gpu_state.pick_pixel(window, start);
+                            let b = gpu_state.pick_pixel(window, end);
+                            gpu_state.box_select(a, b);
+                        }
+                        return;
+                    }
+
                     // GPU picking: render IDs into an offscreen target then read back the clicked pixel.
                     if state == ElementState::Pressed {
                         let Some((x, y)) = self.last_cursor_pos else {
@@ -995,109 +2396,79 @@ impl ApplicationHandler for App {
                             return;
                         };
 
-                        // IMPORTANT: winit cursor positions are in logical pixels.
-                        // `inner_size()` and our swapchain/config are in physical pixels.
-                        // If we don't apply the window scale factor, pick coordinates will be wrong
-                        // (often "about half the time" depending on DPI, window moves, etc).
-                        let scale = 1.0;
-                        let physical_x = (x * scale).round();
-                        let physical_y = (y * scale).round();
-
-                        let size = window.inner_size();
-                        let w = size.width.max(1) as f64;
-                        let h = size.height.max(1) as f64;
-
-                        // Convert physical window-space -> texture pixel coords.
-                        // Clamp to the valid render target range.
-                        let px = ((physical_x / w) * gpu_state.config.width as f64)
-                            .floor()
-                            .clamp(0.0, (gpu_state.config.width.saturating_sub(1)) as f64)
-                            as u32;
-                        let py = ((physical_y / h) * gpu_state.config.height as f64)
-                            .floor()
-                            .clamp(0.0, (gpu_state.config.height.saturating_sub(1)) as f64)
-                            as u32;
-
+                        // Cursor positions are physical window pixels, like `inner_size()`;
+                        // the picking target follows the surface config (render scale).
+                        let (px, py) = gpu_state.pick_pixel(window, (x, y));
                         log::debug!(
-                            "pick click: cursor_logical=({:.1},{:.1}) scale={:.3} cursor_physical=({:.1},{:.1}) window_physical=({}x{}) cfg=({}x{}) pick_px=({}, {})",
+                            "pick click: cursor=({:.1},{:.1}) window=({}x{}) cfg=({}x{}) pick_px=({}, {})",
                             x,
                             y,
-                            scale,
-                            physical_x,
-                            physical_y,
-                            size.width,
-                            size.height,
+                            window.inner_size().width,
+                            window.inner_size().height,
                             gpu_state.config.width,
                             gpu_state.config.height,
                             px,
                             py
                         );
 
-                        let mut encoder = gpu_state.device.create_command_encoder(
-                            &wgpu::CommandEncoderDescriptor {
-                                label: Some("Picking Encoder"),
-                            },
-                        );
-
-                        // Render IDs into offscreen target
-                        // IMPORTANT:
-                        // The visual particle shader scales billboards by `camera.particle_size * particle.data.y`.
-                        // For quarks, `particle.data.y` is very small (~0.03), which makes the visible/on-screen
-                        // footprint extremely tiny unless `camera.particle_size` is large enough.
-                        //
-                        // If the picking pass uses too small a `particle_size`, most clicks will hit background (id=0),
-                        // and picking will appear angle-dependent / unreliable.
-                        //
-                        // Use the shared picking particle size so the click picking render matches
-                        // the picking overlay visualization exactly.
-                        gpu_state.picking_renderer.render(
-                            &gpu_state.device,
-                            &gpu_state.queue,
-                            &mut encoder,
-                            &gpu_state.picker.id_texture_view,
-                            &gpu_state.camera,
-                            gpu_state.simulation.particle_buffer(),
-                            gpu_state.simulation.hadron_buffer(),
-                            gpu_state.simulation.hadron_count_buffer(),
-                            gpu_state.simulation.nucleus_buffer(),
-                            gpu_state.simulation.nucleus_count_buffer(),
-                            gpu_state.simulation.particle_count(),
-                            gpu_state.simulation.particle_count(), // max_hadrons == particle_count allocation
-                            gpu_state.simulation.particle_count() / 4, // match render path's rough max nuclei
-                            gpu_state.picking_particle_size,
-                            gpu_state.ui_state.physics_params.integration[2],
-                            gpu_state.ui_state.lod_shell_fade_start,
-                            gpu_state.ui_state.lod_shell_fade_end,
-                            gpu_state.ui_state.lod_bound_hadron_fade_start,
-                            gpu_state.ui_state.lod_bound_hadron_fade_end,
-                            gpu_state.ui_state.lod_bond_fade_start,
-                            gpu_state.ui_state.lod_bond_fade_end,
-                            gpu_state.ui_state.lod_quark_fade_start,
-                            gpu_state.ui_state.lod_quark_fade_end,
-                            gpu_state.ui_state.lod_nucleus_fade_start,
-                            gpu_state.ui_state.lod_nucleus_fade_end,
-                        );
-
-                        // Copy clicked pixel into staging buffer
-                        gpu_state.picker.encode_read_pixel(&mut encoder, px, py);
-
-                        gpu_state.queue.submit(std::iter::once(encoder.finish()));
+                        let pick = if let Some(cpu_picker) = &mut gpu_state.cpu_picker {
+                            cpu_picker.snapshot(
+                                &gpu_state.device,
+                                &gpu_state.queue,
+                                gpu_state.simulation.particle_buffer(),
+                                gpu_state.simulation.hadron_buffer(),
+                                gpu_state.simulation.hadron_count_buffer(),
+                            );
+                            let ray = PickRay::from_pixel(
+                                &gpu_state.camera,
+                                px as f32,
+                                py as f32,
+                                gpu_state.config.width,
+                                gpu_state.config.height,
+                            );
+                            cpu_picker.pick(&ray, gpu_state.picking_particle_size)
+                        } else {
+                            let mut encoder = gpu_state.device.create_command_encoder(
+                                &wgpu::CommandEncoderDescriptor {
+                                    label: Some("Picking Encoder"),
+                                },
+                            );
 
-                        // Map + blockingly poll for the readback (clicks are rare so this is OK).
-                        let slice = gpu_state.picker.staging_buffer().slice(..);
-                        slice.map_async(wgpu::MapMode::Read, |_| {});
-                        // TODO: Convert to async ring buffer to avoid blocking GPU pipeline
-                        // See: https://toji.dev/webgpu-best-practices/buffer-uploads
-                        gpu_state
-                            .device
-                            .poll(wgpu::PollType::Wait {
-                                submission_index: None,
-                                timeout: None,
-                            })
-                            .unwrap();
+                            // Render IDs into offscreen target
+                            // IMPORTANT:
+                            // The visual particle shader scales billboards by `camera.particle_size * particle.data.y`.
+                            // For quarks, `particle.data.y` is very small (~0.03), which makes the visible/on-screen
+                            // footprint extremely tiny unless `camera.particle_size` is large enough.
+                            //
+                            // If the picking pass uses too small a `particle_size`, most clicks will hit background (id=0),
+                            // and picking will appear angle-dependent / unreliable.
+                            //
+                            // Use the shared picking particle size so the click picking render matches
+                            // the picking overlay visualization exactly.
+                            gpu_state.encode_pick_pass(&mut encoder, None);
+
+                            // Copy clicked pixel into staging buffer
+                            gpu_state.picker.encode_read_pixel(&mut encoder, px, py);
+
+                            gpu_state.queue.submit(std::iter::once(encoder.finish()));
+
+                            // Map + blockingly poll for the readback (clicks are rare so this is OK).
+                            let slice = gpu_state.picker.staging_buffer().slice(..);
+                            slice.map_async(wgpu::MapMode::Read, |_| {});
+                            // TODO: Convert to async ring buffer to avoid blocking GPU pipeline
+                            // See: https://toji.dev/webgpu-best-practices/buffer-uploads
+                            gpu_state
+                                .device
+                                .poll(wgpu::PollType::Wait {
+                                    submission_index: None,
+                                    timeout: None,
+                                })
+                                .unwrap();
 
-                        let pick = gpu_state.picker.read_mapped();
-                        gpu_state.picker.staging_buffer().unmap();
+                            let pick = gpu_state.picker.read_mapped();
+                            gpu_state.picker.staging_buffer().unmap();
+                            pick
+                        };
 
                         let decoded = decode_pick_id(pick.id);
                         log::debug!(
@@ -1106,9 +2477,39 @@ impl ApplicationHandler for App {
                             decoded = decoded
                         );
 
+                        // Shift + click toggles the entity in the selection set and leaves the
+                        // single selection (camera lock) alone.
+                        if self.modifiers.shift_key() {
+                            if !gpu_state.selection_set.toggle(pick.id) {
+                                log::warn!(
+                                    "Selection set is full ({} entities)",
+                                    gpu_state.selection_set.len()
+                                );
+                            }
+                            gpu_state
+                                .simulation
+                                .update_selection_set(&gpu_state.selection_set);
+                            return;
+                        }
+
+                        // In measure mode clicks pick measurement points instead of selecting
+                        if gpu_state.ui_state.measure_mode {
+                            if pick.id != 0 {
+                                gpu_state
+                                    .measure_tool
+                                    .toggle(&gpu_state.simulation, pick.id);
+                            }
+                            return;
+                        }
+
                         // Update selection ID in the simulation and resolve it to a world-space target.
                         gpu_state.simulation.set_selected_id(pick.id);
                         gpu_state.camera_lock = decoded;
+                        // The clicked entity is normally the hovered one; keep its description
+                        gpu_state.selection_info = gpu_state
+                            .ui_state
+                            .hover
+                            .filter(|_| gpu_state.picker.hover().id == pick.id);
 
                         // Reset zoom target on new selection so the initial auto-zoom runs again.
                         gpu_state.camera_distance_target = None;
@@ -1131,7 +2532,7 @@ impl ApplicationHandler for App {
                                 0,
                                 &gpu_state.selection_target_staging_buffer,
                                 0,
-                                16,
+                                SELECTION_TARGET_SIZE,
                             );
 
                             gpu_state
@@ -1152,21 +2553,14 @@ impl ApplicationHandler for App {
 
                             {
                                 let data = slice.get_mapped_range();
-                                let bytes: &[u8] = &data;
-
-                                let x = f32::from_le_bytes(bytes[0..4].try_into().unwrap());
-                                let y = f32::from_le_bytes(bytes[4..8].try_into().unwrap());
-                                let z = f32::from_le_bytes(bytes[8..12].try_into().unwrap());
-                                let w = f32::from_le_bytes(bytes[12..16].try_into().unwrap());
-
-                                gpu_state.selection_target_cached = Some([x, y, z, w]);
+                                let target = SelectionTarget::from_bytes(&data);
+                                gpu_state.selection_target_cached = Some(target);
 
                                 log::debug!(
-                                    "pick resolve: target=({:.3},{:.3},{:.3}) kind_w={:.1}",
-                                    x,
-                                    y,
-                                    z,
-                                    w
+                                    "pick resolve: target={:?} kind={:?} radius={:.3}",
+                                    target.center,
+                                    target.kind,
+                                    target.radius,
                                 );
 
                                 // Do NOT snap the camera on click.
@@ -1206,6 +2600,13 @@ impl ApplicationHandler for App {
             WindowEvent::CursorMoved { position, .. } => {
                 self.last_cursor_pos = Some((position.x, position.y));
 
+                // Hover picking follows the cursor (same pixel mapping as click picking)
+                if let (Some(window), Some(gpu_state)) = (&self.window, &mut self.gpu_state) {
+                    let cursor = (!ui_consumed && !self.mouse_pressed)
+                        .then(|| gpu_state.pick_pixel(window, (position.x, position.y)));
+                    gpu_state.picker.set_hover_cursor(cursor);
+                }
+
                 if ui_consumed {
                     return;
                 }
@@ -1223,6 +2624,12 @@ impl ApplicationHandler for App {
                 }
             }
 
+            WindowEvent::CursorLeft { .. } => {
+                if let Some(gpu_state) = &mut self.gpu_state {
+                    gpu_state.picker.set_hover_cursor(None);
+                }
+            }
+
             WindowEvent::MouseWheel { delta, .. } => {
                 if ui_consumed {
                     return;
@@ -1234,20 +2641,34 @@ impl ApplicationHandler for App {
                 };
 
                 if let Some(gpu_state) = &mut self.gpu_state {
-                    // If the user manually zooms while locked onto a selection:
-                    // - cancel any in-progress auto-zoom
-                    // - and prevent it from re-arming until a new selection is made.
-                    if gpu_state.camera_lock.is_some() {
-                        gpu_state.camera_distance_target = None;
-                        gpu_state.camera_zoom_user_override = true;
+                    // Free-fly: the wheel sets the flying speed instead of zooming
+                    if gpu_state.camera.mode() == CameraMode::FreeFly {
+                        gpu_state.camera.scale_fly_speed(scroll / 10.0);
+                        return;
                     }
-
-                    gpu_state
-                        .camera
-                        .zoom(-scroll * gpu_state.camera.distance / 100.0);
+                    gpu_state.zoom_camera(1.0 - scroll / 100.0);
                 }
             }
 
+            // Touch: one finger orbits (or looks around) like a right drag, two fingers pinch
+            // to zoom like the wheel. Touches starting on the UI are left to it.
+            WindowEvent::Touch(Touch {
+                phase,
+                location,
+                id,
+                ..
+            }) => match phase {
+                TouchPhase::Started => {
+                    if !ui_consumed && self.touches.len() < 2 {
+                        self.touches.push((id, (location.x, location.y)));
+                    }
+                }
+                TouchPhase::Moved => self.touch_moved(id, (location.x, location.y)),
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    self.touches.retain(|&(touch_id, _)| touch_id != id);
+                }
+            },
+
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
@@ -1286,7 +2707,7 @@ impl ApplicationHandler for App {
                         );
                         true
                     }
-                    KeyCode::KeyC => {
+                    KeyCode::KeyO => {
                         self.astra_debug_options.show_content_area =
                             !self.astra_debug_options.show_content_area;
                         println!(
@@ -1345,6 +2766,26 @@ impl ApplicationHandler for App {
                         Err(wgpu::SurfaceError::OutOfMemory) => event_loop.exit(),
                         Err(e) => eprintln!("Render error: {:?}", e),
                     }
+
+                    // Detach / attach the panels (render panel > Window)
+                    if std::mem::take(&mut gpu_state.ui_state.inspector_toggle_requested) {
+                        if gpu_state.inspector.is_some() {
+                            gpu_state.close_inspector();
+                        } else {
+                            let window_attributes = Window::default_attributes()
+                                .with_title("Particle Physics - Inspector")
+                                .with_inner_size(winit::dpi::LogicalSize::new(
+                                    INSPECTOR_SIZE[0],
+                                    INSPECTOR_SIZE[1],
+                                ));
+                            match event_loop.create_window(window_attributes) {
+                                Ok(inspector) => gpu_state.open_inspector(Arc::new(inspector)),
+                                Err(e) => {
+                                    log::error!("Failed to create the inspector window: {}", e)
+                                }
+                            }
+                        }
+                    }
                 }
             }
 
@@ -1357,26 +2798,87 @@ impl ApplicationHandler for App {
     }
 }
 
+/// Binding for `key` with `modifiers` held (Ctrl must match exactly; other modifiers are ignored)
+fn shortcut_for(key: KeyCode, modifiers: winit::keyboard::ModifiersState) -> Option<Shortcut> {
+    SHORTCUTS
+        .iter()
+        .find(|&&(binding, ctrl, _)| binding == key && ctrl == modifiers.control_key())
+        .map(|&(_, _, shortcut)| shortcut)
+}
+
+/// Write the pipeline cache to disk, logging the outcome (a no-op without a cache).
+fn save_pipeline_cache(pipeline_cache: &PipelineCache) {
+    match pipeline_cache.save() {
+        Ok(Some(path)) => log::info!("✓ Pipeline cache saved to {}", path.display()),
+        Ok(None) => {}
+        Err(err) => log::warn!("Failed to save the pipeline cache: {}", err),
+    }
+}
+
+/// Graphics backends from `--backend <list>` (`all`, or comma separated `vulkan`, `metal`,
+/// `dx12`, `gl`), else from `WGPU_BACKEND`, else all of them.
+fn requested_backends() -> wgpu::Backends {
+    let mut args = std::env::args().skip(1);
+    let mut requested = None;
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--backend=") {
+            requested = Some(value.to_string());
+        } else if arg == "--backend" {
+            requested = args.next();
+        }
+    }
+
+    let Some(requested) = requested else {
+        return wgpu::Backends::all().with_env();
+    };
+    if requested.eq_ignore_ascii_case("all") {
+        return wgpu::Backends::all();
+    }
+    let backends = wgpu::Backends::from_comma_list(&requested);
+    if backends.is_empty() {
+        log::warn!("Unknown backend '{}', trying all backends", requested);
+        wgpu::Backends::all()
+    } else {
+        backends
+    }
+}
+
 fn main() {
     // Initialize logger (RUST_LOG=debug for verbose output)
+    #[cfg(not(target_arch = "wasm32"))]
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    #[cfg(target_arch = "wasm32")]
+    web::init_logging();
 
     log::info!("Starting fundamental particle physics simulation...");
 
-    let event_loop = EventLoop::new().unwrap();
+    let event_loop = EventLoop::<GpuState>::with_user_event().build().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
 
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
     let mut app = App {
         window: None,
+        backends: requested_backends(),
+        proxy: event_loop.create_proxy(),
         gpu_state: None,
         mouse_pressed: false,
         last_mouse_pos: None,
 
         left_mouse_pressed: false,
         last_cursor_pos: None,
+        modifiers: winit::keyboard::ModifiersState::empty(),
+        box_select_start: None,
+        touches: Vec::new(),
 
         astra_debug_options: DebugOptions::none(),
     };
 
+    #[cfg(not(target_arch = "wasm32"))]
     event_loop.run_app(&mut app).unwrap();
+    // The browser drives the loop; this returns right away and the app lives on in callbacks
+    #[cfg(target_arch = "wasm32")]
+    {
+        use winit::platform::web::EventLoopExtWebSys;
+        event_loop.spawn_app(app);
+    }
 }
@@ -0,0 +1,43 @@
+//! Browser (wasm32 + WebGPU) glue
+//!
+//! Logging and panics go to the browser console, and the window is a canvas appended to the
+//! page. Everything else runs the same code as the native build, minus the blocking readbacks
+//! (see `READBACKS` in `main.rs`).
+
+use wasm_bindgen::JsValue;
+
+/// `log` backend writing to the browser console
+struct ConsoleLogger;
+
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let message = JsValue::from_str(&format!("[{}] {}", record.target(), record.args()));
+        match record.level() {
+            log::Level::Error => web_sys::console::error_1(&message),
+            log::Level::Warn => web_sys::console::warn_1(&message),
+            log::Level::Info => web_sys::console::info_1(&message),
+            log::Level::Debug | log::Level::Trace => web_sys::console::debug_1(&message),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: ConsoleLogger = ConsoleLogger;
+
+/// Route `log` and panics to the browser console (info level, like the native default).
+pub fn init_logging() {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(log::LevelFilter::Info);
+    }
+    std::panic::set_hook(Box::new(|info| {
+        web_sys::console::error_1(&JsValue::from_str(&info.to_string()));
+    }));
+}